@@ -251,6 +251,7 @@ impl AdminInitializer {
 
         // Emit admin initialization event
         EventEmitter::emit_admin_initialized(env, admin);
+        crate::events::EventSchemaRegistry::sync_schema_version(env);
 
         // Log admin action
         AdminActionLogger::log_action(env, admin, "initialize", None, Map::new(env), true, None)?;
@@ -266,6 +267,14 @@ impl AdminInitializer {
         Ok(())
     }
 
+    /// Returns `true` once `initialize` has stored the primary admin.
+    ///
+    /// Uses the same check as the re-initialization guard, so it is `true`
+    /// exactly when another `initialize` call would be rejected.
+    pub fn is_initialized(env: &Env) -> bool {
+        AdminValidator::validate_contract_not_initialized(env).is_err()
+    }
+
     /// Initializes the contract with admin and environment-specific configuration.
     ///
     /// This advanced initialization function sets up both admin privileges and
@@ -2726,7 +2735,7 @@ impl AdminValidator {
     ///
     /// Returns `Result<(), Error>` where:
     /// - `Ok(())` - Contract is not initialized (safe to initialize)
-    /// - `Err(Error::InvalidState)` - Contract is already initialized
+    /// - `Err(Error::AlreadyInitialized)` - Contract is already initialized
     ///
     /// # Validation Logic
     ///
@@ -2789,7 +2798,7 @@ impl AdminValidator {
         let admin_exists = env.storage().persistent().has(&Symbol::new(env, "Admin"));
 
         if admin_exists {
-            return Err(Error::InvalidState);
+            return Err(Error::AlreadyInitialized);
         }
 
         Ok(())
@@ -4355,3 +4364,34 @@ mod admin_manager_tests {
         assert!(crit_found, "Critical event not found");
     }
 }
+
+#[cfg(test)]
+mod initialization_tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    #[test]
+    fn test_double_initialize_reports_already_initialized() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let admin = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            AdminInitializer::initialize(&env, &admin).unwrap();
+
+            let err = AdminInitializer::initialize(&env, &Address::generate(&env)).unwrap_err();
+            assert_eq!(err, Error::AlreadyInitialized);
+            assert_eq!(err as u32, 540);
+            assert_ne!(err, Error::Unauthorized);
+
+            // The original admin is kept
+            let stored: Address = env
+                .storage()
+                .persistent()
+                .get(&Symbol::new(&env, "Admin"))
+                .unwrap();
+            assert_eq!(stored, admin);
+        });
+    }
+}
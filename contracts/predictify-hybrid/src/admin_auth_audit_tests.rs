@@ -132,3 +132,12 @@ fn test_primary_admin_transfer_rotates_entrypoint_access() {
     let new_admin_result = setup.client().try_set_platform_fee(&new_admin, &250i128);
     assert_eq!(new_admin_result, Ok(Ok(())));
 }
+
+#[test]
+fn test_is_initialized_reports_initialize_state() {
+    let setup = TestSetup::uninitialized();
+    assert!(!setup.client().is_initialized());
+
+    setup.client().initialize(&setup.admin, &None, &None);
+    assert!(setup.client().is_initialized());
+}
@@ -3,6 +3,7 @@ use alloc::string::ToString;
 use soroban_sdk::{contracttype, vec, Address, Env, Map, String, Symbol, Vec};
 
 use crate::err::Error;
+use crate::oracles::ComparisonOp;
 use crate::types::*;
 
 // ===== BATCH OPERATION TYPES =====
@@ -53,7 +54,7 @@ pub struct OracleFeed {
     pub feed_id: String,
     pub provider: OracleProvider,
     pub threshold: i128,
-    pub comparison: String,
+    pub comparison: ComparisonOp,
 }
 
 #[derive(Clone, Debug, PartialEq, Eq)]
@@ -868,7 +869,8 @@ impl BatchTesting {
                 ),
                 feed_id: String::from_str(env, "BTC"),
                 threshold: 10_000_000, // $100,000
-                comparison: String::from_str(env, "gt"),
+                comparison: ComparisonOp::Gt,
+                threshold2: None,
             },
         }
     }
@@ -880,7 +882,7 @@ impl BatchTesting {
             feed_id: String::from_str(env, "BTC/USD"),
             provider: OracleProvider::reflector(),
             threshold: 100_000_000_000, // $100,000
-            comparison: String::from_str(env, "gt"),
+            comparison: ComparisonOp::Gt,
         }
     }
 
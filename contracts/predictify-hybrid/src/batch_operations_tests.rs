@@ -418,6 +418,7 @@ mod batch_operations_tests {
                 feed_id: String::from_str(&env, "BTC"),
                 threshold: 100_000_00,
                 comparison: String::from_str(&env, "gt"),
+                threshold2: None,
             },
         };
 
@@ -439,6 +440,7 @@ mod batch_operations_tests {
                 feed_id: String::from_str(&env, "BTC"),
                 threshold: 100_000_00,
                 comparison: String::from_str(&env, "gt"),
+                threshold2: None,
             },
         };
 
@@ -456,6 +458,7 @@ mod batch_operations_tests {
                 feed_id: String::from_str(&env, "BTC"),
                 threshold: 100_000_00,
                 comparison: String::from_str(&env, "gt"),
+                threshold2: None,
             },
         };
 
@@ -477,6 +480,7 @@ mod batch_operations_tests {
                 feed_id: String::from_str(&env, "BTC"),
                 threshold: 100_000_00,
                 comparison: String::from_str(&env, "gt"),
+                threshold2: None,
             },
         };
 
@@ -97,6 +97,7 @@ impl BetCancellationTestSetup {
             feed_id: String::from_str(env, "test_feed"),
             threshold: 100_000_000,
             comparison: String::from_str(env, "gt"),
+            threshold2: None,
         };
 
         client.create_market(
@@ -234,6 +234,7 @@ impl BetTestSetup {
                 feed_id: String::from_str(env, "BTC/USD"),
                 threshold: 100_000_00000000, // $100,000
                 comparison: String::from_str(env, "gt"),
+                threshold2: None,
             },
             &None,
             &86400u64,
@@ -26,6 +26,7 @@ use crate::reentrancy_guard::{ReentrancyGuard, GuardError as ReentrancyError};
 use crate::events::EventEmitter;
 use crate::markets::{MarketStateManager, MarketUtils, MarketValidator};
 use crate::types::{Bet, BetLimits, BetStats, BetStatus, Market, MarketState};
+use crate::utils::NumericUtils;
 // use crate::validation;
 
 // ===== CONSTANTS =====
@@ -361,6 +362,8 @@ impl BetManager {
         // This allows distribute_payouts to work with both bets and votes
         market.votes.set(user.clone(), outcome.clone());
         market.stakes.set(user.clone(), amount);
+        crate::fees::EarlyBirdFees::snapshot(env, &market_id, &market, &user);
+        MarketStateManager::record_user_market(env, &user, &market_id);
 
         MarketStateManager::update_market(env, &market_id, &market);
 
@@ -516,6 +519,8 @@ impl BetManager {
             // Update votes and stakes for backward compatibility
             market.votes.set(user.clone(), outcome.clone());
             market.stakes.set(user.clone(), amount);
+            crate::fees::EarlyBirdFees::snapshot(env, &market_id, &market, &user);
+            MarketStateManager::record_user_market(env, &user, &market_id);
 
             MarketStateManager::update_market(env, &market_id, &market);
 
@@ -600,6 +605,7 @@ impl BetManager {
 
         // Store updated stats
         BetStorage::store_market_bet_stats(env, market_id, &stats)?;
+        crate::voting::OutcomeMomentum::record_flow(env, market_id, outcome, amount);
 
         Ok(())
     }
@@ -697,6 +703,9 @@ impl BetManager {
         for bet_key in bets.iter() {
             if let Some(mut bet) = BetStorage::get_bet(env, market_id, &bet_key) {
                 if bet.is_active() {
+                    let remaining_staked =
+                        NumericUtils::checked_reduce(market.total_staked, bet.amount)?;
+
                     // Refund the locked funds
                     BetUtils::unlock_funds(env, &bet.user, bet.amount)?;
 
@@ -705,7 +714,7 @@ impl BetManager {
                     BetStorage::store_bet(env, &bet)?;
 
                     // Update market struct to reverse stakes
-                    market.total_staked = market.total_staked.saturating_sub(bet.amount);
+                    market.total_staked = remaining_staked;
                     market.votes.remove(bet.user.clone());
                     market.stakes.remove(bet.user.clone());
 
@@ -726,6 +735,59 @@ impl BetManager {
         Ok(())
     }
 
+    /// Refund the vote stakes of `users` on a cancelled market.
+    ///
+    /// Cancellation refunds bets through [`Self::refund_market_bets`], but vote
+    /// stakes stay locked until refunded here. Each user receives their own
+    /// stake; users with nothing left to refund (no vote, or already refunded)
+    /// are skipped.
+    ///
+    /// # Returns
+    ///
+    /// Returns the total amount refunded.
+    ///
+    /// # Errors
+    ///
+    /// - `Error::MarketNotFound` - Market does not exist
+    /// - `Error::InvalidState` - Market is not cancelled, or a transfer fails
+    pub fn refund_vote_stakes(
+        env: &Env,
+        market_id: &Symbol,
+        users: &Vec<Address>,
+    ) -> Result<i128, Error> {
+        let mut market = MarketStateManager::get_market(env, market_id)?;
+        if market.state != MarketState::Cancelled {
+            return Err(Error::InvalidState);
+        }
+
+        let mut total_refunded: i128 = 0;
+        for user in users.iter() {
+            let stake = market.stakes.get(user.clone()).unwrap_or(0);
+            if stake <= 0 {
+                continue;
+            }
+            market.total_staked = NumericUtils::checked_reduce(market.total_staked, stake)?;
+            market.votes.remove(user.clone());
+            market.stakes.remove(user.clone());
+            BetUtils::unlock_funds(env, &user, stake)?;
+            total_refunded = total_refunded
+                .checked_add(stake)
+                .ok_or(Error::InvalidInput)?;
+
+            EventEmitter::emit_bet_status_updated(
+                env,
+                market_id,
+                &user,
+                &String::from_str(env, "Active"),
+                &String::from_str(env, "Refunded"),
+                Some(stake),
+            );
+        }
+
+        MarketStateManager::update_market(env, market_id, &market);
+        Ok(total_refunded)
+    }
+
     /// Calculate payout for a winning bet.
     ///
     /// The payout is calculated as:
@@ -844,6 +906,7 @@ impl BetManager {
         if current_time >= market.end_time {
             return Err(Error::MarketClosed);
         }
+        let remaining_staked = NumericUtils::checked_reduce(market.total_staked, bet.amount)?;
 
         // Refund the locked funds
         BetUtils::unlock_funds(env, &user, bet.amount)?;
@@ -856,7 +919,7 @@ impl BetManager {
         Self::update_market_bet_stats_on_cancel(env, &market_id, &bet.outcome, bet.amount)?;
 
         // Update market struct to reverse stakes
-        market.total_staked = market.total_staked.saturating_sub(bet.amount);
+        market.total_staked = remaining_staked;
         market.votes.remove(user.clone());
         market.stakes.remove(user.clone());
         MarketStateManager::update_market(env, &market_id, &market);
@@ -885,12 +948,12 @@ impl BetManager {
 
         // Update totals
         stats.total_bets = stats.total_bets.saturating_sub(1);
-        stats.total_amount_locked = stats.total_amount_locked.saturating_sub(amount);
+        stats.total_amount_locked = NumericUtils::checked_reduce(stats.total_amount_locked, amount)?;
         stats.unique_bettors = stats.unique_bettors.saturating_sub(1);
 
         // Update outcome totals
         let current_outcome_total = stats.outcome_totals.get(outcome.clone()).unwrap_or(0);
-        let new_total = current_outcome_total.saturating_sub(amount);
+        let new_total = NumericUtils::checked_reduce(current_outcome_total, amount)?;
         if new_total > 0 {
             stats.outcome_totals.set(outcome.clone(), new_total);
         } else {
@@ -899,6 +962,7 @@ impl BetManager {
 
         // Store updated stats
         BetStorage::store_market_bet_stats(env, market_id, &stats)?;
+        crate::voting::OutcomeMomentum::record_flow(env, market_id, outcome, -amount);
 
         Ok(())
     }
@@ -1350,6 +1414,7 @@ impl BetAnalytics {
 mod tests {
     use super::*;
     use crate::config::ConfigManager;
+    use crate::oracles::ComparisonOp;
     use crate::types::{BetStatus, Market, MarketState, OracleConfig, OracleProvider};
     use soroban_sdk::testutils::{Address as _, Ledger, LedgerInfo};
 
@@ -1372,7 +1437,7 @@ mod tests {
                 ),
                 String::from_str(env, "BTC/USD"),
                 1,
-                String::from_str(env, "gt"),
+                ComparisonOp::Gt,
             ),
             None,
             86400,
@@ -1617,4 +1682,52 @@ mod tests {
             Err(Error::FeeExceedsMax)
         );
     }
+
+    #[test]
+    fn test_cancel_bet_exceeding_tracked_total_underflows_cleanly() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+        let user = Address::generate(&env);
+        let market_id = Symbol::new(&env, "under_mkt");
+
+        // The bet is larger than the stake the market has on record
+        env.as_contract(&contract_id, || {
+            let mut market = test_market(&env, env.ledger().timestamp() + 1000);
+            market.votes.set(user.clone(), String::from_str(&env, "yes"));
+            market.stakes.set(user.clone(), 500);
+            market.total_staked = 100;
+            MarketStateManager::update_market(&env, &market_id, &market);
+            let bet = Bet::new(
+                &env,
+                user.clone(),
+                market_id.clone(),
+                String::from_str(&env, "yes"),
+                500,
+            );
+            BetStorage::store_bet(&env, &bet).unwrap();
+        });
+
+        assert_eq!(
+            client.try_cancel_bet(&user, &market_id),
+            Err(Ok(Error::ArithmeticUnderflow))
+        );
+        env.as_contract(&contract_id, || {
+            let market = MarketStateManager::get_market(&env, &market_id).unwrap();
+            assert_eq!(market.total_staked, 100);
+            let bet = BetStorage::get_bet(&env, &market_id, &user).unwrap();
+            assert_eq!(bet.status, BetStatus::Active);
+        });
+
+        assert_eq!(NumericUtils::checked_reduce(500, 500), Ok(0));
+        assert_eq!(
+            NumericUtils::checked_reduce(100, 101),
+            Err(Error::ArithmeticUnderflow)
+        );
+        assert_eq!(
+            NumericUtils::checked_reduce(i128::MIN, 1),
+            Err(Error::ArithmeticUnderflow)
+        );
+    }
 }
@@ -1,3 +1,4 @@
+use crate::oracles::ComparisonOp;
 use crate::types::{Market, MarketState, OracleConfig, OracleProvider};
 use crate::Error;
 use crate::{PredictifyHybrid, PredictifyHybridClient};
@@ -19,6 +20,11 @@ fn setup_test() -> (Env, PredictifyHybridClient<'static>, Address) {
 
     // Initialize contract
     client.initialize(&admin, &Some(2), &None); // 2% fee
+    env.as_contract(&contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&Symbol::new(&env, "TokenID"), &Address::generate(&env));
+    });
 
     (env, client, admin)
 }
@@ -43,7 +49,8 @@ fn create_test_market(
         ),
         feed_id: String::from_str(env, "BTC/USD"),
         threshold: 100,
-        comparison: String::from_str(env, "gt"),
+        comparison: ComparisonOp::Gt,
+        threshold2: None,
     };
 
     client.create_market(
@@ -270,6 +277,50 @@ fn test_update_event_tags_duplicate_rejected() {
     assert_eq!(r, Err(Ok(Error::InvalidInput)));
 }
 
+#[test]
+fn test_market_language_defaults_to_en() {
+    let (env, client, admin) = setup_test();
+    let market_id = create_test_market(&env, &client, &admin, "LangDefault");
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.language, String::from_str(&env, "en"));
+}
+
+#[test]
+fn test_update_event_language_per_market() {
+    let (env, client, admin) = setup_test();
+    let m1 = create_test_market(&env, &client, &admin, "LangPt");
+    let m2 = create_test_market(&env, &client, &admin, "LangZh");
+    let m3 = create_test_market(&env, &client, &admin, "LangEn");
+
+    client.update_event_language(&admin, &m1, &String::from_str(&env, "pt-BR"));
+    client.update_event_language(&admin, &m2, &String::from_str(&env, "zh-Hant-TW"));
+
+    assert_eq!(
+        client.get_market(&m1).unwrap().language,
+        String::from_str(&env, "pt-BR")
+    );
+    assert_eq!(
+        client.get_market(&m2).unwrap().language,
+        String::from_str(&env, "zh-Hant-TW")
+    );
+    assert_eq!(
+        client.get_market(&m3).unwrap().language,
+        String::from_str(&env, "en")
+    );
+}
+
+#[test]
+fn test_update_event_language_invalid_tag_rejected() {
+    let (env, client, admin) = setup_test();
+    let market_id = create_test_market(&env, &client, &admin, "LangBad");
+    for tag in ["", "en_US", "en--US", "-en"] {
+        let r = client.try_update_event_language(&admin, &market_id, &String::from_str(&env, tag));
+        assert_eq!(r, Err(Ok(Error::InvalidInput)));
+    }
+    let market = client.get_market(&market_id).unwrap();
+    assert_eq!(market.language, String::from_str(&env, "en"));
+}
+
 #[test]
 #[should_panic(expected = "Error(Contract, #100)")]
 fn test_security_update_category_unauthorized() {
@@ -366,7 +417,8 @@ impl TokenTestSetup {
                 ),
                 feed_id: String::from_str(&env, "BTC/USD"),
                 threshold: 100,
-                comparison: String::from_str(&env, "gt"),
+                comparison: ComparisonOp::Gt,
+                threshold2: None,
             },
             &None,
             &86400u64,
@@ -437,6 +489,7 @@ fn test_category_tags_do_not_affect_resolution_and_payouts() {
         &setup.admin,
         &setup.market_id,
         &String::from_str(&setup.env, "yes"),
+        &None,
     );
 
     // Ensure market resolved
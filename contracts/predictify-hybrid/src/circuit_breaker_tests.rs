@@ -436,6 +436,7 @@ mod circuit_breaker_tests {
                     feed_id: String::from_str(&env, "BTC/USD"),
                     threshold: 100_000_00000000,
                     comparison: String::from_str(&env, "gte"),
+                    threshold2: None,
                 },
             );
 
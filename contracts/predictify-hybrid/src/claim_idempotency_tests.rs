@@ -129,6 +129,7 @@ impl ClaimIdempotencyTestSetup {
                 feed_id: String::from_str(env, "BTC/USD"),
                 threshold: 100_000_00000000,
                 comparison: String::from_str(env, "gte"),
+                threshold2: None,
             },
             &None,
             &86400u64,
@@ -165,7 +166,7 @@ impl ClaimIdempotencyTestSetup {
         });
 
         // Resolve market (winner is "yes")
-        client.resolve_market_manual(admin, &market_id, &String::from_str(env, "yes"));
+        client.resolve_market_manual(admin, &market_id, &String::from_str(env, "yes"), &None);
 
         market_id
     }
@@ -66,6 +66,12 @@ pub const MAX_MARKET_DURATION_DAYS: u32 = 365;
 /// Above 7 days may block time-sensitive events.
 pub const MIN_MARKET_DURATION_DAYS: u32 = 1;
 
+/// Minimum market duration in seconds for `create_market_with_duration_secs` (1 hour)
+///
+/// Rationale: sub-day markets are meant for short-term events, but an hour
+/// still leaves time for participation before the market ends.
+pub const MIN_MARKET_DURATION_SECONDS: u64 = 3_600;
+
 /// Maximum number of outcomes per market (10)
 ///
 /// Rationale: 10 outcomes balance flexibility with contract performance.
@@ -118,6 +124,10 @@ pub const MAX_OUTCOME_LENGTH: u32 = 100;
 /// Safe range: 1-10 chars. Below 1 is invalid. Above 10 may be too strict.
 pub const MIN_OUTCOME_LENGTH: u32 = 2;
 
+/// Outcome label reserved for voiding a market as invalid; markets may not
+/// offer it as a user-facing outcome (compared case-insensitively).
+pub const RESERVED_INVALID_OUTCOME: &str = "INVALID";
+
 /// Maximum description length in characters (1000)
 ///
 /// Rationale: 1000 chars allows detailed market descriptions, rules,
@@ -182,6 +192,27 @@ pub const MAX_CATEGORY_LENGTH: u32 = 100;
 /// Safe range: 1-10 chars.
 pub const MIN_CATEGORY_LENGTH: u32 = 2;
 
+/// Default language tag for market questions and outcomes ("en")
+///
+/// Rationale: Existing markets were all authored in English, so untagged
+/// markets keep their current meaning for clients that filter by language.
+pub const DEFAULT_MARKET_LANGUAGE: &str = "en";
+
+/// Maximum language tag length in characters (35)
+///
+/// Rationale: 35 chars covers real-world BCP-47 tags with language, script,
+/// region and a variant (e.g. "sr-Latn-RS", "zh-Hant-TW") with headroom.
+///
+/// Safe range: 8-64 chars.
+pub const MAX_LANGUAGE_TAG_LENGTH: u32 = 35;
+
+/// Minimum language tag length in characters (2)
+///
+/// Rationale: The shortest ISO 639 primary language subtag is 2 letters.
+///
+/// Safe range: 2-3 chars.
+pub const MIN_LANGUAGE_TAG_LENGTH: u32 = 2;
+
 // ===== FEE CONSTANTS =====
 //
 // RATIONALE: Fees serve multiple purposes:
@@ -317,6 +348,22 @@ pub const HIGH_ACTIVITY_THRESHOLD: u32 = 100;
 /// Dispute extension hours
 pub const DISPUTE_EXTENSION_HOURS: u32 = 24;
 
+/// Default contract-wide limit on markets with an open dispute at the same time.
+///
+/// Bounds arbitrator load; opening a dispute on another market beyond it fails with
+/// `DisputeCapacityReached` until one settles. Admin-adjustable at runtime.
+pub const MAX_CONCURRENT_DISPUTES: u32 = 25;
+
+/// Default number of overturned resolutions a creator may accumulate; one more
+/// suspends them from creating markets until an admin reinstates them.
+pub const DEFAULT_CREATOR_SUSPENSION_THRESHOLD: u32 = 3;
+
+/// Default contract-wide cap on markets that are still active; 0 disables it.
+pub const DEFAULT_MAX_ACTIVE_MARKETS: u32 = 0;
+
+/// Default cap on active markets per creator; 0 disables it.
+pub const DEFAULT_MAX_ACTIVE_MARKETS_PER_CREATOR: u32 = 0;
+
 // ===== EXTENSION CONSTANTS =====
 
 /// Maximum extension days
@@ -357,6 +404,44 @@ pub const MIN_VOTES_FOR_CONSENSUS: u32 = 5;
 /// with no oracle result, anyone may trigger refund on oracle failure.
 pub const DEFAULT_RESOLUTION_TIMEOUT_SECONDS: u64 = 604_800;
 
+/// Default grace in seconds (1 day) after a market's resolution deadline before
+/// anyone may trigger a refund on oracle failure. Copied into each market at
+/// creation as `Market::refund_grace_seconds`, which the admin may raise for
+/// markets that warrant a longer oracle-retry window.
+pub const REFUND_GRACE_SECONDS: u64 = 86_400;
+
+/// Denominator for `Market::settlement_rate_bps`: a rate of 10_000 pays one
+/// settlement-token unit per staking-token unit.
+pub const SETTLEMENT_RATE_DENOMINATOR: u32 = 10_000;
+
+/// Highest accepted settlement rate (100 settlement units per staking unit).
+pub const MAX_SETTLEMENT_RATE_BPS: u32 = 1_000_000;
+
+/// Maximum markets per `resolve_markets_oracle_batch` call, so one call cannot
+/// exhaust the instruction budget on oracle reads.
+pub const MAX_ORACLE_RESOLUTION_BATCH: u32 = 25;
+
+/// Maximum markets per `claim_winnings_batch_safe` call.
+pub const MAX_CLAIM_BATCH: u32 = 25;
+
+/// Maximum users per `process_refunds_batch` call.
+pub const MAX_REFUND_BATCH: u32 = 25;
+
+/// Maximum markets per `migrate_comparison_operators` call.
+pub const MAX_COMPARISON_MIGRATION_BATCH: u32 = 25;
+
+/// Maximum markets per `get_user_disputes` call.
+pub const MAX_USER_DISPUTES_QUERY: u32 = 25;
+
+/// Maximum markets per `get_market_summaries` call.
+pub const MAX_MARKET_SUMMARIES_QUERY: u32 = 25;
+
+/// Maximum markets aggregated by one `get_user_pnl` call.
+pub const MAX_PNL_MARKETS: u32 = 50;
+
+/// How long after resolution an admin may still correct the outcome (1 hour).
+pub const RESOLUTION_CORRECTION_WINDOW_SECONDS: u64 = 3_600;
+
 // ===== ORACLE CONSTANTS =====
 
 /// Maximum oracle price age (1 hour)
@@ -365,6 +450,18 @@ pub const MAX_ORACLE_PRICE_AGE: u64 = 3600;
 /// Oracle retry attempts
 pub const ORACLE_RETRY_ATTEMPTS: u32 = 3;
 
+/// Minimum absolute width of an `in_range`/`out_of_range` band (`threshold2 - threshold`),
+/// in the feed's price units ($1.00 for cent-denominated feeds).
+///
+/// Rationale: a band narrower than normal tick noise resolves essentially at random.
+pub const MIN_ORACLE_RANGE_WIDTH: i128 = 100;
+
+/// Minimum width of a range band relative to its lower bound, in basis points (1%).
+///
+/// Rationale: scales the floor with the asset's price so a $10 band on BTC is
+/// rejected while still allowing tight bands on low-priced assets.
+pub const MIN_ORACLE_RANGE_WIDTH_BPS: i128 = 100;
+
 /// Oracle timeout seconds
 pub const ORACLE_TIMEOUT_SECONDS: u64 = 30;
 
@@ -381,6 +478,40 @@ pub const ORACLE_TIMEOUT_SECONDS: u64 = 30;
 /// normal load; values above 1000 increase per-call storage I/O noticeably.
 pub const MONITOR_QUEUE_CAP: u32 = 100;
 
+// ===== CLAIM HISTORY CONSTANTS =====
+
+/// Maximum number of claim records retained per user (FIFO, oldest dropped first).
+///
+/// Keeps the per-user history entry bounded; full history remains available
+/// off-chain through `win_clm` events.
+pub const MAX_CLAIM_HISTORY_PER_USER: u32 = 100;
+
+/// Maximum number of markets kept in a user's participation index (FIFO,
+/// oldest dropped first). Older participation is still visible through
+/// `vote` and `bet_plc` events.
+pub const MAX_USER_MARKETS: u32 = 200;
+
+// ===== LEADERBOARD CONSTANTS =====
+
+/// Number of creators kept on the resolved-volume leaderboard.
+///
+/// Bounds both the stored list and the cost of updating it at each resolution.
+pub const MAX_TOP_CREATORS: u32 = 20;
+
+/// Number of distinct outcome labels tracked by the resolution distribution.
+///
+/// Labels first seen after the cap is reached are not counted.
+pub const MAX_RESOLUTION_LABELS: u32 = 50;
+
+/// Maximum fee-exempt addresses per market.
+pub const MAX_FEE_EXEMPT_ADDRESSES: u32 = 20;
+
+/// Maximum addresses on the contract-wide voter blacklist.
+pub const MAX_BLACKLISTED_ADDRESSES: u32 = 200;
+
+/// Longest pre-close vote freeze an admin can configure (1 hour).
+pub const MAX_VOTE_FREEZE_SECONDS: u64 = 3600;
+
 // ===== STORAGE CONSTANTS =====
 
 /// Storage key for admin address
@@ -65,6 +65,7 @@ impl CustomTokenTestSetup {
                 feed_id: String::from_str(&env, "RAIN"),
                 threshold: 1,
                 comparison: String::from_str(&env, "gt"),
+                threshold2: None,
             },
             &None,       // fallback_oracle_config
             &3600,       // resolution_timeout
@@ -201,6 +202,7 @@ fn test_payout_distribution_flow() {
         &setup.admin,
         &setup.market_id,
         &String::from_str(&setup.env, "yes"),
+        &None,
     );
 
     // Verify market is resolved but no payout distributed yet (due to dispute window)
@@ -372,6 +374,7 @@ fn test_fee_collection_custom_token() {
         &setup.admin,
         &setup.market_id,
         &String::from_str(&setup.env, "yes"),
+        &None,
     );
 
     // Collect fees
@@ -2,8 +2,11 @@
 
 use crate::{
     errors::Error,
-    markets::MarketStateManager,
-    types::Market,
+    markets::{
+        CreatorSuspensionManager, MarketPayouts, MarketStateLogic, MarketStateManager,
+        MarketValidator,
+    },
+    types::{Market, MarketState},
     voting::{VotingUtils, DISPUTE_EXTENSION_HOURS, MIN_DISPUTE_STAKE},
     storage::DataKey,
 };
@@ -780,12 +783,60 @@ impl DisputeManager {
         Ok(())
     }
 
+    /// Delegates dispute adjudication to `resolver` (admin only).
+    ///
+    /// Once set, only `resolver` may call `resolve_dispute`; setting it back to
+    /// the admin address restores admin-adjudicated disputes.
+    pub fn set_dispute_resolver(env: &Env, admin: Address, resolver: Address) -> Result<(), Error> {
+        admin.require_auth();
+        DisputeValidator::validate_admin_permissions(env, &admin)?;
+
+        let key = DataKey::DisputeResolver;
+        env.storage().persistent().set(&key, &resolver);
+        env.storage().persistent().extend_ttl(&key, 535680, 535680);
+        Ok(())
+    }
+
+    /// Retrieves the address allowed to resolve disputes: the configured
+    /// resolver, or the admin when none has been set.
+    pub fn get_dispute_resolver(env: &Env) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&DataKey::DisputeResolver)
+            .or_else(|| env.storage().persistent().get(&Symbol::new(env, "Admin")))
+    }
+
     /// Retrieves the anti-grief minimum stake floor.
     pub fn get_anti_grief_floor(env: &Env) -> Option<i128> {
         let key = DataKey::AntiGriefFloor;
         env.storage().persistent().get(&key)
     }
 
+    /// Sets the maximum stake one address may post in a dispute round, so a
+    /// well-funded actor cannot crowd out other disputers. `0` removes the
+    /// ceiling; any other value must be at least `MIN_DISPUTE_STAKE`.
+    pub fn set_max_dispute_stake(env: &Env, admin: Address, max_stake: i128) -> Result<(), Error> {
+        admin.require_auth();
+        DisputeValidator::validate_admin_permissions(env, &admin)?;
+
+        let key = DataKey::MaxDisputeStake;
+        if max_stake == 0 {
+            env.storage().persistent().remove(&key);
+            return Ok(());
+        }
+        if max_stake < MIN_DISPUTE_STAKE {
+            return Err(Error::InvalidInput);
+        }
+        env.storage().persistent().set(&key, &max_stake);
+        env.storage().persistent().extend_ttl(&key, 535680, 535680);
+        Ok(())
+    }
+
+    /// Retrieves the per-round dispute stake ceiling, if one is set.
+    pub fn get_max_dispute_stake(env: &Env) -> Option<i128> {
+        env.storage().persistent().get(&DataKey::MaxDisputeStake)
+    }
+
     /// Evicts the oldest resolved/expired disputes if history size exceeds the cap.
     pub fn apply_eviction(
         env: &Env,
@@ -900,20 +951,38 @@ impl DisputeManager {
     ) -> Result<(), Error> {
         // Require authentication from the user
         user.require_auth();
+        crate::voting::VoterBlacklist::check(env, &user)?;
 
         // Get and validate market
         let mut market = MarketStateManager::get_market(env, &market_id)?;
-        DisputeValidator::validate_market_for_dispute(env, &market)?;
+
+        // One dispute per address per round. Checked before the window validation
+        // because an accepted dispute pushes `end_time` forward.
+        if DisputeUtils::has_user_disputed(&market, &user) {
+            return Err(Error::AlreadyDisputed);
+        }
+        DisputeValidator::validate_market_for_dispute(env, &market_id, &market)?;
 
         // Enforce anti-grief floor
         let anti_grief_floor = Self::get_anti_grief_floor(env).unwrap_or(0);
         if stake < anti_grief_floor {
             return Err(Error::InvalidStakeAmount);
         }
+        // One dispute per address per round, so the round's stake is this stake
+        if let Some(max_stake) = Self::get_max_dispute_stake(env) {
+            if stake > max_stake {
+                return Err(Error::DisputeStakeTooLarge);
+            }
+        }
 
         // Validate dispute parameters
         DisputeValidator::validate_dispute_parameters(env, &market_id, &user, &market, stake)?;
 
+        // First open dispute on this market takes one contract-wide slot
+        if !DisputeCapacity::has_active_dispute(env, &market_id) {
+            DisputeCapacity::reserve(env)?;
+        }
+
         // Process stake transfer
         VotingUtils::transfer_stake(env, &user, stake)?;
 
@@ -940,6 +1009,17 @@ impl DisputeManager {
         // Extend market for dispute period
         DisputeUtils::extend_market_for_dispute(&mut market, env)?;
 
+        // Freeze claims on the contested outcome until `resolve_dispute` settles it
+        if matches!(
+            market.state,
+            MarketState::Active | MarketState::Ended | MarketState::Resolved
+        ) {
+            let old_state = market.state;
+            MarketStateLogic::transition_state(&mut market, MarketState::Disputed)?;
+            MarketStateLogic::emit_state_change_event(env, &market_id, old_state, market.state);
+            crate::markets::MarketStateCounts::record_transition(env, old_state, market.state);
+        }
+
         // Update market in storage
         MarketStateManager::update_market(env, &market_id, &market);
 
@@ -1067,7 +1147,8 @@ impl DisputeManager {
     ///
     /// # Administrative Authority
     ///
-    /// Only authorized admins can resolve disputes to ensure:
+    /// Only the dispute resolver can resolve disputes. This is the admin unless
+    /// a separate resolver was delegated via `set_dispute_resolver`, ensuring:
     /// - Proper validation of voting completion
     /// - Correct application of resolution algorithms
     /// - Appropriate handling of edge cases
@@ -1077,11 +1158,11 @@ impl DisputeManager {
         market_id: Symbol,
         admin: Address,
     ) -> Result<DisputeResolution, Error> {
-        // Require authentication from the admin
+        // Require authentication from the resolver
         admin.require_auth();
 
-        // Validate admin permissions
-        DisputeValidator::validate_admin_permissions(env, &admin)?;
+        // Validate resolver permissions
+        DisputeValidator::validate_resolver_permissions(env, &admin)?;
 
         // Get and validate market
         let mut market = MarketStateManager::get_market(env, &market_id)?;
@@ -1092,6 +1173,10 @@ impl DisputeManager {
 
         // Determine final outcome with dispute consideration
         let final_outcome = DisputeUtils::determine_final_outcome_with_disputes(env, &market)?;
+        let overturned = DisputeUtils::contested_outcome(&market)
+            .map_or(false, |contested| contested != final_outcome);
+        // A bonded manual resolution was already counted as closed when it was made
+        let bonded = market.resolution_bond > 0;
 
         // Calculate weights
         let oracle_weight = DisputeAnalytics::calculate_oracle_weight(&market);
@@ -1109,8 +1194,31 @@ impl DisputeManager {
 
         // Update market with final outcome
         DisputeUtils::finalize_market_with_resolution(&mut market, final_outcome)?;
+        if market.state == MarketState::Disputed {
+            MarketStateLogic::transition_state(&mut market, MarketState::Resolved)?;
+            MarketStateLogic::emit_state_change_event(
+                env,
+                &market_id,
+                MarketState::Disputed,
+                market.state,
+            );
+            crate::markets::MarketStateCounts::record_transition(
+                env,
+                MarketState::Disputed,
+                market.state,
+            );
+            if !bonded {
+                crate::markets::MarketCreationCaps::record_closed(env, &market.creator);
+            }
+        }
+        crate::fees::ResolutionBond::settle(env, &market_id, &mut market, overturned)?;
         MarketStateManager::update_market(env, &market_id, &market);
 
+        // The dispute reversed the original resolution; count it against the creator.
+        if overturned {
+            CreatorSuspensionManager::record_overturn(env, &market.creator);
+        }
+
         // Update history status to Resolved
         let mut history = env.storage().persistent()
             .get::<_, Vec<Dispute>>(&DataKey::DisputeHistory(market_id.clone()))
@@ -1128,9 +1236,10 @@ impl DisputeManager {
             Self::apply_eviction(env, &market_id, &mut history)?;
             env.storage().persistent().set(&DataKey::DisputeHistory(market_id.clone()), &history);
             env.storage().persistent().extend_ttl(&DataKey::DisputeHistory(market_id.clone()), 535680, 535680);
+            DisputeCapacity::release(env);
         }
 
-        let _ = crate::resolution::ResolutionOutcomeCache::refresh(env, &market_id, &market);
+        crate::resolution::ResolutionOutcomeCache::refresh(env, &market_id, &market)?;
         crate::monitoring::ContractMonitor::emit_dispute_transition_hook(
             env,
             &market_id,
@@ -1432,6 +1541,32 @@ impl DisputeManager {
         Ok(DisputeUtils::get_user_dispute_stake(&market, &user))
     }
 
+    /// Lists the user's dispute stake and the dispute round on each of
+    /// `market_ids`, for dispute dashboards.
+    ///
+    /// Markets the user has not disputed, including ones that do not exist,
+    /// are skipped. The rest keep input order as `(market_id, stake, round)`,
+    /// where the round is 1 for the initial dispute and grows with each
+    /// escalation level (see [`DisputeUtils::dispute_round`]).
+    pub fn get_user_disputes(
+        env: &Env,
+        user: &Address,
+        market_ids: &Vec<Symbol>,
+    ) -> Vec<(Symbol, i128, u32)> {
+        let mut disputes = Vec::new(env);
+        for market_id in market_ids.iter() {
+            let Ok(market) = MarketStateManager::get_market(env, &market_id) else {
+                continue;
+            };
+            let stake = DisputeUtils::get_user_dispute_stake(&market, user);
+            if stake > 0 {
+                let round = DisputeUtils::dispute_round(env, &market_id);
+                disputes.push_back((market_id, stake, round));
+            }
+        }
+        disputes
+    }
+
     /// Allows community members to vote on the validity of a dispute.
     ///
     /// This function enables users to participate in dispute resolution by casting
@@ -2082,6 +2217,7 @@ impl DisputeManager {
             Self::apply_eviction(env, &timeout.market_id, &mut history)?;
             env.storage().persistent().set(&DataKey::DisputeHistory(timeout.market_id.clone()), &history);
             env.storage().persistent().extend_ttl(&DataKey::DisputeHistory(timeout.market_id.clone()), 535680, 535680);
+            DisputeCapacity::release(env);
         }
 
         // Determine timeout outcome
@@ -2306,12 +2442,17 @@ impl DisputeValidator {
     ///
     /// A dispute is only valid when:
     /// 1. The market has ended (`current_time >= end_time`).
-    /// 2. The dispute window is still open (`current_time < end_time + dispute_window_seconds`).
+    /// 2. The dispute window is still open (see `MarketPayouts::dispute_window_end`).
     ///    Allowing disputes after the window closes would create an ambiguous overlap with
     ///    the payout phase and could re-open markets that users already consider settled.
-    /// 3. The market has not already been resolved.
+    /// 3. The market has not already been resolved, unless it is still contestable
+    ///    after resolution (see `MarketPayouts::contestable_after_resolution`).
     /// 4. An oracle result is available to dispute.
-    pub fn validate_market_for_dispute(env: &Env, market: &Market) -> Result<(), Error> {
+    pub fn validate_market_for_dispute(
+        env: &Env,
+        market_id: &Symbol,
+        market: &Market,
+    ) -> Result<(), Error> {
         let current_time = env.ledger().timestamp();
 
         // Market must have ended before a dispute can be filed.
@@ -2319,22 +2460,30 @@ impl DisputeValidator {
             return Err(Error::MarketClosed);
         }
 
-        // Disputes must be filed within the dispute window.
-        // After `end_time + dispute_window_seconds` the window is closed and payouts
-        // are unambiguously allowed, so late disputes are rejected.
+        // Disputes must be filed within the dispute window. Once it closes
+        // payouts are unambiguously allowed, so late disputes are rejected.
+        let resolved_at = crate::recovery::UnclaimedWinningsPolicy::get_claim_window_start(
+            env,
+            market_id,
+            market.end_time,
+        );
         if market.dispute_window_seconds > 0
-            && current_time >= market.end_time + market.dispute_window_seconds
+            && current_time >= MarketPayouts::dispute_window_end(market, resolved_at)
         {
             return Err(Error::MarketResolved);
         }
 
-        // Check if market is already resolved.
-        if market.winning_outcomes.is_some() {
+        // Check if market is already resolved. A bonded manual resolution, or
+        // a market holding claims for its dispute window, stays open to
+        // dispute until the window closes.
+        if market.winning_outcomes.is_some()
+            && !MarketPayouts::contestable_after_resolution(market)
+        {
             return Err(Error::MarketResolved);
         }
 
-        // Check if oracle result is available to dispute.
-        if market.oracle_result.is_none() {
+        // Check if there is a result to dispute.
+        if DisputeUtils::contested_outcome(market).is_none() {
             return Err(Error::OracleUnavailable);
         }
 
@@ -2343,8 +2492,8 @@ impl DisputeValidator {
 
     /// Validate market state for resolution
     pub fn validate_market_for_resolution(_env: &Env, market: &Market) -> Result<(), Error> {
-        // Check if market is already resolved
-        if market.winning_outcomes.is_some() {
+        // Check if market is already resolved (a bonded manual resolution is still contestable)
+        if market.winning_outcomes.is_some() && market.resolution_bond == 0 {
             return Err(Error::MarketResolved);
         }
 
@@ -2372,6 +2521,14 @@ impl DisputeValidator {
         }
     }
 
+    /// Validate that `caller` is the configured dispute resolver
+    pub fn validate_resolver_permissions(env: &Env, caller: &Address) -> Result<(), Error> {
+        match DisputeManager::get_dispute_resolver(env) {
+            Some(resolver) if &resolver == caller => Ok(()),
+            _ => Err(Error::Unauthorized),
+        }
+    }
+
     /// Validate dispute parameters
     pub fn validate_dispute_parameters(
         env: &Env,
@@ -2443,11 +2600,7 @@ impl DisputeValidator {
         final_outcome: &String,
     ) -> Result<(), Error> {
         // Validate that final outcome is one of the valid outcomes
-        if !market.outcomes.contains(final_outcome) {
-            return Err(Error::InvalidOutcome);
-        }
-
-        Ok(())
+        MarketValidator::assert_valid_outcome(market, final_outcome)
     }
 
     /// Validate dispute voting conditions
@@ -2592,6 +2745,75 @@ impl DisputeValidator {
     }
 }
 
+// ===== DISPUTE CAPACITY =====
+
+/// Contract-wide cap on markets with an open dispute, to bound arbitrator load.
+///
+/// A market takes one slot when its first active dispute is opened and frees it
+/// when its disputes are resolved (by admin or timeout). Further disputes on an
+/// already-disputed market do not take another slot.
+pub struct DisputeCapacity;
+
+impl DisputeCapacity {
+    /// Current cap (defaults to [`crate::config::MAX_CONCURRENT_DISPUTES`]).
+    pub fn get_cap(env: &Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&symbol_short!("dsp_cap"))
+            .unwrap_or(crate::config::MAX_CONCURRENT_DISPUTES)
+    }
+
+    /// Sets the cap (admin only). Lowering it below the open count only blocks new disputes.
+    pub fn set_cap(env: &Env, admin: &Address, cap: u32) -> Result<(), Error> {
+        DisputeValidator::validate_admin_permissions(env, admin)?;
+        if cap == 0 {
+            return Err(Error::InvalidInput);
+        }
+        env.storage().persistent().set(&symbol_short!("dsp_cap"), &cap);
+        Ok(())
+    }
+
+    /// Number of markets currently holding a dispute slot.
+    pub fn open_count(env: &Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&symbol_short!("dsp_open"))
+            .unwrap_or(0)
+    }
+
+    /// Returns `true` if `market_id` has a dispute still marked `Active`.
+    pub fn has_active_dispute(env: &Env, market_id: &Symbol) -> bool {
+        env.storage()
+            .persistent()
+            .get::<_, Vec<Dispute>>(&DataKey::DisputeHistory(market_id.clone()))
+            .map_or(false, |history| {
+                history
+                    .iter()
+                    .any(|d| matches!(d.status, DisputeStatus::Active))
+            })
+    }
+
+    /// Takes a slot, failing with `DisputeCapacityReached` when none are free.
+    pub fn reserve(env: &Env) -> Result<(), Error> {
+        let open = Self::open_count(env);
+        if open >= Self::get_cap(env) {
+            return Err(Error::DisputeCapacityReached);
+        }
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("dsp_open"), &(open + 1));
+        Ok(())
+    }
+
+    /// Frees a slot once a market's disputes settle.
+    pub fn release(env: &Env) {
+        let open = Self::open_count(env);
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("dsp_open"), &open.saturating_sub(1));
+    }
+}
+
 // ===== DISPUTE UTILITIES =====
 
 /// Low-level storage and computation helpers for dispute operations.
@@ -2627,10 +2849,7 @@ impl DisputeUtils {
         env: &Env,
         market: &Market,
     ) -> Result<String, Error> {
-        let oracle_result = market
-            .oracle_result
-            .as_ref()
-            .ok_or(Error::OracleUnavailable)?;
+        let oracle_result = Self::contested_outcome(market).ok_or(Error::OracleUnavailable)?;
 
         // If there are significant disputes, consider community consensus more heavily
         let dispute_impact = DisputeAnalytics::calculate_dispute_impact(market);
@@ -2646,7 +2865,18 @@ impl DisputeUtils {
         }
 
         // Default to oracle result
-        Ok(oracle_result.clone())
+        Ok(oracle_result)
+    }
+
+    /// Outcome a dispute on `market` contests: the resolved outcome while it is
+    /// still contestable, otherwise the oracle result.
+    pub fn contested_outcome(market: &Market) -> Option<String> {
+        if MarketPayouts::contestable_after_resolution(market) && market.winning_outcomes.is_some()
+        {
+            market.winning_outcomes.as_ref().and_then(|outcomes| outcomes.get(0))
+        } else {
+            market.oracle_result.clone()
+        }
     }
 
     /// Finalize market with resolution
@@ -2700,6 +2930,13 @@ impl DisputeUtils {
         market.dispute_stakes.get(user.clone()).unwrap_or(0)
     }
 
+    /// Current dispute round of a market: 1, plus the escalation level once
+    /// its dispute has been escalated (disputes are keyed by market ID).
+    pub fn dispute_round(env: &Env, market_id: &Symbol) -> u32 {
+        Self::get_dispute_escalation(env, market_id)
+            .map_or(1, |escalation| escalation.escalation_level + 1)
+    }
+
     /// Calculate dispute impact on market resolution
     pub fn calculate_dispute_impact(market: &Market) -> f64 {
         let total_staked = market.total_staked;
@@ -3333,7 +3570,8 @@ pub struct CommunityConsensus {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use soroban_sdk::testutils::Address as _;
+    use crate::oracles::ComparisonOp;
+    use soroban_sdk::testutils::{Address as _, Ledger};
 
     fn create_test_market(env: &Env, end_time: u64) -> Market {
         let mut outcomes = Vec::new(env);
@@ -3354,7 +3592,7 @@ mod tests {
                 ),
                 String::from_str(env, "BTC/USD"),
                 2500000,
-                String::from_str(env, "gt"),
+                ComparisonOp::Gt,
             ),
             None,
             86400,
@@ -3365,23 +3603,68 @@ mod tests {
     #[test]
     fn test_dispute_validator_market_validation() {
         let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let market_id = Symbol::new(&env, "market");
+        let validate = |market: &Market| {
+            env.as_contract(&contract_id, || {
+                DisputeValidator::validate_market_for_dispute(&env, &market_id, market)
+            })
+        };
         let mut market = create_test_market(&env, env.ledger().timestamp() + 86400);
 
         // Market not ended - should fail
-        assert!(DisputeValidator::validate_market_for_dispute(&env, &market).is_err());
+        assert!(validate(&market).is_err());
 
         // Set market as ended
 
         market.end_time = env.ledger().timestamp().saturating_sub(1);
 
         // No oracle result - should fail
-        assert!(DisputeValidator::validate_market_for_dispute(&env, &market).is_err());
+        assert!(validate(&market).is_err());
 
         // Add oracle result
         market.oracle_result = Some(String::from_str(&env, "yes"));
 
         // Should pass
-        assert!(DisputeValidator::validate_market_for_dispute(&env, &market).is_ok());
+        assert!(validate(&market).is_ok());
+    }
+
+    #[test]
+    fn test_held_market_accepts_disputes_after_late_resolution() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let market_id = Symbol::new(&env, "market");
+        let validate = |market: &Market| {
+            env.as_contract(&contract_id, || {
+                DisputeValidator::validate_market_for_dispute(&env, &market_id, market)
+            })
+        };
+        let mut market = create_test_market(&env, 1_000);
+        let resolved_at = market.end_time + market.dispute_window_seconds + 1_000;
+        market.winning_outcomes = Some(Vec::from_array(&env, [String::from_str(&env, "no")]));
+        market.state = crate::types::MarketState::Resolved;
+        env.as_contract(&contract_id, || {
+            crate::recovery::UnclaimedWinningsPolicy::set_claim_window_start_if_missing(
+                &env,
+                &market_id,
+                resolved_at,
+            );
+        });
+        env.ledger().set_timestamp(resolved_at + 1);
+
+        // Without the hold the resolution is final
+        assert_eq!(validate(&market), Err(Error::MarketResolved));
+
+        market.claims_wait_for_dispute_window = true;
+        assert_eq!(validate(&market), Ok(()));
+        assert_eq!(
+            DisputeUtils::contested_outcome(&market),
+            Some(String::from_str(&env, "no"))
+        );
+
+        // The same deadline that releases the claims closes the disputes
+        env.ledger().set_timestamp(resolved_at + market.dispute_window_seconds);
+        assert_eq!(validate(&market), Err(Error::MarketResolved));
     }
 
     #[test]
@@ -3585,5 +3868,385 @@ mod tests {
         DisputeManager::apply_eviction(&env, &market_id, &mut history2).unwrap();
         assert_eq!(history2.len(), 2); // No eviction because cap is 0
     }
-}
 
+    #[test]
+    fn test_claim_winnings_ignores_dispute_stake() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &None, &None);
+
+        let market_id = Symbol::new(&env, "dbl_dip");
+        let user = Address::generate(&env);
+        let loser = Address::generate(&env);
+        let vote_stake = 1_000i128;
+        let dispute_stake = 5_000i128;
+
+        env.as_contract(&contract_id, || {
+            let mut market = create_test_market(&env, env.ledger().timestamp());
+            market.votes.set(user.clone(), String::from_str(&env, "yes"));
+            market.stakes.set(user.clone(), vote_stake);
+            market.votes.set(loser.clone(), String::from_str(&env, "no"));
+            market.stakes.set(loser.clone(), 1_000);
+            market.total_staked = vote_stake + 1_000;
+            // Same user also disputed the outcome
+            market.dispute_stakes.set(user.clone(), dispute_stake);
+            market.winning_outcomes = Some(Vec::from_array(&env, [String::from_str(&env, "yes")]));
+            market.state = crate::types::MarketState::Resolved;
+            market.dispute_window_seconds = 0;
+            env.storage().persistent().set(&market_id, &market);
+        });
+
+        client.claim_winnings(&user, &market_id);
+
+        env.as_contract(&contract_id, || {
+            let fee_percent = crate::config::ConfigManager::get_config(&env)
+                .unwrap()
+                .fees
+                .platform_fee_percentage;
+            let market: Market = env.storage().persistent().get(&market_id).unwrap();
+            let total_pool = market.total_staked;
+            let expected = vote_stake * (crate::config::PERCENTAGE_DENOMINATOR - fee_percent)
+                / crate::config::PERCENTAGE_DENOMINATOR
+                * total_pool
+                / vote_stake;
+
+            let claim = market.claimed.get(user.clone()).unwrap();
+            assert_eq!(claim.get_payout(), expected);
+            // Payout can never exceed the stake-only pool
+            assert!(claim.get_payout() <= total_pool);
+
+            // Dispute stake is left untouched for resolve_dispute to settle
+            assert_eq!(market.dispute_stakes.get(user.clone()), Some(dispute_stake));
+        });
+    }
+
+    #[test]
+    fn test_dispute_capacity_blocks_until_slot_released() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let admin = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "Admin"), &admin);
+
+            assert_eq!(
+                DisputeCapacity::get_cap(&env),
+                crate::config::MAX_CONCURRENT_DISPUTES
+            );
+            assert_eq!(
+                DisputeCapacity::set_cap(&env, &admin, 0),
+                Err(Error::InvalidInput)
+            );
+            DisputeCapacity::set_cap(&env, &admin, 2).unwrap();
+
+            DisputeCapacity::reserve(&env).unwrap();
+            DisputeCapacity::reserve(&env).unwrap();
+            assert_eq!(
+                DisputeCapacity::reserve(&env),
+                Err(Error::DisputeCapacityReached)
+            );
+            assert_eq!(DisputeCapacity::open_count(&env), 2);
+
+            // Settling one disputed market frees a slot
+            DisputeCapacity::release(&env);
+            assert!(DisputeCapacity::reserve(&env).is_ok());
+            assert_eq!(DisputeCapacity::open_count(&env), 2);
+        });
+    }
+
+    #[test]
+    fn test_claims_blocked_while_market_disputed() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &None, &None);
+
+        let token_id = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        let disputer = Address::generate(&env);
+        soroban_sdk::token::StellarAssetClient::new(&env, &token_id)
+            .mint(&disputer, &MIN_DISPUTE_STAKE);
+
+        let market_id = Symbol::new(&env, "contested");
+        let winner = Address::generate(&env);
+        let loser = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "TokenID"), &token_id);
+            crate::rate_limiter::RateLimiter::init_for_tests(&env, &admin);
+
+            // The oracle has reported "yes" on an ended market
+            let mut market = create_test_market(&env, env.ledger().timestamp());
+            market.votes.set(winner.clone(), String::from_str(&env, "yes"));
+            market.stakes.set(winner.clone(), 1_000_0000000);
+            market.votes.set(loser.clone(), String::from_str(&env, "no"));
+            market.stakes.set(loser.clone(), 1_000_0000000);
+            market.total_staked = 2_000_0000000;
+            market.oracle_result = Some(String::from_str(&env, "yes"));
+            market.state = MarketState::Ended;
+            market.dispute_window_seconds = 0;
+            env.storage().persistent().set(&market_id, &market);
+        });
+
+        client.dispute_market(&disputer, &market_id, &MIN_DISPUTE_STAKE, &None);
+        assert_eq!(
+            client.get_market(&market_id).unwrap().state,
+            MarketState::Disputed
+        );
+        assert_eq!(
+            client.try_claim_winnings(&winner, &market_id),
+            Err(Ok(Error::MarketUnderDispute))
+        );
+
+        client.resolve_dispute(&admin, &market_id);
+        let market = client.get_market(&market_id).unwrap();
+        assert_eq!(market.state, MarketState::Resolved);
+        assert_eq!(
+            market.winning_outcomes,
+            Some(Vec::from_array(&env, [String::from_str(&env, "yes")]))
+        );
+
+        client.claim_winnings(&winner, &market_id);
+        assert!(client
+            .get_market(&market_id)
+            .unwrap()
+            .claimed
+            .get(winner.clone())
+            .unwrap()
+            .is_claimed());
+    }
+
+    #[test]
+    fn test_delegated_dispute_resolver_replaces_admin() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &None, &None);
+        assert_eq!(client.get_dispute_resolver(), Some(admin.clone()));
+
+        let token_id = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        let disputer = Address::generate(&env);
+        soroban_sdk::token::StellarAssetClient::new(&env, &token_id)
+            .mint(&disputer, &MIN_DISPUTE_STAKE);
+
+        let market_id = Symbol::new(&env, "delegated");
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "TokenID"), &token_id);
+            crate::rate_limiter::RateLimiter::init_for_tests(&env, &admin);
+
+            let mut market = create_test_market(&env, env.ledger().timestamp());
+            market.oracle_result = Some(String::from_str(&env, "yes"));
+            market.state = MarketState::Ended;
+            market.dispute_window_seconds = 0;
+            env.storage().persistent().set(&market_id, &market);
+        });
+        client.dispute_market(&disputer, &market_id, &MIN_DISPUTE_STAKE, &None);
+
+        // Only the admin may delegate
+        let resolver = Address::generate(&env);
+        assert_eq!(
+            client.try_set_dispute_resolver(&resolver, &resolver),
+            Err(Ok(Error::Unauthorized))
+        );
+        client.set_dispute_resolver(&admin, &resolver);
+        assert_eq!(client.get_dispute_resolver(), Some(resolver.clone()));
+
+        // Once delegated, the plain admin can no longer adjudicate
+        assert_eq!(
+            client.try_resolve_dispute(&admin, &market_id),
+            Err(Ok(Error::Unauthorized))
+        );
+        client.resolve_dispute(&resolver, &market_id);
+        assert_eq!(
+            client.get_market(&market_id).unwrap().state,
+            MarketState::Resolved
+        );
+    }
+
+    #[test]
+    fn test_same_address_cannot_dispute_twice_in_one_round() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &None, &None);
+
+        let token_id = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        let disputer = Address::generate(&env);
+        soroban_sdk::token::StellarAssetClient::new(&env, &token_id)
+            .mint(&disputer, &(2 * MIN_DISPUTE_STAKE));
+
+        let market_id = Symbol::new(&env, "twice");
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "TokenID"), &token_id);
+            crate::rate_limiter::RateLimiter::init_for_tests(&env, &admin);
+
+            let mut market = create_test_market(&env, env.ledger().timestamp());
+            market.oracle_result = Some(String::from_str(&env, "yes"));
+            market.state = MarketState::Ended;
+            market.dispute_window_seconds = 0;
+            env.storage().persistent().set(&market_id, &market);
+        });
+
+        client.dispute_market(&disputer, &market_id, &MIN_DISPUTE_STAKE, &None);
+        assert_eq!(
+            client.try_dispute_market(&disputer, &market_id, &MIN_DISPUTE_STAKE, &None),
+            Err(Ok(Error::AlreadyDisputed))
+        );
+
+        // The rejected attempt left the original stake untouched
+        let market = client.get_market(&market_id).unwrap();
+        assert_eq!(market.dispute_stakes.get(disputer.clone()), Some(MIN_DISPUTE_STAKE));
+        assert_eq!(market.total_dispute_stakes(), MIN_DISPUTE_STAKE);
+    }
+
+    #[test]
+    fn test_dispute_stake_above_max_is_rejected() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &None, &None);
+
+        let token_id = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        let whale = Address::generate(&env);
+        let honest = Address::generate(&env);
+        for disputer in [&whale, &honest] {
+            soroban_sdk::token::StellarAssetClient::new(&env, &token_id)
+                .mint(disputer, &(10 * MIN_DISPUTE_STAKE));
+        }
+
+        let market_id = Symbol::new(&env, "capped");
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "TokenID"), &token_id);
+            crate::rate_limiter::RateLimiter::init_for_tests(&env, &admin);
+
+            let mut market = create_test_market(&env, env.ledger().timestamp());
+            market.oracle_result = Some(String::from_str(&env, "yes"));
+            market.state = MarketState::Ended;
+            market.dispute_window_seconds = 0;
+            env.storage().persistent().set(&market_id, &market);
+        });
+
+        // The ceiling cannot sit below the minimum dispute stake
+        assert_eq!(
+            client.try_set_max_dispute_stake(&admin, &(MIN_DISPUTE_STAKE - 1)),
+            Err(Ok(Error::InvalidInput))
+        );
+        let max_stake = 2 * MIN_DISPUTE_STAKE;
+        client.set_max_dispute_stake(&admin, &max_stake);
+        assert_eq!(client.get_max_dispute_stake(), Some(max_stake));
+
+        assert_eq!(
+            client.try_dispute_market(&whale, &market_id, &(max_stake + 1), &None),
+            Err(Ok(Error::DisputeStakeTooLarge))
+        );
+        client.dispute_market(&honest, &market_id, &max_stake, &None);
+
+        let market = client.get_market(&market_id).unwrap();
+        assert_eq!(market.dispute_stakes.get(whale), None);
+        assert_eq!(market.dispute_stakes.get(honest), Some(max_stake));
+
+        // Clearing the ceiling lifts the limit
+        client.set_max_dispute_stake(&admin, &0);
+        assert_eq!(client.get_max_dispute_stake(), None);
+    }
+
+    #[test]
+    fn test_get_user_disputes_lists_disputed_markets_only() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &None, &None);
+
+        let token_id = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        let disputer = Address::generate(&env);
+        soroban_sdk::token::StellarAssetClient::new(&env, &token_id)
+            .mint(&disputer, &(3 * MIN_DISPUTE_STAKE));
+
+        let first = Symbol::new(&env, "first");
+        let untouched = Symbol::new(&env, "untouched");
+        let escalated = Symbol::new(&env, "escalated");
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "TokenID"), &token_id);
+            crate::rate_limiter::RateLimiter::init_for_tests(&env, &admin);
+
+            for market_id in [&first, &untouched, &escalated] {
+                let mut market = create_test_market(&env, env.ledger().timestamp());
+                market.oracle_result = Some(String::from_str(&env, "yes"));
+                market.state = MarketState::Ended;
+                market.dispute_window_seconds = 0;
+                env.storage().persistent().set(market_id, &market);
+            }
+        });
+
+        client.dispute_market(&disputer, &first, &MIN_DISPUTE_STAKE, &None);
+        client.dispute_market(&disputer, &escalated, &(2 * MIN_DISPUTE_STAKE), &None);
+        env.as_contract(&contract_id, || {
+            let escalation = DisputeEscalation {
+                dispute_id: escalated.clone(),
+                escalated_by: disputer.clone(),
+                escalation_reason: String::from_str(&env, "oracle feed was stale"),
+                escalation_timestamp: env.ledger().timestamp(),
+                escalation_level: 1,
+                requires_admin_review: true,
+            };
+            DisputeUtils::store_dispute_escalation(&env, &escalated, &escalation).unwrap();
+        });
+
+        let queried = Vec::from_array(
+            &env,
+            [
+                escalated.clone(),
+                untouched.clone(),
+                Symbol::new(&env, "missing"),
+                first.clone(),
+            ],
+        );
+        assert_eq!(
+            client.get_user_disputes(&disputer, &queried),
+            Vec::from_array(
+                &env,
+                [
+                    (escalated.clone(), 2 * MIN_DISPUTE_STAKE, 2u32),
+                    (first.clone(), MIN_DISPUTE_STAKE, 1u32),
+                ]
+            )
+        );
+        assert!(client
+            .get_user_disputes(&Address::generate(&env), &queried)
+            .is_empty());
+    }
+}
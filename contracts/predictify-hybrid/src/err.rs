@@ -85,6 +85,10 @@ pub enum Error {
     OracleCallbackReplayDetected = 213,
     /// Oracle callback timeout. Response time exceeded maximum allowed duration.
     OracleCallbackTimeout = 214,
+    /// The oracle contract call reverted or returned malformed data. Usually transient; retry.
+    OracleCallFailed = 215,
+    /// The oracle answered but has no price for the feed. Retrying will not help; escalate.
+    OracleNoData = 216,
 
     // ===== VALIDATION ERRORS =====
     /// Market question is empty or invalid. Question must be non-empty and descriptive.
@@ -245,6 +249,58 @@ pub enum Error {
     ReplayedOverride = 526,
     /// Oracle quote is an outlier relative to the rolling median history.
     OracleQuoteOutlier = 527,
+    /// Market belongs to a resolution group and must be resolved via `resolve_group`.
+    MarketGrouped = 528,
+    /// Creator is suspended from creating markets after repeated overturned resolutions.
+    CreatorSuspended = 529,
+    /// Oracle range band is narrower than the configured minimum width.
+    RangeTooNarrow = 530,
+    /// Oracle feed ID is empty or malformed for its provider.
+    InvalidFeedId = 531,
+    /// Contract-wide limit on concurrently disputed markets reached.
+    DisputeCapacityReached = 532,
+    /// Oracle provider has been disabled for this deployment.
+    ProviderNotAllowed = 533,
+    /// Oracle feed has been permanently delisted.
+    FeedDelisted = 534,
+    /// Contract-wide cap on active markets reached.
+    MarketCapReached = 535,
+    /// Creator already has the maximum number of active markets.
+    CreatorMarketCapReached = 536,
+    /// Market outcome is under dispute; claims resume once it is resolved.
+    MarketUnderDispute = 537,
+    /// Voting cutoff has passed, though the market has not yet ended.
+    VotingClosed = 538,
+    /// Extended end time would not be in the future at the current ledger time.
+    InvalidExtension = 539,
+    /// Contract has already been initialized; `initialize` may only run once.
+    AlreadyInitialized = 540,
+    /// Market outcome uses a label reserved for contract-level resolution.
+    ReservedOutcome = 541,
+    /// Vote would push its outcome past the market's per-outcome stake cap.
+    OutcomeCapExceeded = 542,
+    /// Vote stake is so small its payout would round to zero even if it won.
+    StakeTooSmall = 543,
+    /// Resolution can no longer be corrected: the window has passed or a claim was made.
+    ResolutionCorrectionClosed = 544,
+    /// Reducing a tracked stake total would take it below zero.
+    ArithmeticUnderflow = 545,
+    /// Resolution evidence exceeds the maximum length.
+    EvidenceTooLong = 546,
+    /// Settlement token is missing, unusable, or the same as the staking token.
+    InvalidSettlementToken = 547,
+    /// Dispute stake exceeds the configured per-round maximum.
+    DisputeStakeTooLarge = 548,
+    /// Address is blacklisted from voting and disputing.
+    AddressBlacklisted = 549,
+    /// No staking token has been configured for the contract.
+    TokenNotConfigured = 550,
+    /// Conditional market's parent market has not resolved yet.
+    ParentMarketUnresolved = 551,
+    /// Platform fee is outside the configured minimum and maximum.
+    FeeOutOfBounds = 552,
+    /// The settlement token refused to transfer a payout.
+    SettlementTransferFailed = 553,
 }
 
 // ===== ERROR CATEGORIZATION AND RECOVERY SYSTEM =====
@@ -748,7 +804,8 @@ impl ErrorHandler {
     ///
     /// | Error | Strategy |
     /// |-------|----------|
-    /// | OracleUnavailable | RetryWithDelay |
+    /// | OracleUnavailable, OracleCallFailed | RetryWithDelay |
+    /// | OracleNoData | ManualIntervention |
     /// | InvalidInput | Retry |
     /// | Unauthorized, MarketClosed | Abort |
     /// | AlreadyVoted, AlreadyBet | Skip |
@@ -763,7 +820,8 @@ impl ErrorHandler {
     /// The recommended `RecoveryStrategy` for this error.
     pub fn get_error_recovery_strategy(error: &Error) -> RecoveryStrategy {
         match error {
-            Error::OracleUnavailable => RecoveryStrategy::RetryWithDelay,
+            Error::OracleUnavailable | Error::OracleCallFailed => RecoveryStrategy::RetryWithDelay,
+            Error::OracleNoData => RecoveryStrategy::ManualIntervention,
             Error::InvalidInput => RecoveryStrategy::Retry,
             Error::OracleConfidenceTooWide => RecoveryStrategy::NoRecovery,
             Error::MarketNotFound => RecoveryStrategy::AlternativeMethod,
@@ -1171,7 +1229,7 @@ impl ErrorHandler {
     /// The maximum allowed recovery attempts (0-3).
     fn get_max_recovery_attempts(error: &Error) -> u32 {
         match error {
-            Error::OracleUnavailable => 3,
+            Error::OracleUnavailable | Error::OracleCallFailed => 3,
             Error::InvalidInput => 2,
             Error::MarketNotFound | Error::ConfigNotFound => 1,
             Error::AlreadyVoted
@@ -1186,6 +1244,7 @@ impl ErrorHandler {
             | Error::DisputeFeeFailed
             | Error::InvalidState
             | Error::InvalidOracleConfig
+            | Error::OracleNoData
             | Error::OperationWouldExceedBudget => 0,
             _ => 1,
         }
@@ -1303,11 +1362,16 @@ impl ErrorHandler {
                 ErrorCategory::Authentication,
                 RecoveryStrategy::Abort,
             ),
-            Error::OracleUnavailable => (
+            Error::OracleUnavailable | Error::OracleCallFailed => (
                 ErrorSeverity::High,
                 ErrorCategory::Oracle,
                 RecoveryStrategy::RetryWithDelay,
             ),
+            Error::OracleNoData => (
+                ErrorSeverity::High,
+                ErrorCategory::Oracle,
+                RecoveryStrategy::ManualIntervention,
+            ),
             Error::InvalidState => (
                 ErrorSeverity::High,
                 ErrorCategory::System,
@@ -1451,6 +1515,57 @@ impl ErrorHandler {
     }
 }
 
+// ===== ERROR DIAGNOSTICS =====
+
+/// Storage key for the diagnostics on/off flag (bool).
+const DIAGNOSTICS_ENABLED_KEY: Symbol = soroban_sdk::symbol_short!("diag_on");
+
+/// Opt-in reporting of hot-path failures, for debugging.
+///
+/// Disabled by default so failing calls pay nothing extra. When enabled, each
+/// reported failure publishes an `err_log` event carrying the error code,
+/// the operation, user, market and timestamp. Nothing is stored: the call
+/// still fails with its original error, which rolls back its writes, while
+/// its events remain visible in the failed transaction's diagnostics (for
+/// example when the transaction is simulated).
+pub struct ErrorDiagnostics;
+
+impl ErrorDiagnostics {
+    /// Whether failures are currently being reported.
+    pub fn is_enabled(env: &Env) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DIAGNOSTICS_ENABLED_KEY)
+            .unwrap_or(false)
+    }
+
+    /// Turn reporting on or off (admin only).
+    pub fn set_enabled(env: &Env, admin: &Address, enabled: bool) -> Result<(), Error> {
+        admin.require_auth();
+        crate::resolution::MarketResolutionValidator::validate_admin_permissions(env, admin)?;
+        env.storage()
+            .persistent()
+            .set(&DIAGNOSTICS_ENABLED_KEY, &enabled);
+        Ok(())
+    }
+
+    /// Report `error` raised by `operation` for `user` on `market_id`.
+    /// A no-op while diagnostics are disabled.
+    pub fn report(env: &Env, operation: &str, user: &Address, market_id: &Symbol, error: Error) {
+        if !Self::is_enabled(env) {
+            return;
+        }
+        crate::events::EventEmitter::emit_error_logged(
+            env,
+            error as u32,
+            &String::from_str(env, error.description()),
+            &String::from_str(env, operation),
+            Some(user.clone()),
+            Some(market_id.clone()),
+        );
+    }
+}
+
 // ===== ERROR DISPLAY HELPERS =====
 
 impl Error {
@@ -1520,6 +1635,8 @@ impl Error {
             Error::OracleCallbackInvalidSignature => "Oracle callback signature invalid",
             Error::OracleCallbackReplayDetected => "Oracle callback replay detected",
             Error::OracleCallbackTimeout => "Oracle callback timed out",
+            Error::OracleCallFailed => "Oracle call failed",
+            Error::OracleNoData => "Oracle returned no data for the feed",
 
             // Metadata length limit errors
             Error::QuestionTooLong => "Market question exceeds maximum allowed length",
@@ -1563,6 +1680,32 @@ impl Error {
             Error::CumulativeExtensionCapHit => "Cumulative extension cap reached; no further extensions allowed",
             Error::IllegalMarketStateTransition => "Illegal market state transition attempted",
             Error::OracleQuoteOutlier => "Oracle quote is an outlier relative to the rolling median",
+            Error::MarketGrouped => "Market is grouped; resolve it together with its group",
+            Error::CreatorSuspended => "Creator is suspended after repeated overturned resolutions",
+            Error::RangeTooNarrow => "Oracle range is narrower than the minimum allowed width",
+            Error::InvalidFeedId => "Oracle feed ID is empty or malformed for its provider",
+            Error::DisputeCapacityReached => "Too many markets are disputed at once; try again after one settles",
+            Error::ProviderNotAllowed => "Oracle provider is not allowed on this deployment",
+            Error::FeedDelisted => "Oracle feed has been permanently delisted",
+            Error::MarketCapReached => "Too many markets are active; try again after one resolves",
+            Error::CreatorMarketCapReached => "Creator has reached the limit of active markets",
+            Error::MarketUnderDispute => "Market outcome is under dispute; claims are paused until it is resolved",
+            Error::VotingClosed => "Voting has closed for this market ahead of its end time",
+            Error::InvalidExtension => "Extended end time must be after the current ledger time",
+            Error::AlreadyInitialized => "Contract has already been initialized",
+            Error::ReservedOutcome => "Outcome label is reserved and cannot be used by a market",
+            Error::OutcomeCapExceeded => "Outcome already holds the maximum share of the market's stake",
+            Error::StakeTooSmall => "Stake is too small to yield a non-zero payout",
+            Error::ResolutionCorrectionClosed => "Resolution correction window has passed or winnings were already claimed",
+            Error::ArithmeticUnderflow => "Stake reduction exceeds the tracked total",
+            Error::EvidenceTooLong => "Resolution evidence exceeds maximum length",
+            Error::InvalidSettlementToken => "Settlement token is invalid or matches the staking token",
+            Error::DisputeStakeTooLarge => "Dispute stake exceeds the maximum allowed per round",
+            Error::AddressBlacklisted => "Address is blacklisted from voting and disputing",
+            Error::TokenNotConfigured => "Staking token is not configured",
+            Error::ParentMarketUnresolved => "Parent market of this conditional market is not resolved yet",
+            Error::FeeOutOfBounds => "Platform fee is outside the allowed range",
+            Error::SettlementTransferFailed => "Settlement token transfer failed",
         }
     }
 
@@ -1631,6 +1774,8 @@ impl Error {
             Error::OracleCallbackInvalidSignature => "ORACLE_CALLBACK_INVALID_SIGNATURE",
             Error::OracleCallbackReplayDetected => "ORACLE_CALLBACK_REPLAY_DETECTED",
             Error::OracleCallbackTimeout => "ORACLE_CALLBACK_TIMEOUT",
+            Error::OracleCallFailed => "ORACLE_CALL_FAILED",
+            Error::OracleNoData => "ORACLE_NO_DATA",
 
             // Metadata length limit errors
             Error::QuestionTooLong => "QUESTION_TOO_LONG",
@@ -1672,6 +1817,32 @@ impl Error {
             Error::CumulativeExtensionCapHit => "CUMULATIVE_EXTENSION_CAP_HIT",
             Error::IllegalMarketStateTransition => "ILLEGAL_MARKET_STATE_TRANSITION",
             Error::OracleQuoteOutlier => "ORACLE_QUOTE_OUTLIER",
+            Error::MarketGrouped => "MARKET_GROUPED",
+            Error::CreatorSuspended => "CREATOR_SUSPENDED",
+            Error::RangeTooNarrow => "RANGE_TOO_NARROW",
+            Error::InvalidFeedId => "INVALID_FEED_ID",
+            Error::DisputeCapacityReached => "DISPUTE_CAPACITY_REACHED",
+            Error::ProviderNotAllowed => "PROVIDER_NOT_ALLOWED",
+            Error::FeedDelisted => "FEED_DELISTED",
+            Error::MarketCapReached => "MARKET_CAP_REACHED",
+            Error::CreatorMarketCapReached => "CREATOR_MARKET_CAP_REACHED",
+            Error::MarketUnderDispute => "MARKET_UNDER_DISPUTE",
+            Error::VotingClosed => "VOTING_CLOSED",
+            Error::InvalidExtension => "INVALID_EXTENSION",
+            Error::AlreadyInitialized => "ALREADY_INITIALIZED",
+            Error::ReservedOutcome => "RESERVED_OUTCOME",
+            Error::OutcomeCapExceeded => "OUTCOME_CAP_EXCEEDED",
+            Error::StakeTooSmall => "STAKE_TOO_SMALL",
+            Error::ResolutionCorrectionClosed => "RESOLUTION_CORRECTION_CLOSED",
+            Error::ArithmeticUnderflow => "ARITHMETIC_UNDERFLOW",
+            Error::EvidenceTooLong => "EVIDENCE_TOO_LONG",
+            Error::InvalidSettlementToken => "INVALID_SETTLEMENT_TOKEN",
+            Error::DisputeStakeTooLarge => "DISPUTE_STAKE_TOO_LARGE",
+            Error::AddressBlacklisted => "ADDRESS_BLACKLISTED",
+            Error::TokenNotConfigured => "TOKEN_NOT_CONFIGURED",
+            Error::ParentMarketUnresolved => "PARENT_MARKET_UNRESOLVED",
+            Error::FeeOutOfBounds => "FEE_OUT_OF_BOUNDS",
+            Error::SettlementTransferFailed => "SETTLEMENT_TRANSFER_FAILED",
         }
     }
 }
@@ -1722,6 +1893,8 @@ mod tests {
             Error::FallbackOracleUnavailable,
             Error::ResolutionTimeoutReached,
             Error::OracleConfidenceTooWide,
+            Error::OracleCallFailed,
+            Error::OracleNoData,
             Error::InvalidQuestion,
             Error::InvalidOutcomes,
             Error::InvalidDuration,
@@ -1789,6 +1962,32 @@ mod tests {
             Error::UpgradeChainMismatch,
             Error::ReplayedOverride,
             Error::OracleQuoteOutlier,
+            Error::MarketGrouped,
+            Error::CreatorSuspended,
+            Error::RangeTooNarrow,
+            Error::InvalidFeedId,
+            Error::DisputeCapacityReached,
+            Error::ProviderNotAllowed,
+            Error::FeedDelisted,
+            Error::MarketCapReached,
+            Error::CreatorMarketCapReached,
+            Error::MarketUnderDispute,
+            Error::VotingClosed,
+            Error::InvalidExtension,
+            Error::AlreadyInitialized,
+            Error::ReservedOutcome,
+            Error::OutcomeCapExceeded,
+            Error::StakeTooSmall,
+            Error::ResolutionCorrectionClosed,
+            Error::ArithmeticUnderflow,
+            Error::EvidenceTooLong,
+            Error::InvalidSettlementToken,
+            Error::DisputeStakeTooLarge,
+            Error::AddressBlacklisted,
+            Error::TokenNotConfigured,
+            Error::ParentMarketUnresolved,
+            Error::FeeOutOfBounds,
+            Error::SettlementTransferFailed,
         ]
     }
 
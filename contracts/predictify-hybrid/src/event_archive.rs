@@ -1080,6 +1080,7 @@ mod tests {
 
             let market = Market {
                 admin: test.admin.clone(),
+                creator: test.admin.clone(),
                 question: String::from_str(env, "Will BTC reach 100k?"),
                 outcomes: soroban_sdk::vec![env, String::from_str(env, "yes")],
                 end_time: env.ledger().timestamp() + 3600,
@@ -1107,10 +1108,23 @@ mod tests {
                 extension_history: soroban_sdk::vec![env],
                 category: None,
                 tags: soroban_sdk::vec![env],
+                language: String::from_str(env, crate::config::DEFAULT_MARKET_LANGUAGE),
                 min_pool_size: None,
+                min_voters: None,
+                outcome_cap_bps: None,
+                resolution_bounty: 0,
                 bet_deadline: 0,
                 dispute_window_seconds: 3600,
                 winnings_swept: false,
+                refund_grace_seconds: crate::config::REFUND_GRACE_SECONDS,
+                early_bird_fee: None,
+                settlement_token: None,
+                settlement_rate_bps: 0,
+                resolution_bond: 0,
+                condition: None,
+                tiebreak_policy: None,
+                payout_model: crate::types::PayoutModel::Parimutuel,
+                claims_wait_for_dispute_window: false,
             };
 
             let res =
@@ -1130,6 +1144,7 @@ mod tests {
 
             let market = Market {
                 admin: test.admin.clone(),
+                creator: test.admin.clone(),
                 question: String::from_str(env, "Will BTC reach 100k?"),
                 outcomes: soroban_sdk::vec![env, String::from_str(env, "yes")],
                 end_time: env.ledger().timestamp() + 3600,
@@ -1157,10 +1172,23 @@ mod tests {
                 extension_history: soroban_sdk::vec![env],
                 category: None,
                 tags: soroban_sdk::vec![env],
+                language: String::from_str(env, crate::config::DEFAULT_MARKET_LANGUAGE),
                 min_pool_size: None,
+                min_voters: None,
+                outcome_cap_bps: None,
+                resolution_bounty: 0,
                 bet_deadline: 0,
                 dispute_window_seconds: 3600,
                 winnings_swept: false,
+                refund_grace_seconds: crate::config::REFUND_GRACE_SECONDS,
+                early_bird_fee: None,
+                settlement_token: None,
+                settlement_rate_bps: 0,
+                resolution_bond: 0,
+                condition: None,
+                tiebreak_policy: None,
+                payout_model: crate::types::PayoutModel::Parimutuel,
+                claims_wait_for_dispute_window: false,
             };
 
             let res1 =
@@ -1397,6 +1425,7 @@ mod tests {
 
             let market = Market {
                 admin: test.admin.clone(),
+                creator: test.admin.clone(),
                 question: String::from_str(env, "Will BTC reach 100k?"),
                 outcomes: soroban_sdk::vec![env, String::from_str(env, "yes")],
                 end_time: env.ledger().timestamp() + 3600,
@@ -1424,10 +1453,23 @@ mod tests {
                 extension_history: soroban_sdk::vec![env],
                 category: None,
                 tags: soroban_sdk::vec![env],
+                language: String::from_str(env, crate::config::DEFAULT_MARKET_LANGUAGE),
                 min_pool_size: None,
+                min_voters: None,
+                outcome_cap_bps: None,
+                resolution_bounty: 0,
                 bet_deadline: 0,
                 dispute_window_seconds: 3600,
                 winnings_swept: false,
+                refund_grace_seconds: crate::config::REFUND_GRACE_SECONDS,
+                early_bird_fee: None,
+                settlement_token: None,
+                settlement_rate_bps: 0,
+                resolution_bond: 0,
+                condition: None,
+                tiebreak_policy: None,
+                payout_model: crate::types::PayoutModel::Parimutuel,
+                claims_wait_for_dispute_window: false,
             };
 
             let res =
@@ -71,6 +71,7 @@ fn test_create_event_success() {
         feed_id: String::from_str(&setup.env, "BTC/USD"),
         threshold: 50000,
         comparison: String::from_str(&setup.env, "gt"),
+        threshold2: None,
     };
 
     let event_id = client.create_event(
@@ -106,6 +107,7 @@ fn test_create_event_invalid_outcomes_too_few() {
         feed_id: String::from_str(&setup.env, "BTC/USD"),
         threshold: 50000,
         comparison: String::from_str(&setup.env, "gt"),
+        threshold2: None,
     };
 
     client.create_event(
@@ -139,6 +141,7 @@ fn test_create_event_invalid_empty_description() {
         feed_id: String::from_str(&setup.env, "BTC/USD"),
         threshold: 50000,
         comparison: String::from_str(&setup.env, "gt"),
+        threshold2: None,
     };
 
     client.create_event(
@@ -172,6 +175,7 @@ fn test_create_event_invalid_end_time_past() {
         feed_id: String::from_str(&setup.env, "BTC/USD"),
         threshold: 50000,
         comparison: String::from_str(&setup.env, "gt"),
+        threshold2: None,
     };
 
     client.create_event(
@@ -205,6 +209,7 @@ fn test_create_event_invalid_end_time_current() {
         feed_id: String::from_str(&setup.env, "BTC/USD"),
         threshold: 50000,
         comparison: String::from_str(&setup.env, "gt"),
+        threshold2: None,
     };
 
     client.create_event(
@@ -239,6 +244,7 @@ fn test_create_event_unauthorized_caller() {
         feed_id: String::from_str(&setup.env, "BTC/USD"),
         threshold: 50000,
         comparison: String::from_str(&setup.env, "gt"),
+        threshold2: None,
     };
 
     client.create_event(
@@ -271,6 +277,7 @@ fn test_create_event_with_fallback_oracle() {
         feed_id: String::from_str(&setup.env, "BTC/USD"),
         threshold: 50000,
         comparison: String::from_str(&setup.env, "gt"),
+        threshold2: None,
     };
     let fallback_oracle_config = OracleConfig {
         provider: OracleProvider::pyth(),
@@ -278,6 +285,7 @@ fn test_create_event_with_fallback_oracle() {
         feed_id: String::from_str(&setup.env, "BTC/USD"),
         threshold: 50000,
         comparison: String::from_str(&setup.env, "gt"),
+        threshold2: None,
     };
 
     let event_id = client.create_event(
@@ -314,6 +322,7 @@ fn test_create_event_with_resolution_timeout() {
         feed_id: String::from_str(&setup.env, "BTC/USD"),
         threshold: 50000,
         comparison: String::from_str(&setup.env, "gt"),
+        threshold2: None,
     };
     let resolution_timeout = 86400; // 1 day
 
@@ -352,6 +361,7 @@ fn test_create_event_multiple_outcomes() {
         feed_id: String::from_str(&setup.env, "BTC/USD"),
         threshold: 50000,
         comparison: String::from_str(&setup.env, "gt"),
+        threshold2: None,
     };
 
     let event_id = client.create_event(
@@ -387,6 +397,7 @@ fn test_create_market_success() {
         feed_id: String::from_str(&setup.env, "BTC/USD"),
         threshold: 50000,
         comparison: String::from_str(&setup.env, "gt"),
+        threshold2: None,
     };
 
     let market_id = client.create_market(
@@ -422,6 +433,7 @@ fn test_create_event_unauthorized() {
         feed_id: String::from_str(&setup.env, "BTC/USD"),
         threshold: 50000,
         comparison: String::from_str(&setup.env, "gt"),
+        threshold2: None,
     };
 
     client.create_event(
@@ -454,6 +466,7 @@ fn test_create_event_invalid_end_time() {
         feed_id: String::from_str(&setup.env, "BTC/USD"),
         threshold: 50000,
         comparison: String::from_str(&setup.env, "gt"),
+        threshold2: None,
     };
 
     client.create_event(
@@ -482,6 +495,7 @@ fn test_create_event_empty_outcomes() {
         feed_id: String::from_str(&setup.env, "BTC/USD"),
         threshold: 50000,
         comparison: String::from_str(&setup.env, "gt"),
+        threshold2: None,
     };
 
     client.create_event(
@@ -514,6 +528,7 @@ fn test_create_event_limit_enforced() {
         feed_id: String::from_str(&setup.env, "BTC/USD"),
         threshold: 50000,
         comparison: String::from_str(&setup.env, "gt"),
+        threshold2: None,
     };
 
     // The default limit is 20. Creating 21 events should panic on the 21st.
@@ -548,6 +563,7 @@ fn test_event_id_unique() {
         feed_id: String::from_str(&setup.env, "BTC/USD"),
         threshold: 50000,
         comparison: String::from_str(&setup.env, "gt"),
+        threshold2: None,
     };
 
     let event_id_1 = client.create_event(
@@ -591,6 +607,7 @@ fn test_event_storage_consistency() {
         feed_id: String::from_str(&setup.env, "BTC/USD"),
         threshold: 50000,
         comparison: String::from_str(&setup.env, "gt"),
+        threshold2: None,
     };
 
     let event_id = client.create_event(
@@ -201,6 +201,7 @@ fn test_market_resolution_publishes_status_events() {
         &setup.admin,
         &market_id,
         &String::from_str(&setup.env, "Yes"),
+        &None,
     );
     assert!(result.is_ok());
 
@@ -318,6 +319,7 @@ fn test_extend_deadline_resolved_market() {
         &setup.admin,
         &market_id,
         &String::from_str(&setup.env, "Yes"),
+        &None,
     );
 
     // Try to extend resolved market — must be rejected
@@ -441,6 +443,7 @@ fn test_extend_market_closed_state_rejected() {
         &setup.admin,
         &market_id,
         &String::from_str(&setup.env, "Yes"),
+        &None,
     );
     let _ = client.try_close_market(&setup.admin, &market_id);
 
@@ -808,6 +811,7 @@ fn test_update_event_outcomes_resolved_market() {
         &setup.admin,
         &market_id,
         &String::from_str(&setup.env, "Yes"),
+        &None,
     );
 
     // Try to update outcomes on resolved market
@@ -964,6 +968,7 @@ fn test_archive_event_success() {
         &setup.admin,
         &market_id,
         &String::from_str(&setup.env, "Yes"),
+        &None,
     );
 
     let result = client.try_archive_event(&setup.admin, &market_id);
@@ -988,6 +993,7 @@ fn test_archive_event_already_archived_returns_error() {
         &setup.admin,
         &market_id,
         &String::from_str(&setup.env, "Yes"),
+        &None,
     );
 
     client.archive_event(&setup.admin, &market_id);
@@ -1041,6 +1047,7 @@ fn test_archive_unauthorized_returns_error() {
         &setup.admin,
         &market_id,
         &String::from_str(&setup.env, "Yes"),
+        &None,
     );
 
     let result = client.try_archive_event(&non_admin, &market_id);
@@ -1065,6 +1072,7 @@ fn test_prune_archive_removes_oldest_entries() {
             &setup.admin,
             &market_id,
             &String::from_str(&setup.env, "Yes"),
+            &None,
         );
         client.archive_event(&setup.admin, &market_id);
     }
@@ -1092,6 +1100,7 @@ fn test_prune_archive_count_zero_removes_nothing() {
         &setup.admin,
         &market_id,
         &String::from_str(&setup.env, "Yes"),
+        &None,
     );
     client.archive_event(&setup.admin, &market_id);
 
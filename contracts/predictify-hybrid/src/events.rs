@@ -6,6 +6,7 @@ use soroban_sdk::{contracttype, symbol_short, vec, Address, BytesN, Env, Map, St
 use crate::admin::Severity;
 use crate::config::Environment;
 use crate::err::Error;
+use crate::oracles::ComparisonOp;
 use crate::types::OracleProvider;
 
 // Define AdminRole locally since it's not available in the crate root
@@ -353,7 +354,7 @@ pub struct BetStatusUpdatedEvent {
 ///     feed_id: String::from_str(&env, "BTC/USD"),
 ///     price: 52_000_00000000, // $52,000 (8 decimal precision)
 ///     threshold: 50_000_00000000, // $50,000 threshold
-///     comparison: String::from_str(&env, "gte"), // greater than or equal
+///     comparison: ComparisonOp::Gte, // greater than or equal
 ///     timestamp: env.ledger().timestamp(),
 /// };
 ///
@@ -404,7 +405,7 @@ pub struct OracleResultEvent {
     /// Threshold value
     pub threshold: i128,
     /// Comparison operator
-    pub comparison: String,
+    pub comparison: ComparisonOp,
     /// Fetch timestamp
     pub timestamp: u64,
 }
@@ -495,6 +496,25 @@ pub struct MarketResolvedEvent {
     pub timestamp: u64,
 }
 
+/// Event emitted when an admin corrects a market's resolved outcome.
+///
+/// Follows an earlier `MarketResolvedEvent` for the same market; indexers
+/// should replace the outcome they recorded with `corrected_outcome`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResolutionCorrectedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Winning outcome(s) before the correction
+    pub previous_outcomes: Vec<String>,
+    /// Winning outcome after the correction
+    pub corrected_outcome: String,
+    /// Admin who made the correction
+    pub admin: Address,
+    /// Correction timestamp
+    pub timestamp: u64,
+}
+
 /// Event emitted when a user creates a formal dispute against a market resolution.
 ///
 /// This event captures dispute initiation details, including the disputing party,
@@ -694,6 +714,23 @@ pub struct FeeCollectedEvent {
     pub timestamp: u64,
 }
 
+/// Fees credited to the treasury, for off-chain treasury reconciliation.
+///
+/// `treasury` is where the fees now sit; collected platform fees stay in the
+/// contract's fee vault until withdrawn, so this is the contract address.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeesCollectedEvent {
+    /// Market the fees were taken from
+    pub market_id: Symbol,
+    /// Fee amount credited
+    pub amount: i128,
+    /// Address holding the collected fees
+    pub treasury: Address,
+    /// Collection timestamp
+    pub timestamp: u64,
+}
+
 /// Admin fee withdrawal attempt event
 ///
 /// Emitted on every admin call to withdraw fees, including blocked attempts
@@ -738,6 +775,52 @@ pub struct FeeWithdrawnEvent {
     pub timestamp: u64,
 }
 
+/// Creator-escrowed resolution bounty paid to a resolver or refunded to the creator
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResolutionBountyReleasedEvent {
+    /// Market the bounty was escrowed for
+    pub market_id: Symbol,
+    /// Resolver (on payment) or creator (on refund)
+    pub recipient: Address,
+    /// Bounty amount released
+    pub amount: i128,
+    /// Whether the bounty was refunded because the market was voided
+    pub refunded: bool,
+    /// Release timestamp
+    pub timestamp: u64,
+}
+
+/// Admin-posted resolution bond refunded or forfeited to disputers
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResolutionBondSettledEvent {
+    /// Market the bond was posted for
+    pub market_id: Symbol,
+    /// Bond amount released
+    pub amount: i128,
+    /// Whether a dispute overturned the resolution and the bond went to the disputers
+    pub forfeited: bool,
+    /// Settlement timestamp
+    pub timestamp: u64,
+}
+
+/// Keeper bounty paid out of the fee vault for triggering oracle resolution
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct KeeperBountyPaidEvent {
+    /// Market that was resolved
+    pub market_id: Symbol,
+    /// Keeper address receiving the bounty
+    pub keeper: Address,
+    /// Bounty amount paid
+    pub amount: i128,
+    /// Remaining fee vault balance after payment
+    pub remaining_fees: i128,
+    /// Payment timestamp
+    pub timestamp: u64,
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct MultisigThresholdProposedEvent {
@@ -860,7 +943,7 @@ pub struct OracleVerifInitiatedEvent {
 ///     outcome: String::from_str(&env, \"yes\"),
 ///     price: 52_000_00,
 ///     threshold: 50_000_00,
-///     comparison: String::from_str(&env, \"gt\"),
+///     comparison: ComparisonOp::Gt,
 ///     provider: String::from_str(&env, \"Reflector\"),
 ///     feed_id: String::from_str(&env, \"BTC/USD\"),
 ///     confidence_score: 95,
@@ -900,7 +983,7 @@ pub struct OracleResultVerifiedEvent {
     /// Threshold configured for market
     pub threshold: i128,
     /// Comparison operator used
-    pub comparison: String,
+    pub comparison: ComparisonOp,
     /// Oracle provider name
     pub provider: String,
     /// Feed ID used
@@ -1199,6 +1282,47 @@ pub struct MarketClosedEvent {
     pub timestamp: u64,
 }
 
+/// Event emitted when a creator deletes a market that never received a vote.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketDeletedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Creator who deleted it
+    pub creator: Address,
+    /// Creation fee returned to the creator (0 if none)
+    pub refunded_fee: i128,
+    /// Deletion timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when a creator is automatically suspended after too many
+/// overturned resolutions.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreatorSuspendedEvent {
+    /// Suspended creator
+    pub creator: Address,
+    /// Overturned resolutions recorded against the creator
+    pub overturn_count: u32,
+    /// Threshold in force when the suspension triggered
+    pub threshold: u32,
+    /// Suspension timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when an admin reinstates a suspended creator.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreatorReinstatedEvent {
+    /// Reinstated creator
+    pub creator: Address,
+    /// Admin who reinstated them
+    pub admin: Address,
+    /// Reinstatement timestamp
+    pub timestamp: u64,
+}
+
 /// Event emitted when a market is refunded due to oracle resolution failure or timeout.
 ///
 /// Emitted after all bets are refunded in full (no fee deduction). The market is marked
@@ -1422,6 +1546,18 @@ pub struct FallbackUsedEvent {
     pub timestamp: u64,
 }
 
+/// Event emitted once when a market passes `end_time` and awaits resolution.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketAwaitingResolutionEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Market end time
+    pub end_time: u64,
+    /// Ledger time the close was announced
+    pub timestamp: u64,
+}
+
 /// Event emitted when a market resolution timeout is reached.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -1625,6 +1761,24 @@ pub struct WinningsClaimedEvent {
     pub timestamp: u64,
 }
 
+/// Event emitted when a participant's stake is returned to them rather than
+/// paid out as winnings, e.g. a losing stake on a stake-back market.
+///
+/// Kept apart from [`WinningsClaimedEvent`] so refunds are never counted as
+/// winnings by indexers.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct StakeRefundedEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Participant refunded
+    pub user: Address,
+    /// Amount refunded
+    pub amount: i128,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
 /// Event emitted when a user claims winnings from multiple resolved markets in a batch operation.
 ///
 /// Provides information about batch winnings claims including each market claim
@@ -1991,8 +2145,59 @@ pub struct MinPoolSizeNotMetEvent {
     pub timestamp: u64,
 }
 
+/// Event emitted when a market is cancelled at resolution for having too few distinct voters.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MinVotersNotMetEvent {
+    /// Market ID
+    pub market_id: Symbol,
+    /// Distinct voters at resolution time
+    pub voter_count: u32,
+    /// Required minimum voters
+    pub required_min: u32,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
+/// Event emitted when an oracle feed is permanently delisted and its dependent markets voided.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeedDelistedEvent {
+    /// Oracle provider of the feed
+    pub provider: OracleProvider,
+    /// Delisted feed ID
+    pub feed_id: String,
+    /// Admin who delisted the feed
+    pub admin: Address,
+    /// Markets voided and refunded as a result
+    pub voided_markets: Vec<Symbol>,
+    /// Event timestamp
+    pub timestamp: u64,
+}
+
 // ===== EVENT SCHEMA REGISTRY =====
 
+/// Schema version of the event set emitted by this build.
+///
+/// Bump together with any `schema_version` change in [`EventSchemaRegistry::get_schema`].
+/// Initialization and the post-upgrade `migrate` entrypoint compare it with the last
+/// recorded version and emit [`SchemaVersionChangedEvent`] when they differ.
+pub const CURRENT_EVENT_SCHEMA_VERSION: u32 = 1;
+
+/// Emitted when the contract's event schema version changes.
+///
+/// `old_version` is 0 on first initialization.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SchemaVersionChangedEvent {
+    /// Last recorded schema version
+    pub old_version: u32,
+    /// Schema version of the running build
+    pub new_version: u32,
+    /// Change timestamp
+    pub timestamp: u64,
+}
+
 /// Describes the canonical topic symbol and schema version for a named event.
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -2053,6 +2258,32 @@ impl EventSchemaRegistry {
             _ => None,
         }
     }
+
+    /// Last event schema version recorded on-chain, if any.
+    pub fn get_recorded_version(env: &Env) -> Option<u32> {
+        env.storage().persistent().get(&symbol_short!("evt_schv"))
+    }
+
+    /// Records [`CURRENT_EVENT_SCHEMA_VERSION`], emitting
+    /// [`SchemaVersionChangedEvent`] if it differs from the recorded version.
+    ///
+    /// Returns `true` if the version changed.
+    pub fn sync_schema_version(env: &Env) -> bool {
+        Self::sync_schema_version_to(env, CURRENT_EVENT_SCHEMA_VERSION)
+    }
+
+    pub(crate) fn sync_schema_version_to(env: &Env, current: u32) -> bool {
+        let recorded = Self::get_recorded_version(env).unwrap_or(0);
+        if recorded == current {
+            return false;
+        }
+
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("evt_schv"), &current);
+        EventEmitter::emit_schema_version_changed(env, recorded, current);
+        true
+    }
 }
 
 // ===== EVENT EMISSION UTILITIES =====
@@ -2131,10 +2362,33 @@ pub struct DeprecatedCall {
     pub timestamp: u64,
 }
 
+const EVENTS_ENABLED_KEY: Symbol = symbol_short!("evt_on");
+
 /// Event emission utilities
 pub struct EventEmitter;
 
 impl EventEmitter {
+    /// Whether informational events are emitted (enabled by default).
+    ///
+    /// Only purely informational events can be turned off: statistics and
+    /// performance metrics, bet status updates, diagnostics and error logs,
+    /// oracle health and storage maintenance. Lifecycle, fund-movement,
+    /// resolution, dispute, admin and upgrade events always fire.
+    pub fn events_enabled(env: &Env) -> bool {
+        env.storage()
+            .persistent()
+            .get(&EVENTS_ENABLED_KEY)
+            .unwrap_or(true)
+    }
+
+    /// Turn informational events on or off (admin only).
+    pub fn set_events_enabled(env: &Env, admin: &Address, enabled: bool) -> Result<(), Error> {
+        admin.require_auth();
+        crate::resolution::MarketResolutionValidator::validate_admin_permissions(env, admin)?;
+        env.storage().persistent().set(&EVENTS_ENABLED_KEY, &enabled);
+        Ok(())
+    }
+
     /// Emit market created event
     pub fn emit_market_created(
         env: &Env,
@@ -2189,6 +2443,19 @@ impl EventEmitter {
             .publish((symbol_short!("res_tmo"), market_id.clone()), event);
     }
 
+    /// Emit market awaiting resolution event
+    pub fn emit_market_awaiting_resolution(env: &Env, market_id: &Symbol, end_time: u64) {
+        let event = MarketAwaitingResolutionEvent {
+            market_id: market_id.clone(),
+            end_time,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("mkt_await"), &event);
+        env.events()
+            .publish((symbol_short!("mkt_await"), market_id.clone()), event);
+    }
+
     /// Emit event created event
     pub fn emit_event_created(
         env: &Env,
@@ -2241,6 +2508,9 @@ impl EventEmitter {
         total_bets: u64,
         active_markets: u32,
     ) {
+        if !Self::events_enabled(env) {
+            return;
+        }
         let event = StatisticsUpdatedEvent {
             total_volume,
             total_bets,
@@ -2330,6 +2600,9 @@ impl EventEmitter {
         new_status: &String,
         payout_amount: Option<i128>,
     ) {
+        if !Self::events_enabled(env) {
+            return;
+        }
         let event = BetStatusUpdatedEvent {
             market_id: market_id.clone(),
             bettor: bettor.clone(),
@@ -2356,7 +2629,7 @@ impl EventEmitter {
         feed_id: &String,
         price: i128,
         threshold: i128,
-        comparison: &String,
+        comparison: &ComparisonOp,
     ) {
         let schema = EventSchemaRegistry::get_schema(env, "oracle_result")
             .unwrap_or(EventSchemaEntry {
@@ -2370,7 +2643,7 @@ impl EventEmitter {
             feed_id: feed_id.clone(),
             price,
             threshold,
-            comparison: comparison.clone(),
+            comparison: *comparison,
             timestamp: env.ledger().timestamp(),
         };
 
@@ -2437,7 +2710,7 @@ impl EventEmitter {
         outcome: &String,
         price: i128,
         threshold: i128,
-        comparison: &String,
+        comparison: &ComparisonOp,
         provider: &String,
         feed_id: &String,
         confidence_score: u32,
@@ -2449,7 +2722,7 @@ impl EventEmitter {
             outcome: outcome.clone(),
             price,
             threshold,
-            comparison: comparison.clone(),
+            comparison: *comparison,
             provider: provider.clone(),
             feed_id: feed_id.clone(),
             confidence_score,
@@ -2595,6 +2868,9 @@ impl EventEmitter {
         current_status: bool,
         consecutive_failures: u32,
     ) {
+        if !Self::events_enabled(env) {
+            return;
+        }
         let event = OracleHealthStatusEvent {
             oracle_address: oracle_address.clone(),
             provider: provider.clone(),
@@ -2640,6 +2916,27 @@ impl EventEmitter {
         );
     }
 
+    /// Emit event when an admin corrects the resolved outcome of `market_id`.
+    pub fn emit_resolution_corrected(
+        env: &Env,
+        market_id: &Symbol,
+        previous_outcomes: &Vec<String>,
+        corrected_outcome: &String,
+        admin: &Address,
+    ) {
+        let event = ResolutionCorrectedEvent {
+            market_id: market_id.clone(),
+            previous_outcomes: previous_outcomes.clone(),
+            corrected_outcome: corrected_outcome.clone(),
+            admin: admin.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("res_fix"), &event);
+        env.events()
+            .publish((symbol_short!("res_fix"), market_id.clone()), event);
+    }
+
     /// Emit event when minimum pool size is not met at resolution time
     pub fn emit_min_pool_size_not_met(
         env: &Env,
@@ -2658,6 +2955,44 @@ impl EventEmitter {
             .publish((symbol_short!("pool_lo"), market_id.clone()), event);
     }
 
+    /// Emit event when a market is cancelled for missing its minimum voter count
+    pub fn emit_min_voters_not_met(
+        env: &Env,
+        market_id: &Symbol,
+        voter_count: u32,
+        required_min: u32,
+    ) {
+        let event = MinVotersNotMetEvent {
+            market_id: market_id.clone(),
+            voter_count,
+            required_min,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("voters_lo"), &event);
+        env.events()
+            .publish((symbol_short!("voters_lo"), market_id.clone()), event);
+    }
+
+    /// Emit event when an oracle feed is permanently delisted
+    pub fn emit_feed_delisted(
+        env: &Env,
+        provider: &OracleProvider,
+        feed_id: &String,
+        admin: &Address,
+        voided_markets: &Vec<Symbol>,
+    ) {
+        let event = FeedDelistedEvent {
+            provider: provider.clone(),
+            feed_id: feed_id.clone(),
+            admin: admin.clone(),
+            voided_markets: voided_markets.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("feed_dlst"), &event);
+        env.events()
+            .publish((symbol_short!("feed_dlst"), provider.clone()), event);
+    }
+
     /// Emit dispute opened event.
     ///
     /// Topic and schema version are resolved from [`EventSchemaRegistry`].
@@ -2747,6 +3082,20 @@ impl EventEmitter {
             .publish((symbol_short!("fee_col"), market_id.clone()), event);
     }
 
+    /// Emit a treasury inflow event for fees collected from `market_id`.
+    pub fn emit_fees_collected(env: &Env, market_id: &Symbol, amount: i128, treasury: &Address) {
+        let event = FeesCollectedEvent {
+            market_id: market_id.clone(),
+            amount,
+            treasury: treasury.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("fees_in"), &event);
+        env.events()
+            .publish((symbol_short!("fees_in"), market_id.clone()), event);
+    }
+
     /// Emit an admin fee withdrawal attempt event.
     ///
     /// This event is emitted for both successful and blocked attempts, enabling
@@ -2803,6 +3152,67 @@ impl EventEmitter {
         Self::store_event(env, &symbol_short!("fwd_ok"), &event);
     }
 
+    /// Emit a keeper bounty payment event.
+    pub fn emit_keeper_bounty_paid(
+        env: &Env,
+        market_id: &Symbol,
+        keeper: &Address,
+        amount: i128,
+        remaining_fees: i128,
+    ) {
+        let event = KeeperBountyPaidEvent {
+            market_id: market_id.clone(),
+            keeper: keeper.clone(),
+            amount,
+            remaining_fees,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        env.events()
+            .publish((symbol_short!("kpr_paid"), market_id.clone()), event.clone());
+        Self::store_event(env, &symbol_short!("kpr_paid"), &event);
+    }
+
+    /// Emit a resolution bounty payment or refund event.
+    pub fn emit_resolution_bounty_released(
+        env: &Env,
+        market_id: &Symbol,
+        recipient: &Address,
+        amount: i128,
+        refunded: bool,
+    ) {
+        let event = ResolutionBountyReleasedEvent {
+            market_id: market_id.clone(),
+            recipient: recipient.clone(),
+            amount,
+            refunded,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        env.events()
+            .publish((symbol_short!("res_bnty"), market_id.clone()), event.clone());
+        Self::store_event(env, &symbol_short!("res_bnty"), &event);
+    }
+
+    /// Emit a resolution bond refund or forfeiture event.
+    pub fn emit_resolution_bond_settled(
+        env: &Env,
+        market_id: &Symbol,
+        amount: i128,
+        forfeited: bool,
+    ) {
+        let event = ResolutionBondSettledEvent {
+            market_id: market_id.clone(),
+            amount,
+            forfeited,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        env.events()
+            .publish((symbol_short!("res_bond"), market_id.clone()), event.clone());
+        Self::store_event(env, &symbol_short!("res_bond"), &event);
+    }
+
     /// Emit extension requested event
     pub fn emit_extension_requested(
         env: &Env,
@@ -2876,6 +3286,9 @@ impl EventEmitter {
         user: Option<Address>,
         market_id: Option<Symbol>,
     ) {
+        if !Self::events_enabled(env) {
+            return;
+        }
         let event = ErrorLoggedEvent {
             error_code,
             message: message.clone(),
@@ -2921,6 +3334,9 @@ impl EventEmitter {
         unit: &String,
         context: &String,
     ) {
+        if !Self::events_enabled(env) {
+            return;
+        }
         let event = PerformanceMetricEvent {
             metric_name: metric_name.clone(),
             value,
@@ -3106,6 +3522,52 @@ impl EventEmitter {
             .publish((symbol_short!("mkt_close"), market_id.clone()), event);
     }
 
+    /// Emit market deleted event
+    pub fn emit_market_deleted(
+        env: &Env,
+        market_id: &Symbol,
+        creator: &Address,
+        refunded_fee: i128,
+    ) {
+        let event = MarketDeletedEvent {
+            market_id: market_id.clone(),
+            creator: creator.clone(),
+            refunded_fee,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("mkt_del"), &event);
+        env.events()
+            .publish((symbol_short!("mkt_del"), market_id.clone()), event);
+    }
+
+    /// Emit creator suspended event.
+    pub fn emit_creator_suspended(env: &Env, creator: &Address, overturn_count: u32, threshold: u32) {
+        let event = CreatorSuspendedEvent {
+            creator: creator.clone(),
+            overturn_count,
+            threshold,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("crt_susp"), &event);
+        env.events()
+            .publish((symbol_short!("crt_susp"), creator.clone()), event);
+    }
+
+    /// Emit creator reinstated event.
+    pub fn emit_creator_reinstated(env: &Env, creator: &Address, admin: &Address) {
+        let event = CreatorReinstatedEvent {
+            creator: creator.clone(),
+            admin: admin.clone(),
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("crt_rein"), &event);
+        env.events()
+            .publish((symbol_short!("crt_rein"), creator.clone()), event);
+    }
+
     /// Emit refund on oracle failure event (market cancelled, all bets refunded in full).
     pub fn emit_refund_on_oracle_failure(env: &Env, market_id: &Symbol, total_refunded: i128) {
         let event = RefundOnOracleFailureEvent {
@@ -3237,6 +3699,9 @@ impl EventEmitter {
 
     /// Emit storage cleanup event
     pub fn emit_storage_cleanup_event(env: &Env, market_id: &Symbol, cleanup_type: &String) {
+        if !Self::events_enabled(env) {
+            return;
+        }
         let event = StorageCleanupEvent {
             market_id: market_id.clone(),
             cleanup_type: cleanup_type.clone(),
@@ -3254,6 +3719,9 @@ impl EventEmitter {
         market_id: &Symbol,
         optimization_type: &String,
     ) {
+        if !Self::events_enabled(env) {
+            return;
+        }
         let event = StorageOptimizationEvent {
             market_id: market_id.clone(),
             optimization_type: optimization_type.clone(),
@@ -3421,6 +3889,22 @@ impl EventEmitter {
             .publish((symbol_short!("win_clm"), market_id.clone()), event);
     }
 
+    /// Emit stake refunded event
+    ///
+    /// Emits an event when `amount` of `user`'s stake on `market_id` is
+    /// returned to them. Always emitted, as it records a movement of funds.
+    pub fn emit_stake_refunded(env: &Env, market_id: &Symbol, user: &Address, amount: i128) {
+        let event = StakeRefundedEvent {
+            market_id: market_id.clone(),
+            user: user.clone(),
+            amount,
+            timestamp: env.ledger().timestamp(),
+        };
+        Self::store_event(env, &symbol_short!("stk_ref"), &event);
+        env.events()
+            .publish((symbol_short!("stk_ref"), market_id.clone()), event);
+    }
+
     /// Emit winnings claimed batch event
     ///
     /// Emits an event when a user claims winnings from multiple markets in a batch.
@@ -3766,6 +4250,9 @@ impl EventEmitter {
     /// EventEmitter::emit_error_event(&env, Error::NothingToClaim, &context);
     /// ```
     pub fn emit_diagnostic_event(env: &Env, error: Error, context: &crate::err::ErrorContext) {
+        if !Self::events_enabled(env) {
+            return;
+        }
         let error_code = error as u32;
 
         // Convert error enum to message string
@@ -3903,6 +4390,19 @@ impl EventEmitter {
             .publish((symbol_short!("up_grade"), upgrade_id.clone()), event);
     }
 
+    /// Emit event schema version change notice for indexers
+    pub fn emit_schema_version_changed(env: &Env, old_version: u32, new_version: u32) {
+        let event = SchemaVersionChangedEvent {
+            old_version,
+            new_version,
+            timestamp: env.ledger().timestamp(),
+        };
+
+        Self::store_event(env, &symbol_short!("schema_v"), &event);
+        env.events()
+            .publish((symbol_short!("schema_v"), new_version), event);
+    }
+
     /// Emit contract rollback event when contract is rolled back
     pub fn emit_contract_rollback_event(
         env: &Env,
@@ -4409,7 +4909,7 @@ impl EventTestingUtils {
             feed_id: String::from_str(env, "BTC/USD"),
             price: 2500000,
             threshold: 2500000,
-            comparison: String::from_str(env, "gt"),
+            comparison: ComparisonOp::Gt,
             timestamp: env.ledger().timestamp(),
         }
     }
@@ -4800,7 +5300,7 @@ mod event_schema_registry_tests {
             let result = soroban_sdk::String::from_str(&env, "Yes");
             let provider = soroban_sdk::String::from_str(&env, "Reflector");
             let feed_id = soroban_sdk::String::from_str(&env, "BTC/USD");
-            let comparison = soroban_sdk::String::from_str(&env, "gte");
+            let comparison = ComparisonOp::Gte;
             // Should not panic – registry supplies the topic.
             EventEmitter::emit_oracle_result(
                 &env, &market_id, &result, &provider, &feed_id, 52_000_00000000,
@@ -5156,3 +5656,177 @@ mod focused_dispute_tests {
         assert!(found, "DisputeOpenedEvent not found with correct topic structure");
     }
 }
+
+#[cfg(test)]
+mod schema_version_notice_tests {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, testutils::Events, BytesN, Env, TryIntoVal, Val};
+
+    fn schema_notices(env: &Env) -> soroban_sdk::Vec<SchemaVersionChangedEvent> {
+        let mut notices = soroban_sdk::Vec::new(env);
+        for event in env.events().all().events().iter() {
+            let body = match &event.body {
+                soroban_sdk::xdr::ContractEventBody::V0(v0) => v0,
+            };
+            let topic0: Result<Symbol, _> = body.topics.get(0).unwrap().clone().try_into_val(env);
+            if topic0 == Ok(symbol_short!("schema_v")) {
+                let data: Val = body.data.clone().try_into_val(env).unwrap();
+                notices.push_back(data.try_into_val(env).unwrap());
+            }
+        }
+        notices
+    }
+
+    #[test]
+    fn test_schema_bump_emits_old_and_new_versions() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        env.as_contract(&contract_id, || {
+            // Initialization records the current version
+            assert!(EventSchemaRegistry::sync_schema_version(&env));
+            let notices = schema_notices(&env);
+            assert_eq!(notices.len(), 1);
+            assert_eq!(notices.get(0).unwrap().old_version, 0);
+            assert_eq!(notices.get(0).unwrap().new_version, CURRENT_EVENT_SCHEMA_VERSION);
+        });
+
+        // Upgrade to a build with a bumped schema
+        let bumped = CURRENT_EVENT_SCHEMA_VERSION + 1;
+        env.as_contract(&contract_id, || {
+            assert!(EventSchemaRegistry::sync_schema_version_to(&env, bumped));
+            let notices = schema_notices(&env);
+            assert_eq!(notices.len(), 1);
+            let notice = notices.get(0).unwrap();
+            assert_eq!(notice.old_version, CURRENT_EVENT_SCHEMA_VERSION);
+            assert_eq!(notice.new_version, bumped);
+            assert_eq!(EventSchemaRegistry::get_recorded_version(&env), Some(bumped));
+        });
+    }
+
+    #[test]
+    fn test_unchanged_schema_emits_nothing() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        env.as_contract(&contract_id, || {
+            EventSchemaRegistry::sync_schema_version(&env);
+        });
+
+        env.as_contract(&contract_id, || {
+            assert!(!EventSchemaRegistry::sync_schema_version(&env));
+            assert_eq!(schema_notices(&env).len(), 0);
+            assert_eq!(
+                EventSchemaRegistry::get_recorded_version(&env),
+                Some(CURRENT_EVENT_SCHEMA_VERSION)
+            );
+        });
+    }
+
+    #[test]
+    fn test_schema_notice_waits_for_migrate_after_upgrade() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &None, &None);
+        client.track_contract_version(&crate::versioning::Version::new(
+            &env,
+            1,
+            0,
+            0,
+            String::from_str(&env, "v1"),
+            false,
+        ));
+        // Recorded by a build whose schema predates this one
+        let previous = CURRENT_EVENT_SCHEMA_VERSION - 1;
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&symbol_short!("evt_schv"), &previous);
+        });
+
+        // The swap completes on the outgoing build, which cannot know the new schema
+        let new_wasm = env.deployer().upload_contract_wasm(crate::bandprotocol::WASM);
+        client.upgrade_contract(&admin, &new_wasm, &BytesN::from_array(&env, &[0u8; 32]));
+        assert_eq!(schema_notices(&env).len(), 0);
+
+        // The incoming build announces its schema from `migrate`
+        env.as_contract(&contract_id, || {
+            assert_eq!(crate::PredictifyHybrid::migrate(env.clone(), admin.clone()), Ok(true));
+            let notices = schema_notices(&env);
+            assert_eq!(notices.len(), 1);
+            assert_eq!(notices.get(0).unwrap().old_version, previous);
+            assert_eq!(notices.get(0).unwrap().new_version, CURRENT_EVENT_SCHEMA_VERSION);
+            assert_eq!(
+                EventSchemaRegistry::get_recorded_version(&env),
+                Some(CURRENT_EVENT_SCHEMA_VERSION)
+            );
+            assert_eq!(crate::PredictifyHybrid::migrate(env.clone(), admin.clone()), Ok(false));
+        });
+    }
+}
+
+#[cfg(test)]
+mod event_toggle_tests {
+    use super::*;
+    use soroban_sdk::{testutils::Address as _, testutils::Events, TryIntoVal};
+
+    fn emitted_topics(env: &Env) -> std::vec::Vec<Symbol> {
+        env.events()
+            .all()
+            .events()
+            .iter()
+            .filter_map(|event| {
+                let body = match &event.body {
+                    soroban_sdk::xdr::ContractEventBody::V0(v0) => v0,
+                };
+                body.topics.get(0)?.clone().try_into_val(env).ok()
+            })
+            .collect()
+    }
+
+    fn emit_informational_and_resolution(env: &Env, market_id: &Symbol) {
+        EventEmitter::emit_statistics_updated(env, 1_000, 3, 1);
+        EventEmitter::emit_market_resolved(
+            env,
+            market_id,
+            &String::from_str(env, "yes"),
+            &String::from_str(env, "N/A"),
+            &String::from_str(env, "Manual"),
+            &String::from_str(env, "Manual"),
+            100,
+        );
+    }
+
+    #[test]
+    fn test_disabling_events_suppresses_only_informational_ones() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &None, &None);
+        let market_id = Symbol::new(&env, "toggle_mkt");
+
+        assert!(client.events_enabled());
+        env.as_contract(&contract_id, || {
+            emit_informational_and_resolution(&env, &market_id);
+            let topics = emitted_topics(&env);
+            assert!(topics.contains(&symbol_short!("stats_upd")));
+            assert!(topics.contains(&symbol_short!("mkt_res")));
+        });
+
+        assert_eq!(
+            client.try_set_events_enabled(&Address::generate(&env), &false),
+            Err(Ok(Error::Unauthorized))
+        );
+        client.set_events_enabled(&admin, &false);
+        assert!(!client.events_enabled());
+        env.as_contract(&contract_id, || {
+            emit_informational_and_resolution(&env, &market_id);
+            let topics = emitted_topics(&env);
+            assert!(!topics.contains(&symbol_short!("stats_upd")));
+            assert!(topics.contains(&symbol_short!("mkt_res")));
+        });
+    }
+}
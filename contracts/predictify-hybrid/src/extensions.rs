@@ -234,6 +234,63 @@ impl ExtensionManager {
         Ok(())
     }
 
+    /// Sets a market's `max_extension_days` to `new_max` (admin only).
+    ///
+    /// Lets governance raise the per-market extension allowance, which is fixed
+    /// at creation. The new cap may never fall below the days already used,
+    /// so existing extensions always stay within it. Logged as an admin action.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Unauthorized` - `admin` is not the contract admin
+    /// * `Error::MarketNotFound` - Market does not exist
+    /// * `Error::MarketResolved` - Market is already resolved
+    /// * `Error::InvalidState` - Market is cancelled or closed
+    /// * `Error::InvalidDuration` - `new_max` is below `total_extension_days`
+    pub fn raise_extension_cap(
+        env: &Env,
+        admin: &Address,
+        market_id: &Symbol,
+        new_max: u32,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        crate::resolution::MarketResolutionValidator::validate_admin_permissions(env, admin)?;
+
+        let mut market = MarketStateManager::get_market(env, market_id)?;
+        if market.winning_outcomes.is_some() {
+            return Err(Error::MarketResolved);
+        }
+        if matches!(market.state, MarketState::Cancelled | MarketState::Closed) {
+            return Err(Error::InvalidState);
+        }
+        if new_max < market.total_extension_days {
+            return Err(Error::InvalidDuration);
+        }
+
+        let old_max = market.max_extension_days;
+        market.max_extension_days = new_max;
+        MarketStateManager::update_market(env, market_id, &market);
+
+        let mut params = soroban_sdk::Map::new(env);
+        params.set(
+            String::from_str(env, "old_max"),
+            String::from_str(env, &alloc::format!("{}", old_max)),
+        );
+        params.set(
+            String::from_str(env, "new_max"),
+            String::from_str(env, &alloc::format!("{}", new_max)),
+        );
+        crate::admin::AdminActionLogger::log_action(
+            env,
+            admin,
+            "raise_extension_cap",
+            Some(String::from_str(env, "market_id")),
+            params,
+            true,
+            None,
+        )
+    }
+
     /// Retrieves the complete extension history for a specific market.
     ///
     /// This function returns a chronological list of all extensions that have been
@@ -607,17 +664,18 @@ impl ExtensionValidator {
 
         let current_time = env.ledger().timestamp();
 
-        // Reject if the market has already passed its end time.
-        if current_time >= market.end_time {
-            return Err(Error::ExtensionDenied);
-        }
-
-        // Reject if the resulting deadline would still be in the past or present.
-        // This guards against edge cases where end_time is very close to now.
+        // The new deadline is measured against the ledger clock, not the old
+        // end_time: an old end_time may itself be in the past, so "later than
+        // before" is not enough.
         let new_end_time = market
             .end_time
             .saturating_add((additional_days as u64) * 24 * 60 * 60);
         if new_end_time <= current_time {
+            return Err(Error::InvalidExtension);
+        }
+
+        // Reject if the market has already passed its end time.
+        if current_time >= market.end_time {
             return Err(Error::ExtensionDenied);
         }
 
@@ -837,6 +895,7 @@ use crate::markets::{MarketStateManager, MarketUtils};
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::oracles::ComparisonOp;
     use crate::types::ExtensionStats;
     use soroban_sdk::testutils::{Address as _, Ledger, LedgerInfo};
 
@@ -870,6 +929,160 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_extension_end_time_checked_against_ledger_time() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let market_id = symbol_short!("lapsed");
+
+        env.as_contract(&contract_id, || {
+            env.ledger().with_mut(|li| li.timestamp = 1_000);
+            let market = Market::new(
+                &env,
+                Address::generate(&env),
+                String::from_str(&env, "Lapsed market"),
+                soroban_sdk::vec![
+                    &env,
+                    String::from_str(&env, "yes"),
+                    String::from_str(&env, "no"),
+                ],
+                1_000 + 86_400,
+                crate::types::OracleConfig::new(
+                    crate::types::OracleProvider::reflector(),
+                    Address::generate(&env),
+                    String::from_str(&env, "BTC/USD"),
+                    100,
+                    ComparisonOp::Gt,
+                ),
+                None,
+                0,
+                MarketState::Active,
+            );
+            env.storage().persistent().set(&market_id, &market);
+            let day = 24 * 60 * 60;
+
+            // Old end_time is ten days in the past: a 5-day extension is later
+            // than before but still behind the ledger clock
+            env.ledger().with_mut(|li| li.timestamp = market.end_time + 10 * day);
+            assert_eq!(
+                ExtensionValidator::validate_extension_conditions(&env, &market_id, 5),
+                Err(Error::InvalidExtension)
+            );
+            // Landing exactly on the current time is not strictly in the future
+            assert_eq!(
+                ExtensionValidator::validate_extension_conditions(&env, &market_id, 10),
+                Err(Error::InvalidExtension)
+            );
+            // A future end time gets past the clock check; the lapsed market is
+            // still not extendable
+            assert_eq!(
+                ExtensionValidator::validate_extension_conditions(&env, &market_id, 11),
+                Err(Error::ExtensionDenied)
+            );
+
+            // Before the old end_time the same extension goes through
+            env.ledger().with_mut(|li| li.timestamp = market.end_time - 1);
+            assert_eq!(
+                ExtensionValidator::validate_extension_conditions(&env, &market_id, 5),
+                Ok(())
+            );
+        });
+    }
+
+    #[test]
+    fn test_raise_extension_cap_unblocks_extensions() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let admin = Address::generate(&env);
+        let market_id = symbol_short!("longrun");
+        let token_id = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "Admin"), &admin);
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "TokenID"), &token_id);
+            let mut market = Market::new(
+                &env,
+                admin.clone(),
+                String::from_str(&env, "Long-running market"),
+                soroban_sdk::vec![
+                    &env,
+                    String::from_str(&env, "yes"),
+                    String::from_str(&env, "no"),
+                ],
+                env.ledger().timestamp() + 86_400,
+                crate::types::OracleConfig::new(
+                    crate::types::OracleProvider::reflector(),
+                    Address::generate(&env),
+                    String::from_str(&env, "BTC/USD"),
+                    100,
+                    ComparisonOp::Gt,
+                ),
+                None,
+                0,
+                MarketState::Active,
+            );
+            market.total_extension_days = 28;
+            env.storage().persistent().set(&market_id, &market);
+
+            let reason = String::from_str(&env, "Awaiting final data");
+            assert_eq!(
+                ExtensionManager::extend_market_duration(
+                    &env,
+                    admin.clone(),
+                    market_id.clone(),
+                    5,
+                    reason.clone()
+                ),
+                Err(Error::InvalidDuration)
+            );
+
+            assert_eq!(
+                ExtensionManager::raise_extension_cap(
+                    &env,
+                    &Address::generate(&env),
+                    &market_id,
+                    40
+                ),
+                Err(Error::Unauthorized)
+            );
+            ExtensionManager::raise_extension_cap(&env, &admin, &market_id, 40).unwrap();
+            ExtensionManager::extend_market_duration(
+                &env,
+                admin.clone(),
+                market_id.clone(),
+                5,
+                reason,
+            )
+            .unwrap();
+
+            let market = MarketStateManager::get_market(&env, &market_id).unwrap();
+            assert_eq!(market.max_extension_days, 40);
+            assert_eq!(market.total_extension_days, 33);
+
+            // The cap can never drop below the 33 days already used
+            assert_eq!(
+                ExtensionManager::raise_extension_cap(&env, &admin, &market_id, 32),
+                Err(Error::InvalidDuration)
+            );
+            let logged: crate::admin::AdminAction = env
+                .storage()
+                .persistent()
+                .get(&Symbol::new(&env, "admin_action"))
+                .unwrap();
+            assert_eq!(
+                logged.action,
+                String::from_str(&env, "raise_extension_cap")
+            );
+        });
+    }
+
     #[test]
     fn test_extension_fee_calculation() {
         assert_eq!(
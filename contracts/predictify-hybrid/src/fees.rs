@@ -3,8 +3,9 @@ use soroban_sdk::{contracttype, symbol_short, vec, Address, BytesN, Env, Map, St
 use crate::err::Error;
 use crate::markets::{MarketStateManager, MarketUtils};
 use crate::reentrancy_guard::ReentrancyGuard;
-use crate::types::Market;
+use crate::types::{Market, PayoutModel};
 use crate::reentrancy_guard::GuardError as ReentrancyError;
+use crate::utils::{NumericUtils, PayoutRounding};
 
 /// Fee management system for Predictify Hybrid contract
 ///
@@ -720,6 +721,13 @@ pub struct FeeManager;
 
 impl FeeManager {
     /// Collect platform fees from a market
+    ///
+    /// The vault is credited with the platform fee calculated on the market's
+    /// `total_staked`, not with the fees its claims have withheld so far; the
+    /// collection settles the market's [`PendingFees`] entry whatever amount
+    /// it holds. A collection rejected by validation (market unresolved, stake
+    /// below `FEE_COLLECTION_THRESHOLD`, fee outside the fee bounds) changes
+    /// nothing, so the pending entry stays until a collection succeeds.
     pub fn collect_fees(env: &Env, admin: Address, market_id: Symbol) -> Result<i128, Error> {
         // Require authentication from the admin
         // Note: admin.require_auth() causes "Error(Auth, ExistingValue)" panic in tests with mock_all_auths
@@ -752,6 +760,7 @@ impl FeeManager {
         // fee withdrawal function which enforces a timelock/schedule.
 
         FeeTracker::record_fee_collection(env, &market_id, fee_amount, &admin)?;
+        PendingFees::clear(env, &market_id);
 
         // Mark fees as collected
         MarketStateManager::mark_fees_collected(&mut market, Some(&market_id));
@@ -765,6 +774,12 @@ impl FeeManager {
             fee_amount,
             &soroban_sdk::String::from_str(env, "platform_fee"),
         );
+        crate::events::EventEmitter::emit_fees_collected(
+            env,
+            &market_id,
+            fee_amount,
+            &env.current_contract_address(),
+        );
 
         crate::audit_trail::AuditTrailManager::append_record(
             env,
@@ -1533,13 +1548,17 @@ pub struct FeeUtils;
 
 impl FeeUtils {
     /// Transfer fees to admin
+    ///
+    /// This is the only path that moves fees out of the contract, so it is also
+    /// where the lifetime fee total is advanced.
     pub fn transfer_fees_to_admin(env: &Env, admin: &Address, amount: i128) -> Result<(), Error> {
         let token_client = MarketUtils::get_token_client(env)?;
         ReentrancyGuard::with_external_call(env, || {
             token_client.transfer(&env.current_contract_address(), admin, &amount);
             Ok::<(), ReentrancyError>(())
         })
-        .map_err(|_| Error::InvalidState)
+        .map_err(|_| Error::InvalidState)?;
+        FeeTracker::record_lifetime_fees(env, amount)
     }
 
     /// Get fee statistics for a market
@@ -1670,6 +1689,26 @@ impl FeeTracker {
         Ok(env.storage().persistent().get(&total_key).unwrap_or(0))
     }
 
+    /// Add `amount` to the lifetime total of fees transferred out to the treasury.
+    ///
+    /// Unlike `tot_fees`, which is the withdrawable vault balance, this total
+    /// never decreases and does not count fees that are only accrued.
+    pub fn record_lifetime_fees(env: &Env, amount: i128) -> Result<(), Error> {
+        let updated_total = FeeCalculator::checked_fee_add(Self::get_lifetime_fees(env), amount)?;
+        env.storage()
+            .persistent()
+            .set(&LIFETIME_FEES_KEY, &updated_total);
+        Ok(())
+    }
+
+    /// Get the lifetime total of fees transferred out to the treasury.
+    pub fn get_lifetime_fees(env: &Env) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&LIFETIME_FEES_KEY)
+            .unwrap_or(0)
+    }
+
     /// Record fee structure update
     pub fn record_fee_structure_update(
         env: &Env,
@@ -1687,6 +1726,182 @@ impl FeeTracker {
     }
 }
 
+// ===== PENDING FEES =====
+
+const PENDING_FEES_KEY: Symbol = symbol_short!("pend_fee");
+const PENDING_FEES_TOTAL_KEY: Symbol = symbol_short!("pend_tot");
+
+/// Platform fees withheld from winners' claims but not yet collected into the
+/// fee vault by `FeeManager::collect_fees`.
+///
+/// Between the two the fees are a liability of the contract; tracking them per
+/// market and in total lets the treasury reconcile its balance. Collection
+/// credits the vault with the market's calculated platform fee and clears the
+/// pending entry, so the two amounts need not match.
+pub struct PendingFees;
+
+impl PendingFees {
+    /// Fees accrued on `market_id` and not yet collected.
+    pub fn get(env: &Env, market_id: &Symbol) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&(PENDING_FEES_KEY, market_id.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Fees accrued across all markets and not yet collected.
+    pub fn get_total(env: &Env) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&PENDING_FEES_TOTAL_KEY)
+            .unwrap_or(0)
+    }
+
+    /// Record `amount` of fees withheld from a claim on `market_id`.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InvalidInput` - A pending total would overflow
+    pub fn accrue(env: &Env, market_id: &Symbol, amount: i128) -> Result<(), Error> {
+        if let Some(totals) = Self::checked_accrue(env, market_id, amount)? {
+            Self::store(env, market_id, totals);
+        }
+        Ok(())
+    }
+
+    /// The `(market, contract-wide)` pending totals after accruing `amount` on
+    /// `market_id`, without storing them; `None` when there is nothing to accrue.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InvalidInput` - A pending total would overflow
+    pub fn checked_accrue(
+        env: &Env,
+        market_id: &Symbol,
+        amount: i128,
+    ) -> Result<Option<(i128, i128)>, Error> {
+        if amount <= 0 {
+            return Ok(None);
+        }
+        let market_total = FeeCalculator::checked_fee_add(Self::get(env, market_id), amount)?;
+        let total = FeeCalculator::checked_fee_add(Self::get_total(env), amount)?;
+        Ok(Some((market_total, total)))
+    }
+
+    /// Store totals computed by [`Self::checked_accrue`].
+    pub fn store(env: &Env, market_id: &Symbol, (market_total, total): (i128, i128)) {
+        env.storage()
+            .persistent()
+            .set(&(PENDING_FEES_KEY, market_id.clone()), &market_total);
+        env.storage()
+            .persistent()
+            .set(&PENDING_FEES_TOTAL_KEY, &total);
+    }
+
+    /// Clear the pending fees of `market_id` once they have been collected,
+    /// returning the amount cleared.
+    pub fn clear(env: &Env, market_id: &Symbol) -> i128 {
+        let amount = Self::get(env, market_id);
+        if amount > 0 {
+            env.storage()
+                .persistent()
+                .remove(&(PENDING_FEES_KEY, market_id.clone()));
+            let total = (Self::get_total(env) - amount).max(0);
+            env.storage()
+                .persistent()
+                .set(&PENDING_FEES_TOTAL_KEY, &total);
+        }
+        amount
+    }
+}
+
+// ===== BONUS POOL =====
+
+const BONUS_POOL_KEY: Symbol = symbol_short!("bonus_pl");
+
+/// Largest winner bonus on a stake-back market: 100% of the stake, in bps.
+pub const MAX_STAKE_BACK_BONUS_BPS: u32 = 10_000;
+
+/// Contract-wide pool that pays the winners' bonus on
+/// `PayoutModel::StakeBackPlusBonus` markets.
+///
+/// Those markets refund every stake, so the bonus cannot come from the losing
+/// side; sponsors fund the pool up front instead.
+pub struct BonusPool;
+
+impl BonusPool {
+    /// Current bonus-pool balance (0 if never funded).
+    pub fn get_balance(env: &Env) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&BONUS_POOL_KEY)
+            .unwrap_or(0)
+    }
+
+    /// Transfer `amount` of the staking token from `funder` into the pool and
+    /// return the new balance.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InvalidInput` - `amount` is not positive, or the balance would overflow
+    /// * `Error::InvalidState` - No staking token is configured
+    pub fn fund(env: &Env, funder: &Address, amount: i128) -> Result<i128, Error> {
+        funder.require_auth();
+        if amount <= 0 {
+            return Err(Error::InvalidInput);
+        }
+        let updated = FeeCalculator::checked_fee_add(Self::get_balance(env), amount)?;
+
+        let token_client = MarketUtils::get_token_client(env)?;
+        ReentrancyGuard::with_external_call(env, || {
+            token_client.transfer(funder, &env.current_contract_address(), &amount);
+            Ok::<(), ReentrancyError>(())
+        })
+        .map_err(|_| Error::InvalidState)?;
+
+        env.storage().persistent().set(&BONUS_POOL_KEY, &updated);
+        Ok(updated)
+    }
+
+    /// Bonus owed on `stake` at `bonus_bps`, capped at what the pool holds.
+    pub fn bonus_for(env: &Env, stake: i128, bonus_bps: u32) -> Result<i128, Error> {
+        let bonus = stake
+            .checked_mul(bonus_bps as i128)
+            .ok_or(Error::InvalidInput)?
+            / MAX_STAKE_BACK_BONUS_BPS as i128;
+        Ok(bonus.min(Self::get_balance(env)).max(0))
+    }
+
+    /// Remove a paid-out `amount` from the pool.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InsufficientBalance` - The pool cannot cover `amount`
+    pub fn debit(env: &Env, amount: i128) -> Result<(), Error> {
+        let updated = Self::checked_debit(env, amount)?;
+        Self::set_balance(env, updated);
+        Ok(())
+    }
+
+    /// Pool balance left after paying out `amount`, without storing it.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InsufficientBalance` - The pool cannot cover `amount`
+    pub fn checked_debit(env: &Env, amount: i128) -> Result<i128, Error> {
+        let balance = Self::get_balance(env);
+        if amount > balance {
+            return Err(Error::InsufficientBalance);
+        }
+        Ok(balance - amount)
+    }
+
+    /// Store a balance computed by [`Self::checked_debit`].
+    pub fn set_balance(env: &Env, balance: i128) {
+        env.storage().persistent().set(&BONUS_POOL_KEY, &balance);
+    }
+}
+
 // ===== ADMIN FEE WITHDRAWAL SCHEDULE =====
 
 /// Status for an admin fee withdrawal attempt.
@@ -1722,6 +1937,7 @@ pub struct FeeWithdrawalSchedule {
 }
 
 const FEE_VAULT_KEY: Symbol = symbol_short!("tot_fees");
+const LIFETIME_FEES_KEY: Symbol = symbol_short!("life_fees");
 const WITHDRAWAL_LAST_TS_KEY: Symbol = symbol_short!("wd_last");
 const WITHDRAWAL_SCHEDULE_KEY: Symbol = symbol_short!("wd_cfg");
 
@@ -2113,166 +2329,1100 @@ impl FeeConfigManager {
     }
 }
 
-// ===== FEE ANALYTICS =====
+// ===== INSURANCE FUND =====
 
-impl FeeAnalytics {
-    /// Calculate fee analytics
-    pub fn calculate_analytics(env: &Env) -> Result<FeeAnalytics, Error> {
-        let total_fees = FeeTracker::get_total_fees_collected(env)?;
-        let history = FeeTracker::get_fee_history(env)?;
-        let markets_with_fees = history.len() as u32;
+/// Storage key for the insurance-fund balance (i128, base token units).
+const INSURANCE_FUND_KEY: Symbol = symbol_short!("ins_fund");
 
-        let average_fee = if markets_with_fees > 0 {
-            total_fees / (markets_with_fees as i128)
-        } else {
-            0
-        };
+/// Coverage reported when there are no outstanding liabilities (100% in bps).
+pub const FULL_COVERAGE_BPS: u32 = 10_000;
 
-        // Create fee distribution map
-        let fee_distribution = Map::new(env);
-        // TODO: Implement proper fee distribution calculation
+/// Storage key for the share of each losing pool diverted to the fund (u32 bps).
+const INSURANCE_BPS_KEY: Symbol = symbol_short!("ins_bps");
 
-        Ok(FeeAnalytics {
-            total_fees_collected: total_fees,
-            markets_with_fees,
-            average_fee_per_market: average_fee,
-            collection_history: history,
-            fee_distribution,
-        })
-    }
+/// Storage key for the address the fund is paid out to (Address).
+const INSURANCE_ADDRESS_KEY: Symbol = symbol_short!("ins_addr");
 
-    /// Get fee statistics for a specific market
-    pub fn get_market_fee_stats(market: &Market) -> Result<FeeBreakdown, Error> {
-        FeeCalculator::calculate_fee_breakdown(market)
+/// Insurance-fund accounting.
+///
+/// The fund is a single contract-wide balance that other modules credit
+/// (e.g. treasury overflow routing) and debit when covering shortfalls.
+/// The admin pays it out to the configured fund address with
+/// [`InsuranceFund::withdraw`].
+/// Risk tooling reads it through [`InsuranceFund::coverage_ratio_bps`].
+pub struct InsuranceFund;
+
+impl InsuranceFund {
+    /// Current insurance-fund balance (0 if never funded).
+    pub fn get_balance(env: &Env) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&INSURANCE_FUND_KEY)
+            .unwrap_or(0)
     }
 
-    /// Calculate fee efficiency (fees collected vs potential)
-    pub fn calculate_fee_efficiency(market: &Market) -> Result<f64, Error> {
-        let potential_fee = FeeCalculator::calculate_platform_fee(market)?;
-        let actual_fee = if market.fee_collected {
-            potential_fee
-        } else {
-            0
-        };
+    /// Add `amount` to the fund and return the new balance.
+    pub fn credit(env: &Env, amount: i128) -> Result<i128, Error> {
+        let updated = Self::checked_credit(env, amount)?;
+        Self::set_balance(env, updated);
+        Ok(updated)
+    }
 
-        if potential_fee == 0 {
-            return Ok(0.0);
+    /// Balance the fund would hold after crediting `amount`, without storing it.
+    pub fn checked_credit(env: &Env, amount: i128) -> Result<i128, Error> {
+        if amount <= 0 {
+            return Err(Error::InvalidInput);
         }
-
-        Ok((actual_fee as f64) / (potential_fee as f64))
+        FeeCalculator::checked_fee_add(Self::get_balance(env), amount)
     }
-}
-
-// ===== FEE TESTING UTILITIES =====
 
-#[cfg(test)]
-pub mod testing {
-    use super::*;
-    use soroban_sdk::testutils::Address as _;
+    /// Store a balance computed by [`Self::checked_credit`].
+    pub fn set_balance(env: &Env, balance: i128) {
+        env.storage().persistent().set(&INSURANCE_FUND_KEY, &balance);
+    }
 
-    /// Create a test fee configuration
-    pub fn create_test_fee_config() -> FeeConfig {
-        FeeConfig {
-            platform_fee_percentage: PLATFORM_FEE_PERCENTAGE,
-            creation_fee: MARKET_CREATION_FEE,
-            min_fee_amount: MIN_FEE_AMOUNT,
-            max_fee_amount: MAX_FEE_AMOUNT,
-            collection_threshold: FEE_COLLECTION_THRESHOLD,
-            fees_enabled: true,
+    /// Remove `amount` from the fund and return the new balance.
+    ///
+    /// Returns `Error::InsufficientBalance` if the fund cannot cover `amount`.
+    pub fn debit(env: &Env, amount: i128) -> Result<i128, Error> {
+        if amount <= 0 {
+            return Err(Error::InvalidInput);
+        }
+        let balance = Self::get_balance(env);
+        if amount > balance {
+            return Err(Error::InsufficientBalance);
         }
+        let updated = FeeCalculator::checked_fee_sub(balance, amount)?;
+        env.storage().persistent().set(&INSURANCE_FUND_KEY, &updated);
+        Ok(updated)
     }
 
-    /// Create a test fee collection record
-    pub fn create_test_fee_collection(
-        env: &Env,
-        market_id: Symbol,
-        amount: i128,
-        admin: Address,
-    ) -> FeeCollection {
-        FeeCollection {
-            market_id,
-            amount,
-            collected_by: admin,
-            timestamp: env.ledger().timestamp(),
-            fee_percentage: PLATFORM_FEE_PERCENTAGE,
-        }
+    /// Address the fund is paid out to, if the admin has set one.
+    pub fn get_fund_address(env: &Env) -> Option<Address> {
+        env.storage().persistent().get(&INSURANCE_ADDRESS_KEY)
     }
 
-    /// Create a test fee breakdown
-    pub fn create_test_fee_breakdown() -> FeeBreakdown {
-        FeeBreakdown {
-            total_staked: 1_000_000_000, // 100 XLM
-            fee_percentage: PLATFORM_FEE_PERCENTAGE,
-            fee_amount: 20_000_000, // 2 XLM
-            platform_fee: 20_000_000,
-            user_payout_amount: 980_000_000, // 98 XLM
-        }
+    /// Set the address [`Self::withdraw`] pays the fund out to (admin only).
+    pub fn set_fund_address(env: &Env, admin: &Address, address: &Address) -> Result<(), Error> {
+        FeeValidator::validate_admin_permissions(env, admin)?;
+        env.storage().persistent().set(&INSURANCE_ADDRESS_KEY, address);
+        Ok(())
     }
 
-    /// Validate fee configuration
-    pub fn validate_fee_config_structure(config: &FeeConfig) -> Result<(), Error> {
-        if config.platform_fee_percentage < 0 {
-            return Err(Error::InvalidInput);
-        }
+    /// Transfer `amount` of the fund to the fund address (admin only) and
+    /// return the remaining balance.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Unauthorized` - `admin` is not the contract admin
+    /// * `Error::ConfigNotFound` - No fund address has been set
+    /// * `Error::InvalidInput` - `amount` is not positive
+    /// * `Error::InsufficientBalance` - The fund cannot cover `amount`
+    pub fn withdraw(env: &Env, admin: &Address, amount: i128) -> Result<i128, Error> {
+        FeeValidator::validate_admin_permissions(env, admin)?;
+        let recipient = Self::get_fund_address(env).ok_or(Error::ConfigNotFound)?;
+        let remaining = Self::debit(env, amount)?;
 
-        if config.creation_fee < 0 {
-            return Err(Error::InvalidInput);
-        }
+        let token_client = MarketUtils::get_token_client(env)?;
+        ReentrancyGuard::with_external_call(env, || {
+            token_client.transfer(&env.current_contract_address(), &recipient, &amount);
+            Ok::<(), ReentrancyError>(())
+        })
+        .map_err(|_| Error::InvalidState)?;
 
-        if config.min_fee_amount < 0 {
-            return Err(Error::InvalidInput);
-        }
+        Ok(remaining)
+    }
 
-        if config.max_fee_amount < config.min_fee_amount {
+    /// Share of each market's losing pool diverted to the fund, in bps (0 if never set).
+    pub fn get_insurance_bps(env: &Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&INSURANCE_BPS_KEY)
+            .unwrap_or(0)
+    }
+
+    /// Set the losing-pool share diverted to the fund (admin only, at most 100%).
+    ///
+    /// Markets that already resolved keep the rate locked at resolution.
+    pub fn set_insurance_bps(env: &Env, admin: &Address, bps: u32) -> Result<(), Error> {
+        FeeValidator::validate_admin_permissions(env, admin)?;
+        if bps > FULL_COVERAGE_BPS {
             return Err(Error::InvalidInput);
         }
-
+        env.storage().persistent().set(&INSURANCE_BPS_KEY, &bps);
         Ok(())
     }
 
-    /// Validate fee collection record
-    pub fn validate_fee_collection_structure(collection: &FeeCollection) -> Result<(), Error> {
-        if collection.amount <= 0 {
-            return Err(Error::InvalidInput);
-        }
+    /// Insurance rate applying to `market_id`: its snapshot if it has
+    /// resolved, otherwise the current configured rate.
+    pub fn market_insurance_bps(env: &Env, market_id: &Symbol) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&(INSURANCE_BPS_KEY, market_id.clone()))
+            .unwrap_or_else(|| Self::get_insurance_bps(env))
+    }
 
-        if collection.fee_percentage < 0 {
-            return Err(Error::InvalidInput);
+    /// Like [`Self::market_insurance_bps`], but snapshots the rate on first use.
+    /// Called when the market resolves, so every claim on it is diverted at
+    /// the rate in force then, whatever the admin sets afterwards.
+    pub fn lock_market_insurance_bps(env: &Env, market_id: &Symbol) -> u32 {
+        let key = (INSURANCE_BPS_KEY, market_id.clone());
+        if let Some(bps) = env.storage().persistent().get(&key) {
+            return bps;
         }
-
-        Ok(())
+        let bps = Self::get_insurance_bps(env);
+        env.storage().persistent().set(&key, &bps);
+        bps
     }
 
-    /// Create test fee tier
-    pub fn create_test_fee_tier(env: &Env) -> FeeTier {
-        FeeTier {
-            min_size: 0,
-            max_size: 100_000_000, // 10 XLM
-            fee_percentage: 150,   // 1.5%
-            tier_name: String::from_str(env, "Small"),
-        }
+    /// Pool left for winners once `bps` of the losing pool
+    /// (`total_pool - winning_total`) is diverted to the fund.
+    pub fn winners_pool(total_pool: i128, winning_total: i128, bps: u32) -> Result<i128, Error> {
+        let losing_pool = (total_pool - winning_total).max(0);
+        let diverted = losing_pool
+            .checked_mul(bps as i128)
+            .ok_or(Error::InvalidInput)?
+            / FULL_COVERAGE_BPS as i128;
+        Ok(total_pool - diverted)
     }
 
-    /// Create test activity adjustment
-    pub fn create_test_activity_adjustment(env: &Env) -> ActivityAdjustment {
-        ActivityAdjustment {
-            activity_level: 50,
-            fee_multiplier: 110, // 10% increase
-            description: String::from_str(env, "Medium Activity"),
-        }
+    /// Split a winner's fee-adjusted share into `(payout, insurance_slice)`.
+    ///
+    /// `payout = user_share * winners_pool / winning_total`; the slice is what
+    /// the winner would have received without insurance minus `payout`.
+    pub fn split_winner_payout(
+        user_share: i128,
+        total_pool: i128,
+        winning_total: i128,
+        bps: u32,
+    ) -> Result<(i128, i128), Error> {
+        Self::split_winner_payout_rounded(
+            user_share,
+            total_pool,
+            winning_total,
+            bps,
+            PayoutRounding::Floor,
+        )
     }
 
-    /// Create test fee calculation factors
-    pub fn create_test_fee_calculation_factors(env: &Env) -> FeeCalculationFactors {
-        FeeCalculationFactors {
-            base_fee_percentage: 200,  // 2%
-            size_multiplier: 100,      // No change
-            activity_multiplier: 110,  // 10% increase
-            complexity_factor: 100,    // No change
-            final_fee_percentage: 220, // 2.2%
-            market_size_tier: String::from_str(env, "Medium"),
-            activity_level: String::from_str(env, "Medium"),
-        }
+    /// [`Self::split_winner_payout`] with both divisions rounded by `rounding`.
+    ///
+    /// Rounding is monotonic, so the slice stays non-negative in every mode.
+    pub fn split_winner_payout_rounded(
+        user_share: i128,
+        total_pool: i128,
+        winning_total: i128,
+        bps: u32,
+        rounding: PayoutRounding,
+    ) -> Result<(i128, i128), Error> {
+        let undiverted = NumericUtils::div_rounded(
+            user_share.checked_mul(total_pool).ok_or(Error::InvalidInput)?,
+            winning_total,
+            rounding,
+        );
+        let payout = NumericUtils::div_rounded(
+            user_share
+                .checked_mul(Self::winners_pool(total_pool, winning_total, bps)?)
+                .ok_or(Error::InvalidInput)?,
+            winning_total,
+            rounding,
+        );
+        Ok((payout, undiverted - payout))
+    }
+
+    /// Express `balance` as basis points of `liability`, flooring the result.
+    ///
+    /// No outstanding liability is reported as [`FULL_COVERAGE_BPS`]; ratios above
+    /// `u32::MAX` saturate. Negative inputs are treated as zero.
+    pub fn coverage_ratio_bps(balance: i128, liability: i128) -> u32 {
+        if liability <= 0 {
+            return FULL_COVERAGE_BPS;
+        }
+        if balance <= 0 {
+            return 0;
+        }
+        let ratio = balance
+            .checked_mul(FULL_COVERAGE_BPS as i128)
+            .map(|scaled| scaled / liability)
+            .unwrap_or(i128::MAX);
+        if ratio > u32::MAX as i128 {
+            u32::MAX
+        } else {
+            ratio as u32
+        }
+    }
+}
+
+// ===== CREATION FEE REFUNDS =====
+
+/// Storage key for the creation-fee refund toggle (bool, defaults to refundable).
+const CREATION_FEE_REFUNDABLE_KEY: Symbol = symbol_short!("cfee_rfd");
+
+/// Per-market storage key prefix for the creation fee actually charged.
+const MARKET_CREATION_FEE_KEY: Symbol = symbol_short!("mkt_cfee");
+
+/// Tracks the creation fee paid for each market so it can be returned if the
+/// creator deletes the market before anyone votes.
+///
+/// Refundability is an admin toggle: when disabled, deleting a market forfeits
+/// the fee (it stays in the contract like any other collected creation fee).
+pub struct CreationFeeRefunds;
+
+impl CreationFeeRefunds {
+    /// Whether creation fees are currently refunded on deletion (default: true).
+    pub fn is_refundable(env: &Env) -> bool {
+        env.storage()
+            .persistent()
+            .get(&CREATION_FEE_REFUNDABLE_KEY)
+            .unwrap_or(true)
+    }
+
+    /// Enable or disable creation-fee refunds on market deletion (admin only).
+    pub fn set_refundable(env: &Env, admin: &Address, refundable: bool) -> Result<(), Error> {
+        FeeValidator::validate_admin_permissions(env, admin)?;
+        env.storage()
+            .persistent()
+            .set(&CREATION_FEE_REFUNDABLE_KEY, &refundable);
+        Ok(())
+    }
+
+    /// Record the creation fee charged for `market_id`. Zero fees are not stored.
+    pub fn record_paid(env: &Env, market_id: &Symbol, amount: i128) {
+        if amount > 0 {
+            env.storage()
+                .persistent()
+                .set(&(MARKET_CREATION_FEE_KEY, market_id.clone()), &amount);
+        }
+    }
+
+    /// Creation fee recorded for `market_id` (0 if none was charged).
+    pub fn get_paid(env: &Env, market_id: &Symbol) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&(MARKET_CREATION_FEE_KEY, market_id.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Return the recorded creation fee for `market_id` to `creator`.
+    ///
+    /// The per-market record is always cleared. Returns the amount transferred,
+    /// which is 0 when no fee was charged or refunds are disabled.
+    pub fn refund(env: &Env, market_id: &Symbol, creator: &Address) -> Result<i128, Error> {
+        let paid = Self::get_paid(env, market_id);
+        env.storage()
+            .persistent()
+            .remove(&(MARKET_CREATION_FEE_KEY, market_id.clone()));
+
+        if paid <= 0 || !Self::is_refundable(env) {
+            return Ok(0);
+        }
+
+        let token_client = MarketUtils::get_token_client(env)?;
+        ReentrancyGuard::with_external_call(env, || {
+            token_client.transfer(&env.current_contract_address(), creator, &paid);
+            Ok::<(), ReentrancyError>(())
+        })
+        .map_err(|_| Error::InvalidState)?;
+
+        // Keep creation-fee analytics in line with what the contract still holds.
+        let creation_key = symbol_short!("creat_fee");
+        let current_total: i128 = env.storage().persistent().get(&creation_key).unwrap_or(0);
+        let updated_total = FeeCalculator::checked_fee_sub(current_total, paid).unwrap_or(0);
+        env.storage().persistent().set(&creation_key, &updated_total.max(0));
+
+        Ok(paid)
+    }
+}
+
+// ===== KEEPER BOUNTY =====
+
+/// Storage key for the configured keeper bounty (i128, base token units).
+const KEEPER_BOUNTY_KEY: Symbol = symbol_short!("kpr_bnty");
+
+/// Bounty paid from the fee vault to whoever triggers oracle resolution of a
+/// past-due market, so resolution does not depend on the admin showing up.
+///
+/// The bounty defaults to 0 (disabled). It is only paid when the fee vault
+/// holds at least the full amount; otherwise the payment is skipped and the
+/// resolution still stands.
+pub struct KeeperBounty;
+
+impl KeeperBounty {
+    /// Configured bounty per resolution (0 if never set).
+    pub fn get_bounty(env: &Env) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&KEEPER_BOUNTY_KEY)
+            .unwrap_or(0)
+    }
+
+    /// Set the bounty (admin only). 0 disables it; otherwise it may not exceed
+    /// [`MAX_FEE_AMOUNT`].
+    pub fn set_bounty(env: &Env, admin: &Address, amount: i128) -> Result<(), Error> {
+        FeeValidator::validate_admin_permissions(env, admin)?;
+        if amount < 0 || amount > MAX_FEE_AMOUNT {
+            return Err(Error::InvalidInput);
+        }
+        env.storage().persistent().set(&KEEPER_BOUNTY_KEY, &amount);
+        Ok(())
+    }
+
+    /// Pay the configured bounty to `keeper` out of the fee vault.
+    ///
+    /// Returns the amount paid, which is 0 when no bounty is configured or the
+    /// vault cannot cover it in full.
+    pub fn pay(env: &Env, keeper: &Address, market_id: &Symbol) -> Result<i128, Error> {
+        let bounty = Self::get_bounty(env);
+        if bounty <= 0 {
+            return Ok(0);
+        }
+
+        let available_fees: i128 = env.storage().persistent().get(&FEE_VAULT_KEY).unwrap_or(0);
+        if available_fees < bounty {
+            return Ok(0);
+        }
+
+        // Update vault balance first (defensive accounting) and then transfer.
+        let remaining_fees = FeeCalculator::checked_fee_sub(available_fees, bounty)?;
+        env.storage()
+            .persistent()
+            .set(&FEE_VAULT_KEY, &remaining_fees);
+
+        let token_client = MarketUtils::get_token_client(env)?;
+        ReentrancyGuard::with_external_call(env, || {
+            token_client.transfer(&env.current_contract_address(), keeper, &bounty);
+            Ok::<(), ReentrancyError>(())
+        })
+        .map_err(|_| Error::InvalidState)?;
+
+        crate::events::EventEmitter::emit_keeper_bounty_paid(
+            env,
+            market_id,
+            keeper,
+            bounty,
+            remaining_fees,
+        );
+
+        Ok(bounty)
+    }
+}
+
+// ===== FEE EXEMPTIONS =====
+
+const FEE_EXEMPT_KEY: Symbol = symbol_short!("fee_exmp");
+
+/// Per-market allowlist of addresses that claim winnings without the platform
+/// fee, e.g. market makers or partners.
+///
+/// The list is admin-managed and capped at
+/// [`crate::config::MAX_FEE_EXEMPT_ADDRESSES`] entries per market.
+pub struct FeeExemptions;
+
+impl FeeExemptions {
+    /// Fee-exempt addresses for `market_id` (empty if none).
+    pub fn get(env: &Env, market_id: &Symbol) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&(FEE_EXEMPT_KEY, market_id.clone()))
+            .unwrap_or(vec![env])
+    }
+
+    /// Whether `user` claims on `market_id` without the platform fee.
+    pub fn is_exempt(env: &Env, market_id: &Symbol, user: &Address) -> bool {
+        Self::get(env, market_id).contains(user)
+    }
+
+    /// Add `address` to the market's allowlist (admin only). Adding an address
+    /// that is already exempt is a no-op.
+    pub fn add(
+        env: &Env,
+        admin: &Address,
+        market_id: &Symbol,
+        address: &Address,
+    ) -> Result<(), Error> {
+        FeeValidator::validate_admin_permissions(env, admin)?;
+        MarketStateManager::get_market(env, market_id)?;
+
+        let mut exempt = Self::get(env, market_id);
+        if exempt.contains(address) {
+            return Ok(());
+        }
+        if exempt.len() >= crate::config::MAX_FEE_EXEMPT_ADDRESSES {
+            return Err(Error::InvalidInput);
+        }
+        exempt.push_back(address.clone());
+        env.storage()
+            .persistent()
+            .set(&(FEE_EXEMPT_KEY, market_id.clone()), &exempt);
+        Ok(())
+    }
+
+    /// Remove `address` from the market's allowlist (admin only). Removing an
+    /// address that is not exempt is a no-op.
+    pub fn remove(
+        env: &Env,
+        admin: &Address,
+        market_id: &Symbol,
+        address: &Address,
+    ) -> Result<(), Error> {
+        FeeValidator::validate_admin_permissions(env, admin)?;
+
+        let mut exempt = Self::get(env, market_id);
+        if let Some(index) = exempt.first_index_of(address) {
+            exempt.remove(index);
+            env.storage()
+                .persistent()
+                .set(&(FEE_EXEMPT_KEY, market_id.clone()), &exempt);
+        }
+        Ok(())
+    }
+}
+
+// ===== EARLY-BIRD FEES =====
+
+const EARLY_BIRD_VOTERS_KEY: Symbol = symbol_short!("eb_voters");
+
+/// Reduced claim fee for voters who commit before a market's early-bird cutoff.
+///
+/// The schedule lives on `Market::early_bird_fee`. Each vote snapshots the
+/// voter's applicable fee: qualifying voters are recorded with the reduced
+/// fee, everyone else pays the platform fee current at claim time.
+pub struct EarlyBirdFees;
+
+impl EarlyBirdFees {
+    /// Set or clear the early-bird schedule on an active market (admin only).
+    ///
+    /// The cutoff must lie between now and `end_time`, and the reduced fee
+    /// must be below the current platform fee. Votes are snapshotted against
+    /// the schedule in force when they are cast, so it cannot change once the
+    /// market has a vote (`Error::AlreadyVoted`).
+    pub fn set_schedule(
+        env: &Env,
+        admin: &Address,
+        market_id: &Symbol,
+        schedule: Option<crate::types::EarlyBirdFee>,
+    ) -> Result<(), Error> {
+        FeeValidator::validate_admin_permissions(env, admin)?;
+        let mut market = MarketStateManager::get_market(env, market_id)?;
+        if market.state != crate::types::MarketState::Active {
+            return Err(Error::InvalidState);
+        }
+        if !market.votes.is_empty() {
+            return Err(Error::AlreadyVoted);
+        }
+
+        if let Some(ref early) = schedule {
+            let now = env.ledger().timestamp();
+            if early.cutoff <= now || early.cutoff > market.end_time {
+                return Err(Error::InvalidInput);
+            }
+            let platform_fee = crate::config::ConfigManager::get_config(env)
+                .map_err(|_| Error::ConfigNotFound)?
+                .fees
+                .platform_fee_percentage;
+            if early.fee_percentage < 0 || early.fee_percentage >= platform_fee {
+                return Err(Error::InvalidFeeConfig);
+            }
+        }
+
+        market.early_bird_fee = schedule;
+        MarketStateManager::update_market(env, market_id, &market);
+        Ok(())
+    }
+
+    /// Snapshot `user`'s applicable fee for a vote placed now on `market`.
+    ///
+    /// Called wherever a vote is recorded; a vote after the cutoff clears any
+    /// earlier snapshot, e.g. from a cancelled early bet.
+    pub fn snapshot(env: &Env, market_id: &Symbol, market: &Market, user: &Address) {
+        let Some(ref early) = market.early_bird_fee else {
+            return;
+        };
+        let key = (EARLY_BIRD_VOTERS_KEY, market_id.clone());
+        let mut voters: Map<Address, i128> =
+            env.storage().persistent().get(&key).unwrap_or(Map::new(env));
+        if env.ledger().timestamp() < early.cutoff {
+            voters.set(user.clone(), early.fee_percentage);
+        } else if voters.remove(user.clone()).is_none() {
+            return;
+        }
+        env.storage().persistent().set(&key, &voters);
+    }
+
+    /// Fee snapshotted for `user` on `market_id`, if they voted before the cutoff.
+    pub fn snapshotted_fee(env: &Env, market_id: &Symbol, user: &Address) -> Option<i128> {
+        env.storage()
+            .persistent()
+            .get::<_, Map<Address, i128>>(&(EARLY_BIRD_VOTERS_KEY, market_id.clone()))
+            .and_then(|voters| voters.get(user.clone()))
+    }
+}
+
+// ===== WINNER PAYOUTS =====
+
+/// One participant's settlement on a resolved market, in staking-token units.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct PayoutQuote {
+    /// Amount owed to the participant.
+    pub payout: i128,
+    /// Platform fee withheld from the participant's share of the pool.
+    pub fee: i128,
+    /// Part of the losing pool diverted to the insurance fund.
+    pub insurance_slice: i128,
+    /// Bonus drawn from the bonus pool on a stake-back market.
+    pub bonus: i128,
+}
+
+/// Payout math shared by every path that pays or previews a claim: claims,
+/// `distribute_payouts`, the payout simulations and the dust-stake guard.
+///
+/// Keeping it in one place means a fee exemption, an early-bird snapshot, the
+/// market's payout model, its insurance rate and the rounding policy apply
+/// identically whether a payout is simulated or made.
+pub struct WinnerPayouts;
+
+impl WinnerPayouts {
+    /// Fee percentage `user` pays on a winning claim on `market_id`.
+    ///
+    /// Fee-exempt users pay none and early voters pay their snapshotted
+    /// early-bird fee, capped at the platform fee. `None` is an unknown
+    /// participant, who pays the platform fee.
+    pub fn fee_percent(
+        env: &Env,
+        market_id: &Symbol,
+        user: Option<&Address>,
+    ) -> Result<i128, Error> {
+        if let Some(user) = user {
+            if FeeExemptions::is_exempt(env, market_id, user) {
+                return Ok(0);
+            }
+        }
+        let platform_fee = crate::config::ConfigManager::get_config(env)
+            .map_err(|_| Error::ConfigNotFound)?
+            .fees
+            .platform_fee_percentage;
+        Ok(user
+            .and_then(|user| EarlyBirdFees::snapshotted_fee(env, market_id, user))
+            .map_or(platform_fee, |early| early.min(platform_fee)))
+    }
+
+    /// Quote the settlement of `stake` on `market`, where `won` tells whether
+    /// it backed a winning outcome and `pools` is `(total_pool, winning_total)`.
+    ///
+    /// A parimutuel winner receives its fee-adjusted share of the pool less
+    /// the market's insurance slice, rounded by the configured policy; a
+    /// loser receives nothing. A stake-back participant recovers the stake,
+    /// plus the bonus if it won. The insurance rate is the one locked at
+    /// resolution by [`InsuranceFund::lock_market_insurance_bps`]. Paying
+    /// callers cap a rounded-up payout with [`Self::capped_payout`].
+    ///
+    /// # Errors
+    ///
+    /// * `Error::ConfigNotFound` - Fee configuration missing
+    /// * `Error::InvalidInput` - An intermediate product overflows
+    pub fn quote(
+        env: &Env,
+        market_id: &Symbol,
+        market: &Market,
+        user: Option<&Address>,
+        stake: i128,
+        won: bool,
+        (total_pool, winning_total): (i128, i128),
+    ) -> Result<PayoutQuote, Error> {
+        if stake <= 0 {
+            return Ok(PayoutQuote::default());
+        }
+        if let PayoutModel::StakeBackPlusBonus(bonus_bps) = market.payout_model {
+            let bonus = if won {
+                BonusPool::bonus_for(env, stake, bonus_bps)?
+            } else {
+                0
+            };
+            return Ok(PayoutQuote {
+                payout: stake.checked_add(bonus).ok_or(Error::InvalidInput)?,
+                bonus,
+                ..PayoutQuote::default()
+            });
+        }
+        if !won || winning_total <= 0 {
+            return Ok(PayoutQuote::default());
+        }
+
+        let fee_percent = Self::fee_percent(env, market_id, user)?;
+        let user_share = stake
+            .checked_mul(crate::config::PERCENTAGE_DENOMINATOR - fee_percent)
+            .ok_or(Error::InvalidInput)?
+            / crate::config::PERCENTAGE_DENOMINATOR;
+        let (payout, insurance_slice) = InsuranceFund::split_winner_payout_rounded(
+            user_share,
+            total_pool,
+            winning_total,
+            InsuranceFund::market_insurance_bps(env, market_id),
+            crate::utils::PayoutRoundingPolicy::get(env),
+        )?;
+
+        // Fee = gross share of the pool minus the net (pre-insurance) share
+        let gross = stake.checked_mul(total_pool).ok_or(Error::InvalidInput)? / winning_total;
+        let undiverted = user_share
+            .checked_mul(total_pool)
+            .ok_or(Error::InvalidInput)?
+            / winning_total;
+        Ok(PayoutQuote {
+            payout,
+            fee: gross - undiverted,
+            insurance_slice,
+            bonus: 0,
+        })
+    }
+
+    /// `quote.payout`, capped so that everything taken from `total_pool` so
+    /// far - earlier payouts, fees and insurance slices, plus this quote's
+    /// own fee and slice - never exceeds it.
+    ///
+    /// Rounding payouts up could otherwise overdraw the pool.
+    pub fn capped_payout(
+        env: &Env,
+        market_id: &Symbol,
+        total_pool: i128,
+        quote: &PayoutQuote,
+    ) -> i128 {
+        let progress = MarketStateManager::get_claim_progress(env, market_id);
+        let left = total_pool
+            .saturating_sub(progress.total_paid)
+            .saturating_sub(progress.total_withheld)
+            .saturating_sub(quote.fee)
+            .saturating_sub(quote.insurance_slice);
+        quote.payout.min(left.max(0))
+    }
+}
+
+// ===== RESOLUTION BOUNTY =====
+
+/// Per-market bounty escrowed by the market creator and paid to whoever
+/// resolves the market, so resolution does not depend on the creator.
+///
+/// The bounty is held on `Market::resolution_bounty`. It is paid once: paying
+/// or refunding zeroes it on the passed market, which the caller persists
+/// together with its own state change. A voided market returns it to the creator.
+pub struct ResolutionBounty;
+
+impl ResolutionBounty {
+    /// Escrow `amount` from `creator` as the bounty for `market_id`.
+    ///
+    /// Only the market's creator may fund it, once, while the market is active.
+    pub fn escrow(
+        env: &Env,
+        creator: &Address,
+        market_id: &Symbol,
+        amount: i128,
+    ) -> Result<(), Error> {
+        creator.require_auth();
+        if amount <= 0 || amount > MAX_FEE_AMOUNT {
+            return Err(Error::InvalidInput);
+        }
+        let mut market = MarketStateManager::get_market(env, market_id)?;
+        if market.creator != *creator {
+            return Err(Error::Unauthorized);
+        }
+        if market.state != crate::types::MarketState::Active || market.resolution_bounty > 0 {
+            return Err(Error::InvalidState);
+        }
+
+        let token_client = MarketUtils::get_token_client(env)?;
+        ReentrancyGuard::with_external_call(env, || {
+            token_client.transfer(creator, &env.current_contract_address(), &amount);
+            Ok::<(), ReentrancyError>(())
+        })
+        .map_err(|_| Error::InvalidState)?;
+
+        market.resolution_bounty = amount;
+        MarketStateManager::update_market(env, market_id, &market);
+        Ok(())
+    }
+
+    /// Pay `market`'s bounty to `resolver`. Returns the amount paid (0 if none).
+    pub fn pay(
+        env: &Env,
+        market_id: &Symbol,
+        market: &mut Market,
+        resolver: &Address,
+    ) -> Result<i128, Error> {
+        Self::release(env, market_id, market, resolver, false)
+    }
+
+    /// Return `market`'s bounty to its creator. Returns the amount refunded (0 if none).
+    pub fn refund(env: &Env, market_id: &Symbol, market: &mut Market) -> Result<i128, Error> {
+        let creator = market.creator.clone();
+        Self::release(env, market_id, market, &creator, true)
+    }
+
+    fn release(
+        env: &Env,
+        market_id: &Symbol,
+        market: &mut Market,
+        recipient: &Address,
+        refunded: bool,
+    ) -> Result<i128, Error> {
+        let bounty = market.resolution_bounty;
+        if bounty <= 0 {
+            return Ok(0);
+        }
+        // Zero first (defensive accounting), then transfer.
+        market.resolution_bounty = 0;
+
+        let token_client = MarketUtils::get_token_client(env)?;
+        ReentrancyGuard::with_external_call(env, || {
+            token_client.transfer(&env.current_contract_address(), recipient, &bounty);
+            Ok::<(), ReentrancyError>(())
+        })
+        .map_err(|_| Error::InvalidState)?;
+
+        crate::events::EventEmitter::emit_resolution_bounty_released(
+            env, market_id, recipient, bounty, refunded,
+        );
+        Ok(bounty)
+    }
+}
+
+// ===== RESOLUTION BOND =====
+
+/// Storage key for the bond required with a manual resolution (i128, base token units).
+const RESOLUTION_BOND_KEY: Symbol = symbol_short!("res_bond");
+
+/// Storage key prefix for the address that posted a market's resolution bond.
+const RESOLUTION_BOND_POSTER_KEY: Symbol = symbol_short!("bond_by");
+
+/// Bond the admin posts with `resolve_market_manual`, so a wrong manual
+/// resolution costs the resolver and not only the voters.
+///
+/// The bond is held on `Market::resolution_bond`. While it is held the
+/// resolution can still be disputed until the dispute window closes, and
+/// payouts wait for it. `resolve_dispute` settles the bond: forfeited pro rata
+/// to the disputers when the outcome is overturned, refunded to the poster
+/// otherwise. Without a dispute the poster gets it back once the window closes.
+pub struct ResolutionBond;
+
+impl ResolutionBond {
+    /// Bond required per manual resolution (0 if never set).
+    pub fn get_required(env: &Env) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&RESOLUTION_BOND_KEY)
+            .unwrap_or(0)
+    }
+
+    /// Set the required bond (admin only). 0 disables it; otherwise it may not
+    /// exceed [`MAX_FEE_AMOUNT`]. Applies to resolutions made after the change.
+    pub fn set_required(env: &Env, admin: &Address, amount: i128) -> Result<(), Error> {
+        FeeValidator::validate_admin_permissions(env, admin)?;
+        if amount < 0 || amount > MAX_FEE_AMOUNT {
+            return Err(Error::InvalidInput);
+        }
+        env.storage().persistent().set(&RESOLUTION_BOND_KEY, &amount);
+        Ok(())
+    }
+
+    /// Address that posted `market_id`'s bond, if one is held.
+    pub fn poster(env: &Env, market_id: &Symbol) -> Option<Address> {
+        env.storage()
+            .persistent()
+            .get(&(RESOLUTION_BOND_POSTER_KEY, market_id.clone()))
+    }
+
+    /// Take the required bond from `admin` for resolving `market`.
+    ///
+    /// Returns the amount posted (0 when no bond is required). The caller
+    /// persists `market` together with its resolution.
+    pub fn post(
+        env: &Env,
+        market_id: &Symbol,
+        market: &mut Market,
+        admin: &Address,
+    ) -> Result<i128, Error> {
+        let amount = Self::get_required(env);
+        if amount <= 0 {
+            return Ok(0);
+        }
+        if market.resolution_bond > 0 {
+            return Err(Error::InvalidState);
+        }
+
+        let token_client = MarketUtils::get_token_client(env)?;
+        ReentrancyGuard::with_external_call(env, || {
+            token_client.transfer(admin, &env.current_contract_address(), &amount);
+            Ok::<(), ReentrancyError>(())
+        })
+        .map_err(|_| Error::InvalidState)?;
+
+        market.resolution_bond = amount;
+        env.storage()
+            .persistent()
+            .set(&(RESOLUTION_BOND_POSTER_KEY, market_id.clone()), admin);
+        Ok(amount)
+    }
+
+    /// Settle `market`'s bond once its dispute is resolved.
+    ///
+    /// An overturned resolution forfeits the bond to the disputers in
+    /// proportion to their dispute stakes; the last one takes the rounding
+    /// remainder. An upheld one refunds it to the poster. Returns the amount
+    /// released (0 if none was held).
+    pub fn settle(
+        env: &Env,
+        market_id: &Symbol,
+        market: &mut Market,
+        overturned: bool,
+    ) -> Result<i128, Error> {
+        let bond = market.resolution_bond;
+        let total_stake = market.total_dispute_stakes();
+        if bond <= 0 || !overturned || total_stake <= 0 {
+            return Self::refund(env, market_id, market);
+        }
+        // Zero first (defensive accounting), then transfer.
+        market.resolution_bond = 0;
+        env.storage()
+            .persistent()
+            .remove(&(RESOLUTION_BOND_POSTER_KEY, market_id.clone()));
+
+        let mut shares: Vec<(Address, i128)> = Vec::new(env);
+        let mut paid = 0;
+        let mut remaining = market.dispute_stakes.len();
+        for (disputer, stake) in market.dispute_stakes.iter() {
+            remaining -= 1;
+            let share = if remaining == 0 {
+                bond - paid
+            } else {
+                bond * stake / total_stake
+            };
+            paid += share;
+            if share > 0 {
+                shares.push_back((disputer, share));
+            }
+        }
+
+        let token_client = MarketUtils::get_token_client(env)?;
+        ReentrancyGuard::with_external_call(env, || {
+            for (disputer, share) in shares.iter() {
+                token_client.transfer(&env.current_contract_address(), &disputer, &share);
+            }
+            Ok::<(), ReentrancyError>(())
+        })
+        .map_err(|_| Error::InvalidState)?;
+
+        crate::events::EventEmitter::emit_resolution_bond_settled(env, market_id, bond, true);
+        Ok(bond)
+    }
+
+    /// Return `market`'s bond to whoever posted it. Returns the amount
+    /// refunded (0 if none was held).
+    pub fn refund(env: &Env, market_id: &Symbol, market: &mut Market) -> Result<i128, Error> {
+        let bond = market.resolution_bond;
+        if bond <= 0 {
+            return Ok(0);
+        }
+        let poster = Self::poster(env, market_id).ok_or(Error::InvalidState)?;
+        market.resolution_bond = 0;
+        env.storage()
+            .persistent()
+            .remove(&(RESOLUTION_BOND_POSTER_KEY, market_id.clone()));
+
+        let token_client = MarketUtils::get_token_client(env)?;
+        ReentrancyGuard::with_external_call(env, || {
+            token_client.transfer(&env.current_contract_address(), &poster, &bond);
+            Ok::<(), ReentrancyError>(())
+        })
+        .map_err(|_| Error::InvalidState)?;
+
+        crate::events::EventEmitter::emit_resolution_bond_settled(env, market_id, bond, false);
+        Ok(bond)
+    }
+
+    /// Refund the bond on an undisputed resolution once its dispute window has
+    /// closed. Anyone may call this; the bond always goes back to the poster.
+    ///
+    /// The window runs from the timestamp of the market's
+    /// [`crate::resolution::ResolutionRecords`] entry.
+    pub fn reclaim(env: &Env, market_id: &Symbol) -> Result<i128, Error> {
+        let mut market = MarketStateManager::get_market(env, market_id)?;
+        if market.resolution_bond <= 0 {
+            return Err(Error::NothingToClaim);
+        }
+        // A bond is only posted by a manual resolution, which leaves a record
+        let resolved_at = crate::resolution::ResolutionRecords::get(env, market_id)
+            .map(|record| record.timestamp)
+            .ok_or(Error::InvalidState)?;
+        if market.state == crate::types::MarketState::Disputed
+            || env.ledger().timestamp()
+                < crate::markets::MarketPayouts::dispute_window_end(&market, resolved_at)
+        {
+            return Err(Error::InvalidState);
+        }
+        let refunded = Self::refund(env, market_id, &mut market)?;
+        MarketStateManager::update_market(env, market_id, &market);
+        Ok(refunded)
+    }
+}
+
+// ===== FEE ANALYTICS =====
+
+impl FeeAnalytics {
+    /// Calculate fee analytics
+    pub fn calculate_analytics(env: &Env) -> Result<FeeAnalytics, Error> {
+        let total_fees = FeeTracker::get_total_fees_collected(env)?;
+        let history = FeeTracker::get_fee_history(env)?;
+        let markets_with_fees = history.len() as u32;
+
+        let average_fee = if markets_with_fees > 0 {
+            total_fees / (markets_with_fees as i128)
+        } else {
+            0
+        };
+
+        // Create fee distribution map
+        let fee_distribution = Map::new(env);
+        // TODO: Implement proper fee distribution calculation
+
+        Ok(FeeAnalytics {
+            total_fees_collected: total_fees,
+            markets_with_fees,
+            average_fee_per_market: average_fee,
+            collection_history: history,
+            fee_distribution,
+        })
+    }
+
+    /// Get fee statistics for a specific market
+    pub fn get_market_fee_stats(market: &Market) -> Result<FeeBreakdown, Error> {
+        FeeCalculator::calculate_fee_breakdown(market)
+    }
+
+    /// Calculate fee efficiency (fees collected vs potential)
+    pub fn calculate_fee_efficiency(market: &Market) -> Result<f64, Error> {
+        let potential_fee = FeeCalculator::calculate_platform_fee(market)?;
+        let actual_fee = if market.fee_collected {
+            potential_fee
+        } else {
+            0
+        };
+
+        if potential_fee == 0 {
+            return Ok(0.0);
+        }
+
+        Ok((actual_fee as f64) / (potential_fee as f64))
+    }
+}
+
+// ===== FEE TESTING UTILITIES =====
+
+#[cfg(test)]
+pub mod testing {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+
+    /// Create a test fee configuration
+    pub fn create_test_fee_config() -> FeeConfig {
+        FeeConfig {
+            platform_fee_percentage: PLATFORM_FEE_PERCENTAGE,
+            creation_fee: MARKET_CREATION_FEE,
+            min_fee_amount: MIN_FEE_AMOUNT,
+            max_fee_amount: MAX_FEE_AMOUNT,
+            collection_threshold: FEE_COLLECTION_THRESHOLD,
+            fees_enabled: true,
+        }
+    }
+
+    /// Create a test fee collection record
+    pub fn create_test_fee_collection(
+        env: &Env,
+        market_id: Symbol,
+        amount: i128,
+        admin: Address,
+    ) -> FeeCollection {
+        FeeCollection {
+            market_id,
+            amount,
+            collected_by: admin,
+            timestamp: env.ledger().timestamp(),
+            fee_percentage: PLATFORM_FEE_PERCENTAGE,
+        }
+    }
+
+    /// Create a test fee breakdown
+    pub fn create_test_fee_breakdown() -> FeeBreakdown {
+        FeeBreakdown {
+            total_staked: 1_000_000_000, // 100 XLM
+            fee_percentage: PLATFORM_FEE_PERCENTAGE,
+            fee_amount: 20_000_000, // 2 XLM
+            platform_fee: 20_000_000,
+            user_payout_amount: 980_000_000, // 98 XLM
+        }
+    }
+
+    /// Validate fee configuration
+    pub fn validate_fee_config_structure(config: &FeeConfig) -> Result<(), Error> {
+        if config.platform_fee_percentage < 0 {
+            return Err(Error::InvalidInput);
+        }
+
+        if config.creation_fee < 0 {
+            return Err(Error::InvalidInput);
+        }
+
+        if config.min_fee_amount < 0 {
+            return Err(Error::InvalidInput);
+        }
+
+        if config.max_fee_amount < config.min_fee_amount {
+            return Err(Error::InvalidInput);
+        }
+
+        Ok(())
+    }
+
+    /// Validate fee collection record
+    pub fn validate_fee_collection_structure(collection: &FeeCollection) -> Result<(), Error> {
+        if collection.amount <= 0 {
+            return Err(Error::InvalidInput);
+        }
+
+        if collection.fee_percentage < 0 {
+            return Err(Error::InvalidInput);
+        }
+
+        Ok(())
+    }
+
+    /// Create test fee tier
+    pub fn create_test_fee_tier(env: &Env) -> FeeTier {
+        FeeTier {
+            min_size: 0,
+            max_size: 100_000_000, // 10 XLM
+            fee_percentage: 150,   // 1.5%
+            tier_name: String::from_str(env, "Small"),
+        }
+    }
+
+    /// Create test activity adjustment
+    pub fn create_test_activity_adjustment(env: &Env) -> ActivityAdjustment {
+        ActivityAdjustment {
+            activity_level: 50,
+            fee_multiplier: 110, // 10% increase
+            description: String::from_str(env, "Medium Activity"),
+        }
+    }
+
+    /// Create test fee calculation factors
+    pub fn create_test_fee_calculation_factors(env: &Env) -> FeeCalculationFactors {
+        FeeCalculationFactors {
+            base_fee_percentage: 200,  // 2%
+            size_multiplier: 100,      // No change
+            activity_multiplier: 110,  // 10% increase
+            complexity_factor: 100,    // No change
+            final_fee_percentage: 220, // 2.2%
+            market_size_tier: String::from_str(env, "Medium"),
+            activity_level: String::from_str(env, "Medium"),
+        }
     }
 
     /// Create test fee history
@@ -2287,130 +3437,1082 @@ pub mod testing {
             calculation_factors: testing::create_test_fee_calculation_factors(env),
         }
     }
-}
-
-// ===== MODULE TESTS =====
+}
+
+// ===== MODULE TESTS =====
+
+#[cfg(test)]
+mod checked_arithmetic_tests {
+    extern crate std;
+
+    use super::*;
+    use crate::validation::ValidationTestingUtils;
+    use soroban_sdk::{
+        testutils::{Address as _, EnvTestConfig},
+        Address, String, Symbol, Vec,
+    };
+
+    fn test_env() -> Env {
+        let mut env = Env::default();
+        env.set_config(EnvTestConfig {
+            capture_snapshot_at_drop: false,
+        });
+        env
+    }
+
+    fn resolved_market(env: &Env, total_staked: i128) -> Market {
+        let mut market = ValidationTestingUtils::create_test_market(env);
+        let mut winning_outcomes = Vec::new(env);
+        winning_outcomes.push_back(String::from_str(env, "yes"));
+        market.winning_outcomes = Some(winning_outcomes);
+        market.total_staked = total_staked;
+        market
+    }
+
+    #[test]
+    fn test_calculate_platform_fee_rejects_zero_pool() {
+        let env = test_env();
+        let market = resolved_market(&env, 0);
+
+        let result = FeeCalculator::calculate_platform_fee(&market);
+
+        assert_eq!(result, Err(Error::InvalidFeeConfig));
+    }
+
+    #[test]
+    fn test_checked_bps_floor_rounds_down_for_one_basis_point() {
+        let result = FeeCalculator::checked_bps_floor(10_001, 1).unwrap();
+
+        assert_eq!(result, 1);
+    }
+
+    #[test]
+    fn test_calculate_platform_fee_errors_on_overflow_adjacent_pool() {
+        let env = test_env();
+        let market = resolved_market(&env, i128::MAX);
+
+        let result = FeeCalculator::calculate_platform_fee(&market);
+
+        assert_eq!(result, Err(Error::FeeArithmeticOverflow));
+    }
+
+    #[test]
+    fn test_fee_breakdown_reconciles_after_floor_rounding() {
+        let env = test_env();
+        let market = resolved_market(&env, 50_000_001);
+
+        let breakdown = FeeCalculator::calculate_fee_breakdown(&market).unwrap();
+
+        assert_eq!(breakdown.fee_amount, 1_000_000);
+        assert_eq!(
+            breakdown.platform_fee + breakdown.user_payout_amount,
+            breakdown.total_staked
+        );
+    }
+
+    #[test]
+    fn test_collect_fees_returns_overflow_error_without_mutation() {
+        let env = test_env();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let admin = Address::generate(&env);
+        let market_id = Symbol::new(&env, "fee_overflow");
+        let market = resolved_market(&env, i128::MAX);
+
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "Admin"), &admin);
+            MarketStateManager::update_market(&env, &market_id, &market);
+
+            let result = FeeManager::collect_fees(&env, admin.clone(), market_id.clone());
+
+            assert_eq!(result, Err(Error::FeeArithmeticOverflow));
+            assert_eq!(
+                env.storage()
+                    .persistent()
+                    .get::<Symbol, i128>(&FEE_VAULT_KEY)
+                    .unwrap_or(0),
+                0
+            );
+            assert!(!MarketStateManager::get_market(&env, &market_id)
+                .unwrap()
+                .fee_collected);
+        });
+    }
+
+    #[test]
+    fn test_collect_fees_credits_calculated_fee_and_clears_pending() {
+        let env = test_env();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let admin = Address::generate(&env);
+        let market_id = Symbol::new(&env, "fee_pending");
+        let market = resolved_market(&env, 1_000_000_000);
+
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "Admin"), &admin);
+            MarketStateManager::update_market(&env, &market_id, &market);
+            PendingFees::accrue(&env, &market_id, 3).unwrap();
+            let expected =
+                FeeCalculator::calculate_platform_fee_with_env(&env, &market_id, &market).unwrap();
+
+            let collected =
+                FeeManager::collect_fees(&env, admin.clone(), market_id.clone()).unwrap();
+
+            assert_eq!(collected, expected);
+            assert_ne!(collected, 3);
+            assert_eq!(
+                env.storage()
+                    .persistent()
+                    .get::<Symbol, i128>(&FEE_VAULT_KEY)
+                    .unwrap_or(0),
+                expected
+            );
+            assert_eq!(PendingFees::get(&env, &market_id), 0);
+            assert_eq!(PendingFees::get_total(&env), 0);
+        });
+    }
+
+    #[test]
+    fn test_rejected_collection_keeps_pending_fees() {
+        let env = test_env();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let admin = Address::generate(&env);
+        let market_id = Symbol::new(&env, "fee_small");
+        let market = resolved_market(&env, FEE_COLLECTION_THRESHOLD - 1);
+
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "Admin"), &admin);
+            MarketStateManager::update_market(&env, &market_id, &market);
+            PendingFees::accrue(&env, &market_id, 1_000).unwrap();
+
+            let result = FeeManager::collect_fees(&env, admin.clone(), market_id.clone());
+
+            assert_eq!(result, Err(Error::InsufficientStake));
+            assert_eq!(PendingFees::get(&env, &market_id), 1_000);
+            assert_eq!(PendingFees::get_total(&env), 1_000);
+        });
+    }
+
+    #[test]
+    fn test_collect_fees_emits_treasury_inflow_event() {
+        use soroban_sdk::{testutils::Events, TryFromVal, TryIntoVal};
+
+        let env = test_env();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let admin = Address::generate(&env);
+        let market_id = Symbol::new(&env, "fee_event");
+        let market = resolved_market(&env, 1_000_000_000);
+
+        let collected = env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "Admin"), &admin);
+            MarketStateManager::update_market(&env, &market_id, &market);
+            FeeManager::collect_fees(&env, admin.clone(), market_id.clone()).unwrap()
+        });
+        assert!(collected > 0);
+
+        let inflows: std::vec::Vec<crate::events::FeesCollectedEvent> = env
+            .events()
+            .all()
+            .events()
+            .iter()
+            .filter_map(|event| {
+                let body = match &event.body {
+                    soroban_sdk::xdr::ContractEventBody::V0(v0) => v0,
+                };
+                let topic: Symbol = body.topics.get(0)?.clone().try_into_val(&env).ok()?;
+                if topic != symbol_short!("fees_in") {
+                    return None;
+                }
+                crate::events::FeesCollectedEvent::try_from_val(&env, &body.data).ok()
+            })
+            .collect();
+        assert_eq!(inflows.len(), 1);
+        assert_eq!(inflows[0].market_id, market_id);
+        assert_eq!(inflows[0].amount, collected);
+        assert_eq!(inflows[0].treasury, contract_id);
+    }
+
+    #[test]
+    fn test_record_fee_collection_errors_on_total_overflow() {
+        let env = test_env();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let admin = Address::generate(&env);
+        let market_id = Symbol::new(&env, "fee_sum");
+
+        env.as_contract(&contract_id, || {
+            env.storage().persistent().set(&FEE_VAULT_KEY, &i128::MAX);
+
+            let result = FeeTracker::record_fee_collection(&env, &market_id, 1, &admin);
+
+            assert_eq!(result, Err(Error::FeeArithmeticOverflow));
+        });
+    }
+}
+
+#[cfg(test)]
+mod insurance_fund_tests {
+    use super::*;
+    use crate::oracles::ComparisonOp;
+
+    #[test]
+    fn test_coverage_ratio_for_known_balance_and_liability() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+
+        env.as_contract(&contract_id, || {
+            InsuranceFund::credit(&env, 2_500_0000000).unwrap();
+            let balance = InsuranceFund::get_balance(&env);
+
+            assert_eq!(balance, 2_500_0000000);
+            // 2_500 XLM against 10_000 XLM outstanding -> 25.00%
+            assert_eq!(InsuranceFund::coverage_ratio_bps(balance, 10_000_0000000), 2_500);
+        });
+    }
+
+    #[test]
+    fn test_coverage_ratio_edges() {
+        assert_eq!(InsuranceFund::coverage_ratio_bps(0, 1_000), 0);
+        assert_eq!(InsuranceFund::coverage_ratio_bps(500, 0), FULL_COVERAGE_BPS);
+        assert_eq!(InsuranceFund::coverage_ratio_bps(3_000, 1_000), 30_000);
+        assert_eq!(InsuranceFund::coverage_ratio_bps(i128::MAX, 1), u32::MAX);
+    }
+
+    #[test]
+    fn test_debit_rejects_overdraw() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+
+        env.as_contract(&contract_id, || {
+            InsuranceFund::credit(&env, 100).unwrap();
+            assert_eq!(InsuranceFund::debit(&env, 101), Err(Error::InsufficientBalance));
+            assert_eq!(InsuranceFund::debit(&env, 40), Ok(60));
+        });
+    }
+
+    #[test]
+    fn test_split_winner_payout_diverts_losing_pool_slice() {
+        // Pool 1_000, winning side 400 -> losing pool 600; 25% of it (150) is diverted
+        assert_eq!(InsuranceFund::winners_pool(1_000, 400, 2_500), Ok(850));
+        assert_eq!(InsuranceFund::winners_pool(1_000, 400, 0), Ok(1_000));
+        // No losing pool, nothing to divert
+        assert_eq!(InsuranceFund::winners_pool(400, 400, 2_500), Ok(400));
+
+        let (a_payout, a_slice) =
+            InsuranceFund::split_winner_payout(300, 1_000, 400, 2_500).unwrap();
+        let (b_payout, b_slice) =
+            InsuranceFund::split_winner_payout(100, 1_000, 400, 2_500).unwrap();
+        assert_eq!((a_payout, a_slice), (637, 113));
+        assert_eq!((b_payout, b_slice), (212, 38));
+        // Slices add back up to the undiverted payouts (750 / 250)
+        assert_eq!(a_payout + a_slice, 750);
+        assert_eq!(b_payout + b_slice, 250);
+    }
+
+    #[test]
+    fn test_claim_diverts_insurance_slice_to_fund() {
+        use crate::types::{Market, MarketState, OracleConfig, OracleProvider};
+        use soroban_sdk::testutils::Address as _;
+
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        let winner = Address::generate(&env);
+        let loser = Address::generate(&env);
+        client.initialize(&admin, &None, &None);
+
+        assert_eq!(
+            client.try_set_insurance_bps(&admin, &10_001),
+            Err(Ok(Error::InvalidInput))
+        );
+        assert_eq!(
+            client.try_set_insurance_bps(&winner, &2_500),
+            Err(Ok(Error::Unauthorized))
+        );
+
+        let plain = symbol_short!("plain");
+        let insured = symbol_short!("insured");
+        let (stake, losing_stake) = (400_0000000i128, 600_0000000i128);
+        env.as_contract(&contract_id, || {
+            for id in [&plain, &insured] {
+                let mut market = Market::new(
+                    &env,
+                    admin.clone(),
+                    String::from_str(&env, "Insured"),
+                    vec![&env, String::from_str(&env, "yes"), String::from_str(&env, "no")],
+                    env.ledger().timestamp(),
+                    OracleConfig::new(
+                        OracleProvider::reflector(),
+                        Address::generate(&env),
+                        String::from_str(&env, "BTC/USD"),
+                        100,
+                        ComparisonOp::Gt,
+                    ),
+                    None,
+                    86400,
+                    MarketState::Active,
+                );
+                market.votes.set(winner.clone(), String::from_str(&env, "yes"));
+                market.stakes.set(winner.clone(), stake);
+                market.votes.set(loser.clone(), String::from_str(&env, "no"));
+                market.stakes.set(loser.clone(), losing_stake);
+                market.total_staked = stake + losing_stake;
+                market.winning_outcomes = Some(vec![&env, String::from_str(&env, "yes")]);
+                market.state = MarketState::Resolved;
+                market.dispute_window_seconds = 0;
+                env.storage().persistent().set(id, &market);
+            }
+        });
+
+        // Without insurance the winner takes the whole (fee-adjusted) pool
+        client.claim_winnings(&winner, &plain);
+        let payout_of = |id: &Symbol| {
+            client
+                .get_market(id)
+                .unwrap()
+                .claimed
+                .get(winner.clone())
+                .unwrap()
+                .get_payout()
+        };
+        let plain_payout = payout_of(&plain);
+        assert_eq!(client.get_insurance_fund_balance(), 0);
+
+        // With 25% insurance, a quarter of the losing pool goes to the fund
+        client.set_insurance_bps(&admin, &2_500);
+        client.claim_winnings(&winner, &insured);
+        let insured_payout = payout_of(&insured);
+
+        let fee_percent = env.as_contract(&contract_id, || {
+            crate::config::ConfigManager::get_config(&env)
+                .unwrap()
+                .fees
+                .platform_fee_percentage
+        });
+        let user_share = stake * (crate::config::PERCENTAGE_DENOMINATOR - fee_percent)
+            / crate::config::PERCENTAGE_DENOMINATOR;
+        let diverted_pool = losing_stake * 2_500 / 10_000;
+        let expected_slice = user_share * (stake + losing_stake) / stake
+            - user_share * (stake + losing_stake - diverted_pool) / stake;
+
+        assert_eq!(plain_payout - insured_payout, expected_slice);
+        assert_eq!(client.get_insurance_fund_balance(), expected_slice);
+        // Sole winner: the slice is the full diverted share, net of the platform fee
+        assert_eq!(expected_slice, user_share * diverted_pool / stake);
+    }
 
-#[cfg(test)]
-mod checked_arithmetic_tests {
-    extern crate std;
+    #[test]
+    fn test_rate_is_locked_when_the_market_resolves() {
+        use crate::types::{Market, MarketState, OracleConfig, OracleProvider};
+        use soroban_sdk::testutils::Address as _;
 
-    use super::*;
-    use crate::validation::ValidationTestingUtils;
-    use soroban_sdk::{
-        testutils::{Address as _, EnvTestConfig},
-        Address, String, Symbol, Vec,
-    };
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &None, &None);
+        client.set_insurance_bps(&admin, &2_500);
 
-    fn test_env() -> Env {
-        let mut env = Env::default();
-        env.set_config(EnvTestConfig {
-            capture_snapshot_at_drop: false,
+        let resolved = symbol_short!("resolved");
+        let open = symbol_short!("open");
+        env.as_contract(&contract_id, || {
+            let mut market = Market::new(
+                &env,
+                admin.clone(),
+                String::from_str(&env, "Insured"),
+                vec![&env, String::from_str(&env, "yes"), String::from_str(&env, "no")],
+                env.ledger().timestamp(),
+                OracleConfig::new(
+                    OracleProvider::reflector(),
+                    Address::generate(&env),
+                    String::from_str(&env, "BTC/USD"),
+                    100,
+                    ComparisonOp::Gt,
+                ),
+                None,
+                86400,
+                MarketState::Resolved,
+            );
+            market.winning_outcomes = Some(vec![&env, String::from_str(&env, "yes")]);
+            env.storage().persistent().set(&resolved, &market);
+            crate::resolution::MarketResolutionManager::record_resolved(&env, &resolved, &market)
+                .unwrap();
         });
-        env
-    }
 
-    fn resolved_market(env: &Env, total_staked: i128) -> Market {
-        let mut market = ValidationTestingUtils::create_test_market(env);
-        let mut winning_outcomes = Vec::new(env);
-        winning_outcomes.push_back(String::from_str(env, "yes"));
-        market.winning_outcomes = Some(winning_outcomes);
-        market.total_staked = total_staked;
-        market
+        // Raising the rate afterwards only reaches markets still to resolve
+        client.set_insurance_bps(&admin, &5_000);
+        env.as_contract(&contract_id, || {
+            assert_eq!(InsuranceFund::market_insurance_bps(&env, &resolved), 2_500);
+            assert_eq!(InsuranceFund::market_insurance_bps(&env, &open), 5_000);
+        });
     }
 
     #[test]
-    fn test_calculate_platform_fee_rejects_zero_pool() {
-        let env = test_env();
-        let market = resolved_market(&env, 0);
+    fn test_withdraw_pays_the_fund_address() {
+        use soroban_sdk::testutils::Address as _;
+        use soroban_sdk::token::{Client as TokenClient, StellarAssetClient};
 
-        let result = FeeCalculator::calculate_platform_fee(&market);
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        let fund = Address::generate(&env);
+        let token_id = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        client.initialize(&admin, &None, &None);
+
+        // The contract holds the fund balance in tokens
+        StellarAssetClient::new(&env, &token_id).mint(&contract_id, &1_000);
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "TokenID"), &token_id);
+            InsuranceFund::credit(&env, 1_000).unwrap();
+        });
 
-        assert_eq!(result, Err(Error::InvalidFeeConfig));
+        assert_eq!(
+            client.try_withdraw_insurance_fund(&admin, &400),
+            Err(Ok(Error::ConfigNotFound))
+        );
+        assert_eq!(
+            client.try_set_insurance_fund_address(&fund, &fund),
+            Err(Ok(Error::Unauthorized))
+        );
+        client.set_insurance_fund_address(&admin, &fund);
+        assert_eq!(client.get_insurance_fund_address(), Some(fund.clone()));
+
+        assert_eq!(
+            client.try_withdraw_insurance_fund(&fund, &400),
+            Err(Ok(Error::Unauthorized))
+        );
+        assert_eq!(
+            client.try_withdraw_insurance_fund(&admin, &1_001),
+            Err(Ok(Error::InsufficientBalance))
+        );
+
+        assert_eq!(client.withdraw_insurance_fund(&admin, &400), 600);
+        assert_eq!(client.get_insurance_fund_balance(), 600);
+        let token = TokenClient::new(&env, &token_id);
+        assert_eq!(token.balance(&fund), 400);
+        assert_eq!(token.balance(&contract_id), 600);
     }
+}
+
+#[cfg(test)]
+mod payout_rounding_tests {
+    use super::*;
+    use crate::oracles::ComparisonOp;
+    use crate::types::{MarketState, OracleConfig, OracleProvider};
+    use soroban_sdk::testutils::Address as _;
 
     #[test]
-    fn test_checked_bps_floor_rounds_down_for_one_basis_point() {
-        let result = FeeCalculator::checked_bps_floor(10_001, 1).unwrap();
+    fn test_div_rounded_floor_vs_half_even() {
+        // (numerator, denominator, floor, half_even)
+        let cases = [
+            (7, 2, 3, 4),   // 3.5 -> even 4
+            (5, 2, 2, 2),   // 2.5 -> even 2
+            (11, 3, 3, 4),  // 3.67
+            (10, 3, 3, 3),  // 3.33
+            (12, 3, 4, 4),  // exact
+            (0, 7, 0, 0),
+        ];
+        for (n, d, floor, half_even) in cases {
+            assert_eq!(NumericUtils::div_rounded(n, d, PayoutRounding::Floor), floor);
+            assert_eq!(NumericUtils::div_rounded(n, d, PayoutRounding::HalfEven), half_even);
+        }
+    }
 
-        assert_eq!(result, 1);
+    #[test]
+    fn test_uneven_split_pays_more_under_half_even() {
+        // Three equal winners (W = 300) share a 501 pool: exactly 167 each
+        // before the fee; a 98 share gives 163.66
+        let floor =
+            InsuranceFund::split_winner_payout_rounded(98, 501, 300, 0, PayoutRounding::Floor);
+        let half_even =
+            InsuranceFund::split_winner_payout_rounded(98, 501, 300, 0, PayoutRounding::HalfEven);
+        assert_eq!(floor, Ok((163, 0)));
+        assert_eq!(half_even, Ok((164, 0)));
+        // Default split keeps flooring
+        assert_eq!(InsuranceFund::split_winner_payout(98, 501, 300, 0), floor);
+
+        // With insurance the slice stays non-negative in both modes
+        let (payout, slice) = InsuranceFund::split_winner_payout_rounded(
+            98,
+            501,
+            300,
+            2_500,
+            PayoutRounding::HalfEven,
+        )
+        .unwrap();
+        assert!(slice >= 0);
+        assert!(payout <= half_even.unwrap().0);
     }
 
     #[test]
-    fn test_calculate_platform_fee_errors_on_overflow_adjacent_pool() {
-        let env = test_env();
-        let market = resolved_market(&env, i128::MAX);
+    fn test_claims_under_each_rounding_mode_stay_within_pool() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        let loser = Address::generate(&env);
+        let winners = [
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env),
+        ];
+        client.initialize(&admin, &None, &None);
+        assert_eq!(client.get_payout_rounding(), PayoutRounding::Floor);
+        assert_eq!(
+            client.try_set_payout_rounding(&loser, &PayoutRounding::HalfEven),
+            Err(Ok(Error::Unauthorized))
+        );
 
-        let result = FeeCalculator::calculate_platform_fee(&market);
+        // 3 x 1_000_001 winning vs 2_000_003 losing: shares never divide evenly
+        let (stake, losing_stake) = (1_000_001i128, 2_000_003i128);
+        let total_pool = stake * 3 + losing_stake;
+        let floored = symbol_short!("floored");
+        let rounded = symbol_short!("rounded");
+        env.as_contract(&contract_id, || {
+            for id in [&floored, &rounded] {
+                let mut market = Market::new(
+                    &env,
+                    admin.clone(),
+                    String::from_str(&env, "Rounding"),
+                    vec![&env, String::from_str(&env, "yes"), String::from_str(&env, "no")],
+                    env.ledger().timestamp(),
+                    OracleConfig::new(
+                        OracleProvider::reflector(),
+                        Address::generate(&env),
+                        String::from_str(&env, "BTC/USD"),
+                        100,
+                        ComparisonOp::Gt,
+                    ),
+                    None,
+                    86400,
+                    MarketState::Active,
+                );
+                for winner in winners.iter() {
+                    market.votes.set(winner.clone(), String::from_str(&env, "yes"));
+                    market.stakes.set(winner.clone(), stake);
+                }
+                market.votes.set(loser.clone(), String::from_str(&env, "no"));
+                market.stakes.set(loser.clone(), losing_stake);
+                market.total_staked = total_pool;
+                market.winning_outcomes = Some(vec![&env, String::from_str(&env, "yes")]);
+                market.state = MarketState::Resolved;
+                market.dispute_window_seconds = 0;
+                env.storage().persistent().set(id, &market);
+            }
+        });
 
-        assert_eq!(result, Err(Error::FeeArithmeticOverflow));
+        let fee_percent = env.as_contract(&contract_id, || {
+            crate::config::ConfigManager::get_config(&env)
+                .unwrap()
+                .fees
+                .platform_fee_percentage
+        });
+        let user_share = stake * (crate::config::PERCENTAGE_DENOMINATOR - fee_percent)
+            / crate::config::PERCENTAGE_DENOMINATOR;
+        let claim_all = |id: &Symbol| -> i128 {
+            let mut total = 0;
+            for winner in winners.iter() {
+                client.claim_winnings(winner, id);
+                total += client
+                    .get_market(id)
+                    .unwrap()
+                    .claimed
+                    .get(winner.clone())
+                    .unwrap()
+                    .get_payout();
+            }
+            total
+        };
+
+        let floor_total = claim_all(&floored);
+        client.set_payout_rounding(&admin, &PayoutRounding::HalfEven);
+        let half_even_total = claim_all(&rounded);
+
+        let expected = |rounding| {
+            NumericUtils::div_rounded(user_share * total_pool, stake * 3, rounding) * 3
+        };
+        assert_eq!(floor_total, expected(PayoutRounding::Floor));
+        assert_eq!(half_even_total, expected(PayoutRounding::HalfEven));
+        assert!(half_even_total >= floor_total);
+        assert!(half_even_total <= total_pool);
     }
 
     #[test]
-    fn test_fee_breakdown_reconciles_after_floor_rounding() {
-        let env = test_env();
-        let market = resolved_market(&env, 50_000_001);
+    fn test_rounded_payout_capped_at_remaining_pool() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        let winner = Address::generate(&env);
+        client.initialize(&admin, &None, &None);
+        client.set_payout_rounding(&admin, &PayoutRounding::HalfEven);
 
-        let breakdown = FeeCalculator::calculate_fee_breakdown(&market).unwrap();
+        let market_id = symbol_short!("capped");
+        let stake = 1_000i128;
+        env.as_contract(&contract_id, || {
+            let mut market = Market::new(
+                &env,
+                admin.clone(),
+                String::from_str(&env, "Capped"),
+                vec![&env, String::from_str(&env, "yes"), String::from_str(&env, "no")],
+                env.ledger().timestamp(),
+                OracleConfig::new(
+                    OracleProvider::reflector(),
+                    Address::generate(&env),
+                    String::from_str(&env, "BTC/USD"),
+                    100,
+                    ComparisonOp::Gt,
+                ),
+                None,
+                86400,
+                MarketState::Active,
+            );
+            market.votes.set(winner.clone(), String::from_str(&env, "yes"));
+            market.stakes.set(winner.clone(), stake);
+            market.total_staked = stake;
+            market.winning_outcomes = Some(vec![&env, String::from_str(&env, "yes")]);
+            market.state = MarketState::Resolved;
+            market.dispute_window_seconds = 0;
+            env.storage().persistent().set(&market_id, &market);
+            // Earlier claims have already drained all but 10 units of the pool
+            MarketStateManager::record_winner_claim(&env, &market_id, stake - 10);
+        });
 
-        assert_eq!(breakdown.fee_amount, 1_000_000);
-        assert_eq!(
-            breakdown.platform_fee + breakdown.user_payout_amount,
-            breakdown.total_staked
-        );
+        client.claim_winnings(&winner, &market_id);
+        let payout = client
+            .get_market(&market_id)
+            .unwrap()
+            .claimed
+            .get(winner.clone())
+            .unwrap()
+            .get_payout();
+        assert!(payout <= 10);
     }
 
     #[test]
-    fn test_collect_fees_returns_overflow_error_without_mutation() {
-        let env = test_env();
+    fn test_rounded_up_payouts_leave_room_for_fees_and_insurance() {
+        let env = Env::default();
         env.mock_all_auths();
         let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
         let admin = Address::generate(&env);
-        let market_id = Symbol::new(&env, "fee_overflow");
-        let market = resolved_market(&env, i128::MAX);
+        let loser = Address::generate(&env);
+        client.initialize(&admin, &None, &None);
+        client.set_payout_rounding(&admin, &PayoutRounding::HalfEven);
+        client.set_insurance_bps(&admin, &2_500);
+
+        // Seven winners of 1_000 against a 54 losing stake: at the default
+        // fee every winner's share rounds up (985.74 -> 986), so uncapped
+        // payouts plus fees and slices would overdraw the pool
+        let winners = [(); 7].map(|_| Address::generate(&env));
+        let (stake, losing_stake) = (1_000i128, 54i128);
+        let total_pool = stake * 7 + losing_stake;
+        let market_id = symbol_short!("many");
+        env.as_contract(&contract_id, || {
+            let mut market = Market::new(
+                &env,
+                admin.clone(),
+                String::from_str(&env, "Many winners"),
+                vec![&env, String::from_str(&env, "yes"), String::from_str(&env, "no")],
+                env.ledger().timestamp(),
+                OracleConfig::new(
+                    OracleProvider::reflector(),
+                    Address::generate(&env),
+                    String::from_str(&env, "BTC/USD"),
+                    100,
+                    ComparisonOp::Gt,
+                ),
+                None,
+                86400,
+                MarketState::Active,
+            );
+            for winner in winners.iter() {
+                market.votes.set(winner.clone(), String::from_str(&env, "yes"));
+                market.stakes.set(winner.clone(), stake);
+            }
+            market.votes.set(loser.clone(), String::from_str(&env, "no"));
+            market.stakes.set(loser.clone(), losing_stake);
+            market.total_staked = total_pool;
+            market.winning_outcomes = Some(vec![&env, String::from_str(&env, "yes")]);
+            market.state = MarketState::Resolved;
+            market.dispute_window_seconds = 0;
+            env.storage().persistent().set(&market_id, &market);
+        });
+
+        let (quote, fee_percent) = env.as_contract(&contract_id, || {
+            let market = MarketStateManager::get_market(&env, &market_id).unwrap();
+            let pools = (total_pool, stake * 7);
+            (
+                WinnerPayouts::quote(&env, &market_id, &market, None, stake, true, pools).unwrap(),
+                WinnerPayouts::fee_percent(&env, &market_id, None).unwrap(),
+            )
+        });
+        let user_share = stake * (crate::config::PERCENTAGE_DENOMINATOR - fee_percent)
+            / crate::config::PERCENTAGE_DENOMINATOR;
+        let (floored, _) =
+            InsuranceFund::split_winner_payout(user_share, total_pool, stake * 7, 2_500).unwrap();
+        assert!(quote.payout > floored);
+        assert!((quote.payout + quote.fee + quote.insurance_slice) * 7 > total_pool);
+
+        let mut total_paid = 0;
+        for winner in winners.iter() {
+            client.claim_winnings(winner, &market_id);
+            total_paid += client
+                .get_market(&market_id)
+                .unwrap()
+                .claimed
+                .get(winner.clone())
+                .unwrap()
+                .get_payout();
+        }
+
+        let fees = env.as_contract(&contract_id, || PendingFees::get(&env, &market_id));
+        let insurance = client.get_insurance_fund_balance();
+        assert_eq!(fees, quote.fee * 7);
+        assert_eq!(insurance, quote.insurance_slice * 7);
+        assert!(total_paid + fees + insurance <= total_pool);
+        // Only the last winner is cut back, by what rounding overdrew
+        assert!(total_paid >= quote.payout * 6);
+    }
+}
+
+#[cfg(test)]
+mod keeper_bounty_tests {
+    use super::*;
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::token::{Client as TokenClient, StellarAssetClient};
+
+    struct BountySetup {
+        env: Env,
+        contract_id: Address,
+        token_id: Address,
+        admin: Address,
+        keeper: Address,
+    }
+
+    fn setup(bounty: i128, vault: i128) -> BountySetup {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let token_admin = Address::generate(&env);
+        let token_id = env
+            .register_stellar_asset_contract_v2(token_admin)
+            .address();
+        let admin = Address::generate(&env);
+        let keeper = Address::generate(&env);
+
+        // The contract holds the vault balance in tokens
+        StellarAssetClient::new(&env, &token_id).mint(&contract_id, &vault);
 
         env.as_contract(&contract_id, || {
             env.storage()
                 .persistent()
                 .set(&Symbol::new(&env, "Admin"), &admin);
-            MarketStateManager::update_market(&env, &market_id, &market);
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "TokenID"), &token_id);
+            env.storage().persistent().set(&FEE_VAULT_KEY, &vault);
+            KeeperBounty::set_bounty(&env, &admin, bounty).unwrap();
+        });
 
-            let result = FeeManager::collect_fees(&env, admin.clone(), market_id.clone());
+        BountySetup {
+            env,
+            contract_id,
+            token_id,
+            admin,
+            keeper,
+        }
+    }
 
-            assert_eq!(result, Err(Error::FeeArithmeticOverflow));
+    #[test]
+    fn test_keeper_receives_bounty_from_fee_vault() {
+        let s = setup(5_000_000, 20_000_000);
+        let market_id = Symbol::new(&s.env, "stale_mkt");
+
+        let paid = s.env.as_contract(&s.contract_id, || {
+            KeeperBounty::pay(&s.env, &s.keeper, &market_id).unwrap()
+        });
+
+        assert_eq!(paid, 5_000_000);
+        let token = TokenClient::new(&s.env, &s.token_id);
+        assert_eq!(token.balance(&s.keeper), 5_000_000);
+        assert_eq!(token.balance(&s.contract_id), 15_000_000);
+        s.env.as_contract(&s.contract_id, || {
+            let vault: i128 = s.env.storage().persistent().get(&FEE_VAULT_KEY).unwrap();
+            assert_eq!(vault, 15_000_000);
+        });
+    }
+
+    #[test]
+    fn test_bounty_skipped_when_fees_insufficient() {
+        let s = setup(5_000_000, 4_999_999);
+        let market_id = Symbol::new(&s.env, "stale_mkt");
+
+        let paid = s.env.as_contract(&s.contract_id, || {
+            KeeperBounty::pay(&s.env, &s.keeper, &market_id).unwrap()
+        });
+
+        assert_eq!(paid, 0);
+        let token = TokenClient::new(&s.env, &s.token_id);
+        assert_eq!(token.balance(&s.keeper), 0);
+        s.env.as_contract(&s.contract_id, || {
+            let vault: i128 = s.env.storage().persistent().get(&FEE_VAULT_KEY).unwrap();
+            assert_eq!(vault, 4_999_999);
+        });
+    }
+
+    #[test]
+    fn test_set_bounty_validation() {
+        let s = setup(0, 0);
+        s.env.as_contract(&s.contract_id, || {
+            assert_eq!(KeeperBounty::get_bounty(&s.env), 0);
             assert_eq!(
-                env.storage()
-                    .persistent()
-                    .get::<Symbol, i128>(&FEE_VAULT_KEY)
-                    .unwrap_or(0),
-                0
+                KeeperBounty::set_bounty(&s.env, &s.admin, -1),
+                Err(Error::InvalidInput)
             );
-            assert!(!MarketStateManager::get_market(&env, &market_id)
-                .unwrap()
-                .fee_collected);
+            assert_eq!(
+                KeeperBounty::set_bounty(&s.env, &s.admin, MAX_FEE_AMOUNT + 1),
+                Err(Error::InvalidInput)
+            );
+            assert!(KeeperBounty::set_bounty(&s.env, &s.keeper, 1_000_000).is_err());
+            // Disabled bounty pays nothing even with a funded vault
+            assert_eq!(KeeperBounty::pay(&s.env, &s.keeper, &symbol_short!("m")), Ok(0));
         });
     }
+}
+
+#[cfg(test)]
+mod resolution_bounty_tests {
+    use super::*;
+    use crate::oracles::ComparisonOp;
+    use crate::types::{MarketState, OracleConfig, OracleProvider};
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::token::{Client as TokenClient, StellarAssetClient};
+
+    const BOUNTY: i128 = 25_000_000;
+
+    struct Setup {
+        env: Env,
+        contract_id: Address,
+        token_id: Address,
+        admin: Address,
+        creator: Address,
+        market_id: Symbol,
+    }
+
+    impl Setup {
+        /// Initialized contract with an active market whose creator escrowed `BOUNTY`.
+        fn new() -> Self {
+            let env = Env::default();
+            env.mock_all_auths();
+            let contract_id = env.register(crate::PredictifyHybrid, ());
+            let admin = Address::generate(&env);
+            let creator = Address::generate(&env);
+            let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+            client.initialize(&admin, &None, &None);
+
+            let token_id = env
+                .register_stellar_asset_contract_v2(Address::generate(&env))
+                .address();
+            StellarAssetClient::new(&env, &token_id).mint(&creator, &BOUNTY);
+
+            let market_id = Symbol::new(&env, "bountied");
+            env.as_contract(&contract_id, || {
+                env.storage()
+                    .persistent()
+                    .set(&Symbol::new(&env, "TokenID"), &token_id);
+                let market = Market::new(
+                    &env,
+                    creator.clone(),
+                    String::from_str(&env, "Bountied"),
+                    vec![&env, String::from_str(&env, "yes"), String::from_str(&env, "no")],
+                    env.ledger().timestamp() + 100,
+                    OracleConfig::new(
+                        OracleProvider::reflector(),
+                        Address::generate(&env),
+                        String::from_str(&env, "BTC/USD"),
+                        100,
+                        ComparisonOp::Gt,
+                    ),
+                    None,
+                    86400,
+                    MarketState::Active,
+                );
+                env.storage().persistent().set(&market_id, &market);
+            });
+
+            client.escrow_resolution_bounty(&creator, &market_id, &BOUNTY);
+
+            Self {
+                env,
+                contract_id,
+                token_id,
+                admin,
+                creator,
+                market_id,
+            }
+        }
+
+        fn client(&self) -> crate::PredictifyHybridClient<'_> {
+            crate::PredictifyHybridClient::new(&self.env, &self.contract_id)
+        }
+
+        fn token(&self) -> TokenClient<'_> {
+            TokenClient::new(&self.env, &self.token_id)
+        }
+    }
 
     #[test]
-    fn test_record_fee_collection_errors_on_total_overflow() {
-        let env = test_env();
-        let contract_id = env.register(crate::PredictifyHybrid, ());
-        let admin = Address::generate(&env);
-        let market_id = Symbol::new(&env, "fee_sum");
+    fn test_escrow_moves_bounty_into_contract() {
+        let s = Setup::new();
+        let client = s.client();
 
-        env.as_contract(&contract_id, || {
-            env.storage().persistent().set(&FEE_VAULT_KEY, &i128::MAX);
+        assert_eq!(s.token().balance(&s.creator), 0);
+        assert_eq!(s.token().balance(&s.contract_id), BOUNTY);
+        assert_eq!(
+            client.get_market(&s.market_id).unwrap().resolution_bounty,
+            BOUNTY
+        );
 
-            let result = FeeTracker::record_fee_collection(&env, &market_id, 1, &admin);
+        // Only once, and only by the creator
+        assert_eq!(
+            client.try_escrow_resolution_bounty(&s.creator, &s.market_id, &1),
+            Err(Ok(Error::InvalidState))
+        );
+        assert_eq!(
+            client.try_escrow_resolution_bounty(&s.admin, &s.market_id, &1),
+            Err(Ok(Error::Unauthorized))
+        );
+    }
 
-            assert_eq!(result, Err(Error::FeeArithmeticOverflow));
+    #[test]
+    fn test_resolver_receives_bounty() {
+        let s = Setup::new();
+        s.env.ledger().set_timestamp(s.env.ledger().timestamp() + 200);
+
+        s.client()
+            .resolve_market_manual(&s.admin, &s.market_id, &String::from_str(&s.env, "yes"), &None);
+
+        assert_eq!(s.token().balance(&s.admin), BOUNTY);
+        assert_eq!(s.token().balance(&s.contract_id), 0);
+        let market = s.client().get_market(&s.market_id).unwrap();
+        assert_eq!(market.state, MarketState::Resolved);
+        assert_eq!(market.resolution_bounty, 0);
+    }
+
+    #[test]
+    fn test_voided_market_refunds_bounty_to_creator() {
+        let s = Setup::new();
+
+        s.client().cancel_event(&s.admin, &s.market_id, &None);
+
+        assert_eq!(s.token().balance(&s.creator), BOUNTY);
+        assert_eq!(s.token().balance(&s.admin), 0);
+        let market = s.client().get_market(&s.market_id).unwrap();
+        assert_eq!(market.state, MarketState::Cancelled);
+        assert_eq!(market.resolution_bounty, 0);
+    }
+}
+
+#[cfg(test)]
+mod lifetime_fee_tests {
+    use super::*;
+    use crate::oracles::ComparisonOp;
+    use crate::types::{MarketState, OracleConfig, OracleProvider};
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::token::StellarAssetClient;
+
+    /// Stores a resolved market with `total_staked` ready for fee collection.
+    fn store_resolved_market(env: &Env, admin: &Address, name: &str, total_staked: i128) -> Symbol {
+        let market_id = Symbol::new(env, name);
+        let mut market = Market::new(
+            env,
+            admin.clone(),
+            String::from_str(env, "Will BTC close above 100k?"),
+            vec![env, String::from_str(env, "yes"), String::from_str(env, "no")],
+            env.ledger().timestamp() + 86400,
+            OracleConfig::new(
+                OracleProvider::reflector(),
+                Address::generate(env),
+                String::from_str(env, "BTC/USD"),
+                100,
+                ComparisonOp::Gt,
+            ),
+            None,
+            86400,
+            MarketState::Resolved,
+        );
+        market.winning_outcomes = Some(vec![env, String::from_str(env, "yes")]);
+        market.total_staked = total_staked;
+        env.storage().persistent().set(&market_id, &market);
+        market_id
+    }
+
+    #[test]
+    fn test_lifetime_fees_count_only_treasury_transfers() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let admin = Address::generate(&env);
+        let token_id = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        StellarAssetClient::new(&env, &token_id).mint(&contract_id, &10_000_000_000);
+
+        let collected = env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "Admin"), &admin);
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "TokenID"), &token_id);
+
+            let first = store_resolved_market(&env, &admin, "fee_mkt_a", 1_000_000_000);
+            let second = store_resolved_market(&env, &admin, "fee_mkt_b", 2_500_000_000);
+            let collected = FeeManager::collect_fees(&env, admin.clone(), first).unwrap()
+                + FeeManager::collect_fees(&env, admin.clone(), second).unwrap();
+            assert!(collected > 0);
+
+            // Accrual into the vault does not count towards the lifetime total
+            assert_eq!(FeeTracker::get_lifetime_fees(&env), 0);
+
+            let withdrawn = FeeWithdrawalManager::withdraw_fees(&env, &admin, 0).unwrap();
+            assert_eq!(withdrawn, collected);
+            assert_eq!(FeeTracker::get_lifetime_fees(&env), collected);
+            // The vault is drained but the lifetime total is kept
+            assert_eq!(FeeTracker::get_total_fees_collected(&env), Ok(0));
+            collected
         });
+
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+        assert_eq!(client.get_lifetime_fees(), collected);
     }
 }
 
 #[cfg(any())]
 mod tests {
     use super::*;
+    use crate::oracles::ComparisonOp;
     use soroban_sdk::testutils::Address as _;
 
     #[test]
@@ -2434,7 +4536,7 @@ mod tests {
                 ),
                 String::from_str(&env, "BTC/USD"),
                 2_500_000,
-                String::from_str(&env, "gt"),
+                ComparisonOp::Gt,
             ),
             None,
             86400,
@@ -2503,7 +4605,7 @@ mod tests {
                 ),
                 String::from_str(&env, "BTC/USD"),
                 2_500_000,
-                String::from_str(&env, "gt"),
+                ComparisonOp::Gt,
             ),
             None,
             86400,
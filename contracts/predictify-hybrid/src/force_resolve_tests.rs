@@ -2,6 +2,7 @@
 
 use crate::err::Error;
 use crate::force_resolve::ForceResolveManager;
+use crate::oracles::ComparisonOp;
 use crate::types::{MarketState, OracleConfig, OracleProvider};
 use crate::{PredictifyHybrid, PredictifyHybridClient};
 use soroban_sdk::{
@@ -56,7 +57,8 @@ impl Ctx {
                 oracle_address: Address::generate(&self.env),
                 feed_id: String::from_str(&self.env, "BTC"),
                 threshold: 100_000_00,
-                comparison: String::from_str(&self.env, "gt"),
+                comparison: ComparisonOp::Gt,
+                threshold2: None,
             },
             &None,
             &0u64,
@@ -102,6 +104,12 @@ fn test_force_resolve_active_market() {
     let market = ctx.client().get_market(&market_id).unwrap();
     assert_eq!(market.state, MarketState::Resolved);
     assert_eq!(market.winning_outcomes, Some(outcomes(&ctx.env, &["yes"])));
+
+    // The reason is kept as the evidence of the resolution record
+    let record = ctx.client().get_resolution_record(&market_id).unwrap();
+    assert_eq!(record.winning_outcomes, outcomes(&ctx.env, &["yes"]));
+    assert_eq!(record.resolved_by, ctx.admin);
+    assert_eq!(record.evidence, Some(reason(&ctx.env, "Emergency")));
 }
 
 #[test]
@@ -157,6 +157,7 @@ impl GasTestContext {
                 feed_id: String::from_str(&self.env, "BTC"),
                 threshold: 1000,
                 comparison: String::from_str(&self.env, "gt"),
+                threshold2: None,
             },
             &None,
             &3600,
@@ -217,6 +218,7 @@ fn test_gas_create_market_minimal() {
             feed_id: String::from_str(&ctx.env, "BTC"),
             threshold: 1000,
             comparison: String::from_str(&ctx.env, "gt"),
+            threshold2: None,
         },
         &None,
         &3600,
@@ -262,6 +264,7 @@ fn test_gas_create_market_maximal() {
             feed_id: String::from_str(&ctx.env, "BTCUSD"),
             threshold: 10000000,
             comparison: String::from_str(&ctx.env, "gte"),
+            threshold2: None,
         },
         &None,
         &3600,
@@ -443,6 +446,7 @@ fn test_gas_operations_within_expected_ranges() {
             feed_id: String::from_str(&ctx.env, "BTC"),
             threshold: 1000,
             comparison: String::from_str(&ctx.env, "gt"),
+            threshold2: None,
         },
         &None,
         &3600,
@@ -101,6 +101,7 @@ impl IntegrationTestSuite {
                 feed_id: String::from_str(&self.env, "BTC"),
                 threshold: 2500000,
                 comparison: String::from_str(&self.env, "gt"),
+                threshold2: None,
             },
             &None,
             &0,
@@ -159,7 +160,7 @@ impl IntegrationTestSuite {
         let winning_outcome = market.outcomes.get(0).unwrap().clone(); // Use first outcome as default
 
         // Use manual resolution instead of automatic oracle resolution
-        client.resolve_market_manual(&self.admin, market_id, &winning_outcome);
+        client.resolve_market_manual(&self.admin, market_id, &winning_outcome, &None);
         Ok(())
     }
 
@@ -235,6 +236,7 @@ impl IntegrationTestSuite {
             feed_id: String::from_str(&self.env, "BTC"),
             threshold: 2500000,
             comparison: String::from_str(&self.env, "gt"),
+            threshold2: None,
         };
 
         let fallback_oracle = if has_fallback {
@@ -244,6 +246,7 @@ impl IntegrationTestSuite {
                 feed_id: String::from_str(&self.env, "BTC"),
                 threshold: 2000000,
                 comparison: String::from_str(&self.env, "gt"),
+                threshold2: None,
             })
         } else {
             None
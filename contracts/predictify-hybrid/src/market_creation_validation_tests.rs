@@ -369,3 +369,57 @@ fn create_market_accepts_normal_ledger_sequence() {
         String::from_str(&setup.env, "Will normal sequence be accepted?")
     );
 }
+
+#[test]
+fn create_market_rejects_disabled_oracle_provider_until_reenabled() {
+    let setup = TestSetup::new();
+    let client = setup.client();
+    let question = String::from_str(&setup.env, "Will BTC close above fifty thousand?");
+
+    // All providers are allowed by default
+    assert_eq!(client.get_allowed_oracle_providers().len(), 4);
+
+    client.set_oracle_provider_allowed(&setup.admin, &OracleProvider::Reflector, &false);
+    assert!(!client
+        .get_allowed_oracle_providers()
+        .contains(&OracleProvider::Reflector));
+
+    let result = client.try_create_market(
+        &setup.admin,
+        &question,
+        &setup.valid_outcomes(),
+        &30u32,
+        &setup.valid_oracle_config(),
+        &None,
+        &86_400u64,
+        &None,
+        &None,
+        &None,
+    );
+    assert_contract_error(result, Error::ProviderNotAllowed);
+
+    client.set_oracle_provider_allowed(&setup.admin, &OracleProvider::Reflector, &true);
+    let market_id = client.create_market(
+        &setup.admin,
+        &question,
+        &setup.valid_outcomes(),
+        &30u32,
+        &setup.valid_oracle_config(),
+        &None,
+        &86_400u64,
+        &None,
+        &None,
+        &None,
+    );
+    assert!(client.get_market(&market_id).is_some());
+}
+
+#[test]
+fn set_oracle_provider_allowed_requires_admin() {
+    let setup = TestSetup::new();
+    let outsider = Address::generate(&setup.env);
+    let result = setup
+        .client()
+        .try_set_oracle_provider_allowed(&outsider, &OracleProvider::Pyth, &false);
+    assert_contract_error(result, Error::Unauthorized);
+}
@@ -31,9 +31,17 @@
 //! ```
 //!
 //! Example: `mkt_3f9a1b2c_0`
+//!
+//! # Content-addressed mode
+//!
+//! When enabled by the admin, `create_market` instead derives the ID from
+//! SHA-256(question ‖ outcomes ‖ oracle config), formatted as
+//! `mkt_{16 hex chars}_0`. Identical creation parameters always map to the
+//! same ID, so a duplicate is rejected with [`Error::DuplicateMarketId`]
+//! rather than retried.
 
 use crate::Error;
-use crate::types::Market;
+use crate::types::{Market, OracleConfig};
 use alloc::format;
 #[cfg(not(target_family = "wasm"))]
 use alloc::string::ToString;
@@ -74,6 +82,9 @@ impl MarketIdGenerator {
         pub(crate) const GLOBAL_NONCE_KEY: &'static str = "mid_nonce";
         const REGISTRY_KEY: &'static str = "mid_registry";
         const SEED_SEALED_KEY: &'static str = "mid_seed_sealed";
+        const CONTENT_MODE_KEY: &'static str = "mid_content_mode";
+        /// Bytes of the SHA-256 digest kept in a content-addressed ID (16 hex chars).
+        const CONTENT_DIGEST_BYTES: usize = 8;
         /// Hard upper bound on the per-admin counter.
         pub const MAX_COUNTER: u32 = 999_999;
         /// Maximum collision-retry attempts before giving up.
@@ -347,11 +358,78 @@ pub fn parse_market_id_components(
     }
 }
 
+/// Whether `create_market` derives market IDs from content (default `false`).
+pub fn is_content_addressed(env: &Env) -> bool {
+    env.storage()
+        .persistent()
+        .get(&Symbol::new(env, Self::CONTENT_MODE_KEY))
+        .unwrap_or(false)
+}
+
+/// Enable or disable content-addressed market IDs. Callers must check admin auth.
+pub fn set_content_addressed(env: &Env, enabled: bool) {
+    env.storage()
+        .persistent()
+        .set(&Symbol::new(env, Self::CONTENT_MODE_KEY), &enabled);
+}
+
+/// Derive a deterministic market ID from the market's creation parameters.
+///
+/// The same `question`, `outcomes` and `oracle_config` always produce the
+/// same `mkt_{16 hex chars}_0` ID; no storage is read or written.
+pub fn content_market_id(
+    env: &Env,
+    question: &soroban_sdk::String,
+    outcomes: &Vec<soroban_sdk::String>,
+    oracle_config: &OracleConfig,
+) -> Symbol {
+    let mut preimage = Bytes::new(env);
+    preimage.append(&question.clone().to_xdr(env));
+    preimage.append(&outcomes.clone().to_xdr(env));
+    preimage.append(&oracle_config.clone().to_xdr(env));
+    let digest = env.crypto().sha256(&preimage).to_array();
+
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+    let mut id = [0u8; 4 + 2 * Self::CONTENT_DIGEST_BYTES + 2];
+    id[..4].copy_from_slice(b"mkt_");
+    for (i, byte) in digest[..Self::CONTENT_DIGEST_BYTES].iter().enumerate() {
+        id[4 + 2 * i] = HEX[(byte >> 4) as usize];
+        id[5 + 2 * i] = HEX[(byte & 0x0f) as usize];
+    }
+    let len = id.len();
+    id[len - 2..].copy_from_slice(b"_0");
+
+    match core::str::from_utf8(&id) {
+        Ok(s) => Symbol::new(env, s),
+        Err(_) => panic_with_error!(env, Error::InvalidState),
+    }
+}
+
+/// Derive the content-addressed ID and reserve it for a new market.
+///
+/// # Panics
+///
+/// - [`Error::DuplicateMarketId`] if a market with identical creation
+///   parameters (and therefore the same ID) already exists.
+pub fn claim_content_market_id(
+    env: &Env,
+    question: &soroban_sdk::String,
+    outcomes: &Vec<soroban_sdk::String>,
+    oracle_config: &OracleConfig,
+) -> Symbol {
+    let market_id = Self::content_market_id(env, question, outcomes, oracle_config);
+    if Self::check_market_id_collision(env, &market_id) {
+        panic_with_error!(env, Error::DuplicateMarketId);
+    }
+    market_id
+}
+
 // ── Tests ─────────────────────────────────────────────────────────────────────
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::oracles::ComparisonOp;
     use soroban_sdk::testutils::{Address as _, Ledger, LedgerInfo};
 
     // ── Helpers ──────────────────────────────────────────────────────────────
@@ -770,4 +848,75 @@ mod tests {
         }
         assert_eq!(all_ids.len(), 25);
     }
+
+    // ── Content-addressed mode ───────────────────────────────────────────────
+
+    fn content_market_args(
+        env: &Env,
+    ) -> (soroban_sdk::String, Vec<soroban_sdk::String>, OracleConfig) {
+        (
+            soroban_sdk::String::from_str(env, "Will BTC close above 100k?"),
+            soroban_sdk::vec![
+                env,
+                soroban_sdk::String::from_str(env, "yes"),
+                soroban_sdk::String::from_str(env, "no"),
+            ],
+            OracleConfig::new(
+                crate::types::OracleProvider::reflector(),
+                Address::from_str(
+                    env,
+                    "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF",
+                ),
+                soroban_sdk::String::from_str(env, "BTC/USD"),
+                100,
+                ComparisonOp::Gt,
+            ),
+        )
+    }
+
+    #[test]
+    fn test_content_id_is_deterministic_and_parameter_sensitive() {
+        let (env, _, _) = setup();
+        let (question, outcomes, oracle) = content_market_args(&env);
+
+        let a = MarketIdGenerator::content_market_id(&env, &question, &outcomes, &oracle);
+        let b = MarketIdGenerator::content_market_id(&env, &question, &outcomes, &oracle);
+        assert_eq!(a, b);
+        assert_eq!(a.to_string().len(), 22);
+        assert!(MarketIdGenerator::validate_market_id_format(&env, &a));
+
+        let other_q = soroban_sdk::String::from_str(&env, "Will BTC close above 90k?");
+        let c = MarketIdGenerator::content_market_id(&env, &other_q, &outcomes, &oracle);
+        assert_ne!(a, c);
+    }
+
+    #[test]
+    fn test_content_mode_rejects_identical_market() {
+        let (env, contract_id, admin) = setup();
+        env.mock_all_auths();
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+        client.initialize(&admin, &None, &None);
+        client.set_content_addressed_market_ids(&admin, &true);
+
+        let (question, outcomes, oracle) = content_market_args(&env);
+        let create = || {
+            client.try_create_market(
+                &admin, &question, &outcomes, &30, &oracle, &None, &86400u64, &None, &None,
+                &None,
+            )
+        };
+
+        let first = create().unwrap().unwrap();
+        assert_eq!(
+            first,
+            with_contract(&env, &contract_id, || {
+                MarketIdGenerator::content_market_id(&env, &question, &outcomes, &oracle)
+            })
+        );
+        assert_eq!(create(), Err(Ok(Error::DuplicateMarketId)));
+
+        // Leaving content mode restores the collision-resistant generator.
+        client.set_content_addressed_market_ids(&admin, &false);
+        assert_ne!(create().unwrap().unwrap(), first);
+    }
 }
@@ -20,10 +20,10 @@
 //! # Legal Transition Diagram (reproduced here for quick reference)
 //!
 //! ```text
-//!   Active    → Ended | Cancelled | Closed | Disputed
+//!   Active    → Ended | Resolved | Cancelled | Closed | Disputed
 //!   Ended     → Resolved | Disputed | Closed | Cancelled
 //!   Disputed  → Resolved | Closed | Cancelled
-//!   Resolved  → Closed
+//!   Resolved  → Disputed | Closed
 //!   Closed    → (terminal)
 //!   Cancelled → (terminal)
 //! ```
@@ -32,7 +32,9 @@
 mod market_state_matrix {
     use crate::err::Error;
     use crate::markets::MarketStateLogic;
-    use crate::types::MarketState;
+    use crate::types::{Market, MarketState, OracleConfig};
+    use soroban_sdk::testutils::Address as _;
+    use soroban_sdk::{vec, Address, String};
 
     // -----------------------------------------------------------------------
     // Helpers
@@ -65,10 +67,10 @@ mod market_state_matrix {
     fn is_legal(from: MarketState, to: MarketState) -> bool {
         use MarketState::*;
         match from {
-            Active => matches!(to, Ended | Cancelled | Closed | Disputed),
+            Active => matches!(to, Ended | Resolved | Cancelled | Closed | Disputed),
             Ended => matches!(to, Resolved | Disputed | Closed | Cancelled),
             Disputed => matches!(to, Resolved | Closed | Cancelled),
-            Resolved => matches!(to, Closed),
+            Resolved => matches!(to, Disputed | Closed),
             Closed => false,
             Cancelled => false,
         }
@@ -172,15 +174,15 @@ mod market_state_matrix {
         }
 
         // Sanity-check the legal/illegal split.
-        // Legal edges:  Active(4) + Ended(4) + Disputed(3) + Resolved(1) = 12
-        // Illegal edges: 36 - 12 = 24  (includes all 6 self-loops)
+        // Legal edges:  Active(5) + Ended(4) + Disputed(3) + Resolved(2) = 14
+        // Illegal edges: 36 - 14 = 22  (includes all 6 self-loops)
         assert_eq!(
-            legal_count, 12,
-            "Expected 12 legal edges in the matrix, found {legal_count}"
+            legal_count, 14,
+            "Expected 14 legal edges in the matrix, found {legal_count}"
         );
         assert_eq!(
-            illegal_count, 24,
-            "Expected 24 illegal edges in the matrix, found {illegal_count}"
+            illegal_count, 22,
+            "Expected 22 illegal edges in the matrix, found {illegal_count}"
         );
     }
 
@@ -197,6 +199,15 @@ mod market_state_matrix {
         .is_ok());
     }
 
+    #[test]
+    fn test_legal_active_to_resolved() {
+        assert!(MarketStateLogic::validate_state_transition(
+            MarketState::Active,
+            MarketState::Resolved
+        )
+        .is_ok());
+    }
+
     #[test]
     fn test_legal_active_to_cancelled() {
         assert!(MarketStateLogic::validate_state_transition(
@@ -287,6 +298,15 @@ mod market_state_matrix {
         .is_ok());
     }
 
+    #[test]
+    fn test_legal_resolved_to_disputed() {
+        assert!(MarketStateLogic::validate_state_transition(
+            MarketState::Resolved,
+            MarketState::Disputed
+        )
+        .is_ok());
+    }
+
     #[test]
     fn test_legal_resolved_to_closed() {
         assert!(MarketStateLogic::validate_state_transition(
@@ -505,17 +525,6 @@ mod market_state_matrix {
         );
     }
 
-    #[test]
-    fn test_illegal_resolved_to_disputed() {
-        assert_eq!(
-            MarketStateLogic::validate_state_transition(
-                MarketState::Resolved,
-                MarketState::Disputed
-            ),
-            Err(Error::IllegalMarketStateTransition)
-        );
-    }
-
     #[test]
     fn test_illegal_resolved_to_cancelled() {
         assert_eq!(
@@ -527,19 +536,6 @@ mod market_state_matrix {
         );
     }
 
-    // --- Active backward edges ---
-
-    #[test]
-    fn test_illegal_active_to_resolved() {
-        assert_eq!(
-            MarketStateLogic::validate_state_transition(
-                MarketState::Active,
-                MarketState::Resolved
-            ),
-            Err(Error::IllegalMarketStateTransition)
-        );
-    }
-
     // --- Ended backward edge ---
 
     #[test]
@@ -592,7 +588,6 @@ mod market_state_matrix {
         let illegal_edges = [
             (MarketState::Resolved, MarketState::Active),
             (MarketState::Resolved, MarketState::Ended),
-            (MarketState::Resolved, MarketState::Disputed),
             (MarketState::Resolved, MarketState::Cancelled),
             // Closed → anything is also illegal after archive
             (MarketState::Closed, MarketState::Active),
@@ -612,4 +607,55 @@ mod market_state_matrix {
             );
         }
     }
+
+    // -----------------------------------------------------------------------
+    // transition_state: the single write path for lifecycle changes
+    // -----------------------------------------------------------------------
+
+    fn market_in(env: &soroban_sdk::Env, state: MarketState) -> Market {
+        Market::new(
+            env,
+            Address::generate(env),
+            String::from_str(env, "Transition"),
+            vec![env, String::from_str(env, "yes"), String::from_str(env, "no")],
+            1_000,
+            OracleConfig::none_sentinel(env),
+            None,
+            86400,
+            state,
+        )
+    }
+
+    #[test]
+    fn test_transition_state_applies_every_legal_edge() {
+        let env = soroban_sdk::Env::default();
+        for &from in ALL_STATES {
+            for &to in ALL_STATES {
+                if !is_legal(from, to) {
+                    continue;
+                }
+                let mut market = market_in(&env, from);
+                assert_eq!(MarketStateLogic::transition_state(&mut market, to), Ok(()));
+                assert_eq!(market.state, to, "{:?} → {:?} was not applied", from, to);
+            }
+        }
+    }
+
+    #[test]
+    fn test_transition_state_rejects_illegal_edges_without_writing() {
+        let env = soroban_sdk::Env::default();
+        for (from, to) in [
+            (MarketState::Resolved, MarketState::Active),
+            (MarketState::Cancelled, MarketState::Resolved),
+            (MarketState::Closed, MarketState::Disputed),
+            (MarketState::Disputed, MarketState::Ended),
+        ] {
+            let mut market = market_in(&env, from);
+            assert_eq!(
+                MarketStateLogic::transition_state(&mut market, to),
+                Err(Error::IllegalMarketStateTransition)
+            );
+            assert_eq!(market.state, from);
+        }
+    }
 }
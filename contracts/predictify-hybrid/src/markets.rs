@@ -1,9 +1,10 @@
 #![allow(dead_code)]
 
-use soroban_sdk::{contracttype, token, vec, Address, Env, Map, String, Symbol, Vec};
+use soroban_sdk::{contracttype, symbol_short, token, vec, Address, Env, Map, String, Symbol, Vec};
 
 // use crate::config; // Unused import
 use crate::err::Error;
+use crate::oracles::ComparisonOp;
 use crate::storage::{check_market_creation_rent, DataKey, MARKET_CACHE_TTL_LEDGERS, MARKET_TTL_LEDGERS};
 use crate::types::*;
 // Oracle imports removed - not currently used
@@ -77,7 +78,7 @@ impl MarketCreator {
     ///     OracleProvider::pyth(),
     ///     String::from_str(&env, "BTC/USD"),
     ///     100_000_00, // $100,000 with 2 decimal places
-    ///     String::from_str(&env, "gte")
+    ///     ComparisonOp::Gte
     /// );
     ///
     /// let market_id = MarketCreator::create_market(
@@ -98,6 +99,8 @@ impl MarketCreator {
         duration_days: u32,
         oracle_config: OracleConfig,
     ) -> Result<Symbol, Error> {
+        CreatorSuspensionManager::require_not_suspended(env, &admin)?;
+
         // Validate market parameters
         MarketValidator::validate_market_params(env, &question, &outcomes, duration_days)?;
 
@@ -123,10 +126,10 @@ impl MarketCreator {
             MarketState::Active,
         );
 
-        // Process market creation fee
-        // Market creator flow does not have the generated market id yet in this helper path.
-        // Use the generated id after creation in higher-level flows when event metadata is required.
-       let _ = MarketUtils::process_creation_fee(env, &admin)?;
+        // Process market creation fee and remember it in case the creator deletes
+        // the market before anyone votes.
+        let creation_fee = MarketUtils::process_creation_fee(env, &admin)?;
+        crate::fees::CreationFeeRefunds::record_paid(env, &market_id, creation_fee);
 
         // Pre-flight check: ensure sufficient storage rent budget
         check_market_creation_rent(env)?;
@@ -158,7 +161,7 @@ impl MarketCreator {
     /// * `duration_days` - Market duration in days (1-365 days)
     /// * `asset_symbol` - Reflector asset symbol (e.g., "BTC", "ETH", "XLM")
     /// * `threshold` - Price threshold for comparison (in asset's base units)
-    /// * `comparison` - Comparison operator ("gt", "gte", "lt", "lte", "eq")
+    /// * `comparison` - Comparison operator ("gt", "gte", "lt", "lte", "eq", "neq")
     ///
     /// # Returns
     ///
@@ -213,7 +216,9 @@ impl MarketCreator {
             oracle_address,
             feed_id: asset_symbol,
             threshold,
-            comparison,
+            comparison: ComparisonOp::parse(_env, &comparison)
+                .map_err(|_| Error::InvalidOracleConfig)?,
+            threshold2: None,
         };
 
         Self::create_market(
@@ -241,7 +246,7 @@ impl MarketCreator {
     /// * `duration_days` - Market duration in days (1-365 days)
     /// * `feed_id` - Pyth price feed identifier (e.g., "BTC/USD", "ETH/USD")
     /// * `threshold` - Price threshold for comparison (in feed's base units)
-    /// * `comparison` - Comparison operator ("gt", "gte", "lt", "lte", "eq")
+    /// * `comparison` - Comparison operator ("gt", "gte", "lt", "lte", "eq", "neq")
     ///
     /// # Returns
     ///
@@ -295,7 +300,9 @@ impl MarketCreator {
             oracle_address,
             feed_id,
             threshold,
-            comparison,
+            comparison: ComparisonOp::parse(_env, &comparison)
+                .map_err(|_| Error::InvalidOracleConfig)?,
+            threshold2: None,
         };
 
         Self::create_market(
@@ -324,7 +331,7 @@ impl MarketCreator {
     /// * `duration_days` - Market duration in days (1-365 days)
     /// * `asset_symbol` - Specific asset symbol (e.g., "BTC", "ETH", "GOLD")
     /// * `threshold` - Price threshold for comparison (in asset's base units)
-    /// * `comparison` - Comparison operator ("gt", "gte", "lt", "lte", "eq")
+    /// * `comparison` - Comparison operator ("gt", "gte", "lt", "lte", "eq", "neq")
     ///
     /// # Returns
     ///
@@ -503,7 +510,7 @@ impl MarketValidator {
     ///     OracleProvider::pyth(),
     ///     String::from_str(&env, "BTC/USD"),
     ///     50_000_00, // $50,000
-    ///     String::from_str(&env, "gt")
+    ///     ComparisonOp::Gt
     /// );
     ///
     /// assert!(MarketValidator::validate_oracle_config(&env, &oracle_config).is_ok());
@@ -521,11 +528,6 @@ impl MarketValidator {
             return Err(Error::InvalidOracleConfig);
         }
 
-        // comparison must not be empty
-        if oracle_config.comparison.len() == 0 {
-            return Err(Error::InvalidOracleConfig);
-        }
-
         Ok(())
     }
 
@@ -647,6 +649,24 @@ impl MarketValidator {
         Err(Error::InvalidOutcome)
     }
 
+    /// Asserts that `outcome` is one of `market.outcomes`.
+    ///
+    /// Every resolution path (manual, tie, force, oracle, hybrid, dispute) must call
+    /// this before recording a winning outcome or oracle result. An outcome outside the
+    /// configured set can never be matched by a vote, so resolving to it would strand
+    /// the whole pool.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InvalidOutcome` - `outcome` is not a configured outcome of `market`
+    pub fn assert_valid_outcome(market: &Market, outcome: &String) -> Result<(), Error> {
+        if market.outcomes.contains(outcome) {
+            Ok(())
+        } else {
+            Err(Error::InvalidOutcome)
+        }
+    }
+
     /// Validates that a stake amount meets minimum requirements and is positive.
     ///
     /// This function ensures that users provide adequate stake amounts for voting
@@ -698,6 +718,46 @@ impl MarketValidator {
 
         Ok(())
     }
+
+    /// Validates a settlement token and conversion rate for a new market.
+    ///
+    /// The staking token (`TokenID`) must be configured, the settlement token
+    /// must differ from it, and both must answer the token interface. The rate
+    /// is expressed in units of `config::SETTLEMENT_RATE_DENOMINATOR`.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InvalidInput` - Rate is zero or above `config::MAX_SETTLEMENT_RATE_BPS`
+    /// * `Error::InvalidState` - No staking token is configured
+    /// * `Error::InvalidSettlementToken` - Settlement token equals the staking
+    ///   token, or either token is not a token contract
+    pub fn validate_settlement(
+        env: &Env,
+        settlement_token: &Address,
+        settlement_rate_bps: u32,
+    ) -> Result<(), Error> {
+        if settlement_rate_bps == 0
+            || settlement_rate_bps > crate::config::MAX_SETTLEMENT_RATE_BPS
+        {
+            return Err(Error::InvalidInput);
+        }
+
+        let staking_token = MarketUtils::get_token_client(env)?;
+        if staking_token.address == *settlement_token {
+            return Err(Error::InvalidSettlementToken);
+        }
+        if !matches!(staking_token.try_decimals(), Ok(Ok(_))) {
+            return Err(Error::InvalidSettlementToken);
+        }
+        if !matches!(
+            token::Client::new(env, settlement_token).try_decimals(),
+            Ok(Ok(_))
+        ) {
+            return Err(Error::InvalidSettlementToken);
+        }
+
+        Ok(())
+    }
 }
 
 // ===== MARKET READ CACHE =====
@@ -949,15 +1009,90 @@ impl MarketStateManager {
             Err(_) => return,
         };
         if market.state != MarketState::Closed {
-            MarketStateLogic::validate_state_transition(market.state, MarketState::Closed).unwrap();
             let old_state = market.state;
-            market.state = MarketState::Closed;
+            MarketStateLogic::transition_state(&mut market, MarketState::Closed).unwrap();
             MarketStateLogic::emit_state_change_event(env, market_id, old_state, market.state);
+            MarketStateCounts::record_transition(env, old_state, market.state);
             Self::update_market(env, market_id, &market);
         }
+        env.storage().persistent().remove(market_id);
+        MarketStateCounts::record_removed(env, market.state);
+        // CACHE INVALIDATION: remove cache entry after persistent removal
+        MarketReadCache::new(env).invalidate(market_id);
+    }
+
+    /// Deletes a market on behalf of its creator while nobody has voted on it.
+    ///
+    /// This is the creator's escape hatch for a market created by mistake: as long
+    /// as the market is still `Active` and has no votes or stake, it is removed from
+    /// storage and from the market indexes, and any refundable creation fee is
+    /// returned (see [`crate::fees::CreationFeeRefunds`]). An escrowed resolution
+    /// bounty is always refunded to the creator.
+    ///
+    /// # Parameters
+    ///
+    /// * `env` - The Soroban environment for blockchain operations
+    /// * `creator` - Address that created the market (must authorize)
+    /// * `market_id` - Unique symbol identifier for the market to delete
+    ///
+    /// # Returns
+    ///
+    /// The creation fee refunded to `creator` (0 if none was charged or refunds are disabled).
+    ///
+    /// # Errors
+    ///
+    /// * `Error::MarketNotFound` - Market does not exist
+    /// * `Error::Unauthorized` - `creator` is not the market's creator
+    /// * `Error::InvalidState` - Market is no longer `Active`
+    /// * `Error::BetsAlreadyPlaced` - At least one vote or stake has been recorded
+    ///
+    /// # Events
+    ///
+    /// Emits `MarketDeletedEvent` on success, preceded by a resolution-bounty
+    /// release event when a bounty is refunded.
+    pub fn delete_market(env: &Env, creator: &Address, market_id: &Symbol) -> Result<i128, Error> {
+        creator.require_auth();
+
+        let mut market = Self::get_market(env, market_id)?;
+        if market.creator != *creator {
+            return Err(Error::Unauthorized);
+        }
+        if market.state != MarketState::Active {
+            return Err(Error::InvalidState);
+        }
+        if market.votes.len() > 0 || market.total_staked > 0 {
+            return Err(Error::BetsAlreadyPlaced);
+        }
+
+        let refunded_fee = crate::fees::CreationFeeRefunds::refund(env, market_id, creator)?;
+        // The market record goes away with its escrowed bounty; return it first
+        crate::fees::ResolutionBounty::refund(env, market_id, &mut market)?;
+
         env.storage().persistent().remove(market_id);
         // CACHE INVALIDATION: remove cache entry after persistent removal
         MarketReadCache::new(env).invalidate(market_id);
+        Self::remove_from_market_index(env, market_id);
+        MarketCreationCaps::record_closed(env, creator);
+        MarketStateCounts::record_removed(env, market.state);
+
+        crate::events::EventEmitter::emit_market_deleted(env, market_id, creator, refunded_fee);
+
+        Ok(refunded_fee)
+    }
+
+    /// Drops `market_id` from the global market index, if present.
+    fn remove_from_market_index(env: &Env, market_id: &Symbol) {
+        let index_key = Symbol::new(env, "market_index");
+        let markets: Option<Vec<Symbol>> = env.storage().persistent().get(&index_key);
+        if let Some(markets) = markets {
+            let mut remaining = Vec::new(env);
+            for id in markets.iter() {
+                if id != *market_id {
+                    remaining.push_back(id);
+                }
+            }
+            env.storage().persistent().set(&index_key, &remaining);
+        }
     }
 
     /// Adds a user's vote to a market with the specified stake amount.
@@ -1098,10 +1233,8 @@ impl MarketStateManager {
         market.dispute_stakes.set(user, existing_stake + stake);
         // State transition: Ended -> Disputed
         if market.state == MarketState::Ended {
-            MarketStateLogic::validate_state_transition(market.state, MarketState::Disputed)
-                .unwrap();
             let old_state = market.state;
-            market.state = MarketState::Disputed;
+            MarketStateLogic::transition_state(market, MarketState::Disputed).unwrap();
             let env = &market.votes.env();
             let owned_event_id = market_id
                 .cloned()
@@ -1112,6 +1245,7 @@ impl MarketStateManager {
                 old_state,
                 market.state,
             );
+            MarketStateCounts::record_transition(env, old_state, market.state);
         }
     }
 
@@ -1183,6 +1317,129 @@ impl MarketStateManager {
         market.claimed.set(user, claim_info);
     }
 
+    /// Returns the running claim counters for `market_id` (zeroed if nobody has been paid).
+    pub fn get_claim_progress(env: &Env, market_id: &Symbol) -> ClaimProgress {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("clm_prog"), market_id.clone()))
+            .unwrap_or_default()
+    }
+
+    /// Records one winner payout against the market's claim counters.
+    ///
+    /// Call once per winner, at the point their `ClaimInfo` is written with a
+    /// positive payout. Counters saturate rather than overflow.
+    pub fn record_winner_claim(env: &Env, market_id: &Symbol, payout_amount: i128) {
+        let mut progress = Self::get_claim_progress(env, market_id);
+        progress.claimed_count = progress.claimed_count.saturating_add(1);
+        progress.total_paid = progress.total_paid.saturating_add(payout_amount);
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("clm_prog"), market_id.clone()), &progress);
+    }
+
+    /// Records the fee and insurance slice withheld from one winner's share.
+    ///
+    /// Call once per winner paid, alongside accruing the fee. Saturates rather
+    /// than overflows.
+    pub fn record_withheld(env: &Env, market_id: &Symbol, amount: i128) {
+        let mut progress = Self::get_claim_progress(env, market_id);
+        progress.total_withheld = progress.total_withheld.saturating_add(amount);
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("clm_prog"), market_id.clone()), &progress);
+    }
+
+    /// Returns unresolved (`Active` or `Ended`) markets whose primary or fallback
+    /// oracle reads `provider`/`feed_id`, in market index order.
+    pub fn get_unresolved_markets_by_feed(
+        env: &Env,
+        provider: &OracleProvider,
+        feed_id: &String,
+    ) -> Vec<Symbol> {
+        let index: Vec<Symbol> = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(env, "market_index"))
+            .unwrap_or_else(|| Vec::new(env));
+
+        let mut matching = Vec::new(env);
+        for market_id in index.iter() {
+            let market: Market = match env.storage().persistent().get(&market_id) {
+                Some(market) => market,
+                None => continue,
+            };
+            if !matches!(market.state, MarketState::Active | MarketState::Ended) {
+                continue;
+            }
+            let uses_primary = market.oracle_config.provider == *provider
+                && market.oracle_config.feed_id == *feed_id;
+            let uses_fallback = market.has_fallback
+                && market.fallback_oracle_config.provider == *provider
+                && market.fallback_oracle_config.feed_id == *feed_id;
+            if uses_primary || uses_fallback {
+                matching.push_back(market_id);
+            }
+        }
+        matching
+    }
+
+    /// Returns `user`'s claim history, oldest first (empty if they never claimed).
+    pub fn get_claim_history(env: &Env, user: &Address) -> Vec<ClaimHistoryEntry> {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("clm_hist"), user.clone()))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Appends a payout to `user`'s claim history.
+    ///
+    /// Call alongside [`Self::record_winner_claim`]. The list is capped at
+    /// [`crate::config::MAX_CLAIM_HISTORY_PER_USER`]; the oldest entry is dropped
+    /// once full.
+    pub fn record_user_claim(env: &Env, user: &Address, market_id: &Symbol, payout: i128) {
+        let mut history = Self::get_claim_history(env, user);
+        while history.len() >= crate::config::MAX_CLAIM_HISTORY_PER_USER {
+            history.pop_front();
+        }
+        history.push_back(ClaimHistoryEntry {
+            market_id: market_id.clone(),
+            payout,
+            timestamp: env.ledger().timestamp(),
+        });
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("clm_hist"), user.clone()), &history);
+    }
+
+    /// Returns the markets `user` has voted or bet in, in first-participation
+    /// order (empty if none).
+    pub fn get_user_markets(env: &Env, user: &Address) -> Vec<Symbol> {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("usr_mkts"), user.clone()))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Adds `market_id` to `user`'s participation index on their first vote
+    /// or bet in it; later calls for the same market are no-ops.
+    ///
+    /// The index is capped at [`crate::config::MAX_USER_MARKETS`]; the oldest
+    /// entry is dropped once full.
+    pub fn record_user_market(env: &Env, user: &Address, market_id: &Symbol) {
+        let mut markets = Self::get_user_markets(env, user);
+        if markets.contains(market_id) {
+            return;
+        }
+        while markets.len() >= crate::config::MAX_USER_MARKETS {
+            markets.pop_front();
+        }
+        markets.push_back(market_id.clone());
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("usr_mkts"), user.clone()), &markets);
+    }
+
     /// Sets the oracle result for a market that has reached its end time.
     ///
     /// This function stores the oracle's resolution data for the market.
@@ -1300,9 +1557,7 @@ impl MarketStateManager {
         market.winning_outcomes = Some(outcomes);
         // State transition: Ended/Disputed -> Resolved
         if market.state == MarketState::Ended || market.state == MarketState::Disputed {
-            MarketStateLogic::validate_state_transition(market.state, MarketState::Resolved)
-                .unwrap();
-            market.state = MarketState::Resolved;
+            MarketStateLogic::transition_state(market, MarketState::Resolved).unwrap();
             let env = &market.votes.env();
             let owned_event_id = market_id
                 .cloned()
@@ -1313,6 +1568,7 @@ impl MarketStateManager {
                 old_state,
                 market.state,
             );
+            MarketStateCounts::record_transition(env, old_state, market.state);
         }
     }
 
@@ -1372,8 +1628,7 @@ impl MarketStateManager {
         let old_state = market.state;
         // State transition: Resolved -> Closed
         if market.state == MarketState::Resolved {
-            MarketStateLogic::validate_state_transition(market.state, MarketState::Closed).unwrap();
-            market.state = MarketState::Closed;
+            MarketStateLogic::transition_state(market, MarketState::Closed).unwrap();
             let env = &market.votes.env();
             let owned_event_id = market_id
                 .cloned()
@@ -1384,6 +1639,7 @@ impl MarketStateManager {
                 old_state,
                 market.state,
             );
+            MarketStateCounts::record_transition(env, old_state, market.state);
         }
         market.fee_collected = true;
     }
@@ -1593,6 +1849,21 @@ impl MarketAnalytics {
         }
     }
 
+    /// Counts the voters entitled to a payout across all winning outcomes.
+    ///
+    /// Sums the per-outcome vote counts of each winning outcome (ties included).
+    /// Returns 0 for unresolved markets.
+    pub fn count_winners(market: &Market) -> u32 {
+        let mut total_winners: u32 = 0;
+        if let Some(winning_outcomes) = &market.winning_outcomes {
+            for outcome in winning_outcomes.iter() {
+                total_winners = total_winners
+                    .saturating_add(Self::calculate_winning_stats(market, &outcome).winning_voters);
+            }
+        }
+        total_winners
+    }
+
     /// Retrieves comprehensive participation statistics for a specific user in a market.
     ///
     /// This function analyzes a user's involvement in a market, including their
@@ -1982,6 +2253,121 @@ impl MarketUtils {
         Ok(token::Client::new(_env, &token_id))
     }
 
+    /// Ensures the staking token (`TokenID`) is configured.
+    ///
+    /// Checked before a market is created, so a missing token surfaces as a
+    /// clear error at creation rather than an opaque failure on the first vote.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::TokenNotConfigured` - No staking token is set in contract storage
+    pub fn require_token_configured(env: &Env) -> Result<(), Error> {
+        if env.storage().persistent().has(&Symbol::new(env, "TokenID")) {
+            Ok(())
+        } else {
+            Err(Error::TokenNotConfigured)
+        }
+    }
+
+    /// Returns the configured staking token (`TokenID`), if any.
+    pub fn get_token(env: &Env) -> Option<Address> {
+        env.storage().persistent().get(&Symbol::new(env, "TokenID"))
+    }
+
+    /// Stores `token` as the staking token (`TokenID`).
+    ///
+    /// The token can only be set once: stakes the contract already holds are
+    /// denominated in it, so swapping it later would strand them.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::AlreadyInitialized` - A staking token is already configured
+    /// * `Error::InvalidInput` - `token` does not answer the token interface
+    pub fn set_staking_token(env: &Env, token: &Address) -> Result<(), Error> {
+        if Self::get_token(env).is_some() {
+            return Err(Error::AlreadyInitialized);
+        }
+        if !matches!(token::Client::new(env, token).try_decimals(), Ok(Ok(_))) {
+            return Err(Error::InvalidInput);
+        }
+        env.storage()
+            .persistent()
+            .set(&Symbol::new(env, "TokenID"), token);
+        Ok(())
+    }
+
+    /// Converts a staking-token payout into the market's settlement token.
+    ///
+    /// Returns `None` for markets that pay out in the staking token, otherwise
+    /// the settlement token and `payout * settlement_rate_bps /
+    /// config::SETTLEMENT_RATE_DENOMINATOR`, rounded down.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InvalidInput` - The conversion overflows
+    pub fn settlement_payout(
+        market: &Market,
+        payout: i128,
+    ) -> Result<Option<(Address, i128)>, Error> {
+        let Some(token) = market.settlement_token.clone() else {
+            return Ok(None);
+        };
+        let converted = payout
+            .checked_mul(market.settlement_rate_bps as i128)
+            .ok_or(Error::InvalidInput)?
+            / crate::config::SETTLEMENT_RATE_DENOMINATOR as i128;
+        Ok(Some((token, converted)))
+    }
+
+    /// Transfers an already converted settlement payout from the contract.
+    ///
+    /// A zero amount is a no-op.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::SettlementTransferFailed` - The token rejected the transfer
+    pub fn transfer_settlement(
+        env: &Env,
+        token: &Address,
+        recipient: &Address,
+        amount: i128,
+    ) -> Result<(), Error> {
+        if amount > 0 {
+            let transfer = token::Client::new(env, token).try_transfer(
+                &env.current_contract_address(),
+                recipient,
+                &amount,
+            );
+            if !matches!(transfer, Ok(Ok(()))) {
+                return Err(Error::SettlementTransferFailed);
+            }
+        }
+        Ok(())
+    }
+
+    /// Pays a winner `payout` staking-token units: credited to the internal
+    /// balance ledger, or converted and transferred in the settlement token
+    /// on settlement markets.
+    pub fn credit_payout(
+        env: &Env,
+        market: &Market,
+        recipient: &Address,
+        payout: i128,
+    ) -> Result<(), Error> {
+        match Self::settlement_payout(market, payout)? {
+            Some((token, amount)) => Self::transfer_settlement(env, &token, recipient, amount)?,
+            None => {
+                crate::storage::BalanceStorage::add_balance(
+                    env,
+                    recipient,
+                    &ReflectorAsset::Stellar,
+                    payout,
+                )?;
+            }
+        }
+        Ok(())
+    }
+
     /// Calculates the payout amount for a winning user based on their stake and pool distribution.
     ///
     /// This function implements the payout algorithm for prediction markets,
@@ -2509,7 +2895,7 @@ impl MarketTestHelpers {
                 ),
                 String::from_str(_env, "BTC/USD"),
                 2_500_000,
-                String::from_str(_env, "gt"),
+                ComparisonOp::Gt,
             ),
             1_000_000, // Creation fee: 1 XLM
         )
@@ -2740,109 +3126,686 @@ impl MarketTestHelpers {
     }
 }
 
-// ===== MARKET STATE LOGIC =====
+// ===== CREATOR SUSPENSION =====
 
-/// Market state logic and transition management utilities.
+/// Per-creator storage key prefix for the overturned-resolution count (u32).
+const CREATOR_OVERTURNS_KEY: Symbol = symbol_short!("crt_ovt");
+
+/// Per-creator storage key prefix for the suspension flag (bool).
+const CREATOR_SUSPENDED_KEY: Symbol = symbol_short!("crt_susp");
+
+/// Storage key for the admin-configured suspension threshold (u32).
+const CREATOR_SUSPENSION_THRESHOLD_KEY: Symbol = symbol_short!("crs_thr");
+
+/// Tracks how often a creator's markets had their resolution overturned by a
+/// dispute, and suspends market creation once the count exceeds the threshold.
 ///
-/// This struct provides comprehensive functions for managing market state
-/// transitions, validating state-dependent operations, and ensuring proper
-/// market lifecycle management. It enforces business rules and maintains
-/// data consistency throughout market operations.
-pub struct MarketStateLogic;
+/// Suspension is sticky: only an admin can lift it, which also resets the count.
+pub struct CreatorSuspensionManager;
 
-impl MarketStateLogic {
-    /// Validates that a market state transition is allowed by the state machine.
-    ///
-    /// This function is the single authoritative gate for all market lifecycle
-    /// transitions.  It must be called before persisting any state change so that
-    /// illegal edges are rejected before touching storage.
-    ///
-    /// # Parameters
-    ///
-    /// * `from` - Current market state
-    /// * `to`   - Target market state
-    ///
-    /// # Returns
-    ///
-    /// * `Ok(())` - Transition is legal and may proceed
-    /// * `Err(Error::IllegalMarketStateTransition)` - Transition is not permitted
-    ///
-    /// # Legal Transition Diagram
-    ///
-    /// ```text
-    ///                   ┌─────────────────────────────────────────────────────┐
-    ///                   │              Market State Machine                    │
-    ///                   └─────────────────────────────────────────────────────┘
-    ///
-    ///   ┌──────────┐  end_time passed   ┌──────────┐  oracle/admin   ┌──────────────┐
-    ///   │  Active  │ ─────────────────► │  Ended   │ ──────────────► │   Resolved   │
-    ///   └──────────┘                    └──────────┘                 └──────────────┘
-    ///        │                               │                               │
-    ///        │ admin cancel/close            │ dispute filed                 │ fees collected
-    ///        ▼                               ▼                               ▼
-    ///   ┌──────────┐               ┌──────────────┐               ┌──────────────────┐
-    ///   │Cancelled │               │   Disputed   │               │     Closed       │
-    ///   └──────────┘               └──────────────┘               └──────────────────┘
-    ///        │                          │     │                     ▲
-    ///        │ (terminal)               │     │ resolved/cancelled  │
-    ///        │                          ▼     └────────────────────►│
-    ///        │                    (Resolved)  (Closed/Cancelled)    │
-    ///        └──────────────────────────────────────────────────────┘
-    ///
-    ///   Legal edges (exhaustive):
-    ///     Active    → Ended, Cancelled, Closed, Disputed
-    ///     Ended     → Resolved, Disputed, Closed, Cancelled
-    ///     Disputed  → Resolved, Closed, Cancelled
-    ///     Resolved  → Closed
-    ///     Closed    → (none — terminal state)
-    ///     Cancelled → (none — terminal state)
-    ///
-    ///   Self-loops (e.g. Active → Active) are ILLEGAL.
-    ///   Resolved → Active, Ended, Disputed are ILLEGAL.
-    /// ```
-    ///
-    /// # Adding a New State
-    ///
-    /// When a new `MarketState` variant is introduced the `match` below will fail to
-    /// compile (non-exhaustive match), forcing the author to consciously define the
-    /// legal edges for the new state.  This is intentional.
-    ///
-    /// # Example
-    ///
-    /// ```rust
-    /// use crate::markets::MarketStateLogic;
-    /// use crate::types::MarketState;
-    ///
-    /// // Legal transition
-    /// assert!(MarketStateLogic::validate_state_transition(
-    ///     MarketState::Active,
-    ///     MarketState::Ended
-    /// ).is_ok());
-    ///
-    /// // Illegal transition returns the dedicated error variant
-    /// assert_eq!(
-    ///     MarketStateLogic::validate_state_transition(
-    ///         MarketState::Closed,
-    ///         MarketState::Active
-    ///     ),
-    ///     Err(Error::IllegalMarketStateTransition)
-    /// );
-    /// ```
-    pub fn validate_state_transition(from: MarketState, to: MarketState) -> Result<(), Error> {
-        use MarketState::*;
-        let allowed = match from {
-            Active => matches!(to, Ended | Cancelled | Closed | Disputed),
-            Ended => matches!(to, Resolved | Disputed | Closed | Cancelled),
-            Disputed => matches!(to, Resolved | Closed | Cancelled),
-            Resolved => matches!(to, Closed),
-            Closed => false,
-            Cancelled => false,
-        };
-        if allowed {
-            Ok(())
-        } else {
-            Err(Error::IllegalMarketStateTransition)
-        }
+impl CreatorSuspensionManager {
+    /// Overturns a creator may accumulate before being suspended.
+    pub fn get_threshold(env: &Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&CREATOR_SUSPENSION_THRESHOLD_KEY)
+            .unwrap_or(crate::config::DEFAULT_CREATOR_SUSPENSION_THRESHOLD)
+    }
+
+    /// Set the suspension threshold (admin only).
+    pub fn set_threshold(env: &Env, admin: &Address, threshold: u32) -> Result<(), Error> {
+        admin.require_auth();
+        crate::resolution::MarketResolutionValidator::validate_admin_permissions(env, admin)?;
+        env.storage()
+            .persistent()
+            .set(&CREATOR_SUSPENSION_THRESHOLD_KEY, &threshold);
+        Ok(())
+    }
+
+    /// Overturned resolutions recorded against `creator` since their last reinstatement.
+    pub fn get_overturn_count(env: &Env, creator: &Address) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&(CREATOR_OVERTURNS_KEY, creator.clone()))
+            .unwrap_or(0)
+    }
+
+    /// Whether `creator` is currently barred from creating markets.
+    pub fn is_suspended(env: &Env, creator: &Address) -> bool {
+        env.storage()
+            .persistent()
+            .get(&(CREATOR_SUSPENDED_KEY, creator.clone()))
+            .unwrap_or(false)
+    }
+
+    /// Fails with `Error::CreatorSuspended` if `creator` is suspended.
+    pub fn require_not_suspended(env: &Env, creator: &Address) -> Result<(), Error> {
+        if Self::is_suspended(env, creator) {
+            return Err(Error::CreatorSuspended);
+        }
+        Ok(())
+    }
+
+    /// Record one overturned resolution against `creator`, suspending them once
+    /// the count exceeds the threshold. Returns `true` if this call suspended them.
+    pub fn record_overturn(env: &Env, creator: &Address) -> bool {
+        let count = Self::get_overturn_count(env, creator).saturating_add(1);
+        env.storage()
+            .persistent()
+            .set(&(CREATOR_OVERTURNS_KEY, creator.clone()), &count);
+
+        let threshold = Self::get_threshold(env);
+        if count <= threshold || Self::is_suspended(env, creator) {
+            return false;
+        }
+
+        env.storage()
+            .persistent()
+            .set(&(CREATOR_SUSPENDED_KEY, creator.clone()), &true);
+        crate::events::EventEmitter::emit_creator_suspended(env, creator, count, threshold);
+        true
+    }
+
+    /// Lift a suspension and reset the creator's overturn count (admin only).
+    pub fn reinstate(env: &Env, admin: &Address, creator: &Address) -> Result<(), Error> {
+        admin.require_auth();
+        crate::resolution::MarketResolutionValidator::validate_admin_permissions(env, admin)?;
+        if !Self::is_suspended(env, creator) {
+            return Err(Error::InvalidState);
+        }
+
+        env.storage()
+            .persistent()
+            .remove(&(CREATOR_SUSPENDED_KEY, creator.clone()));
+        env.storage()
+            .persistent()
+            .remove(&(CREATOR_OVERTURNS_KEY, creator.clone()));
+        crate::events::EventEmitter::emit_creator_reinstated(env, creator, admin);
+        Ok(())
+    }
+}
+
+// ===== MARKET CREATION CAPS =====
+
+/// Storage key for the contract-wide active-markets cap (u32).
+const MAX_ACTIVE_MARKETS_KEY: Symbol = symbol_short!("mkt_cap");
+
+/// Storage key for the per-creator active-markets cap (u32).
+const MAX_CREATOR_MARKETS_KEY: Symbol = symbol_short!("crt_cap");
+
+/// Storage key for the number of markets currently counted as active (u32).
+const ACTIVE_MARKETS_KEY: Symbol = symbol_short!("act_mkts");
+
+/// Admin-configurable limits on how many markets may be active at once,
+/// contract-wide and per creator. A cap of 0 disables it, which is the default.
+///
+/// The contract-wide count is kept here; the per-creator count by
+/// [`crate::storage::CreatorLimitsManager`]. Both are taken by
+/// [`Self::record_opened`] at creation and given back by
+/// [`Self::record_closed`] when a market resolves, is cancelled or is deleted.
+pub struct MarketCreationCaps;
+
+impl MarketCreationCaps {
+    /// Number of markets currently holding an active slot.
+    pub fn get_active_count(env: &Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&ACTIVE_MARKETS_KEY)
+            .unwrap_or(0)
+    }
+
+    /// Count a newly created market by `creator` against both caps.
+    pub fn record_opened(env: &Env, creator: &Address) {
+        let count = Self::get_active_count(env).saturating_add(1);
+        env.storage().persistent().set(&ACTIVE_MARKETS_KEY, &count);
+        crate::storage::CreatorLimitsManager::increment_active_events(env, creator);
+    }
+
+    /// Free the slot of a market by `creator` that is no longer active.
+    pub fn record_closed(env: &Env, creator: &Address) {
+        let count = Self::get_active_count(env).saturating_sub(1);
+        env.storage().persistent().set(&ACTIVE_MARKETS_KEY, &count);
+        crate::storage::CreatorLimitsManager::decrement_active_events(env, creator);
+    }
+
+    /// Contract-wide cap on active markets (0 = unlimited).
+    pub fn get_max_active(env: &Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&MAX_ACTIVE_MARKETS_KEY)
+            .unwrap_or(crate::config::DEFAULT_MAX_ACTIVE_MARKETS)
+    }
+
+    /// Per-creator cap on active markets (0 = unlimited).
+    pub fn get_max_per_creator(env: &Env) -> u32 {
+        env.storage()
+            .persistent()
+            .get(&MAX_CREATOR_MARKETS_KEY)
+            .unwrap_or(crate::config::DEFAULT_MAX_ACTIVE_MARKETS_PER_CREATOR)
+    }
+
+    /// Set both caps (admin only). Pass 0 to disable either.
+    pub fn set_caps(
+        env: &Env,
+        admin: &Address,
+        max_active: u32,
+        max_per_creator: u32,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        crate::resolution::MarketResolutionValidator::validate_admin_permissions(env, admin)?;
+        env.storage()
+            .persistent()
+            .set(&MAX_ACTIVE_MARKETS_KEY, &max_active);
+        env.storage()
+            .persistent()
+            .set(&MAX_CREATOR_MARKETS_KEY, &max_per_creator);
+        Ok(())
+    }
+
+    /// Fails if another market by `creator` would exceed either cap.
+    pub fn require_capacity(env: &Env, creator: &Address) -> Result<(), Error> {
+        let max_active = Self::get_max_active(env);
+        if max_active > 0 && Self::get_active_count(env) >= max_active {
+            return Err(Error::MarketCapReached);
+        }
+        let max_per_creator = Self::get_max_per_creator(env);
+        if max_per_creator > 0
+            && crate::storage::CreatorLimitsManager::get_active_events(env, creator)
+                >= max_per_creator
+        {
+            return Err(Error::CreatorMarketCapReached);
+        }
+        Ok(())
+    }
+}
+
+// ===== MARKET CLOSE NOTICE =====
+
+/// Storage key prefix for the per-market "close announced" flag.
+const CLOSE_NOTICE_KEY: Symbol = symbol_short!("mkt_await");
+
+/// One-time notice that a market has passed `end_time` and is awaiting
+/// resolution, so keepers can react to an event instead of polling.
+///
+/// Markets stay `Active` until resolved, so nothing transitions at close. The
+/// notice is given by the first oracle fetch after `end_time`, or by anyone
+/// calling `mark_closed`; it emits `mkt_await` and sets a flag so it never
+/// repeats for the same market.
+pub struct MarketCloseNotice;
+
+impl MarketCloseNotice {
+    /// Whether the close of `market_id` has already been announced.
+    pub fn is_announced(env: &Env, market_id: &Symbol) -> bool {
+        env.storage()
+            .persistent()
+            .get(&(CLOSE_NOTICE_KEY, market_id.clone()))
+            .unwrap_or(false)
+    }
+
+    /// Announce the close of an ended market if not done yet. Returns `true`
+    /// if this call emitted the event.
+    pub fn announce(env: &Env, market_id: &Symbol, market: &Market) -> bool {
+        if env.ledger().timestamp() < market.end_time || Self::is_announced(env, market_id) {
+            return false;
+        }
+        env.storage()
+            .persistent()
+            .set(&(CLOSE_NOTICE_KEY, market_id.clone()), &true);
+        crate::events::EventEmitter::emit_market_awaiting_resolution(
+            env,
+            market_id,
+            market.end_time,
+        );
+        true
+    }
+
+    /// Announce the close of `market_id` on request.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::MarketNotFound` - Market does not exist
+    /// * `Error::MarketClosed` - Market has not reached `end_time` yet
+    /// * `Error::MarketResolved` - Market is already resolved or cancelled
+    pub fn mark_closed(env: &Env, market_id: &Symbol) -> Result<bool, Error> {
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if market.winning_outcomes.is_some()
+            || matches!(
+                market.state,
+                MarketState::Resolved | MarketState::Closed | MarketState::Cancelled
+            )
+        {
+            return Err(Error::MarketResolved);
+        }
+        if env.ledger().timestamp() < market.end_time {
+            return Err(Error::MarketClosed);
+        }
+        Ok(Self::announce(env, market_id, &market))
+    }
+}
+
+// ===== CONDITIONAL MARKETS =====
+
+/// Conditional ("if A then B") markets: a market that only resolves when a
+/// parent market resolved to a given outcome.
+///
+/// The dependency lives on `Market::condition` and is part of the market's
+/// terms, so it can only be set before anything is staked.
+/// `MarketResolutionManager::cancel_if_condition_unmet` enforces it at
+/// resolution.
+pub struct MarketConditions;
+
+impl MarketConditions {
+    /// Make `market_id` conditional on `parent_market_id` resolving to
+    /// `required_outcome` (admin only).
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::MarketNotFound` - Either market does not exist
+    /// * `Error::InvalidInput` - The parent is the market itself, or itself
+    ///   depends on the market
+    /// * `Error::InvalidOutcome` - `required_outcome` is not a parent outcome
+    /// * `Error::InvalidState` - The market is no longer active or already has stake
+    pub fn set(
+        env: &Env,
+        admin: &Address,
+        market_id: &Symbol,
+        parent_market_id: &Symbol,
+        required_outcome: &String,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        crate::resolution::MarketResolutionValidator::validate_admin_permissions(env, admin)?;
+
+        let mut market = MarketStateManager::get_market(env, market_id)?;
+        let parent = MarketStateManager::get_market(env, parent_market_id)?;
+        if parent_market_id == market_id
+            || parent
+                .condition
+                .as_ref()
+                .map_or(false, |c| c.parent_market_id == *market_id)
+        {
+            return Err(Error::InvalidInput);
+        }
+        MarketValidator::assert_valid_outcome(&parent, required_outcome)?;
+        if market.state != MarketState::Active || market.total_staked > 0 {
+            return Err(Error::InvalidState);
+        }
+
+        market.condition = Some(MarketCondition {
+            parent_market_id: parent_market_id.clone(),
+            required_outcome: required_outcome.clone(),
+        });
+        MarketStateManager::update_market(env, market_id, &market);
+        Ok(())
+    }
+
+    /// Whether `market`'s condition holds.
+    ///
+    /// `Ok(true)` for an unconditional market or a parent resolved to the
+    /// required outcome; `Ok(false)` for a parent resolved otherwise or cancelled.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::ParentMarketUnresolved` - The parent has not resolved yet
+    /// * `Error::MarketNotFound` - The parent market no longer exists
+    pub fn is_met(env: &Env, market: &Market) -> Result<bool, Error> {
+        let condition = match &market.condition {
+            Some(condition) => condition,
+            None => return Ok(true),
+        };
+        let parent = MarketStateManager::get_market(env, &condition.parent_market_id)?;
+        if parent.state == MarketState::Cancelled {
+            return Ok(false);
+        }
+        match &parent.winning_outcomes {
+            Some(outcomes) => Ok(outcomes.contains(&condition.required_outcome)),
+            None => Err(Error::ParentMarketUnresolved),
+        }
+    }
+}
+
+// ===== HYBRID TIE-BREAKING =====
+
+/// Per-market deterministic fallback for hybrid resolutions where the oracle
+/// and the community do not agree.
+///
+/// The policy lives on `Market::tiebreak_policy` and is part of the market's
+/// terms, so it can only be set before anything is staked.
+/// `MarketResolutionManager::resolve_market_hybrid` applies it.
+pub struct MarketTiebreak;
+
+impl MarketTiebreak {
+    /// Set the tie-break policy of `market_id` (admin only).
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::MarketNotFound` - Market does not exist
+    /// * `Error::InvalidState` - The market is no longer active or already has stake
+    pub fn set(
+        env: &Env,
+        admin: &Address,
+        market_id: &Symbol,
+        policy: TiebreakPolicy,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        crate::resolution::MarketResolutionValidator::validate_admin_permissions(env, admin)?;
+
+        let mut market = MarketStateManager::get_market(env, market_id)?;
+        if market.state != MarketState::Active || market.total_staked > 0 {
+            return Err(Error::InvalidState);
+        }
+
+        market.tiebreak_policy = Some(policy);
+        MarketStateManager::update_market(env, market_id, &market);
+        Ok(())
+    }
+
+    /// The policy to apply when resolving `market` against `oracle_result`.
+    ///
+    /// `Some` only if the market has a policy and the sources conflict: the
+    /// community consensus names a different outcome, or the top vote count
+    /// is shared by several outcomes. With no votes there is nothing to conflict.
+    pub fn triggered(market: &Market, oracle_result: &String) -> Option<TiebreakPolicy> {
+        let policy = market.tiebreak_policy?;
+        let consensus = MarketAnalytics::calculate_community_consensus(market);
+        if consensus.total_votes == 0 {
+            return None;
+        }
+
+        let mut counts: Map<String, u32> = Map::new(&market.votes.env());
+        for (_, outcome) in market.votes.iter() {
+            counts.set(outcome.clone(), counts.get(outcome.clone()).unwrap_or(0) + 1);
+        }
+        let leaders = counts
+            .values()
+            .iter()
+            .filter(|count| *count == consensus.votes)
+            .count();
+
+        if consensus.outcome != *oracle_result || leaders > 1 {
+            Some(policy)
+        } else {
+            None
+        }
+    }
+}
+
+// ===== PAYOUT MODELS =====
+
+/// Per-market payout terms: parimutuel or stake-back-plus-bonus payouts, and
+/// whether claims are held for the dispute window after resolution.
+///
+/// Both live on the market and are part of its terms, so they can only be
+/// set before anything is staked.
+pub struct MarketPayouts;
+
+impl MarketPayouts {
+    /// Set the payout model of `market_id` (admin only).
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::MarketNotFound` - Market does not exist
+    /// * `Error::InvalidInput` - A bonus above 100%, or stake-back on a market
+    ///   that settles in another token
+    /// * `Error::InvalidState` - The market is no longer active or already has stake
+    pub fn set_model(
+        env: &Env,
+        admin: &Address,
+        market_id: &Symbol,
+        model: PayoutModel,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        crate::resolution::MarketResolutionValidator::validate_admin_permissions(env, admin)?;
+
+        let mut market = MarketStateManager::get_market(env, market_id)?;
+        if let PayoutModel::StakeBackPlusBonus(bonus_bps) = model {
+            if bonus_bps > crate::fees::MAX_STAKE_BACK_BONUS_BPS
+                || market.settlement_token.is_some()
+            {
+                return Err(Error::InvalidInput);
+            }
+        }
+        if market.state != MarketState::Active || market.total_staked > 0 {
+            return Err(Error::InvalidState);
+        }
+
+        market.payout_model = model;
+        MarketStateManager::update_market(env, market_id, &market);
+        Ok(())
+    }
+
+    /// Set whether claims on `market_id` wait for its dispute window to
+    /// elapse after resolution (admin only).
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::MarketNotFound` - Market does not exist
+    /// * `Error::InvalidState` - The market is no longer active or already has stake
+    pub fn set_claim_dispute_hold(
+        env: &Env,
+        admin: &Address,
+        market_id: &Symbol,
+        enabled: bool,
+    ) -> Result<(), Error> {
+        admin.require_auth();
+        crate::resolution::MarketResolutionValidator::validate_admin_permissions(env, admin)?;
+
+        let mut market = MarketStateManager::get_market(env, market_id)?;
+        if market.state != MarketState::Active || market.total_staked > 0 {
+            return Err(Error::InvalidState);
+        }
+
+        market.claims_wait_for_dispute_window = enabled;
+        MarketStateManager::update_market(env, market_id, &market);
+        Ok(())
+    }
+
+    /// Whether `market` stays open to dispute after it is resolved: a bonded
+    /// manual resolution can be contested, and so can any market that holds
+    /// claims for its dispute window.
+    pub fn contestable_after_resolution(market: &Market) -> bool {
+        market.resolution_bond > 0 || market.claims_wait_for_dispute_window
+    }
+
+    /// When the dispute window of `market` closes. It runs from `end_time`,
+    /// or from `resolved_at` when a late resolution is still contestable, so
+    /// dispute acceptance, payouts and the bond refund all share one deadline.
+    pub fn dispute_window_end(market: &Market, resolved_at: u64) -> u64 {
+        let start = if Self::contestable_after_resolution(market) {
+            resolved_at.max(market.end_time)
+        } else {
+            market.end_time
+        };
+        start.saturating_add(market.dispute_window_seconds)
+    }
+}
+
+// ===== MARKET STATE COUNTS =====
+
+/// Storage key for the per-state market counters (`Map<MarketState, u32>`).
+const STATE_COUNTS_KEY: Symbol = symbol_short!("st_cnts");
+
+/// Running count of markets in each [`MarketState`].
+///
+/// Counters are adjusted at every state transition rather than derived from
+/// the market registry, so reading them never scans storage. States with no
+/// markets are absent from the map.
+pub struct MarketStateCounts;
+
+impl MarketStateCounts {
+    /// Current counters keyed by state.
+    pub fn get(env: &Env) -> Map<MarketState, u32> {
+        env.storage()
+            .persistent()
+            .get(&STATE_COUNTS_KEY)
+            .unwrap_or_else(|| Map::new(env))
+    }
+
+    /// Count a newly stored market in `state`.
+    pub fn record_created(env: &Env, state: MarketState) {
+        let mut counts = Self::get(env);
+        Self::increment(&mut counts, state);
+        env.storage().persistent().set(&STATE_COUNTS_KEY, &counts);
+    }
+
+    /// Move one market from `from` to `to`. A no-op when the states match.
+    pub fn record_transition(env: &Env, from: MarketState, to: MarketState) {
+        if from == to {
+            return;
+        }
+        let mut counts = Self::get(env);
+        Self::decrement(&mut counts, from);
+        Self::increment(&mut counts, to);
+        env.storage().persistent().set(&STATE_COUNTS_KEY, &counts);
+    }
+
+    /// Drop a market in `state` that has been removed from storage.
+    pub fn record_removed(env: &Env, state: MarketState) {
+        let mut counts = Self::get(env);
+        Self::decrement(&mut counts, state);
+        env.storage().persistent().set(&STATE_COUNTS_KEY, &counts);
+    }
+
+    fn increment(counts: &mut Map<MarketState, u32>, state: MarketState) {
+        let count = counts.get(state).unwrap_or(0).saturating_add(1);
+        counts.set(state, count);
+    }
+
+    fn decrement(counts: &mut Map<MarketState, u32>, state: MarketState) {
+        match counts.get(state).unwrap_or(0) {
+            0 | 1 => {
+                counts.remove(state);
+            }
+            count => counts.set(state, count - 1),
+        }
+    }
+}
+
+// ===== MARKET STATE LOGIC =====
+
+/// Market state logic and transition management utilities.
+///
+/// This struct provides comprehensive functions for managing market state
+/// transitions, validating state-dependent operations, and ensuring proper
+/// market lifecycle management. It enforces business rules and maintains
+/// data consistency throughout market operations.
+pub struct MarketStateLogic;
+
+impl MarketStateLogic {
+    /// Validates that a market state transition is allowed by the state machine.
+    ///
+    /// This function is the single authoritative gate for all market lifecycle
+    /// transitions.  It must be called before persisting any state change so that
+    /// illegal edges are rejected before touching storage.
+    ///
+    /// # Parameters
+    ///
+    /// * `from` - Current market state
+    /// * `to`   - Target market state
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(())` - Transition is legal and may proceed
+    /// * `Err(Error::IllegalMarketStateTransition)` - Transition is not permitted
+    ///
+    /// # Legal Transition Diagram
+    ///
+    /// ```text
+    ///                   ┌─────────────────────────────────────────────────────┐
+    ///                   │              Market State Machine                    │
+    ///                   └─────────────────────────────────────────────────────┘
+    ///
+    ///   ┌──────────┐  end_time passed   ┌──────────┐  oracle/admin   ┌──────────────┐
+    ///   │  Active  │ ─────────────────► │  Ended   │ ──────────────► │   Resolved   │
+    ///   └──────────┘                    └──────────┘                 └──────────────┘
+    ///        │                               │                               │
+    ///        │ admin cancel/close            │ dispute filed                 │ fees collected
+    ///        ▼                               ▼                               ▼
+    ///   ┌──────────┐               ┌──────────────┐               ┌──────────────────┐
+    ///   │Cancelled │               │   Disputed   │               │     Closed       │
+    ///   └──────────┘               └──────────────┘               └──────────────────┘
+    ///        │                          │     │                     ▲
+    ///        │ (terminal)               │     │ resolved/cancelled  │
+    ///        │                          ▼     └────────────────────►│
+    ///        │                    (Resolved)  (Closed/Cancelled)    │
+    ///        └──────────────────────────────────────────────────────┘
+    ///
+    ///   Legal edges (exhaustive):
+    ///     Active    → Ended, Resolved, Cancelled, Closed, Disputed
+    ///     Ended     → Resolved, Disputed, Closed, Cancelled
+    ///     Disputed  → Resolved, Closed, Cancelled
+    ///     Resolved  → Disputed, Closed
+    ///     Closed    → (none — terminal state)
+    ///     Cancelled → (none — terminal state)
+    ///
+    ///   Active → Resolved covers markets resolved after `end_time` without an
+    ///   explicit Ended transition; Resolved → Disputed reopens a contested result.
+    ///   Self-loops (e.g. Active → Active) are ILLEGAL.
+    ///   Resolved → Active, Ended, Cancelled are ILLEGAL.
+    /// ```
+    ///
+    /// # Adding a New State
+    ///
+    /// When a new `MarketState` variant is introduced the `match` below will fail to
+    /// compile (non-exhaustive match), forcing the author to consciously define the
+    /// legal edges for the new state.  This is intentional.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// use crate::markets::MarketStateLogic;
+    /// use crate::types::MarketState;
+    ///
+    /// // Legal transition
+    /// assert!(MarketStateLogic::validate_state_transition(
+    ///     MarketState::Active,
+    ///     MarketState::Ended
+    /// ).is_ok());
+    ///
+    /// // Illegal transition returns the dedicated error variant
+    /// assert_eq!(
+    ///     MarketStateLogic::validate_state_transition(
+    ///         MarketState::Closed,
+    ///         MarketState::Active
+    ///     ),
+    ///     Err(Error::IllegalMarketStateTransition)
+    /// );
+    /// ```
+    pub fn validate_state_transition(from: MarketState, to: MarketState) -> Result<(), Error> {
+        use MarketState::*;
+        let allowed = match from {
+            Active => matches!(to, Ended | Resolved | Cancelled | Closed | Disputed),
+            Ended => matches!(to, Resolved | Disputed | Closed | Cancelled),
+            Disputed => matches!(to, Resolved | Closed | Cancelled),
+            Resolved => matches!(to, Disputed | Closed),
+            Closed => false,
+            Cancelled => false,
+        };
+        if allowed {
+            Ok(())
+        } else {
+            Err(Error::IllegalMarketStateTransition)
+        }
+    }
+
+    /// Moves `market` to `new_state` if the state machine allows it.
+    ///
+    /// Every lifecycle change goes through here so no caller can write an
+    /// illegal edge; see [`Self::validate_state_transition`] for the table.
+    /// Only the in-memory market is updated: persisting it, emitting the
+    /// state-change event and updating `MarketStateCounts` stay with the caller.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::IllegalMarketStateTransition` - The edge is not legal; `market`
+    ///   is left unchanged
+    pub fn transition_state(market: &mut Market, new_state: MarketState) -> Result<(), Error> {
+        Self::validate_state_transition(market.state, new_state)?;
+        market.state = new_state;
+        Ok(())
     }
 
     /// Check if a function is allowed in the given state
@@ -3260,7 +4223,7 @@ mod tests {
                 ),
                 String::from_str(&env, "BTC/USD"),
                 2_500_000,
-                String::from_str(&env, "gt"),
+                ComparisonOp::Gt,
             ),
             None,
             86400,
@@ -3279,6 +4242,450 @@ mod tests {
     }
 }
 
+#[cfg(test)]
+mod delete_market_tests {
+    use super::*;
+    use crate::fees::CreationFeeRefunds;
+    use soroban_sdk::testutils::Address as _;
+
+    fn store_market(env: &Env, creator: &Address, market_id: &Symbol) {
+        let market = Market::new(
+            env,
+            creator.clone(),
+            String::from_str(env, "Will ETH flip BTC?"),
+            vec![
+                env,
+                String::from_str(env, "yes"),
+                String::from_str(env, "no"),
+            ],
+            env.ledger().timestamp() + 86400,
+            OracleConfig::new(
+                OracleProvider::reflector(),
+                Address::generate(env),
+                String::from_str(env, "ETH"),
+                100,
+                ComparisonOp::Gt,
+            ),
+            None,
+            86400,
+            MarketState::Active,
+        );
+        env.storage().persistent().set(market_id, &market);
+
+        let index_key = Symbol::new(env, "market_index");
+        let mut index: Vec<Symbol> = env
+            .storage()
+            .persistent()
+            .get(&index_key)
+            .unwrap_or_else(|| Vec::new(env));
+        index.push_back(market_id.clone());
+        env.storage().persistent().set(&index_key, &index);
+    }
+
+    fn market_index(env: &Env) -> Vec<Symbol> {
+        env.storage()
+            .persistent()
+            .get(&Symbol::new(env, "market_index"))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    #[test]
+    fn test_delete_market_before_any_vote() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let creator = Address::generate(&env);
+        let doomed = Symbol::new(&env, "doomed");
+        let kept = Symbol::new(&env, "kept");
+
+        env.as_contract(&contract_id, || {
+            store_market(&env, &creator, &doomed);
+            store_market(&env, &creator, &kept);
+
+            let refunded = MarketStateManager::delete_market(&env, &creator, &doomed).unwrap();
+            assert_eq!(refunded, 0);
+
+            assert_eq!(
+                MarketStateManager::get_market(&env, &doomed).err(),
+                Some(Error::MarketNotFound)
+            );
+            let index = market_index(&env);
+            assert!(!index.contains(&doomed));
+            assert!(index.contains(&kept));
+        });
+    }
+
+    #[test]
+    fn test_delete_market_rejected_after_vote() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let creator = Address::generate(&env);
+        let voter = Address::generate(&env);
+        let market_id = Symbol::new(&env, "voted");
+
+        env.as_contract(&contract_id, || {
+            store_market(&env, &creator, &market_id);
+            let mut market = MarketStateManager::get_market(&env, &market_id).unwrap();
+            market.votes.set(voter.clone(), String::from_str(&env, "yes"));
+            market.stakes.set(voter, 1_000_000);
+            market.total_staked = 1_000_000;
+            MarketStateManager::update_market(&env, &market_id, &market);
+
+            assert_eq!(
+                MarketStateManager::delete_market(&env, &creator, &market_id).err(),
+                Some(Error::BetsAlreadyPlaced)
+            );
+            assert!(MarketStateManager::get_market(&env, &market_id).is_ok());
+            assert!(market_index(&env).contains(&market_id));
+        });
+    }
+
+    #[test]
+    fn test_delete_market_rejects_non_creator() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let creator = Address::generate(&env);
+        let stranger = Address::generate(&env);
+        let market_id = Symbol::new(&env, "not_yours");
+
+        env.as_contract(&contract_id, || {
+            store_market(&env, &creator, &market_id);
+            assert_eq!(
+                MarketStateManager::delete_market(&env, &stranger, &market_id).err(),
+                Some(Error::Unauthorized)
+            );
+        });
+    }
+
+    #[test]
+    fn test_delete_market_forfeits_fee_when_refunds_disabled() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let admin = Address::generate(&env);
+        let creator = Address::generate(&env);
+        let market_id = Symbol::new(&env, "no_refund");
+
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "Admin"), &admin);
+            CreationFeeRefunds::set_refundable(&env, &admin, false).unwrap();
+
+            store_market(&env, &creator, &market_id);
+            CreationFeeRefunds::record_paid(&env, &market_id, 10_000_000);
+
+            let refunded = MarketStateManager::delete_market(&env, &creator, &market_id).unwrap();
+            assert_eq!(refunded, 0);
+            assert_eq!(CreationFeeRefunds::get_paid(&env, &market_id), 0);
+        });
+    }
+
+    #[test]
+    fn test_delete_market_refunds_escrowed_bounty() {
+        use soroban_sdk::token::{Client as TokenClient, StellarAssetClient};
+
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let creator = Address::generate(&env);
+        let market_id = Symbol::new(&env, "bountied");
+        let token_id = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        StellarAssetClient::new(&env, &token_id).mint(&creator, &5_000);
+        let token = TokenClient::new(&env, &token_id);
+
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "TokenID"), &token_id);
+            store_market(&env, &creator, &market_id);
+            crate::fees::ResolutionBounty::escrow(&env, &creator, &market_id, 5_000).unwrap();
+        });
+        assert_eq!(token.balance(&creator), 0);
+        assert_eq!(token.balance(&contract_id), 5_000);
+
+        env.as_contract(&contract_id, || {
+            MarketStateManager::delete_market(&env, &creator, &market_id).unwrap();
+            assert_eq!(
+                MarketStateManager::get_market(&env, &market_id).err(),
+                Some(Error::MarketNotFound)
+            );
+        });
+        assert_eq!(token.balance(&creator), 5_000);
+        assert_eq!(token.balance(&contract_id), 0);
+    }
+}
+
+#[cfg(test)]
+mod creator_suspension_tests {
+    use super::*;
+    use crate::PredictifyHybridClient;
+    use soroban_sdk::testutils::Address as _;
+
+    fn create(env: &Env, client: &PredictifyHybridClient, admin: &Address) -> Symbol {
+        client.create_market(
+            admin,
+            &String::from_str(env, "Will BTC close above 100k?"),
+            &vec![
+                env,
+                String::from_str(env, "yes"),
+                String::from_str(env, "no"),
+            ],
+            &30,
+            &OracleConfig::new(
+                OracleProvider::reflector(),
+                Address::generate(env),
+                String::from_str(env, "BTC/USD"),
+                100,
+                ComparisonOp::Gt,
+            ),
+            &None,
+            &86400u64,
+            &None,
+            &None,
+            &None,
+        )
+    }
+
+    #[test]
+    fn test_repeated_overturns_suspend_creator() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = PredictifyHybridClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &None, &None);
+        client.set_creator_suspension_threshold(&admin, &2);
+
+        env.as_contract(&contract_id, || {
+            assert!(!CreatorSuspensionManager::record_overturn(&env, &admin));
+            assert!(!CreatorSuspensionManager::record_overturn(&env, &admin));
+            assert!(!CreatorSuspensionManager::is_suspended(&env, &admin));
+
+            // Third overturn exceeds the threshold of 2
+            assert!(CreatorSuspensionManager::record_overturn(&env, &admin));
+            assert_eq!(CreatorSuspensionManager::get_overturn_count(&env, &admin), 3);
+        });
+        assert!(client.is_creator_suspended(&admin));
+    }
+
+    #[test]
+    fn test_suspended_creator_cannot_create_until_reinstated() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = PredictifyHybridClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &None, &None);
+        client.set_creator_suspension_threshold(&admin, &0);
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "TokenID"), &Address::generate(&env));
+        });
+
+        create(&env, &client, &admin);
+
+        env.as_contract(&contract_id, || {
+            assert!(CreatorSuspensionManager::record_overturn(&env, &admin));
+        });
+
+        let blocked = client.try_create_market(
+            &admin,
+            &String::from_str(&env, "Will ETH close above 5k?"),
+            &vec![
+                &env,
+                String::from_str(&env, "yes"),
+                String::from_str(&env, "no"),
+            ],
+            &30,
+            &OracleConfig::new(
+                OracleProvider::reflector(),
+                Address::generate(&env),
+                String::from_str(&env, "ETH/USD"),
+                100,
+                ComparisonOp::Gt,
+            ),
+            &None,
+            &86400u64,
+            &None,
+            &None,
+            &None,
+        );
+        assert_eq!(blocked, Err(Ok(Error::CreatorSuspended)));
+
+        // Only the admin can reinstate, and only a suspended creator
+        let stranger = Address::generate(&env);
+        assert_eq!(
+            client.try_reinstate_creator(&stranger, &admin),
+            Err(Ok(Error::Unauthorized))
+        );
+        client.reinstate_creator(&admin, &admin);
+        assert!(!client.is_creator_suspended(&admin));
+        assert_eq!(
+            client.try_reinstate_creator(&admin, &admin),
+            Err(Ok(Error::InvalidState))
+        );
+
+        create(&env, &client, &admin);
+        env.as_contract(&contract_id, || {
+            assert_eq!(CreatorSuspensionManager::get_overturn_count(&env, &admin), 0);
+        });
+    }
+}
+
+#[cfg(test)]
+mod active_market_cap_tests {
+    use super::*;
+    use crate::PredictifyHybridClient;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+
+    fn create(
+        env: &Env,
+        client: &PredictifyHybridClient,
+        admin: &Address,
+    ) -> Result<Symbol, Error> {
+        match client.try_create_market(
+            admin,
+            &String::from_str(env, "Will BTC close above 100k?"),
+            &vec![
+                env,
+                String::from_str(env, "yes"),
+                String::from_str(env, "no"),
+            ],
+            &1,
+            &OracleConfig::new(
+                OracleProvider::reflector(),
+                Address::generate(env),
+                String::from_str(env, "BTC/USD"),
+                100,
+                ComparisonOp::Gt,
+            ),
+            &None,
+            &86400u64,
+            &None,
+            &None,
+            &None,
+        ) {
+            Ok(Ok(market_id)) => Ok(market_id),
+            Err(Ok(e)) => Err(e),
+            other => panic!("unexpected create_market result: {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_resolving_or_cancelling_frees_an_active_slot() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = PredictifyHybridClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &None, &None);
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "TokenID"), &Address::generate(&env));
+            crate::rate_limiter::RateLimiter::init_for_tests(&env, &admin);
+        });
+
+        // Unlimited by default
+        assert_eq!(client.get_market_creation_caps(), (0, 0));
+        client.set_market_creation_caps(&admin, &2, &0);
+
+        let first = create(&env, &client, &admin).unwrap();
+        let second = create(&env, &client, &admin).unwrap();
+        assert_eq!(
+            create(&env, &client, &admin),
+            Err(Error::MarketCapReached)
+        );
+
+        // Resolving one market frees its slot
+        env.ledger().with_mut(|li| li.timestamp += 2 * 86400);
+        client.resolve_market_manual(&admin, &first, &String::from_str(&env, "yes"), &None);
+        create(&env, &client, &admin).unwrap();
+        assert_eq!(
+            create(&env, &client, &admin),
+            Err(Error::MarketCapReached)
+        );
+
+        // So does cancelling one
+        client.cancel_event(&admin, &second, &None);
+        create(&env, &client, &admin).unwrap();
+        env.as_contract(&contract_id, || {
+            assert_eq!(MarketCreationCaps::get_active_count(&env), 2);
+            assert_eq!(
+                crate::storage::CreatorLimitsManager::get_active_events(&env, &admin),
+                2
+            );
+        });
+    }
+}
+
+#[cfg(test)]
+mod close_notice_tests {
+    use super::*;
+    use soroban_sdk::testutils::{Address as _, Events, Ledger};
+    use soroban_sdk::TryIntoVal;
+
+    fn close_notices(env: &Env) -> u32 {
+        let mut count = 0;
+        for event in env.events().all().events().iter() {
+            let body = match &event.body {
+                soroban_sdk::xdr::ContractEventBody::V0(v0) => v0,
+            };
+            let topic0: Result<Symbol, _> = body.topics.get(0).unwrap().clone().try_into_val(env);
+            if topic0 == Ok(symbol_short!("mkt_await")) {
+                count += 1;
+            }
+        }
+        count
+    }
+
+    #[test]
+    fn test_close_is_announced_once_after_end_time() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let market_id = Symbol::new(&env, "closing");
+
+        env.as_contract(&contract_id, || {
+            let end_time = env.ledger().timestamp() + 100;
+            let market = Market::new(
+                &env,
+                Address::generate(&env),
+                String::from_str(&env, "Will BTC close above 100k?"),
+                vec![&env, String::from_str(&env, "yes"), String::from_str(&env, "no")],
+                end_time,
+                OracleConfig::none_sentinel(&env),
+                None,
+                86400,
+                MarketState::Active,
+            );
+            env.storage().persistent().set(&market_id, &market);
+
+            assert_eq!(
+                MarketCloseNotice::mark_closed(&env, &market_id),
+                Err(Error::MarketClosed)
+            );
+            assert_eq!(close_notices(&env), 0);
+
+            env.ledger().set_timestamp(end_time);
+            assert_eq!(MarketCloseNotice::mark_closed(&env, &market_id), Ok(true));
+            assert!(MarketCloseNotice::is_announced(&env, &market_id));
+            assert_eq!(close_notices(&env), 1);
+
+            // Later interactions do not repeat the notice
+            env.ledger().set_timestamp(end_time + 500);
+            assert_eq!(MarketCloseNotice::mark_closed(&env, &market_id), Ok(false));
+            assert!(!MarketCloseNotice::announce(&env, &market_id, &market));
+            assert_eq!(close_notices(&env), 1);
+        });
+    }
+}
+
 /////////////////////////////////////////////////////////////////////////////////
 /// Market pause management system for emergency controls and maintenance.//////
 /// ////////////////////////////////////////////////////////////////////////////
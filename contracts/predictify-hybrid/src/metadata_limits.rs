@@ -53,6 +53,13 @@ pub const MAX_TAG_LENGTH: u32 = crate::config::MAX_TAG_LENGTH;
 pub const MIN_TAG_LENGTH: u32 = crate::config::MIN_TAG_LENGTH;
 /// Maximum number of tags per market (from [`crate::config::MAX_TAGS_PER_MARKET`]).
 pub const MAX_TAGS_COUNT: u32 = crate::config::MAX_TAGS_PER_MARKET;
+/// Maximum length of a market language tag (from [`crate::config::MAX_LANGUAGE_TAG_LENGTH`]).
+pub const MAX_LANGUAGE_TAG_LENGTH: u32 = crate::config::MAX_LANGUAGE_TAG_LENGTH;
+/// Minimum length of a market language tag (from [`crate::config::MIN_LANGUAGE_TAG_LENGTH`]).
+pub const MIN_LANGUAGE_TAG_LENGTH: u32 = crate::config::MIN_LANGUAGE_TAG_LENGTH;
+
+/// Maximum length of a single `-`-separated subtag in a language tag (per BCP-47).
+const MAX_LANGUAGE_SUBTAG_LENGTH: u32 = 8;
 
 // ===== STRING LENGTH LIMITS =====
 
@@ -74,11 +81,11 @@ pub const MAX_OUTCOME_LENGTH: u32 = 100;
 /// 64-char hex strings. 200 chars provides headroom for future oracle formats.
 pub const MAX_FEED_ID_LENGTH: u32 = 200;
 
-/// Maximum length for comparison operators (10 characters)
+/// Maximum length for comparison operators (12 characters)
 ///
-/// Rationale: Valid operators are "gt", "lt", "eq" (2-3 chars). 10 chars allows
-/// for future operators like "gte", "lte", "between" while preventing abuse.
-pub const MAX_COMPARISON_LENGTH: u32 = 10;
+/// Rationale: Valid operators are "gt", "lt", "eq" (2-3 chars) and the range
+/// operators "in_range" / "out_of_range" (up to 12 chars), while preventing abuse.
+pub const MAX_COMPARISON_LENGTH: u32 = 12;
 
 /// Maximum length for extension reason text (300 characters)
 ///
@@ -87,6 +94,13 @@ pub const MAX_COMPARISON_LENGTH: u32 = 10;
 /// more users to participate and ensure fair market resolution."
 pub const MAX_EXTENSION_REASON_LENGTH: u32 = 300;
 
+/// Maximum length for resolution evidence text (300 characters)
+///
+/// Rationale: Evidence is a short justification or a URL to the source of
+/// truth, e.g. "https://coinmarketcap.com/currencies/bitcoin/historical-data/".
+/// 300 chars fits typical URLs with a brief note.
+pub const MAX_EVIDENCE_LENGTH: u32 = 300;
+
 /// Maximum length for oracle source identifiers (100 characters)
 ///
 /// Rationale: Source identifiers like "reflector-mainnet" or oracle URLs should
@@ -309,6 +323,51 @@ pub fn validate_event_tags(tags: &Vec<String>) -> Result<(), crate::Error> {
     Ok(())
 }
 
+/// Validates a BCP-47-style market language tag such as `"en"`, `"pt-BR"` or `"zh-Hant-TW"`.
+///
+/// Only the shape is checked, not registry membership: ASCII letters, digits and `-`,
+/// [`MIN_LANGUAGE_TAG_LENGTH`]..=[`MAX_LANGUAGE_TAG_LENGTH`] characters, subtags of at most
+/// 8 characters with no empty subtags, and a letters-only primary subtag of at least 2.
+/// Any violation is reported as [`crate::Error::InvalidInput`].
+pub fn validate_language_tag(tag: &String) -> Result<(), crate::Error> {
+    let len = tag.len();
+    if len < MIN_LANGUAGE_TAG_LENGTH || len > MAX_LANGUAGE_TAG_LENGTH {
+        return Err(crate::Error::InvalidInput);
+    }
+    let mut buf = [0u8; MAX_LANGUAGE_TAG_LENGTH as usize];
+    let bytes = &mut buf[..len as usize];
+    tag.copy_into_slice(bytes);
+
+    let mut subtag_len = 0u32;
+    let mut in_primary = true;
+    for &b in bytes.iter() {
+        if b == b'-' {
+            if subtag_len == 0 || (in_primary && subtag_len < 2) {
+                return Err(crate::Error::InvalidInput);
+            }
+            subtag_len = 0;
+            in_primary = false;
+            continue;
+        }
+        let allowed = if in_primary {
+            b.is_ascii_alphabetic()
+        } else {
+            b.is_ascii_alphanumeric()
+        };
+        if !allowed {
+            return Err(crate::Error::InvalidInput);
+        }
+        subtag_len += 1;
+        if subtag_len > MAX_LANGUAGE_SUBTAG_LENGTH {
+            return Err(crate::Error::InvalidInput);
+        }
+    }
+    if subtag_len == 0 {
+        return Err(crate::Error::InvalidInput);
+    }
+    Ok(())
+}
+
 pub fn validate_extension_reason_length(reason: &String) -> Result<(), crate::Error> {
     reject_control_characters(reason)?;
     let (len, _) = scan_metadata_text(reason)?;
@@ -318,6 +377,15 @@ pub fn validate_extension_reason_length(reason: &String) -> Result<(), crate::Er
     Ok(())
 }
 
+pub fn validate_evidence_length(evidence: &String) -> Result<(), crate::Error> {
+    reject_control_characters(evidence)?;
+    let (len, _) = scan_metadata_text(evidence)?;
+    if len > MAX_EVIDENCE_LENGTH {
+        return Err(crate::Error::EvidenceTooLong);
+    }
+    Ok(())
+}
+
 pub fn validate_source_length(source: &String) -> Result<(), crate::Error> {
     reject_control_characters(source)?;
     let (len, _) = scan_metadata_text(source)?;
@@ -445,4 +513,33 @@ mod tests {
         }
         assert_eq!(validate_tags_count(&tags), Err(crate::Error::TooManyTags));
     }
+
+    #[test]
+    fn test_validate_language_tag_accepts_bcp47_shapes() {
+        let env = Env::default();
+        for tag in ["en", "fra", "pt-BR", "zh-Hant-TW", "es-419", "sr-Latn-RS"] {
+            assert!(
+                validate_language_tag(&String::from_str(&env, tag)).is_ok(),
+                "{} should be accepted",
+                tag
+            );
+        }
+    }
+
+    #[test]
+    fn test_validate_language_tag_rejects_bad_tags() {
+        let env = Env::default();
+        let too_long = alloc::format!("en{}", "-abcdefgh".repeat(4));
+        for tag in [
+            "", "e", "en_US", "en-", "-en", "en--US", "e-US", "12", "en-abcdefghi", "fr ca", "日本",
+            too_long.as_str(),
+        ] {
+            assert_eq!(
+                validate_language_tag(&String::from_str(&env, tag)),
+                Err(crate::Error::InvalidInput),
+                "{} should be rejected",
+                tag
+            );
+        }
+    }
 }
@@ -65,6 +65,7 @@ impl MetadataTest {
             feed_id: String::from_str(&self.env, "BTC/USD"),
             threshold: 100_000_00,
             comparison: String::from_str(&self.env, "gt"),
+            threshold2: None,
         }
     }
 }
@@ -5,6 +5,7 @@ use soroban_sdk::{contracttype, vec, Address, Env, Map, String, Symbol, Vec};
 
 use crate::err::Error;
 use crate::events::EventEmitter;
+use crate::oracles::ComparisonOp;
 use crate::types::{Market, MarketState, OracleConfig, OracleProvider};
 /// Comprehensive monitoring system for Predictify contract health and performance.
 ///
@@ -204,6 +205,19 @@ pub struct TransitionHookEvent {
     pub timestamp: u64,
 }
 
+/// Result of [`ContractMonitor::health_check`].
+///
+/// `violations` holds one short code per broken invariant, e.g.
+/// `"admin_not_set"` or `"active_count_mismatch"`; `healthy` is true when it
+/// is empty.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[contracttype]
+pub struct HealthReport {
+    pub healthy: bool,
+    pub violations: Vec<String>,
+    pub checked_at: u64,
+}
+
 // ===== CONTRACT MONITOR STRUCT =====
 
 /// Main contract monitoring system
@@ -573,13 +587,18 @@ impl ContractMonitor {
             ),
             feed_id: String::from_str(env, "sample_feed"),
             threshold: 100,
-            comparison: String::from_str(env, ">="),
+            comparison: ComparisonOp::Gte,
+            threshold2: None,
         };
         Ok(Market {
             admin: Address::from_str(
                 env,
                 "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF",
             ),
+            creator: Address::from_str(
+                env,
+                "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF",
+            ),
             question: question.clone(),
             outcomes: outcomes.clone(),
             end_time: env.ledger().timestamp() + 86400,
@@ -607,10 +626,23 @@ impl ContractMonitor {
             extension_history: Vec::new(env),
             category: None,
             tags: Vec::new(env),
+            language: String::from_str(env, crate::config::DEFAULT_MARKET_LANGUAGE),
             min_pool_size: None,
+            min_voters: None,
+            outcome_cap_bps: None,
+            resolution_bounty: 0,
             bet_deadline: 0,
             dispute_window_seconds: 86400,
             winnings_swept: false,
+            refund_grace_seconds: crate::config::REFUND_GRACE_SECONDS,
+            early_bird_fee: None,
+            settlement_token: None,
+            settlement_rate_bps: 0,
+            resolution_bond: 0,
+            condition: None,
+            tiebreak_policy: None,
+            payout_model: crate::types::PayoutModel::Parimutuel,
+            claims_wait_for_dispute_window: false,
         })
     }
 
@@ -1007,6 +1039,76 @@ impl ContractMonitor {
         env.storage().persistent().set(&overflow_key, &false);
         Ok(())
     }
+
+    /// Check the contract's global storage invariants without panicking.
+    ///
+    /// Verifies that:
+    /// - an admin is set (`admin_not_set`);
+    /// - the version history, once written, has a non-zero current version
+    ///   (`invalid_version`);
+    /// - the active-market counter equals the number of markets and events in
+    ///   the registries that are still Active, Ended or Disputed
+    ///   (`active_count_mismatch`);
+    /// - the fee vault and lifetime fee totals are non-negative
+    ///   (`negative_fee_vault`, `negative_lifetime_fees`).
+    pub fn health_check(env: &Env) -> HealthReport {
+        let mut violations = Vec::new(env);
+
+        if !env.storage().persistent().has(&Symbol::new(env, "Admin")) {
+            violations.push_back(String::from_str(env, "admin_not_set"));
+        }
+
+        match crate::versioning::VersionManager::new(env).get_version_history(env) {
+            Ok(history) => {
+                if !history.versions.is_empty()
+                    && history.get_current_version().version_number() == 0
+                {
+                    violations.push_back(String::from_str(env, "invalid_version"));
+                }
+            }
+            Err(_) => violations.push_back(String::from_str(env, "invalid_version")),
+        }
+
+        let is_open = |state: MarketState| {
+            matches!(state, MarketState::Active | MarketState::Ended | MarketState::Disputed)
+        };
+        let mut open_markets: u32 = 0;
+        let market_ids: Vec<Symbol> = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(env, "market_index"))
+            .unwrap_or_else(|| Vec::new(env));
+        for market_id in market_ids.iter() {
+            if let Some(market) = env.storage().persistent().get::<Symbol, Market>(&market_id) {
+                if is_open(market.state) {
+                    open_markets += 1;
+                }
+            }
+        }
+        for event_id in crate::storage::EventManager::get_event_ids(env).iter() {
+            if let Ok(event) = crate::storage::EventManager::get_event(env, &event_id) {
+                if is_open(event.status) {
+                    open_markets += 1;
+                }
+            }
+        }
+        if crate::markets::MarketCreationCaps::get_active_count(env) != open_markets {
+            violations.push_back(String::from_str(env, "active_count_mismatch"));
+        }
+
+        if crate::fees::FeeTracker::get_total_fees_collected(env).unwrap_or(0) < 0 {
+            violations.push_back(String::from_str(env, "negative_fee_vault"));
+        }
+        if crate::fees::FeeTracker::get_lifetime_fees(env) < 0 {
+            violations.push_back(String::from_str(env, "negative_lifetime_fees"));
+        }
+
+        HealthReport {
+            healthy: violations.is_empty(),
+            violations,
+            checked_at: env.ledger().timestamp(),
+        }
+    }
 }
 
 // ===== MONITORING UTILITIES =====
@@ -1234,6 +1336,7 @@ impl MonitoringTestingUtils {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::oracles::ComparisonOp;
     use soroban_sdk::testutils::Address as _;
     use soroban_sdk::testutils::{Address as _, Events};
 
@@ -1446,6 +1549,79 @@ mod tests {
 
         assert_eq!(env.events().all().events().len(), 1);
     }
+
+    #[test]
+    fn test_health_check_reports_corrupted_counters() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+
+        env.as_contract(&contract_id, || {
+            assert_eq!(
+                ContractMonitor::health_check(&env).violations,
+                vec![&env, String::from_str(&env, "admin_not_set")]
+            );
+        });
+
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &None, &None);
+        assert_eq!(
+            client.try_health_check(&Address::generate(&env)),
+            Err(Ok(Error::Unauthorized))
+        );
+
+        // One open market in the registry holding one active slot
+        let creator = Address::generate(&env);
+        let market_id = Symbol::new(&env, "mkt_open");
+        env.as_contract(&contract_id, || {
+            let market = Market::new(
+                &env,
+                creator.clone(),
+                String::from_str(&env, "Will BTC close above 100?"),
+                vec![&env, String::from_str(&env, "yes"), String::from_str(&env, "no")],
+                env.ledger().timestamp() + 86400,
+                OracleConfig::new(
+                    OracleProvider::reflector(),
+                    Address::generate(&env),
+                    String::from_str(&env, "BTC/USD"),
+                    100,
+                    ComparisonOp::Gt,
+                ),
+                None,
+                86400,
+                MarketState::Active,
+            );
+            env.storage().persistent().set(&market_id, &market);
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "market_index"), &vec![&env, market_id.clone()]);
+            crate::markets::MarketCreationCaps::record_opened(&env, &creator);
+        });
+        let report = client.health_check(&admin);
+        assert!(report.healthy);
+        assert_eq!(report.violations.len(), 0);
+
+        // A counter that agrees with the per-state counts but not with the
+        // registry is still caught, as is a negative lifetime total
+        env.as_contract(&contract_id, || {
+            crate::markets::MarketCreationCaps::record_opened(&env, &creator);
+            crate::markets::MarketStateCounts::record_created(&env, MarketState::Active);
+            env.storage()
+                .persistent()
+                .set(&soroban_sdk::symbol_short!("life_fees"), &-5i128);
+        });
+        let report = client.health_check(&admin);
+        assert!(!report.healthy);
+        assert_eq!(
+            report.violations,
+            vec![
+                &env,
+                String::from_str(&env, "active_count_mismatch"),
+                String::from_str(&env, "negative_lifetime_fees"),
+            ]
+        );
+    }
 }
 
 // ===== BOUNDED QUEUE TESTS =====
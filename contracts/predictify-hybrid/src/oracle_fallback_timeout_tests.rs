@@ -139,7 +139,7 @@ fn fetch_oracle_result_without_fallback_reports_primary_only_failure() {
             market.oracle_config.oracle_address.clone(),
         )
     });
-    assert_eq!(result, Err(Error::OracleUnavailable));
+    assert_eq!(result, Err(Error::OracleCallFailed));
 
     let manual =
         find_published_event::<ManualResolutionRequiredEvent>(&setup.env, symbol_short!("man_res"))
@@ -227,7 +227,7 @@ fn refund_on_oracle_failure_uses_market_specific_timeout_for_non_admins() {
     );
 
     let market = setup.get_market(&market_id);
-    setup.advance_to(market.end_time + resolution_timeout - 1);
+    setup.advance_to(market.refund_available_at() - 1);
 
     let early = setup.env.as_contract(&setup.contract_id, || {
         PredictifyHybrid::refund_on_oracle_failure(
@@ -238,7 +238,7 @@ fn refund_on_oracle_failure_uses_market_specific_timeout_for_non_admins() {
     });
     assert_eq!(early, Err(Error::Unauthorized));
 
-    let deadline = market.end_time + resolution_timeout;
+    let deadline = market.end_time + resolution_timeout + crate::config::REFUND_GRACE_SECONDS;
     setup.advance_to(deadline);
     setup.env.mock_all_auths();
     let refunded = setup.env.as_contract(&setup.contract_id, || {
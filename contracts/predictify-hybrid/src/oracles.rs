@@ -5,7 +5,8 @@ use alloc::string::ToString;
 use crate::bandprotocol;
 use crate::err::Error;
 use soroban_sdk::{
-    contracttype, symbol_short, vec, Address, Bytes, Env, IntoVal, String, Symbol, Val, Vec,
+    contracttype, symbol_short, vec, Address, Bytes, Env, IntoVal, Map, String, Symbol, TryFromVal,
+    Val, Vec,
 };
 // use crate::reentrancy_guard::ReentrancyGuard; // Removed - module no longer exists
 use crate::types::*;
@@ -629,6 +630,23 @@ impl<'a> ReflectorOracleClient<'a> {
         res
     }
 
+    /// Get the latest price for an asset without trapping on oracle failure.
+    ///
+    /// Returns `Error::OracleCallFailed` if the oracle call reverts or its
+    /// return value does not decode; `Ok(None)` means the oracle answered but
+    /// has no price for the asset.
+    pub fn try_lastprice(&self, asset: ReflectorAsset) -> Result<Option<ReflectorPriceData>, Error> {
+        let args = vec![self.env, asset.into_val(self.env)];
+        match self.env.try_invoke_contract::<Option<ReflectorPriceData>, soroban_sdk::Error>(
+            &self.contract_id,
+            &symbol_short!("lastprice"),
+            args,
+        ) {
+            Ok(Ok(price)) => Ok(price),
+            _ => Err(Error::OracleCallFailed),
+        }
+    }
+
     /// Get price for an asset at a specific timestamp
     pub fn price(&self, asset: ReflectorAsset, timestamp: u64) -> Option<ReflectorPriceData> {
         let args = vec![
@@ -1400,6 +1418,126 @@ impl OracleInstance {
     }
 }
 
+// ===== COMPARISON OPERATORS =====
+
+/// Comparison applied to an oracle price during resolution.
+///
+/// Stored directly in `OracleConfig.comparison`. String operators from older
+/// configs and external callers go through [`ComparisonOp::parse`]; markets
+/// persisted with the legacy `String` field are rewritten by
+/// `migrate_comparison_operators`. `InRange` / `OutOfRange` test the inclusive
+/// band `[threshold, threshold2]`.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum ComparisonOp {
+    Gt,
+    Lt,
+    Gte,
+    Lte,
+    Eq,
+    Neq,
+    InRange,
+    OutOfRange,
+}
+
+impl ComparisonOp {
+    /// Every operator, in declaration order.
+    pub const ALL: [ComparisonOp; 8] = [
+        ComparisonOp::Gt,
+        ComparisonOp::Lt,
+        ComparisonOp::Gte,
+        ComparisonOp::Lte,
+        ComparisonOp::Eq,
+        ComparisonOp::Neq,
+        ComparisonOp::InRange,
+        ComparisonOp::OutOfRange,
+    ];
+
+    /// Spellings accepted by [`ComparisonOp::parse`]: the canonical names plus
+    /// the symbolic forms written by older configs and tooling.
+    const ALIASES: [(&'static str, ComparisonOp); 14] = [
+        ("gt", ComparisonOp::Gt),
+        ("lt", ComparisonOp::Lt),
+        ("gte", ComparisonOp::Gte),
+        ("lte", ComparisonOp::Lte),
+        ("eq", ComparisonOp::Eq),
+        ("neq", ComparisonOp::Neq),
+        ("in_range", ComparisonOp::InRange),
+        ("out_of_range", ComparisonOp::OutOfRange),
+        (">", ComparisonOp::Gt),
+        ("<", ComparisonOp::Lt),
+        (">=", ComparisonOp::Gte),
+        ("<=", ComparisonOp::Lte),
+        ("==", ComparisonOp::Eq),
+        ("!=", ComparisonOp::Neq),
+    ];
+
+    /// Canonical string form, as accepted by [`ComparisonOp::parse`].
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ComparisonOp::Gt => "gt",
+            ComparisonOp::Lt => "lt",
+            ComparisonOp::Gte => "gte",
+            ComparisonOp::Lte => "lte",
+            ComparisonOp::Eq => "eq",
+            ComparisonOp::Neq => "neq",
+            ComparisonOp::InRange => "in_range",
+            ComparisonOp::OutOfRange => "out_of_range",
+        }
+    }
+
+    /// Returns `true` for the two-threshold range operators.
+    pub fn is_range(&self) -> bool {
+        matches!(self, ComparisonOp::InRange | ComparisonOp::OutOfRange)
+    }
+
+    /// Canonical string as a Soroban `String`.
+    pub fn as_string(&self, env: &Env) -> String {
+        String::from_str(env, self.as_str())
+    }
+
+    /// Parse a comparison string, accepting legacy symbolic spellings.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InvalidComparison` - `comparison` is not a known operator
+    pub fn parse(env: &Env, comparison: &String) -> Result<Self, Error> {
+        Self::ALIASES
+            .iter()
+            .find(|(alias, _)| comparison == &String::from_str(env, alias))
+            .map(|(_, op)| *op)
+            .ok_or(Error::InvalidComparison)
+    }
+
+    /// Returns `true` when `price` satisfies this operator against `threshold`
+    /// (and `threshold2`, the upper bound used by the range operators).
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InvalidThreshold` - a range operator has no `threshold2`
+    pub fn apply(
+        &self,
+        price: i128,
+        threshold: i128,
+        threshold2: Option<i128>,
+    ) -> Result<bool, Error> {
+        let in_range = |upper: Option<i128>| -> Result<bool, Error> {
+            let upper = upper.ok_or(Error::InvalidThreshold)?;
+            Ok(price >= threshold && price <= upper)
+        };
+        Ok(match self {
+            ComparisonOp::Gt => price > threshold,
+            ComparisonOp::Lt => price < threshold,
+            ComparisonOp::Gte => price >= threshold,
+            ComparisonOp::Lte => price <= threshold,
+            ComparisonOp::Eq => price == threshold,
+            ComparisonOp::Neq => price != threshold,
+            ComparisonOp::InRange => in_range(threshold2)?,
+            ComparisonOp::OutOfRange => !in_range(threshold2)?,
+        })
+    }
+}
+
 // ===== ORACLE UTILITIES =====
 
 /// Comprehensive utilities for oracle operations, price analysis, and market resolution.
@@ -1428,8 +1566,12 @@ impl OracleInstance {
 /// - **Greater Than ("gt")**: Price > threshold
 /// - **Less Than ("lt")**: Price < threshold  
 /// - **Equal To ("eq")**: Price == threshold
-/// - **Greater or Equal ("gte")**: Price >= threshold (if implemented)
-/// - **Less or Equal ("lte")**: Price <= threshold (if implemented)
+/// - **Greater or Equal ("gte")**: Price >= threshold
+/// - **Less or Equal ("lte")**: Price <= threshold
+/// - **Not Equal ("neq")**: Price != threshold
+///
+/// Strings are parsed through [`ComparisonOp::parse`], so legacy symbolic
+/// spellings such as `">="` resolve the same way.
 ///
 /// # Example Usage
 ///
@@ -1631,22 +1773,52 @@ impl OracleDeviationHistory {
 pub struct OracleUtils;
 
 impl OracleUtils {
-    /// Compare prices using different operators
+    /// Compare prices using a single-threshold operator given as a string.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InvalidComparison` - `comparison` is unknown or a range operator
     pub fn compare_prices(
         price: i128,
         threshold: i128,
         comparison: &String,
         env: &Env,
     ) -> Result<bool, Error> {
-        if comparison == &String::from_str(env, "gt") {
-            Ok(price > threshold)
-        } else if comparison == &String::from_str(env, "lt") {
-            Ok(price < threshold)
-        } else if comparison == &String::from_str(env, "eq") {
-            Ok(price == threshold)
-        } else {
-            Err(Error::InvalidComparison)
+        let op = ComparisonOp::parse(env, comparison)?;
+        if op.is_range() {
+            return Err(Error::InvalidComparison);
         }
+        op.apply(price, threshold, None)
+    }
+
+    /// Rewrite a legacy `String` comparison inside a raw stored oracle config to
+    /// its [`ComparisonOp`].
+    ///
+    /// `config` is the storage map of an `OracleConfig` written before the field
+    /// was typed. A config that already holds a `ComparisonOp` is left untouched;
+    /// the empty string used by the old "no fallback" sentinel maps to the
+    /// sentinel's operator. Returns `true` when `config` was changed.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InvalidComparison` - the stored operator cannot be parsed
+    pub fn migrate_legacy_comparison(
+        env: &Env,
+        config: &mut Map<Symbol, Val>,
+    ) -> Result<bool, Error> {
+        let key = Symbol::new(env, "comparison");
+        let stored = config.get(key.clone()).ok_or(Error::InvalidComparison)?;
+        let legacy = match String::try_from_val(env, &stored) {
+            Ok(legacy) => legacy,
+            Err(_) => return Ok(false),
+        };
+        let op = if legacy.is_empty() {
+            crate::types::OracleConfig::none_sentinel(env).comparison
+        } else {
+            ComparisonOp::parse(env, &legacy)?
+        };
+        config.set(key, op.into_val(env));
+        Ok(true)
     }
 
     /// Determine market outcome based on price comparison
@@ -1665,6 +1837,47 @@ impl OracleUtils {
         }
     }
 
+    /// Determine market outcome for `price` under a full oracle configuration,
+    /// including the two-threshold range comparisons.
+    pub fn determine_outcome_for_config(
+        price: i128,
+        config: &crate::types::OracleConfig,
+        env: &Env,
+    ) -> Result<String, Error> {
+        if config.evaluate(price)? {
+            Ok(String::from_str(env, "yes"))
+        } else {
+            Ok(String::from_str(env, "no"))
+        }
+    }
+
+    /// Fetch the market outcome for `config` from its oracle.
+    ///
+    /// Failed oracle calls and absent prices are reported separately so callers
+    /// can retry the former and escalate the latter:
+    /// - `Error::OracleCallFailed` - the oracle call reverted or returned malformed data
+    /// - `Error::OracleNoData` - the oracle answered but has no price for the feed
+    pub fn fetch_outcome(env: &Env, config: &crate::types::OracleConfig) -> Result<String, Error> {
+        if config.is_none_sentinel() {
+            return Err(Error::InvalidOracleConfig);
+        }
+
+        let price = match config.provider {
+            OracleProvider::Reflector => {
+                let asset = ReflectorOracle::new(config.oracle_address.clone())
+                    .parse_feed_id(env, &config.feed_id)?;
+                ReflectorOracleClient::new(env, config.oracle_address.clone())
+                    .try_lastprice(asset)?
+                    .ok_or(Error::OracleNoData)?
+                    .price
+            }
+            _ => OracleFactory::create_from_config(config, config.oracle_address.clone())?
+                .get_price(env, &config.feed_id)?,
+        };
+
+        Self::determine_outcome_for_config(price, config, env)
+    }
+
     /// Validate oracle response
     pub fn validate_oracle_response(price: i128) -> Result<(), Error> {
         if price <= 0 {
@@ -1874,6 +2087,54 @@ mod tests {
         assert!(outcome.is_ok());
         assert_eq!(outcome.unwrap(), String::from_str(&env, "yes"));
     }
+
+    #[test]
+    fn test_comparison_op_round_trips_and_rejects_unknown() {
+        let env = Env::default();
+
+        for op in ComparisonOp::ALL.iter() {
+            assert_eq!(ComparisonOp::parse(&env, &op.as_string(&env)), Ok(*op));
+        }
+
+        let legacy = [
+            (">", ComparisonOp::Gt),
+            ("<", ComparisonOp::Lt),
+            (">=", ComparisonOp::Gte),
+            ("<=", ComparisonOp::Lte),
+            ("==", ComparisonOp::Eq),
+            ("!=", ComparisonOp::Neq),
+        ];
+        for (alias, op) in legacy.iter() {
+            assert_eq!(ComparisonOp::parse(&env, &String::from_str(&env, alias)), Ok(*op));
+        }
+
+        for unknown in ["", "GT", "ge", "ne", "=>"].iter() {
+            assert_eq!(
+                ComparisonOp::parse(&env, &String::from_str(&env, unknown)),
+                Err(Error::InvalidComparison)
+            );
+        }
+
+        assert_eq!(ComparisonOp::Gte.apply(100, 100, None), Ok(true));
+        assert_eq!(ComparisonOp::Gt.apply(100, 100, None), Ok(false));
+        assert_eq!(ComparisonOp::Lte.apply(99, 100, None), Ok(true));
+        assert_eq!(ComparisonOp::Neq.apply(99, 100, None), Ok(true));
+        assert_eq!(ComparisonOp::Neq.apply(100, 100, None), Ok(false));
+        assert_eq!(ComparisonOp::InRange.apply(150, 100, Some(200)), Ok(true));
+        assert_eq!(ComparisonOp::OutOfRange.apply(150, 100, Some(200)), Ok(false));
+        assert_eq!(
+            ComparisonOp::InRange.apply(150, 100, None),
+            Err(Error::InvalidThreshold)
+        );
+        assert_eq!(
+            OracleUtils::compare_prices(100, 100, &String::from_str(&env, "gte"), &env),
+            Ok(true)
+        );
+        assert_eq!(
+            OracleUtils::compare_prices(100, 100, &String::from_str(&env, "in_range"), &env),
+            Err(Error::InvalidComparison)
+        );
+    }
 }
 
 // ===== ORACLE WHITELIST AND VALIDATION =====
@@ -2432,6 +2693,195 @@ impl OracleWhitelist {
     }
 }
 
+// ===== PROVIDER ALLOWLIST =====
+
+/// Admin-managed set of oracle providers that markets may be created with.
+///
+/// Deployments can switch off providers that are not available on their chain
+/// (e.g. Pyth). Until the admin changes it, every provider is allowed.
+pub struct ProviderAllowlist;
+
+impl ProviderAllowlist {
+    /// All known providers, the default allowed set.
+    pub fn all_providers(env: &Env) -> Vec<OracleProvider> {
+        vec![
+            env,
+            OracleProvider::Reflector,
+            OracleProvider::Pyth,
+            OracleProvider::BandProtocol,
+            OracleProvider::DIA,
+        ]
+    }
+
+    /// Currently allowed providers.
+    pub fn get_allowed(env: &Env) -> Vec<OracleProvider> {
+        env.storage()
+            .persistent()
+            .get(&symbol_short!("orc_allow"))
+            .unwrap_or_else(|| Self::all_providers(env))
+    }
+
+    pub fn is_allowed(env: &Env, provider: &OracleProvider) -> bool {
+        Self::get_allowed(env).contains(provider)
+    }
+
+    /// Fails with `ProviderNotAllowed` if `provider` has been disabled.
+    pub fn require_allowed(env: &Env, provider: &OracleProvider) -> Result<(), Error> {
+        if Self::is_allowed(env, provider) {
+            Ok(())
+        } else {
+            Err(Error::ProviderNotAllowed)
+        }
+    }
+
+    /// Enable or disable `provider` (admin only). Existing markets are unaffected.
+    pub fn set_allowed(
+        env: &Env,
+        admin: &Address,
+        provider: OracleProvider,
+        allowed: bool,
+    ) -> Result<(), Error> {
+        let stored_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(env, "Admin"))
+            .ok_or(Error::AdminNotSet)?;
+        if *admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+
+        let current = Self::get_allowed(env);
+        let mut updated = Vec::new(env);
+        for p in current.iter() {
+            if p != provider {
+                updated.push_back(p);
+            }
+        }
+        if allowed {
+            updated.push_back(provider.clone());
+        }
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("orc_allow"), &updated);
+
+        env.events().publish(
+            (symbol_short!("orc_allow"), provider),
+            (allowed, env.ledger().timestamp()),
+        );
+        Ok(())
+    }
+}
+
+// ===== PROVIDER USAGE =====
+
+/// Per-provider count of markets created on each oracle provider.
+///
+/// Only the primary oracle is counted; a fallback does not make a market
+/// rely on its provider. Counts are never decremented, so they reflect
+/// lifetime usage rather than currently open markets.
+pub struct ProviderUsage;
+
+impl ProviderUsage {
+    /// Markets created per provider; providers never used are absent.
+    pub fn get_usage(env: &Env) -> Map<OracleProvider, u32> {
+        env.storage()
+            .persistent()
+            .get(&symbol_short!("prv_use"))
+            .unwrap_or_else(|| Map::new(env))
+    }
+
+    /// Counts one more market created on `provider`.
+    pub fn record_market_created(env: &Env, provider: &OracleProvider) {
+        let mut usage = Self::get_usage(env);
+        let count = usage.get(provider.clone()).unwrap_or(0);
+        usage.set(provider.clone(), count.saturating_add(1));
+        env.storage()
+            .persistent()
+            .set(&symbol_short!("prv_use"), &usage);
+    }
+}
+
+// ===== FEED DELISTING =====
+
+/// Permanent delisting of oracle feeds.
+///
+/// A delisted feed can never resolve a market, so markets that depend on it
+/// are voided and refunded rather than left stuck until their resolution
+/// timeout, and new markets can no longer be created on it.
+pub struct FeedDelisting;
+
+impl FeedDelisting {
+    pub fn is_delisted(env: &Env, provider: &OracleProvider, feed_id: &String) -> bool {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("feed_dlst"), provider.clone(), feed_id.clone()))
+            .unwrap_or(false)
+    }
+
+    /// Fails with `FeedDelisted` if `config` reads a delisted feed.
+    pub fn require_listed(env: &Env, config: &OracleConfig) -> Result<(), Error> {
+        if Self::is_delisted(env, &config.provider, &config.feed_id) {
+            return Err(Error::FeedDelisted);
+        }
+        Ok(())
+    }
+
+    /// Marks `provider`/`feed_id` permanently delisted (admin only) and voids
+    /// every unresolved market left without a listed oracle.
+    ///
+    /// A market is voided when its primary feed is delisted and it has no
+    /// fallback, or its fallback is delisted too; a market whose other oracle
+    /// is still listed keeps it. Voided markets move to `Cancelled` with all
+    /// active bets refunded. Disputed markets are left to the dispute process.
+    /// Safe to call again for the same feed.
+    ///
+    /// Returns the IDs of the voided markets.
+    pub fn handle_delisted_feed(
+        env: &Env,
+        admin: &Address,
+        provider: OracleProvider,
+        feed_id: String,
+    ) -> Result<Vec<Symbol>, Error> {
+        let stored_admin: Address = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(env, "Admin"))
+            .ok_or(Error::AdminNotSet)?;
+        if *admin != stored_admin {
+            return Err(Error::Unauthorized);
+        }
+        if feed_id.is_empty() {
+            return Err(Error::InvalidFeedId);
+        }
+
+        env.storage().persistent().set(
+            &(symbol_short!("feed_dlst"), provider.clone(), feed_id.clone()),
+            &true,
+        );
+
+        let reason = String::from_str(env, "Oracle feed permanently delisted");
+        let dependents = crate::markets::MarketStateManager::get_unresolved_markets_by_feed(
+            env, &provider, &feed_id,
+        );
+        let mut voided = Vec::new(env);
+        for market_id in dependents.iter() {
+            let market = crate::markets::MarketStateManager::get_market(env, &market_id)?;
+            let primary_listed = Self::require_listed(env, &market.oracle_config).is_ok();
+            let fallback_listed = market.has_fallback
+                && Self::require_listed(env, &market.fallback_oracle_config).is_ok();
+            if primary_listed || fallback_listed {
+                continue;
+            }
+
+            crate::resolution::MarketResolutionManager::void_and_refund(env, &market_id, &reason)?;
+            voided.push_back(market_id);
+        }
+
+        crate::events::EventEmitter::emit_feed_delisted(env, &provider, &feed_id, admin, &voided);
+        Ok(voided)
+    }
+}
+
 // ===== ORACLE INTEGRATION MANAGER =====
 
 /// Storage keys for oracle integration
@@ -2956,12 +3406,8 @@ impl OracleIntegrationManager {
                     // Validate price is within acceptable range
                     if Self::validate_price_range(price) {
                         // Determine outcome for this source
-                        let outcome = OracleUtils::determine_outcome(
-                            price,
-                            oracle_config.threshold,
-                            &oracle_config.comparison,
-                            env,
-                        )?;
+                        let outcome =
+                            OracleUtils::determine_outcome_for_config(price, oracle_config, env)?;
 
                         successful_results.push_back((price, outcome));
                         total_price += price;
@@ -3048,7 +3494,7 @@ impl OracleIntegrationManager {
             outcome: final_outcome,
             price: average_price,
             threshold: oracle_config.threshold,
-            comparison: oracle_config.comparison.clone(),
+            comparison: oracle_config.comparison,
             provider: oracle_config.provider.clone(),
             feed_id: oracle_config.feed_id.clone(),
             timestamp: env.ledger().timestamp(),
@@ -3322,7 +3768,7 @@ impl OracleIntegrationManager {
             outcome: outcome.clone(),
             price: 0, // Manual override - no price
             threshold: market.oracle_config.threshold,
-            comparison: market.oracle_config.comparison.clone(),
+            comparison: market.oracle_config.comparison,
             provider: crate::types::OracleProvider::reflector(), // Placeholder
             feed_id: market.oracle_config.feed_id.clone(),
             timestamp: env.ledger().timestamp(),
@@ -3444,7 +3890,7 @@ mod oracle_integration_tests {
                 outcome: String::from_str(&env, "yes"),
                 price: 52_000_00,
                 threshold: 50_000_00,
-                comparison: String::from_str(&env, "gt"),
+                comparison: ComparisonOp::Gt,
                 provider: crate::types::OracleProvider::reflector(),
                 feed_id: String::from_str(&env, "BTC/USD"),
                 timestamp: env.ledger().timestamp(),
@@ -4078,6 +4524,285 @@ mod whitelist_tests {
     }
 }
 
+// ===== FEED DELISTING TESTS =====
+
+#[cfg(test)]
+mod feed_delisting_tests {
+    use super::*;
+    use crate::bets::BetStorage;
+    use soroban_sdk::testutils::Address as _;
+
+    struct Setup {
+        env: Env,
+        contract_id: Address,
+        admin: Address,
+        token_id: Address,
+    }
+
+    impl Setup {
+        fn new() -> Self {
+            let env = Env::default();
+            env.mock_all_auths();
+            let contract_id = env.register(crate::PredictifyHybrid, ());
+            let admin = Address::generate(&env);
+            crate::PredictifyHybridClient::new(&env, &contract_id).initialize(
+                &admin,
+                &None,
+                &None,
+            );
+
+            let token_id = env
+                .register_stellar_asset_contract_v2(Address::generate(&env))
+                .address();
+            env.as_contract(&contract_id, || {
+                env.storage()
+                    .persistent()
+                    .set(&Symbol::new(&env, "TokenID"), &token_id);
+            });
+
+            Self {
+                env,
+                contract_id,
+                admin,
+                token_id,
+            }
+        }
+
+        fn client(&self) -> crate::PredictifyHybridClient<'_> {
+            crate::PredictifyHybridClient::new(&self.env, &self.contract_id)
+        }
+
+        fn config(&self, feed: &str) -> OracleConfig {
+            OracleConfig::new(
+                OracleProvider::reflector(),
+                Address::generate(&self.env),
+                String::from_str(&self.env, feed),
+                100,
+                ComparisonOp::Gt,
+            )
+        }
+
+        /// Stores an active market on `feed` (with optional fallback) holding one
+        /// locked bet of `amount` from a fresh user, and returns that user.
+        fn market_with_bet(
+            &self,
+            name: &str,
+            feed: &str,
+            fallback: Option<&str>,
+            amount: i128,
+        ) -> (Symbol, Address) {
+            let env = &self.env;
+            let market_id = Symbol::new(env, name);
+            let user = Address::generate(env);
+            soroban_sdk::token::StellarAssetClient::new(env, &self.token_id)
+                .mint(&self.contract_id, &amount);
+
+            env.as_contract(&self.contract_id, || {
+                let mut market = Market::new(
+                    env,
+                    self.admin.clone(),
+                    String::from_str(env, "Feed market"),
+                    vec![env, String::from_str(env, "yes"), String::from_str(env, "no")],
+                    env.ledger().timestamp() + 1000,
+                    self.config(feed),
+                    fallback.map(|f| self.config(f)),
+                    86400,
+                    MarketState::Active,
+                );
+                market.votes.set(user.clone(), String::from_str(env, "yes"));
+                market.stakes.set(user.clone(), amount);
+                market.total_staked = amount;
+                env.storage().persistent().set(&market_id, &market);
+
+                let index_key = Symbol::new(env, "market_index");
+                let mut index: Vec<Symbol> = env
+                    .storage()
+                    .persistent()
+                    .get(&index_key)
+                    .unwrap_or_else(|| Vec::new(env));
+                index.push_back(market_id.clone());
+                env.storage().persistent().set(&index_key, &index);
+
+                BetStorage::store_bet(
+                    env,
+                    &Bet::new(
+                        env,
+                        user.clone(),
+                        market_id.clone(),
+                        String::from_str(env, "yes"),
+                        amount,
+                    ),
+                )
+                .unwrap();
+            });
+            (market_id, user)
+        }
+    }
+
+    #[test]
+    fn delisting_feed_voids_and_refunds_dependent_markets_only() {
+        let setup = Setup::new();
+        let env = &setup.env;
+        let client = setup.client();
+        let token = soroban_sdk::token::Client::new(env, &setup.token_id);
+
+        let (dead, dead_user) = setup.market_with_bet("dead", "XLM/USD", None, 500);
+        let (backed, _) = setup.market_with_bet("backed", "XLM/USD", Some("XLM/EUR"), 300);
+        let (other, other_user) = setup.market_with_bet("other", "BTC/USD", None, 700);
+
+        let delisted = String::from_str(env, "XLM/USD");
+        let voided =
+            client.handle_delisted_feed(&setup.admin, &OracleProvider::Reflector, &delisted);
+        assert_eq!(voided, vec![env, dead.clone()]);
+        assert!(client.is_feed_delisted(&OracleProvider::Reflector, &delisted));
+
+        // Dependent market is voided and its bettor refunded
+        assert_eq!(client.get_market(&dead).unwrap().state, MarketState::Cancelled);
+        assert_eq!(
+            client.get_bet(&dead, &dead_user).unwrap().status,
+            BetStatus::Refunded
+        );
+        assert_eq!(token.balance(&dead_user), 500);
+
+        // A market with a listed fallback and markets on other feeds are untouched
+        assert_eq!(client.get_market(&backed).unwrap().state, MarketState::Active);
+        assert_eq!(client.get_market(&other).unwrap().state, MarketState::Active);
+        assert_eq!(
+            client.get_bet(&other, &other_user).unwrap().status,
+            BetStatus::Active
+        );
+        assert_eq!(token.balance(&other_user), 0);
+        assert_eq!(token.balance(&setup.contract_id), 1_000);
+
+        // Once its fallback is delisted too, the backed market is voided
+        let voided = client.handle_delisted_feed(
+            &setup.admin,
+            &OracleProvider::Reflector,
+            &String::from_str(env, "XLM/EUR"),
+        );
+        assert_eq!(voided, vec![env, backed.clone()]);
+        assert_eq!(client.get_market(&backed).unwrap().state, MarketState::Cancelled);
+    }
+
+    #[test]
+    fn handle_delisted_feed_requires_admin() {
+        let setup = Setup::new();
+        let outsider = Address::generate(&setup.env);
+        let feed = String::from_str(&setup.env, "XLM/USD");
+        assert_eq!(
+            setup
+                .client()
+                .try_handle_delisted_feed(&outsider, &OracleProvider::Reflector, &feed),
+            Err(Ok(Error::Unauthorized))
+        );
+        assert!(!setup
+            .client()
+            .is_feed_delisted(&OracleProvider::Reflector, &feed));
+    }
+}
+
+// ===== ORACLE FAILURE KIND TESTS =====
+
+#[cfg(test)]
+mod oracle_failure_kind_tests {
+    use super::*;
+    use crate::err::{ErrorHandler, RecoveryStrategy};
+    use soroban_sdk::testutils::{Address as _, Ledger};
+
+    mod reverting {
+        use crate::types::{ReflectorAsset, ReflectorPriceData};
+        use soroban_sdk::{contract, contractimpl, Env};
+
+        #[contract]
+        pub struct RevertingOracle;
+
+        #[contractimpl]
+        impl RevertingOracle {
+            pub fn lastprice(_env: Env, _asset: ReflectorAsset) -> Option<ReflectorPriceData> {
+                panic!("oracle reverted")
+            }
+        }
+    }
+
+    mod empty {
+        use crate::types::{ReflectorAsset, ReflectorPriceData};
+        use soroban_sdk::{contract, contractimpl, Env};
+
+        #[contract]
+        pub struct EmptyOracle;
+
+        #[contractimpl]
+        impl EmptyOracle {
+            pub fn lastprice(_env: Env, _asset: ReflectorAsset) -> Option<ReflectorPriceData> {
+                None
+            }
+        }
+    }
+
+    /// Stores an ended market whose primary oracle is `oracle` and returns the
+    /// result of `fetch_oracle_result` on it.
+    fn fetch_from(env: &Env, oracle: Address) -> Result<String, Error> {
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let market_id = Symbol::new(env, "oracle_fail");
+
+        env.as_contract(&contract_id, || {
+            let market = Market::new(
+                env,
+                Address::generate(env),
+                String::from_str(env, "BTC above 100?"),
+                vec![env, String::from_str(env, "yes"), String::from_str(env, "no")],
+                env.ledger().timestamp() + 1000,
+                OracleConfig::new(
+                    OracleProvider::reflector(),
+                    oracle,
+                    String::from_str(env, "BTC/USD"),
+                    100,
+                    ComparisonOp::Gt,
+                ),
+                None,
+                86400,
+                MarketState::Active,
+            );
+            env.storage().persistent().set(&market_id, &market);
+        });
+
+        env.ledger().with_mut(|li| li.timestamp += 1001);
+        env.as_contract(&contract_id, || {
+            crate::PredictifyHybrid::fetch_oracle_result(
+                env.clone(),
+                market_id.clone(),
+                env.current_contract_address(),
+            )
+        })
+    }
+
+    #[test]
+    fn reverting_oracle_reports_call_failure() {
+        let env = Env::default();
+        let oracle = env.register(reverting::RevertingOracle, ());
+
+        let err = fetch_from(&env, oracle).unwrap_err();
+        assert_eq!(err, Error::OracleCallFailed);
+        assert_eq!(
+            ErrorHandler::get_error_recovery_strategy(&err),
+            RecoveryStrategy::RetryWithDelay
+        );
+    }
+
+    #[test]
+    fn oracle_without_price_reports_no_data() {
+        let env = Env::default();
+        let oracle = env.register(empty::EmptyOracle, ());
+
+        let err = fetch_from(&env, oracle).unwrap_err();
+        assert_eq!(err, Error::OracleNoData);
+        assert_eq!(
+            ErrorHandler::get_error_recovery_strategy(&err),
+            RecoveryStrategy::ManualIntervention
+        );
+    }
+}
+
 // ===== ORACLE CALLBACK AUTHENTICATION SYSTEM =====
 
 /// Oracle callback authentication system for secure oracle integration.
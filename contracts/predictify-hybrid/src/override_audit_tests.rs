@@ -2,6 +2,7 @@
 
 use crate::audit_trail::{AuditAction, AuditTrailManager};
 use crate::err::Error;
+use crate::oracles::ComparisonOp;
 use crate::types::{MarketState, OracleConfig, OracleProvider};
 use crate::{PredictifyHybrid, PredictifyHybridClient};
 use soroban_sdk::{
@@ -10,6 +11,15 @@ use soroban_sdk::{
 
 // ── shared setup ─────────────────────────────────────────────────────────────
 
+/// Configures a staking token so markets can be created.
+fn set_staking_token(env: &Env, contract_id: &Address) {
+    env.as_contract(contract_id, || {
+        env.storage()
+            .persistent()
+            .set(&Symbol::new(env, "TokenID"), &Address::generate(env));
+    });
+}
+
 struct Ctx {
     env: Env,
     contract_id: Address,
@@ -23,6 +33,7 @@ impl Ctx {
         let admin = Address::generate(&env);
         let contract_id = env.register(PredictifyHybrid, ());
         PredictifyHybridClient::new(&env, &contract_id).initialize(&admin, &None, &None);
+        set_staking_token(&env, &contract_id);
         Self { env, contract_id, admin }
     }
 
@@ -46,7 +57,8 @@ impl Ctx {
                 oracle_address: Address::generate(&self.env),
                 feed_id: String::from_str(&self.env, "BTC"),
                 threshold: 100_000_00,
-                comparison: String::from_str(&self.env, "gt"),
+                comparison: ComparisonOp::Gt,
+                threshold2: None,
             },
             &None,
             &0u64,
@@ -170,6 +182,7 @@ fn test_override_rejects_non_admin() {
     let contract_id = env.register(PredictifyHybrid, ());
     let client = PredictifyHybridClient::new(&env, &contract_id);
     client.initialize(&admin, &None, &None);
+    set_staking_token(&env, &contract_id);
 
     // Create market while auths are still mocked
     let market_id = client.create_market(
@@ -186,7 +199,8 @@ fn test_override_rejects_non_admin() {
             oracle_address: Address::generate(&env),
             feed_id: String::from_str(&env, "BTC"),
             threshold: 100_000_00,
-            comparison: String::from_str(&env, "gt"),
+            comparison: ComparisonOp::Gt,
+            threshold2: None,
         },
         &None,
         &0u64,
@@ -237,6 +251,7 @@ fn test_override_no_partial_state_on_auth_failure() {
     let contract_id = env.register(PredictifyHybrid, ());
     let client = PredictifyHybridClient::new(&env, &contract_id);
     client.initialize(&admin, &None, &None);
+    set_staking_token(&env, &contract_id);
 
     let market_id = client.create_market(
         &admin,
@@ -252,7 +267,8 @@ fn test_override_no_partial_state_on_auth_failure() {
             oracle_address: Address::generate(&env),
             feed_id: String::from_str(&env, "BTC"),
             threshold: 100_000_00,
-            comparison: String::from_str(&env, "gt"),
+            comparison: ComparisonOp::Gt,
+            threshold2: None,
         },
         &None,
         &0u64,
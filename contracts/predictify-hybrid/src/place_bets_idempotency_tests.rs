@@ -86,6 +86,7 @@ impl Setup {
                 feed_id: String::from_str(&env, "BTC/USD"),
                 threshold: 100_000_00000000,
                 comparison: String::from_str(&env, "gt"),
+                threshold2: None,
             },
             &None,
             &86400u64,
@@ -11,6 +11,7 @@ use crate::config::{
     DEFAULT_PLATFORM_FEE_PERCENTAGE, MAX_MARKET_DURATION_DAYS, MAX_MARKET_OUTCOMES,
     MIN_MARKET_DURATION_DAYS, MIN_MARKET_OUTCOMES,
 };
+use crate::oracles::ComparisonOp;
 use crate::types::*;
 use alloc::vec::Vec as StdVec;
 
@@ -209,13 +210,18 @@ mod legacy_contract_property_tests {
         }
 
         /// Generate a valid oracle configuration for testing
-        pub fn generate_oracle_config(&self, threshold: i128, comparison: &str) -> OracleConfig {
+        pub fn generate_oracle_config(
+            &self,
+            threshold: i128,
+            comparison: ComparisonOp,
+        ) -> OracleConfig {
             OracleConfig {
                 provider: OracleProvider::reflector(),
                 oracle_address: Address::generate(&self.env),
                 feed_id: SorobanString::from_str(&self.env, "BTC/USD"),
                 threshold,
-                comparison: SorobanString::from_str(&self.env, comparison),
+                comparison,
+                threshold2: None,
             }
         }
 
@@ -263,9 +269,16 @@ mod legacy_contract_property_tests {
         1i128..=1_000_000_00i128 // $1 to $1M in cents
     }
 
-    /// Generate valid comparison operators
-    fn arb_comparison() -> impl Strategy<Value = &'static str> {
-        prop_oneof![Just("gt"), Just("lt"), Just("eq")]
+    /// Generate valid single-threshold comparison operators
+    fn arb_comparison() -> impl Strategy<Value = ComparisonOp> {
+        prop_oneof![
+            Just(ComparisonOp::Gt),
+            Just(ComparisonOp::Gte),
+            Just(ComparisonOp::Lt),
+            Just(ComparisonOp::Lte),
+            Just(ComparisonOp::Eq),
+            Just(ComparisonOp::Neq),
+        ]
     }
 
     /// Generate valid stake amounts
@@ -449,7 +462,8 @@ mod legacy_contract_property_tests {
                 oracle_address: Address::generate(&suite.env),
                 feed_id: SorobanString::from_str(&suite.env, &feed_id),
                 threshold,
-                comparison: SorobanString::from_str(&suite.env, comparison),
+                comparison,
+                threshold2: None,
             };
 
             // Property: Oracle configuration validation should pass for valid inputs
@@ -477,15 +491,15 @@ mod legacy_contract_property_tests {
                 oracle_address: Address::generate(&suite.env),
                 feed_id: SorobanString::from_str(&suite.env, "BTC/USD"),
                 threshold,
-                comparison: SorobanString::from_str(&suite.env, comparison),
+                comparison,
+                threshold2: None,
             };
 
             // Invariant: Threshold must always be positive
             prop_assert!(oracle_config.threshold > 0);
 
-            // Invariant: Comparison must be one of the valid operators
-            let valid_comparisons = ["gt", "lt", "eq"];
-            prop_assert!(valid_comparisons.contains(&comparison));
+            // Invariant: Comparison is a single-threshold operator (no threshold2)
+            prop_assert!(!oracle_config.comparison.is_range());
 
             // Invariant: Provider must be supported
             prop_assert!(oracle_config.provider.is_supported());
@@ -734,7 +748,7 @@ mod legacy_contract_property_tests {
             });
 
             let winning_outcome = outcomes.get(0).unwrap();
-            client.resolve_market_manual(&suite.admin, &market_id, &winning_outcome);
+            client.resolve_market_manual(&suite.admin, &market_id, &winning_outcome, &None);
 
             let distributed_total = client.distribute_payouts(&market_id);
 
@@ -814,7 +828,7 @@ mod legacy_contract_property_tests {
             });
 
             let winning_outcome = outcomes.get(0).unwrap();
-            client.resolve_market_manual(&suite.admin, &market_id, &winning_outcome);
+            client.resolve_market_manual(&suite.admin, &market_id, &winning_outcome, &None);
 
             let stellar_client = soroban_sdk::token::TokenClient::new(&suite.env, &suite.token_id);
 
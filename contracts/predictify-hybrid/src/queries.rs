@@ -85,20 +85,21 @@ use crate::{
     bets::BetManager,
     disputes::{Dispute, DisputeManager, DisputeStats, DisputeVote},
     errors::Error,
+    fees::InsuranceFund,
     governance::{GovernanceContract, GovernanceProposal},
     markets::{MarketAnalytics, MarketStateManager, MarketValidator},
     oracles::{OracleMetadata, OracleWhitelist},
     statistics::StatisticsManager,
     storage::EventManager,
-    types::{Market, MarketState, PagedMarketIds, PagedUserBets},
+    types::{Market, MarketState, OracleConfig, PagedMarketIds, PagedUserBets},
     voting::VotingStats,
 };
 use soroban_sdk::{contracttype, vec, Address, Env, Map, String, Symbol, Vec};
 
 use crate::types::{
-    CategoryStatisticsV1, ContractStateQuery, DashboardStatisticsV1, EventDetailsQuery,
-    MarketPoolQuery, MarketStatisticsV1, MarketStatus, MultipleBetsQuery, UserBalanceQuery,
-    UserBetQuery, UserLeaderboardEntryV1,
+    CategoryStatisticsV1, ClaimHistoryEntry, ContractStateQuery, DashboardStatisticsV1, EventDetailsQuery,
+    MarketFlags, MarketPoolQuery, MarketStatisticsV1, MarketStatus, MarketSummary, MultipleBetsQuery,
+    UserBalanceQuery, UserBetQuery, UserLeaderboardEntryV1,
 };
 
 /// Maximum items returned per paginated query (gas safety cap).
@@ -703,6 +704,660 @@ impl QueryManager {
         Ok(total)
     }
 
+    /// Query total outstanding liability across all markets.
+    ///
+    /// Liability is the amount the contract may still owe participants:
+    /// - Unresolved markets (`Active`, `Ended`, `Disputed`): the full `total_staked`
+    /// - `Resolved` / `Closed` markets: `total_staked` minus payouts already
+    ///   claimed, as claims stay open after fees are collected
+    /// - `Cancelled` markets: the `total_staked` not yet refunded, which
+    ///   `process_refunds_batch` draws down
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(i128)` - Outstanding liability in base token units
+    pub fn query_outstanding_liability(env: &Env) -> Result<i128, Error> {
+        let all_markets = Self::get_all_markets(env)?;
+        let mut total = 0i128;
+
+        for market_id in all_markets.iter() {
+            if let Ok(market) = Self::get_market_from_storage(env, &market_id) {
+                total = total
+                    .checked_add(Self::market_outstanding_liability(&market))
+                    .ok_or(Error::FeeArithmeticOverflow)?;
+            }
+        }
+
+        Ok(total)
+    }
+
+    /// Query the insurance-fund coverage ratio in basis points.
+    ///
+    /// Returns the insurance-fund balance as basis points of
+    /// [`query_outstanding_liability`] (10_000 = fully covered). Values above
+    /// 10_000 indicate over-collateralisation; no liability reports 10_000.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(u32)` - Coverage ratio in basis points
+    pub fn get_insurance_coverage_ratio(env: &Env) -> Result<u32, Error> {
+        let liability = Self::query_outstanding_liability(env)?;
+        let balance = InsuranceFund::get_balance(env);
+        Ok(InsuranceFund::coverage_ratio_bps(balance, liability))
+    }
+
+    /// Query the mean resolution latency in seconds.
+    ///
+    /// Latency is measured from a market's `end_time` to the ledger time it was
+    /// resolved, averaged over every resolution recorded so far. Backed by running
+    /// counters maintained at resolution, so this is O(1) regardless of market count.
+    ///
+    /// # Returns
+    ///
+    /// * `u64` - Mean latency in seconds (0 if no market has been resolved)
+    pub fn get_average_resolution_latency(env: &Env) -> u64 {
+        crate::resolution::ResolutionLatencyTracker::get_stats(env).average()
+    }
+
+    /// Query the top `limit` creators by cumulative resolved-market volume.
+    ///
+    /// Volume is the `total_staked` of each market a creator resolved, summed
+    /// at resolution. Ranked highest first; ties keep whoever reached the
+    /// volume first ahead. `limit` is capped at
+    /// [`crate::config::MAX_TOP_CREATORS`].
+    ///
+    /// # Returns
+    ///
+    /// * `Vec<(Address, i128)>` - `(creator, volume)` pairs in rank order
+    pub fn get_top_creators(env: &Env, limit: u32) -> Vec<(Address, i128)> {
+        let limit = core::cmp::min(limit, crate::config::MAX_TOP_CREATORS);
+        let mut top = Vec::new(env);
+        for entry in crate::resolution::CreatorLeaderboard::get_top(env).iter() {
+            if top.len() >= limit {
+                break;
+            }
+            top.push_back((entry.creator, entry.volume));
+        }
+        top
+    }
+
+    /// Query how many markets have resolved to each outcome label.
+    ///
+    /// Label-based aggregate across all markets (see
+    /// [`crate::resolution::ResolutionDistribution`]); ties count once per
+    /// winning label and at most [`crate::config::MAX_RESOLUTION_LABELS`]
+    /// labels are tracked.
+    ///
+    /// # Returns
+    ///
+    /// * `Map<String, u32>` - Outcome label to resolved-market count
+    pub fn get_resolution_distribution(env: &Env) -> Map<String, u32> {
+        crate::resolution::ResolutionDistribution::get(env)
+    }
+
+    /// Query settlement progress for a market.
+    ///
+    /// `total_winners` is derived from the per-outcome vote counts of the winning
+    /// outcome(s); `claimed_count` and `total_paid` are running counters advanced
+    /// as winning voters are paid, via `claim_winnings` or `distribute_payouts`.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((claimed_count, total_winners, total_paid))` - Settlement counters
+    ///   (`total_winners` is 0 while the market is unresolved)
+    /// * `Err(Error::MarketNotFound)` - Market does not exist
+    pub fn get_claim_stats(env: &Env, market_id: &Symbol) -> Result<(u32, u32, i128), Error> {
+        let market = Self::get_market_from_storage(env, market_id)?;
+        let total_winners = crate::markets::MarketAnalytics::count_winners(&market);
+        let progress = crate::markets::MarketStateManager::get_claim_progress(env, market_id);
+        Ok((progress.claimed_count, total_winners, progress.total_paid))
+    }
+
+    /// Query every per-market configuration flag in one call.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(MarketFlags)` - Flags read from the market and its side tables
+    /// * `Err(Error::MarketNotFound)` - Market does not exist
+    pub fn get_market_flags(env: &Env, market_id: &Symbol) -> Result<MarketFlags, Error> {
+        let market = Self::get_market_from_storage(env, market_id)?;
+
+        Ok(MarketFlags {
+            market_id: market_id.clone(),
+            state: market.state,
+            has_fallback: market.has_fallback,
+            fee_collected: market.fee_collected,
+            winnings_swept: market.winnings_swept,
+            grouped: crate::resolution::MarketGroupManager::group_of(env, market_id).is_some(),
+            result_verified: crate::oracles::OracleIntegrationManager::is_result_verified(
+                env, market_id,
+            ),
+            archived: crate::event_archive::EventArchive::is_archived(env, market_id),
+        })
+    }
+
+    /// Query a market's display data in one call: question, outcomes,
+    /// per-outcome totals, stake, voter count, state, end time and resolution.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(MarketSummary)` - Summary of the market
+    /// * `Err(Error::MarketNotFound)` - Market does not exist
+    pub fn get_market_summary(env: &Env, market_id: &Symbol) -> Result<MarketSummary, Error> {
+        let market = Self::get_market_from_storage(env, market_id)?;
+
+        let mut outcome_totals: Map<String, i128> = Map::new(env);
+        for outcome in market.outcomes.iter() {
+            let total = Self::calculate_outcome_pool(env, &market, &outcome)?;
+            outcome_totals.set(outcome, total);
+        }
+
+        Ok(MarketSummary {
+            market_id: market_id.clone(),
+            question: market.question,
+            outcomes: market.outcomes,
+            outcome_totals,
+            total_staked: market.total_staked,
+            voter_count: market.votes.len(),
+            state: market.state,
+            end_time: market.end_time,
+            winning_outcomes: market.winning_outcomes,
+            oracle_result: market.oracle_result,
+        })
+    }
+
+    /// Query [`Self::get_market_summary`] for several markets at once, in
+    /// input order, skipping IDs that do not exist.
+    ///
+    /// The caller bounds `market_ids`; see `config::MAX_MARKET_SUMMARIES_QUERY`.
+    pub fn get_market_summaries(env: &Env, market_ids: &Vec<Symbol>) -> Vec<MarketSummary> {
+        let mut summaries = Vec::new(env);
+        for market_id in market_ids.iter() {
+            if let Ok(summary) = Self::get_market_summary(env, &market_id) {
+                summaries.push_back(summary);
+            }
+        }
+        summaries
+    }
+
+    /// Query the cheapest additional stake that makes one outcome the sole leader.
+    ///
+    /// Per-outcome totals come from [`Self::calculate_outcome_pool`]. For every
+    /// outcome the stake needed to strictly exceed all trailing outcomes is
+    /// computed, and the smallest one is returned. If an outcome already leads
+    /// strictly the answer is that outcome with 0. When several outcomes share
+    /// the top total (including an empty market), each needs 1 and the first in
+    /// `market.outcomes` order is returned.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((outcome, additional_stake))` - Cheapest path to a strict lead
+    /// * `Err(Error::MarketNotFound)` - Market does not exist
+    /// * `Err(Error::InvalidOutcomes)` - Market has no outcomes
+    pub fn min_stake_to_lead(env: &Env, market_id: &Symbol) -> Result<(String, i128), Error> {
+        let market = Self::get_market_from_storage(env, market_id)?;
+
+        let mut totals: Vec<i128> = Vec::new(env);
+        for outcome in market.outcomes.iter() {
+            totals.push_back(Self::calculate_outcome_pool(env, &market, &outcome)?);
+        }
+
+        let (index, stake) = crate::utils::NumericUtils::cheapest_strict_lead(&totals)
+            .ok_or(Error::InvalidOutcomes)?;
+        Ok((market.outcomes.get_unchecked(index), stake))
+    }
+
+    /// Query the outcome with the strictly highest staked total.
+    ///
+    /// Per-outcome totals come from the market's `BetStats.outcome_totals`. A
+    /// tie for the top total, which includes a market with no stake, has no
+    /// leader.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Some(outcome))` - The sole leading outcome
+    /// * `Ok(None)` - The top total is tied or nothing has been staked
+    /// * `Err(Error::MarketNotFound)` - Market does not exist
+    pub fn get_leading_outcome(env: &Env, market_id: &Symbol) -> Result<Option<String>, Error> {
+        let market = Self::get_market_from_storage(env, market_id)?;
+        let totals = crate::bets::BetStorage::get_market_bet_stats(env, market_id).outcome_totals;
+
+        let mut leader: Option<String> = None;
+        let mut top_total = 0i128;
+        let mut tied = true;
+        for outcome in market.outcomes.iter() {
+            let total = totals.get(outcome.clone()).unwrap_or(0);
+            if total > top_total {
+                top_total = total;
+                leader = Some(outcome);
+                tied = false;
+            } else if total == top_total {
+                tied = true;
+            }
+        }
+
+        Ok(if tied { None } else { leader })
+    }
+
+    /// Query every outcome with its staked total and voter count.
+    ///
+    /// Entries follow `market.outcomes` order; outcomes nobody backed are
+    /// included with zero stake and zero voters.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<(outcome, total_staked, voter_count)>)` - One entry per outcome
+    /// * `Err(Error::MarketNotFound)` - Market does not exist
+    pub fn get_outcome_breakdown(
+        env: &Env,
+        market_id: &Symbol,
+    ) -> Result<Vec<(String, i128, u32)>, Error> {
+        let market = Self::get_market_from_storage(env, market_id)?;
+
+        let mut breakdown = Vec::new(env);
+        for outcome in market.outcomes.iter() {
+            let mut total = 0i128;
+            let mut voters = 0u32;
+            for (user, voted_outcome) in market.votes.iter() {
+                if voted_outcome == outcome {
+                    total += market.stakes.get(user).unwrap_or(0);
+                    voters += 1;
+                }
+            }
+            breakdown.push_back((outcome, total, voters));
+        }
+
+        Ok(breakdown)
+    }
+
+    /// Query each outcome's implied odds: its share of `total_staked` in basis
+    /// points.
+    ///
+    /// Shares are rounded down, so they sum to at most 10_000. A market with
+    /// nothing staked yields an empty map.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Map<outcome, bps>)` - One entry per outcome, or empty when nothing is staked
+    /// * `Err(Error::MarketNotFound)` - Market does not exist
+    /// * `Err(Error::InvalidInput)` - An outcome pool overflows the calculation
+    pub fn get_implied_odds(env: &Env, market_id: &Symbol) -> Result<Map<String, u32>, Error> {
+        let market = Self::get_market_from_storage(env, market_id)?;
+
+        let mut odds = Map::new(env);
+        if market.total_staked <= 0 {
+            return Ok(odds);
+        }
+        for outcome in market.outcomes.iter() {
+            let pool = Self::calculate_outcome_pool(env, &market, &outcome)?;
+            let bps = pool.checked_mul(10_000).ok_or(Error::InvalidInput)? / market.total_staked;
+            odds.set(outcome, bps as u32);
+        }
+
+        Ok(odds)
+    }
+
+    /// Query how much `user` can reclaim from `market_id` through a refund.
+    ///
+    /// A market is refundable once it is cancelled, or once its refund grace
+    /// has expired without an oracle result or resolution (see
+    /// [`Market::refund_available_at`]), at which point anyone may call
+    /// `refund_on_oracle_failure`. Refunds return active bets, so the amount is
+    /// the user's bet while it is still active.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(i128)` - Refundable amount; 0 if the market is not refundable, the
+    ///   user has no bet, or the bet was already refunded or settled
+    /// * `Err(Error::MarketNotFound)` - Market does not exist
+    pub fn refundable_amount(env: &Env, market_id: &Symbol, user: &Address) -> Result<i128, Error> {
+        let market = Self::get_market_from_storage(env, market_id)?;
+
+        let refundable = market.state == MarketState::Cancelled
+            || (market.winning_outcomes.is_none()
+                && market.oracle_result.is_none()
+                && env.ledger().timestamp() >= market.refund_available_at());
+        if !refundable {
+            return Ok(0);
+        }
+
+        Ok(crate::bets::BetStorage::get_bet(env, market_id, user)
+            .filter(|bet| bet.is_active())
+            .map_or(0, |bet| bet.amount))
+    }
+
+    /// Query what a `hypothetical_stake` on `outcome` would pay if that outcome
+    /// wins, without placing it.
+    ///
+    /// Uses the claim math ([`crate::fees::WinnerPayouts`]) over the current
+    /// vote pools plus the hypothetical stake, under the market's payout
+    /// model: a parimutuel winner is paid its share of the pool less the
+    /// platform fee and insurance slice, a stake-back winner its stake plus
+    /// the bonus. An unresolved market is assumed to resolve to `outcome`
+    /// alone; on a resolved market the actual winning outcomes apply, so a
+    /// losing `outcome` pays 0 (its stake, on a stake-back market) and a tie
+    /// shares the pool.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(i128)` - Simulated payout (0 for a zero stake)
+    /// * `Err(Error::MarketNotFound)` - Market does not exist
+    /// * `Err(Error::InvalidOutcome)` - `outcome` is not one of the market's outcomes
+    /// * `Err(Error::InvalidInput)` - `hypothetical_stake` is negative or overflows the pool
+    pub fn simulate_payout(
+        env: &Env,
+        market_id: &Symbol,
+        outcome: &String,
+        hypothetical_stake: i128,
+    ) -> Result<i128, Error> {
+        let market = Self::get_market_from_storage(env, market_id)?;
+        if !market.outcomes.contains(outcome) {
+            return Err(Error::InvalidOutcome);
+        }
+        if hypothetical_stake < 0 {
+            return Err(Error::InvalidInput);
+        }
+        if hypothetical_stake == 0 {
+            return Ok(0);
+        }
+
+        let winners = market
+            .winning_outcomes
+            .clone()
+            .unwrap_or_else(|| vec![env, outcome.clone()]);
+        let won = winners.contains(outcome);
+        let mut winning_total = hypothetical_stake;
+        for winner in winners.iter() {
+            winning_total = winning_total
+                .checked_add(Self::calculate_outcome_pool(env, &market, &winner)?)
+                .ok_or(Error::InvalidInput)?;
+        }
+        let total_pool = market
+            .total_staked
+            .checked_add(hypothetical_stake)
+            .ok_or(Error::InvalidInput)?;
+
+        let quote = crate::fees::WinnerPayouts::quote(
+            env,
+            market_id,
+            &market,
+            None,
+            hypothetical_stake,
+            won,
+            (total_pool, winning_total),
+        )?;
+        Ok(quote.payout)
+    }
+
+    /// Reconcile a market's aggregate counters against its per-user records.
+    ///
+    /// `expected` is derived from the aggregates: `total_staked` plus all
+    /// dispute stakes, minus the claim progress `total_paid`. `stored` is the
+    /// liability tracked per user: the sum of `stakes` plus dispute stakes,
+    /// minus every recorded `ClaimInfo` payout. The two agree on a consistent
+    /// market; any difference is an accounting discrepancy.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok((i128, i128))` - `(expected, stored)`
+    /// * `Err(Error::MarketNotFound)` - Market does not exist
+    /// * `Err(Error::InvalidInput)` - A sum overflows
+    pub fn reconcile_market(env: &Env, market_id: &Symbol) -> Result<(i128, i128), Error> {
+        let market = Self::get_market_from_storage(env, market_id)?;
+
+        let mut dispute_total: i128 = 0;
+        for (_, stake) in market.dispute_stakes.iter() {
+            dispute_total = dispute_total.checked_add(stake).ok_or(Error::InvalidInput)?;
+        }
+
+        let total_paid =
+            crate::markets::MarketStateManager::get_claim_progress(env, market_id).total_paid;
+        let expected = market
+            .total_staked
+            .checked_add(dispute_total)
+            .and_then(|held| held.checked_sub(total_paid))
+            .ok_or(Error::InvalidInput)?;
+
+        let mut stored = dispute_total;
+        for (_, stake) in market.stakes.iter() {
+            stored = stored.checked_add(stake).ok_or(Error::InvalidInput)?;
+        }
+        for (_, claim) in market.claimed.iter() {
+            stored = stored
+                .checked_sub(claim.payout_amount)
+                .ok_or(Error::InvalidInput)?;
+        }
+
+        Ok((expected, stored))
+    }
+
+    /// Query the full payout table for a resolved market without paying anyone.
+    ///
+    /// See [`crate::resolution::MarketResolutionManager::simulate_full_distribution`].
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<(Address, i128)>)` - Every winner with their simulated payout
+    /// * `Err(Error::MarketNotFound)` - Market does not exist
+    /// * `Err(Error::MarketNotResolved)` - Market has no winning outcome yet
+    pub fn simulate_full_distribution(
+        env: &Env,
+        market_id: &Symbol,
+    ) -> Result<Vec<(Address, i128)>, Error> {
+        crate::resolution::MarketResolutionManager::simulate_full_distribution(env, market_id)
+    }
+
+    /// Query net stake added per outcome over the last `window_seconds`.
+    ///
+    /// Flows are kept in hourly buckets for the last 24 hours (see
+    /// [`crate::voting::OutcomeMomentum`]); longer windows are truncated and the
+    /// window edge is rounded out to a whole bucket. Every outcome is present,
+    /// with 0 if it saw no activity.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Map<String, i128>)` - Net flow (stakes minus withdrawals) per outcome
+    /// * `Err(Error::MarketNotFound)` - Market does not exist
+    pub fn get_outcome_momentum(
+        env: &Env,
+        market_id: &Symbol,
+        window_seconds: u64,
+    ) -> Result<Map<String, i128>, Error> {
+        let market = Self::get_market_from_storage(env, market_id)?;
+        let flows = crate::voting::OutcomeMomentum::net_flows(env, market_id, window_seconds);
+
+        let mut momentum = Map::new(env);
+        for outcome in market.outcomes.iter() {
+            let flow = flows.get(outcome.clone()).unwrap_or(0);
+            momentum.set(outcome, flow);
+        }
+        Ok(momentum)
+    }
+
+    /// Query a page of the markets a user has voted or bet in, in the order
+    /// they first participated.
+    ///
+    /// # Parameters
+    ///
+    /// * `user` - User whose markets to list
+    /// * `start` - Index of the first entry to return
+    /// * `limit` - Page size; capped at [`MAX_PAGE_SIZE`] (50)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<Symbol>)` - Market IDs (empty past the end)
+    pub fn get_user_markets(
+        env: &Env,
+        user: &Address,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<Symbol>, Error> {
+        let limit = core::cmp::min(limit, MAX_PAGE_SIZE);
+        let markets = crate::markets::MarketStateManager::get_user_markets(env, user);
+
+        let end = core::cmp::min(start.saturating_add(limit), markets.len());
+        let mut page = Vec::new(env);
+        for i in start..end {
+            page.push_back(markets.get_unchecked(i));
+        }
+        Ok(page)
+    }
+
+    /// Query a page of a user's claim history, oldest first.
+    ///
+    /// # Parameters
+    ///
+    /// * `user` - User whose payouts to list
+    /// * `start` - Index of the first entry to return
+    /// * `limit` - Page size; capped at [`MAX_PAGE_SIZE`] (50)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<ClaimHistoryEntry>)` - Entries in claim order (empty past the end)
+    pub fn get_user_claim_history(
+        env: &Env,
+        user: &Address,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<ClaimHistoryEntry>, Error> {
+        let limit = core::cmp::min(limit, MAX_PAGE_SIZE);
+        let history = crate::markets::MarketStateManager::get_claim_history(env, user);
+
+        let end = core::cmp::min(start.saturating_add(limit), history.len());
+        let mut page = Vec::new(env);
+        for i in start..end {
+            page.push_back(history.get_unchecked(i));
+        }
+        Ok(page)
+    }
+
+    /// Query a page of a market's raw votes as `(voter, outcome, stake)`.
+    ///
+    /// Votes are listed in the order of the `votes` map keys, which is stable
+    /// across calls, so consecutive pages never skip or repeat a voter while
+    /// no new votes arrive.
+    ///
+    /// # Parameters
+    ///
+    /// * `market_id` - Market whose votes to list
+    /// * `start` - Index of the first vote to return
+    /// * `limit` - Page size; capped at [`MAX_PAGE_SIZE`] (50)
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Vec<(Address, String, i128)>)` - Votes (empty past the end)
+    /// * `Err(Error::MarketNotFound)` - Market does not exist
+    pub fn get_votes_page(
+        env: &Env,
+        market_id: &Symbol,
+        start: u32,
+        limit: u32,
+    ) -> Result<Vec<(Address, String, i128)>, Error> {
+        let limit = core::cmp::min(limit, MAX_PAGE_SIZE);
+        let market = Self::get_market_from_storage(env, market_id)?;
+        let voters = market.votes.keys();
+
+        let end = core::cmp::min(start.saturating_add(limit), voters.len());
+        let mut page = Vec::new(env);
+        for i in start..end {
+            let voter = voters.get_unchecked(i);
+            let outcome = market.votes.get_unchecked(voter.clone());
+            let stake = market.stakes.get(voter.clone()).unwrap_or(0);
+            page.push_back((voter, outcome, stake));
+        }
+        Ok(page)
+    }
+
+    /// Query the token allowance `user` must grant the contract before voting `stake`.
+    ///
+    /// Votes lock funds with a direct `transfer` authorized by the voter (see
+    /// [`crate::bets::BetUtils::lock_funds`]), not `transfer_from`, so no
+    /// allowance is consumed and this returns 0. The configured token is a
+    /// Stellar Asset Contract-compatible token without transfer fees, so the
+    /// user's balance only needs to cover `stake` itself. Clients should still
+    /// call this rather than hard-coding 0 in case the funding path changes.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(i128)` - Allowance to approve for the contract (currently always 0)
+    /// * `Err(Error::InvalidInput)` - `stake` is not positive
+    /// * `Err(Error::MarketNotFound)` - Market does not exist
+    /// * `Err(Error::InvalidState)` - Market is not accepting votes or no token is configured
+    pub fn get_required_allowance(
+        env: &Env,
+        _user: &Address,
+        market_id: &Symbol,
+        stake: i128,
+    ) -> Result<i128, Error> {
+        if stake <= 0 {
+            return Err(Error::InvalidInput);
+        }
+        let market = Self::get_market_from_storage(env, market_id)?;
+        if market.state != MarketState::Active {
+            return Err(Error::InvalidState);
+        }
+        crate::markets::MarketUtils::get_token_client(env)?;
+        Ok(0)
+    }
+
+    /// Query the address that created a market.
+    ///
+    /// Unlike `Market::admin`, the creator is fixed when the market is created
+    /// and is unaffected by later admin changes. Creator-side accounting
+    /// (resolution bounty, creator leaderboard, creation limits) keys on it.
+    ///
+    /// # Returns
+    ///
+    /// * `Ok(Address)` - The market's creator
+    /// * `Err(Error::MarketNotFound)` - Market does not exist
+    pub fn get_market_creator(env: &Env, market_id: &Symbol) -> Result<Address, Error> {
+        Ok(Self::get_market_from_storage(env, market_id)?.creator)
+    }
+
+    /// Query a market's primary oracle configuration.
+    ///
+    /// Only the `OracleConfig` is returned, so clients avoid transferring the
+    /// full market (votes, stakes, claims) to read it. The market is still a
+    /// single storage entry, so the contract reads it whole.
+    ///
+    /// # Returns
+    ///
+    /// * `Some(OracleConfig)` - The market's oracle configuration
+    /// * `None` - Market does not exist
+    pub fn get_oracle_config(env: &Env, market_id: &Symbol) -> Option<OracleConfig> {
+        Self::get_market_from_storage(env, market_id)
+            .ok()
+            .map(|market| market.oracle_config)
+    }
+
+    /// Pre-flight check: would `create_market` by `creator` pass its creation gates now?
+    ///
+    /// Covers the creation rate limits (cooldown), creator suspension and the
+    /// contract-wide and per-creator active-market caps, in the order
+    /// `create_market` applies them. Parameter validation is not covered.
+    ///
+    /// # Returns
+    ///
+    /// * `(true, None)` - Creation is currently allowed
+    /// * `(false, Some(error))` - The first gate that would reject it, e.g.
+    ///   `RateLimitExceeded`, `CreatorSuspended`, `MarketCapReached`
+    ///   or `CreatorMarketCapReached`
+    pub fn can_create_market(env: &Env, creator: &Address) -> (bool, Option<Error>) {
+        let limiter = crate::rate_limiter::RateLimiter::new(env.clone());
+        let gate = limiter
+            .check_admin_events(creator)
+            .and_then(|_| limiter.check_market_creation(creator))
+            .map_err(Error::from)
+            .and_then(|_| {
+                crate::markets::CreatorSuspensionManager::require_not_suspended(env, creator)
+            })
+            .and_then(|_| crate::markets::MarketCreationCaps::require_capacity(env, creator));
+        match gate {
+            Ok(()) => (true, None),
+            Err(e) => (false, Some(e)),
+        }
+    }
+
     // ===== CONTRACT STATE QUERIES =====
 
     /// Query global contract state and statistics.
@@ -827,7 +1482,23 @@ impl QueryManager {
             .ok_or(Error::MarketNotFound)
     }
 
-    /// Calculate payout for a user based on stake and market outcome.
+    /// Amount still owed to participants of a single market.
+    ///
+    /// See [`query_outstanding_liability`] for the per-state rules.
+    pub(crate) fn market_outstanding_liability(market: &Market) -> i128 {
+        match market.state {
+            MarketState::Resolved | MarketState::Closed => {
+                let mut paid = 0i128;
+                for (_, info) in market.claimed.iter() {
+                    paid = paid.saturating_add(info.payout_amount);
+                }
+                (market.total_staked - paid).max(0)
+            }
+            _ => market.total_staked,
+        }
+    }
+
+    /// Calculate payout for a user based on stake and market outcome.
     ///
     /// Computes the user's payout considering:
     /// - User's stake proportion
@@ -1221,9 +1892,39 @@ impl QueryManager {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::oracles::ComparisonOp;
     use soroban_sdk::testutils::Address as _;
     use soroban_sdk::Env;
 
+    /// Builds an `Active` market over `outcomes` ending at `end_time`; tests set
+    /// the fields they exercise on the result.
+    fn test_market(env: &Env, admin: &Address, outcomes: &[&str], end_time: u64) -> Market {
+        let mut market_outcomes = Vec::new(env);
+        for outcome in outcomes {
+            market_outcomes.push_back(String::from_str(env, outcome));
+        }
+        Market::new(
+            env,
+            admin.clone(),
+            String::from_str(env, "Test"),
+            market_outcomes,
+            end_time,
+            crate::types::OracleConfig::new(
+                crate::types::OracleProvider::reflector(),
+                Address::from_str(
+                    env,
+                    "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF",
+                ),
+                String::from_str(env, "TEST"),
+                100,
+                ComparisonOp::Gt,
+            ),
+            None,
+            86400,
+            MarketState::Active,
+        )
+    }
+
     #[test]
     fn test_market_status_conversion() {
         let status = MarketStatus::from_market_state(MarketState::Active);
@@ -1237,26 +1938,7 @@ mod tests {
     fn test_payout_calculation_zero_stake() {
         let env = Env::default();
         let admin = Address::generate(&env);
-        let market = Market::new(
-            &env,
-            admin,
-            String::from_str(&env, "Test"),
-            vec![&env, String::from_str(&env, "yes")],
-            env.ledger().timestamp() + 1000,
-            crate::types::OracleConfig::new(
-                crate::types::OracleProvider::reflector(),
-                Address::from_str(
-                    &env,
-                    "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF",
-                ),
-                String::from_str(&env, "TEST"),
-                100,
-                String::from_str(&env, "gt"),
-            ),
-            None,
-            86400,
-            MarketState::Active,
-        );
+        let market = test_market(&env, &admin, &["yes"], env.ledger().timestamp() + 1000);
 
         let payout = QueryManager::calculate_payout(&env, &market, 0);
         assert!(payout.is_ok());
@@ -1267,29 +1949,11 @@ mod tests {
     fn test_implied_probabilities_equal_stakes() {
         let env = Env::default();
         let admin = Address::generate(&env);
-        let mut market = Market::new(
+        let mut market = test_market(
             &env,
-            admin,
-            String::from_str(&env, "Test"),
-            vec![
-                &env,
-                String::from_str(&env, "yes"),
-                String::from_str(&env, "no"),
-            ],
+            &admin,
+            &["yes", "no"],
             env.ledger().timestamp() + 1000,
-            crate::types::OracleConfig::new(
-                crate::types::OracleProvider::reflector(),
-                Address::from_str(
-                    &env,
-                    "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF",
-                ),
-                String::from_str(&env, "TEST"),
-                100,
-                String::from_str(&env, "gt"),
-            ),
-            None,
-            86400,
-            MarketState::Active,
         );
 
         // Set total staked and outcome pools
@@ -1302,23 +1966,344 @@ mod tests {
     }
 
     #[test]
-    fn test_outcome_pool_calculation() {
+    fn test_market_outstanding_liability_subtracts_claimed_payouts() {
         let env = Env::default();
         let admin = Address::generate(&env);
-        let user1 = Address::generate(&env);
-        let user2 = Address::generate(&env);
-
-        let mut market = Market::new(
+        let winner = Address::generate(&env);
+        let mut market = test_market(
             &env,
-            admin,
-            String::from_str(&env, "Test"),
-            vec![
-                &env,
-                String::from_str(&env, "yes"),
-                String::from_str(&env, "no"),
-            ],
+            &admin,
+            &["yes", "no"],
             env.ledger().timestamp() + 1000,
-            crate::types::OracleConfig::new(
+        );
+        market.total_staked = 1_000;
+        assert_eq!(QueryManager::market_outstanding_liability(&market), 1_000);
+
+        market.state = MarketState::Resolved;
+        market
+            .claimed
+            .set(winner, crate::types::ClaimInfo::new(&env, 400));
+        assert_eq!(QueryManager::market_outstanding_liability(&market), 600);
+
+        // Claims stay open once fees are collected
+        market.state = MarketState::Closed;
+        assert_eq!(QueryManager::market_outstanding_liability(&market), 600);
+
+        // Stakes are owed until refunded
+        market.state = MarketState::Cancelled;
+        market.claimed = soroban_sdk::Map::new(&env);
+        assert_eq!(QueryManager::market_outstanding_liability(&market), 1_000);
+
+        // 150 in the fund against 600 outstanding -> 25.00%
+        assert_eq!(InsuranceFund::coverage_ratio_bps(150, 600), 2_500);
+    }
+
+    #[test]
+    fn test_coverage_ratio_counts_closed_and_cancelled_markets() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let admin = Address::generate(&env);
+        let winner = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            let closed = Symbol::new(&env, "closed_mkt");
+            let cancelled = Symbol::new(&env, "cancel_mkt");
+            for (id, state) in [
+                (&closed, MarketState::Closed),
+                (&cancelled, MarketState::Cancelled),
+            ] {
+                let mut market = test_market(
+                    &env,
+                    &admin,
+                    &["yes", "no"],
+                    env.ledger().timestamp() + 1000,
+                );
+                market.state = state;
+                market.total_staked = 1_000;
+                env.storage().persistent().set(id, &market);
+            }
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "market_index"), &vec![&env, closed.clone()]);
+            InsuranceFund::credit(&env, 300).unwrap();
+
+            // Closed with 400 of 1_000 claimed: 600 outstanding -> 50.00%
+            let mut market: Market = env.storage().persistent().get(&closed).unwrap();
+            market
+                .claimed
+                .set(winner.clone(), crate::types::ClaimInfo::new(&env, 400));
+            env.storage().persistent().set(&closed, &market);
+            assert_eq!(QueryManager::query_outstanding_liability(&env), Ok(600));
+            assert_eq!(QueryManager::get_insurance_coverage_ratio(&env), Ok(5_000));
+
+            // Cancelled with nothing refunded yet: 1_000 more outstanding -> 18.75%
+            env.storage().persistent().set(
+                &Symbol::new(&env, "market_index"),
+                &vec![&env, closed.clone(), cancelled.clone()],
+            );
+            assert_eq!(QueryManager::query_outstanding_liability(&env), Ok(1_600));
+            assert_eq!(QueryManager::get_insurance_coverage_ratio(&env), Ok(1_875));
+        });
+    }
+
+    #[test]
+    fn test_average_resolution_latency() {
+        use soroban_sdk::testutils::Ledger;
+
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &None, &None);
+        assert_eq!(client.get_average_resolution_latency(), 0);
+
+        // All markets end at t = 1_000 and are resolved 60s, 120s and 300s later.
+        env.ledger().with_mut(|li| li.timestamp = 0);
+        let ids = [
+            Symbol::new(&env, "lat_a"),
+            Symbol::new(&env, "lat_b"),
+            Symbol::new(&env, "lat_c"),
+        ];
+        env.as_contract(&contract_id, || {
+            for id in ids.iter() {
+                let market = test_market(&env, &admin, &["yes", "no"], 1_000);
+                env.storage().persistent().set(id, &market);
+            }
+        });
+
+        for (id, resolved_at) in ids.iter().zip([1_060u64, 1_120, 1_300]) {
+            env.ledger().with_mut(|li| li.timestamp = resolved_at);
+            client.resolve_market_manual(&admin, id, &String::from_str(&env, "yes"), &None);
+            assert_eq!(client.get_market(id).unwrap().state, MarketState::Resolved);
+        }
+
+        // (60 + 120 + 300) / 3
+        assert_eq!(client.get_average_resolution_latency(), 160);
+    }
+
+    #[test]
+    fn test_top_creators_ranked_by_resolved_volume() {
+        use soroban_sdk::testutils::Ledger;
+
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let admin = Address::generate(&env);
+        let big = Address::generate(&env);
+        let steady = Address::generate(&env);
+        let small = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "Admin"), &admin);
+            assert_eq!(QueryManager::get_top_creators(&env, 10).len(), 0);
+
+            env.ledger().with_mut(|li| li.timestamp = 0);
+            // `steady` resolves two markets (100 + 300), `big` one of 500, `small` one of 50
+            let markets = [
+                (Symbol::new(&env, "crt_a"), steady.clone(), 100i128),
+                (Symbol::new(&env, "crt_b"), big.clone(), 500),
+                (Symbol::new(&env, "crt_c"), small.clone(), 50),
+                (Symbol::new(&env, "crt_d"), steady.clone(), 300),
+            ];
+            for (id, creator, volume) in markets.iter() {
+                let mut market = test_market(&env, creator, &["yes", "no"], 1_000);
+                market.total_staked = *volume;
+                env.storage().persistent().set(id, &market);
+            }
+
+            env.ledger().with_mut(|li| li.timestamp = 2_000);
+            for (id, _, _) in markets.iter() {
+                crate::resolution::MarketResolutionManager::finalize_market(
+                    &env,
+                    &admin,
+                    id,
+                    &String::from_str(&env, "yes"),
+                )
+                .unwrap();
+            }
+
+            assert_eq!(
+                QueryManager::get_top_creators(&env, 10),
+                vec![
+                    &env,
+                    (big.clone(), 500),
+                    (steady.clone(), 400),
+                    (small.clone(), 50)
+                ]
+            );
+            // `limit` truncates from the bottom of the ranking
+            assert_eq!(
+                QueryManager::get_top_creators(&env, 2),
+                vec![&env, (big.clone(), 500), (steady.clone(), 400)]
+            );
+            assert_eq!(QueryManager::get_top_creators(&env, 0).len(), 0);
+        });
+    }
+
+    #[test]
+    fn test_creator_leaderboard_is_capped() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+
+        env.as_contract(&contract_id, || {
+            let cap = crate::config::MAX_TOP_CREATORS;
+            for i in 0..(cap + 5) {
+                let creator = Address::generate(&env);
+                let volume = (i as i128 + 1) * 10;
+                crate::resolution::CreatorLeaderboard::record(&env, &creator, volume);
+            }
+
+            let top = QueryManager::get_top_creators(&env, u32::MAX);
+            assert_eq!(top.len(), cap);
+            // Highest volumes survive, in descending order
+            assert_eq!(top.get(0).unwrap().1, (cap as i128 + 5) * 10);
+            assert_eq!(top.get(cap - 1).unwrap().1, 60);
+        });
+    }
+
+    #[test]
+    fn test_resolution_distribution_counts_winning_labels() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let admin = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "Admin"), &admin);
+            assert_eq!(QueryManager::get_resolution_distribution(&env).len(), 0);
+
+            env.ledger().with_mut(|li| li.timestamp = 0);
+            let resolutions = [
+                (Symbol::new(&env, "dist_a"), "yes"),
+                (Symbol::new(&env, "dist_b"), "no"),
+                (Symbol::new(&env, "dist_c"), "yes"),
+                (Symbol::new(&env, "dist_d"), "yes"),
+            ];
+            for (id, _) in resolutions.iter() {
+                let market = test_market(&env, &admin, &["yes", "no"], 1_000);
+                env.storage().persistent().set(id, &market);
+            }
+
+            env.ledger().with_mut(|li| li.timestamp = 2_000);
+            for (id, outcome) in resolutions.iter() {
+                crate::resolution::MarketResolutionManager::finalize_market(
+                    &env,
+                    &admin,
+                    id,
+                    &String::from_str(&env, outcome),
+                )
+                .unwrap();
+            }
+
+            let distribution = QueryManager::get_resolution_distribution(&env);
+            assert_eq!(distribution.len(), 2);
+            assert_eq!(distribution.get(String::from_str(&env, "yes")), Some(3));
+            assert_eq!(distribution.get(String::from_str(&env, "no")), Some(1));
+        });
+    }
+
+    #[test]
+    fn test_resolution_distribution_is_capped() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+
+        env.as_contract(&contract_id, || {
+            let cap = crate::config::MAX_RESOLUTION_LABELS;
+            let mut market = test_market(&env, &Address::generate(&env), &["label_0"], 1_000);
+            market.state = MarketState::Resolved;
+            let first = String::from_str(&env, "label_0");
+            for i in 0..(cap + 5) {
+                let label = String::from_str(&env, &alloc::format!("label_{}", i));
+                market.winning_outcomes = Some(vec![&env, label]);
+                crate::resolution::ResolutionDistribution::record(&env, &market);
+            }
+            // Labels past the cap are dropped; known labels keep counting
+            market.winning_outcomes = Some(vec![&env, first.clone()]);
+            crate::resolution::ResolutionDistribution::record(&env, &market);
+
+            let distribution = QueryManager::get_resolution_distribution(&env);
+            assert_eq!(distribution.len(), cap);
+            assert_eq!(distribution.get(first), Some(2));
+            assert!(distribution
+                .get(String::from_str(&env, &alloc::format!("label_{}", cap)))
+                .is_none());
+        });
+    }
+
+    #[test]
+    fn test_claim_stats_advance_as_winners_claim() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let admin = Address::generate(&env);
+        let market_id = Symbol::new(&env, "clm_mkt");
+        let winners = [
+            Address::generate(&env),
+            Address::generate(&env),
+            Address::generate(&env),
+        ];
+        let loser = Address::generate(&env);
+
+        env.as_contract(&contract_id, || {
+            let mut market = test_market(
+                &env,
+                &admin,
+                &["yes", "no"],
+                env.ledger().timestamp() + 1000,
+            );
+            for winner in winners.iter() {
+                market
+                    .votes
+                    .set(winner.clone(), String::from_str(&env, "yes"));
+            }
+            market.votes.set(loser, String::from_str(&env, "no"));
+            env.storage().persistent().set(&market_id, &market);
+
+            // Unresolved: nobody is a winner yet
+            assert_eq!(
+                QueryManager::get_claim_stats(&env, &market_id).unwrap(),
+                (0, 0, 0)
+            );
+
+            market.winning_outcomes = Some(vec![&env, String::from_str(&env, "yes")]);
+            market.state = MarketState::Resolved;
+            env.storage().persistent().set(&market_id, &market);
+            assert_eq!(
+                QueryManager::get_claim_stats(&env, &market_id).unwrap(),
+                (0, 3, 0)
+            );
+
+            crate::markets::MarketStateManager::record_winner_claim(&env, &market_id, 400);
+            assert_eq!(
+                QueryManager::get_claim_stats(&env, &market_id).unwrap(),
+                (1, 3, 400)
+            );
+
+            crate::markets::MarketStateManager::record_winner_claim(&env, &market_id, 250);
+            crate::markets::MarketStateManager::record_winner_claim(&env, &market_id, 350);
+            assert_eq!(
+                QueryManager::get_claim_stats(&env, &market_id).unwrap(),
+                (3, 3, 1_000)
+            );
+        });
+    }
+
+    #[test]
+    fn test_get_market_flags_reflects_configured_flags() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let admin = Address::generate(&env);
+        let flagged = Symbol::new(&env, "flagged");
+        let sibling = Symbol::new(&env, "sibling");
+        let plain = Symbol::new(&env, "plain");
+
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "Admin"), &admin);
+            let oracle = crate::types::OracleConfig::new(
                 crate::types::OracleProvider::reflector(),
                 Address::from_str(
                     &env,
@@ -1326,11 +2311,362 @@ mod tests {
                 ),
                 String::from_str(&env, "TEST"),
                 100,
-                String::from_str(&env, "gt"),
-            ),
-            None,
-            86400,
-            MarketState::Active,
+                ComparisonOp::Gt,
+            );
+            for id in [&flagged, &sibling, &plain] {
+                let market = Market::new(
+                    &env,
+                    admin.clone(),
+                    String::from_str(&env, "Test"),
+                    vec![
+                        &env,
+                        String::from_str(&env, "yes"),
+                        String::from_str(&env, "no"),
+                    ],
+                    env.ledger().timestamp() + 1000,
+                    oracle.clone(),
+                    Some(oracle.clone()),
+                    86400,
+                    MarketState::Active,
+                );
+                env.storage().persistent().set(id, &market);
+            }
+
+            let mut market: Market = env.storage().persistent().get(&flagged).unwrap();
+            market.fee_collected = true;
+            market.winnings_swept = true;
+            market.state = MarketState::Ended;
+            env.storage().persistent().set(&flagged, &market);
+
+            crate::resolution::MarketGroupManager::create_group(
+                &env,
+                &admin,
+                &Symbol::new(&env, "grp"),
+                &vec![&env, flagged.clone(), sibling.clone()],
+            )
+            .unwrap();
+            env.storage().persistent().set(
+                &crate::oracles::OracleIntegrationKey::VerificationStatus(flagged.clone()),
+                &true,
+            );
+
+            let flags = QueryManager::get_market_flags(&env, &flagged).unwrap();
+            assert_eq!(
+                flags,
+                MarketFlags {
+                    market_id: flagged.clone(),
+                    state: MarketState::Ended,
+                    has_fallback: true,
+                    fee_collected: true,
+                    winnings_swept: true,
+                    grouped: true,
+                    result_verified: true,
+                    archived: false,
+                }
+            );
+
+            let flags = QueryManager::get_market_flags(&env, &plain).unwrap();
+            assert_eq!(flags.state, MarketState::Active);
+            assert!(flags.has_fallback);
+            assert!(!flags.fee_collected && !flags.winnings_swept);
+            assert!(!flags.grouped && !flags.result_verified && !flags.archived);
+
+            assert_eq!(
+                QueryManager::get_market_flags(&env, &Symbol::new(&env, "missing")).err(),
+                Some(Error::MarketNotFound)
+            );
+        });
+    }
+
+    #[test]
+    fn test_min_stake_to_lead_makes_outcome_strict_leader() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let admin = Address::generate(&env);
+        let market_id = Symbol::new(&env, "lead_mkt");
+        let outcomes = vec![
+            &env,
+            String::from_str(&env, "a"),
+            String::from_str(&env, "b"),
+            String::from_str(&env, "c"),
+        ];
+
+        env.as_contract(&contract_id, || {
+            let mut market = test_market(
+                &env,
+                &admin,
+                &["a", "b", "c"],
+                env.ledger().timestamp() + 1000,
+            );
+
+            // Empty market: every outcome is tied at 0, first one wins the tie
+            env.storage().persistent().set(&market_id, &market);
+            assert_eq!(
+                QueryManager::min_stake_to_lead(&env, &market_id).unwrap(),
+                (String::from_str(&env, "a"), 1)
+            );
+
+            // b=300 leads a=120 and c=250: already the strict leader
+            for (outcome, stake) in [("a", 120i128), ("b", 300), ("c", 250)] {
+                let voter = Address::generate(&env);
+                market.votes.set(voter.clone(), String::from_str(&env, outcome));
+                market.stakes.set(voter, stake);
+            }
+            env.storage().persistent().set(&market_id, &market);
+            assert_eq!(
+                QueryManager::min_stake_to_lead(&env, &market_id).unwrap(),
+                (String::from_str(&env, "b"), 0)
+            );
+
+            // Lift c to tie b at 300: b and c both need 1, b comes first
+            let voter = Address::generate(&env);
+            market.votes.set(voter.clone(), String::from_str(&env, "c"));
+            market.stakes.set(voter, 50);
+            env.storage().persistent().set(&market_id, &market);
+            let (outcome, stake) = QueryManager::min_stake_to_lead(&env, &market_id).unwrap();
+            assert_eq!((outcome.clone(), stake), (String::from_str(&env, "b"), 1));
+
+            // Applying the stake makes the outcome the strict leader, one less does not
+            let pool = |o: &str| {
+                QueryManager::calculate_outcome_pool(&env, &market, &String::from_str(&env, o))
+                    .unwrap()
+            };
+            let boosted = pool("b") + stake;
+            assert!(boosted > pool("a") && boosted > pool("c"));
+            assert!(boosted - 1 <= pool("c"));
+
+            // Every other outcome needs at least as much
+            for other in outcomes.iter() {
+                let own = QueryManager::calculate_outcome_pool(&env, &market, &other).unwrap();
+                let max_other = outcomes
+                    .iter()
+                    .filter(|o| *o != other)
+                    .map(|o| QueryManager::calculate_outcome_pool(&env, &market, &o).unwrap())
+                    .max()
+                    .unwrap();
+                assert!((max_other - own + 1).max(0) >= stake);
+            }
+        });
+    }
+
+    #[test]
+    fn test_min_stake_to_lead_through_contract() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        let market_id = Symbol::new(&env, "lead_api");
+        let add_vote = |outcome: &str, stake: i128| {
+            env.as_contract(&contract_id, || {
+                let mut market: Market = env.storage().persistent().get(&market_id).unwrap();
+                let voter = Address::generate(&env);
+                market.votes.set(voter.clone(), String::from_str(&env, outcome));
+                market.stakes.set(voter, stake);
+                env.storage().persistent().set(&market_id, &market);
+            });
+        };
+
+        env.as_contract(&contract_id, || {
+            let market = test_market(
+                &env,
+                &admin,
+                &["yes", "no"],
+                env.ledger().timestamp() + 1000,
+            );
+            env.storage().persistent().set(&market_id, &market);
+        });
+        add_vote("yes", 250);
+        add_vote("no", 250);
+
+        // Tied at 250: one more on "yes" is the cheapest strict lead
+        let (outcome, stake) = client.min_stake_to_lead(&market_id);
+        assert_eq!((outcome.clone(), stake), (String::from_str(&env, "yes"), 1));
+
+        // Staking exactly that makes it the sole leader
+        add_vote("yes", stake);
+        assert_eq!(client.min_stake_to_lead(&market_id), (outcome, 0));
+
+        assert_eq!(
+            client.try_min_stake_to_lead(&Symbol::new(&env, "missing")),
+            Err(Ok(Error::MarketNotFound))
+        );
+    }
+
+    #[test]
+    fn test_get_user_claim_history_lists_claims_in_order() {
+        use soroban_sdk::testutils::Ledger;
+
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        let user = Address::generate(&env);
+        let loser = Address::generate(&env);
+        client.initialize(&admin, &None, &None);
+
+        env.ledger().set_timestamp(10_000);
+        let first = Symbol::new(&env, "first");
+        let second = Symbol::new(&env, "second");
+        env.as_contract(&contract_id, || {
+            for (id, stake) in [(&first, 100i128), (&second, 300i128)] {
+                let mut market = test_market(
+                    &env,
+                    &admin,
+                    &["yes", "no"],
+                    env.ledger().timestamp() + 1000,
+                );
+                market.dispute_window_seconds = 0;
+                market.votes.set(user.clone(), String::from_str(&env, "yes"));
+                market.stakes.set(user.clone(), stake);
+                market.votes.set(loser.clone(), String::from_str(&env, "no"));
+                market.stakes.set(loser.clone(), 100);
+                market.total_staked = stake + 100;
+                market.winning_outcomes = Some(vec![&env, String::from_str(&env, "yes")]);
+                market.state = MarketState::Resolved;
+                env.storage().persistent().set(id, &market);
+            }
+        });
+
+        assert_eq!(client.get_user_claim_history(&user, &0, &10).len(), 0);
+
+        env.ledger().set_timestamp(20_000);
+        client.claim_winnings(&user, &first);
+        env.ledger().set_timestamp(30_000);
+        client.claim_winnings(&user, &second);
+
+        let history = client.get_user_claim_history(&user, &0, &10);
+        assert_eq!(history.len(), 2);
+        let a = history.get(0).unwrap();
+        let b = history.get(1).unwrap();
+        assert_eq!((a.market_id.clone(), a.timestamp), (first.clone(), 20_000));
+        assert_eq!((b.market_id.clone(), b.timestamp), (second.clone(), 30_000));
+        assert!(a.payout > 0 && b.payout > a.payout);
+
+        // Entries match the claim records on each market
+        for entry in history.iter() {
+            let market = client.get_market(&entry.market_id).unwrap();
+            assert_eq!(
+                market.claimed.get(user.clone()).unwrap().get_payout(),
+                entry.payout
+            );
+        }
+
+        // Paging
+        assert_eq!(client.get_user_claim_history(&user, &1, &10), vec![&env, b]);
+        assert_eq!(client.get_user_claim_history(&user, &0, &1), vec![&env, a]);
+        assert_eq!(client.get_user_claim_history(&user, &2, &10).len(), 0);
+
+        // Losers and non-claimants have no history
+        assert_eq!(client.get_user_claim_history(&loser, &0, &10).len(), 0);
+    }
+
+    #[test]
+    fn test_get_outcome_momentum_sums_flows_in_window() {
+        use crate::voting::{OutcomeMomentum, MAX_MOMENTUM_BUCKETS, MOMENTUM_BUCKET_SECONDS};
+        use soroban_sdk::testutils::Ledger;
+
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let admin = Address::generate(&env);
+        let market_id = Symbol::new(&env, "momentum");
+        let yes = String::from_str(&env, "yes");
+        let no = String::from_str(&env, "no");
+        let maybe = String::from_str(&env, "maybe");
+        let hour = MOMENTUM_BUCKET_SECONDS;
+
+        env.as_contract(&contract_id, || {
+            let market = test_market(
+                &env,
+                &admin,
+                &["yes", "no", "maybe"],
+                env.ledger().timestamp() + 100 * hour,
+            );
+            env.storage().persistent().set(&market_id, &market);
+
+            // Hour 0: early "no" money
+            env.ledger().set_timestamp(10 * hour);
+            OutcomeMomentum::record_flow(&env, &market_id, &no, 500);
+            // Hour 2: "yes" gains, one "no" stake is withdrawn
+            env.ledger().set_timestamp(12 * hour + 10);
+            OutcomeMomentum::record_flow(&env, &market_id, &yes, 300);
+            OutcomeMomentum::record_flow(&env, &market_id, &no, 200);
+            OutcomeMomentum::record_flow(&env, &market_id, &no, -500);
+            // Hour 3: more "yes"
+            env.ledger().set_timestamp(13 * hour + 10);
+            OutcomeMomentum::record_flow(&env, &market_id, &yes, 150);
+
+            // Window inside the current bucket
+            let m = QueryManager::get_outcome_momentum(&env, &market_id, 10).unwrap();
+            assert_eq!(m.get(yes.clone()), Some(150));
+            assert_eq!(m.get(no.clone()), Some(0));
+            assert_eq!(m.get(maybe.clone()), Some(0));
+
+            // Last two hours: "yes" gaining, "no" net outflow
+            let m = QueryManager::get_outcome_momentum(&env, &market_id, 2 * hour).unwrap();
+            assert_eq!(m.get(yes.clone()), Some(450));
+            assert_eq!(m.get(no.clone()), Some(-300));
+
+            // Whole history
+            let m = QueryManager::get_outcome_momentum(&env, &market_id, 10 * hour).unwrap();
+            assert_eq!(m.get(yes.clone()), Some(450));
+            assert_eq!(m.get(no.clone()), Some(200));
+
+            // Bucket storage stays bounded
+            for i in 0..(MAX_MOMENTUM_BUCKETS as u64 + 10) {
+                env.ledger().set_timestamp((20 + i) * hour);
+                OutcomeMomentum::record_flow(&env, &market_id, &maybe, 1);
+            }
+            assert_eq!(
+                OutcomeMomentum::get_buckets(&env, &market_id).len(),
+                MAX_MOMENTUM_BUCKETS
+            );
+            let m = QueryManager::get_outcome_momentum(&env, &market_id, 1_000 * hour).unwrap();
+            assert_eq!(m.get(maybe.clone()), Some(MAX_MOMENTUM_BUCKETS as i128));
+            assert_eq!(m.get(yes.clone()), Some(0));
+
+            assert_eq!(
+                QueryManager::get_outcome_momentum(&env, &Symbol::new(&env, "missing"), hour).err(),
+                Some(Error::MarketNotFound)
+            );
+        });
+    }
+
+    #[test]
+    fn test_claim_history_is_bounded() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let user = Address::generate(&env);
+        let cap = crate::config::MAX_CLAIM_HISTORY_PER_USER;
+
+        env.as_contract(&contract_id, || {
+            let market_id = Symbol::new(&env, "mkt");
+            for payout in 0..(cap as i128 + 5) {
+                crate::markets::MarketStateManager::record_user_claim(
+                    &env, &user, &market_id, payout,
+                );
+            }
+
+            let history = crate::markets::MarketStateManager::get_claim_history(&env, &user);
+            assert_eq!(history.len(), cap);
+            // Oldest five dropped
+            assert_eq!(history.get(0).unwrap().payout, 5);
+            assert_eq!(history.last().unwrap().payout, cap as i128 + 4);
+        });
+    }
+
+    #[test]
+    fn test_outcome_pool_calculation() {
+        let env = Env::default();
+        let admin = Address::generate(&env);
+        let user1 = Address::generate(&env);
+        let user2 = Address::generate(&env);
+
+        let mut market = test_market(
+            &env,
+            &admin,
+            &["yes", "no"],
+            env.ledger().timestamp() + 1000,
         );
 
         // Add votes
@@ -1345,4 +2681,776 @@ mod tests {
         assert!(pool.is_ok());
         assert_eq!(pool.unwrap(), 125);
     }
+
+    #[test]
+    fn test_required_allowance_is_enough_to_vote() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        let user = Address::generate(&env);
+        client.initialize(&admin, &None, &None);
+
+        let token_admin = Address::generate(&env);
+        let token_id = env
+            .register_stellar_asset_contract_v2(token_admin)
+            .address();
+        let stake = 1_000_000i128;
+        soroban_sdk::token::StellarAssetClient::new(&env, &token_id).mint(&user, &stake);
+
+        let market_id = Symbol::new(&env, "allowance");
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "TokenID"), &token_id);
+            let market = test_market(
+                &env,
+                &admin,
+                &["yes", "no"],
+                env.ledger().timestamp() + 1000,
+            );
+            env.storage().persistent().set(&market_id, &market);
+        });
+
+        assert_eq!(
+            client.try_get_required_allowance(&user, &market_id, &0),
+            Err(Ok(Error::InvalidInput))
+        );
+        let allowance = client.get_required_allowance(&user, &market_id, &stake);
+        assert!(allowance >= 0 && allowance <= stake);
+
+        // Grant exactly the reported allowance, then vote the full stake
+        let token = soroban_sdk::token::Client::new(&env, &token_id);
+        token.approve(&user, &contract_id, &allowance, &1_000);
+        client.vote(&user, &market_id, &String::from_str(&env, "yes"), &stake);
+
+        assert_eq!(token.balance(&user), 0);
+        assert_eq!(token.balance(&contract_id), stake);
+        let market = client.get_market(&market_id).unwrap();
+        assert_eq!(market.stakes.get(user.clone()), Some(stake));
+    }
+
+    #[test]
+    fn test_market_creator_survives_admin_transfer() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        let new_admin = Address::generate(&env);
+        client.initialize(&admin, &None, &None);
+
+        let market_id = Symbol::new(&env, "creator");
+        env.as_contract(&contract_id, || {
+            let market = test_market(
+                &env,
+                &admin,
+                &["yes", "no"],
+                env.ledger().timestamp() + 1000,
+            );
+            env.storage().persistent().set(&market_id, &market);
+        });
+        assert_eq!(client.get_market_creator(&market_id), admin);
+
+        env.as_contract(&contract_id, || {
+            crate::admin::ContractPauseManager::transfer_admin(&env, &admin, &new_admin).unwrap();
+            // Reassign the market's admin as well; the creator must not follow
+            let mut market =
+                crate::markets::MarketStateManager::get_market(&env, &market_id).unwrap();
+            market.admin = new_admin.clone();
+            crate::markets::MarketStateManager::update_market(&env, &market_id, &market);
+        });
+
+        assert_eq!(client.get_market(&market_id).unwrap().admin, new_admin);
+        assert_eq!(client.get_market_creator(&market_id), admin);
+        assert_eq!(
+            client.try_get_market_creator(&Symbol::new(&env, "missing")),
+            Err(Ok(Error::MarketNotFound))
+        );
+    }
+
+    fn creation_gate_setup() -> (Env, Address, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let admin = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "Admin"), &admin);
+            crate::rate_limiter::RateLimiter::init_for_tests(&env, &admin);
+        });
+        (env, contract_id, admin)
+    }
+
+    #[test]
+    fn test_can_create_market_when_clear() {
+        let (env, contract_id, admin) = creation_gate_setup();
+        env.as_contract(&contract_id, || {
+            assert_eq!(QueryManager::can_create_market(&env, &admin), (true, None));
+        });
+    }
+
+    #[test]
+    fn test_can_create_market_blocked_by_cooldown() {
+        let (env, contract_id, admin) = creation_gate_setup();
+        env.as_contract(&contract_id, || {
+            let limiter = crate::rate_limiter::RateLimiter::new(env.clone());
+            limiter
+                .set_market_creation_limit(crate::rate_limiter::MarketCreationLimit {
+                    max_markets: 1,
+                    window_seconds: 3600,
+                })
+                .unwrap();
+            limiter.rate_limit_market_creation(admin.clone()).unwrap();
+
+            assert_eq!(
+                QueryManager::can_create_market(&env, &admin),
+                (false, Some(Error::RateLimitExceeded))
+            );
+            // The pre-flight itself must not consume the window
+            assert_eq!(
+                QueryManager::can_create_market(&env, &Address::generate(&env)),
+                (true, None)
+            );
+        });
+    }
+
+    #[test]
+    fn test_can_create_market_blocked_by_suspension() {
+        let (env, contract_id, admin) = creation_gate_setup();
+        env.as_contract(&contract_id, || {
+            crate::markets::CreatorSuspensionManager::set_threshold(&env, &admin, 0).unwrap();
+            assert!(crate::markets::CreatorSuspensionManager::record_overturn(
+                &env, &admin
+            ));
+
+            assert_eq!(
+                QueryManager::can_create_market(&env, &admin),
+                (false, Some(Error::CreatorSuspended))
+            );
+        });
+    }
+
+    #[test]
+    fn test_can_create_market_blocked_by_active_market_cap() {
+        let (env, contract_id, admin) = creation_gate_setup();
+        env.as_contract(&contract_id, || {
+            crate::markets::MarketCreationCaps::set_caps(&env, &admin, 1, 0).unwrap();
+            assert_eq!(QueryManager::can_create_market(&env, &admin), (true, None));
+
+            let creator = Address::generate(&env);
+            crate::markets::MarketCreationCaps::record_opened(&env, &creator);
+            assert_eq!(
+                QueryManager::can_create_market(&env, &Address::generate(&env)),
+                (false, Some(Error::MarketCapReached))
+            );
+
+            crate::markets::MarketCreationCaps::record_closed(&env, &creator);
+            assert_eq!(QueryManager::can_create_market(&env, &admin), (true, None));
+        });
+    }
+
+    #[test]
+    fn test_can_create_market_blocked_by_creator_cap() {
+        let (env, contract_id, admin) = creation_gate_setup();
+        let other = Address::generate(&env);
+        env.as_contract(&contract_id, || {
+            crate::markets::MarketCreationCaps::set_caps(&env, &admin, 0, 1).unwrap();
+            crate::storage::CreatorLimitsManager::increment_active_events(&env, &admin);
+
+            assert_eq!(
+                QueryManager::can_create_market(&env, &admin),
+                (false, Some(Error::CreatorMarketCapReached))
+            );
+            assert_eq!(QueryManager::can_create_market(&env, &other), (true, None));
+        });
+    }
+
+    #[test]
+    fn test_market_summary_reports_every_field() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &None, &None);
+
+        let token_id = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        let token_admin = soroban_sdk::token::StellarAssetClient::new(&env, &token_id);
+        let voters = [
+            (Address::generate(&env), "yes", 100_0000000i128),
+            (Address::generate(&env), "yes", 200_0000000),
+            (Address::generate(&env), "no", 300_0000000),
+        ];
+        for (voter, _, stake) in voters.iter() {
+            token_admin.mint(voter, stake);
+        }
+
+        let market_id = Symbol::new(&env, "summary");
+        let end_time = env.ledger().timestamp() + 1000;
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "TokenID"), &token_id);
+            crate::rate_limiter::RateLimiter::init_for_tests(&env, &admin);
+            let mut market = test_market(&env, &admin, &["yes", "no"], end_time);
+            market.question = String::from_str(&env, "Will it rain?");
+            env.storage().persistent().set(&market_id, &market);
+        });
+        for (voter, outcome, stake) in voters.iter() {
+            client.vote(voter, &market_id, &String::from_str(&env, outcome), stake);
+        }
+
+        let mut outcome_totals = Map::new(&env);
+        outcome_totals.set(String::from_str(&env, "yes"), 300_0000000i128);
+        outcome_totals.set(String::from_str(&env, "no"), 300_0000000i128);
+        let open = client.get_market_summary(&market_id);
+        assert_eq!(
+            open,
+            MarketSummary {
+                market_id: market_id.clone(),
+                question: String::from_str(&env, "Will it rain?"),
+                outcomes: vec![
+                    &env,
+                    String::from_str(&env, "yes"),
+                    String::from_str(&env, "no"),
+                ],
+                outcome_totals,
+                total_staked: 600_0000000,
+                voter_count: 3,
+                state: MarketState::Active,
+                end_time,
+                winning_outcomes: None,
+                oracle_result: None,
+            }
+        );
+
+        // Once resolved, the summary carries the resolution record
+        env.as_contract(&contract_id, || {
+            let mut market: Market = env.storage().persistent().get(&market_id).unwrap();
+            market.state = MarketState::Resolved;
+            market.oracle_result = Some(String::from_str(&env, "yes"));
+            market.winning_outcomes = Some(vec![&env, String::from_str(&env, "yes")]);
+            env.storage().persistent().set(&market_id, &market);
+        });
+        let resolved = client.get_market_summary(&market_id);
+        assert_eq!(resolved.state, MarketState::Resolved);
+        assert_eq!(resolved.oracle_result, Some(String::from_str(&env, "yes")));
+        assert_eq!(
+            resolved.winning_outcomes,
+            Some(vec![&env, String::from_str(&env, "yes")])
+        );
+        assert_eq!(resolved.outcome_totals, open.outcome_totals);
+
+        assert_eq!(
+            client.try_get_market_summary(&Symbol::new(&env, "missing")),
+            Err(Ok(Error::MarketNotFound))
+        );
+    }
+
+    #[test]
+    fn test_simulated_payout_matches_actual_claim() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &None, &None);
+
+        let token_id = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        let token_admin = soroban_sdk::token::StellarAssetClient::new(&env, &token_id);
+        let user = Address::generate(&env);
+        let voters = [
+            (Address::generate(&env), "yes", 100_0000000i128),
+            (Address::generate(&env), "no", 300_0000000),
+        ];
+        for (voter, _, stake) in voters.iter() {
+            token_admin.mint(voter, stake);
+        }
+        let stake = 200_0000000i128;
+        token_admin.mint(&user, &stake);
+
+        let market_id = Symbol::new(&env, "sim_pay");
+        let end_time = env.ledger().timestamp() + 1000;
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "TokenID"), &token_id);
+            crate::rate_limiter::RateLimiter::init_for_tests(&env, &admin);
+            let mut market = test_market(&env, &admin, &["yes", "no"], end_time);
+            market.question = String::from_str(&env, "Will it rain?");
+            market.dispute_window_seconds = 0;
+            env.storage().persistent().set(&market_id, &market);
+        });
+        let yes = String::from_str(&env, "yes");
+        let no = String::from_str(&env, "no");
+        for (voter, outcome, amount) in voters.iter() {
+            client.vote(voter, &market_id, &String::from_str(&env, outcome), amount);
+        }
+
+        // Unresolved: "yes" is assumed to win; nothing is written
+        let simulated = client.simulate_payout(&market_id, &yes, &stake);
+        assert!(simulated > stake);
+        assert_eq!(client.get_market(&market_id).unwrap().total_staked, 400_0000000);
+        assert_eq!(client.simulate_payout(&market_id, &yes, &0), 0);
+
+        client.vote(&user, &market_id, &yes, &stake);
+        env.ledger().with_mut(|li| li.timestamp = end_time + 1);
+        client.resolve_market_manual(&admin, &market_id, &yes, &None);
+        client.claim_winnings(&user, &market_id);
+
+        let claimed = client
+            .get_market(&market_id)
+            .unwrap()
+            .claimed
+            .get(user.clone())
+            .unwrap();
+        assert_eq!(claimed.payout_amount, simulated);
+
+        // Resolved: the real winners apply
+        assert_eq!(client.simulate_payout(&market_id, &no, &stake), 0);
+        assert_eq!(
+            client.try_simulate_payout(&market_id, &String::from_str(&env, "maybe"), &stake),
+            Err(Ok(Error::InvalidOutcome))
+        );
+        assert_eq!(
+            client.try_simulate_payout(&market_id, &yes, &-1),
+            Err(Ok(Error::InvalidInput))
+        );
+    }
+    #[test]
+    fn test_reconcile_market_balances_through_votes_and_claims() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &None, &None);
+
+        let token_id = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        let token_admin = soroban_sdk::token::StellarAssetClient::new(&env, &token_id);
+        let voters = [
+            (Address::generate(&env), "yes", 100_0000000i128),
+            (Address::generate(&env), "yes", 250_0000000),
+            (Address::generate(&env), "no", 300_0000000),
+        ];
+        for (voter, _, stake) in voters.iter() {
+            token_admin.mint(voter, stake);
+        }
+
+        let market_id = Symbol::new(&env, "reconcile");
+        let end_time = env.ledger().timestamp() + 1000;
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "TokenID"), &token_id);
+            crate::rate_limiter::RateLimiter::init_for_tests(&env, &admin);
+            let mut market = test_market(&env, &admin, &["yes", "no"], end_time);
+            market.question = String::from_str(&env, "Will it rain?");
+            market.dispute_window_seconds = 0;
+            env.storage().persistent().set(&market_id, &market);
+        });
+
+        assert_eq!(client.reconcile_market(&market_id), (0, 0));
+        let mut held = 0i128;
+        for (voter, outcome, stake) in voters.iter() {
+            client.vote(voter, &market_id, &String::from_str(&env, outcome), stake);
+            held += stake;
+            assert_eq!(client.reconcile_market(&market_id), (held, held));
+        }
+
+        env.ledger().with_mut(|li| li.timestamp = end_time + 1);
+        client.resolve_market_manual(&admin, &market_id, &String::from_str(&env, "yes"), &None);
+        for (voter, _, _) in voters.iter() {
+            client.claim_winnings(voter, &market_id);
+            let (expected, stored) = client.reconcile_market(&market_id);
+            assert_eq!(expected, stored);
+        }
+        // Winners were paid out of the pool; only fees and rounding dust remain
+        let (remaining, _) = client.reconcile_market(&market_id);
+        assert!(remaining >= 0 && remaining < held);
+
+        // A drifted aggregate shows up as a discrepancy
+        env.as_contract(&contract_id, || {
+            let mut market: Market = env.storage().persistent().get(&market_id).unwrap();
+            market.total_staked += 1;
+            env.storage().persistent().set(&market_id, &market);
+        });
+        let (expected, stored) = client.reconcile_market(&market_id);
+        assert_eq!(expected, stored + 1);
+    }
+
+    #[test]
+    fn test_get_oracle_config_matches_creation_inputs() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &None, &None);
+
+        let oracle_config = crate::types::OracleConfig::new(
+            crate::types::OracleProvider::reflector(),
+            Address::generate(&env),
+            String::from_str(&env, "ETH/USD"),
+            3_000_00,
+            ComparisonOp::Lt,
+        );
+        let market_id = client.create_market(
+            &admin,
+            &String::from_str(&env, "Will ETH close below $3,000?"),
+            &vec![
+                &env,
+                String::from_str(&env, "yes"),
+                String::from_str(&env, "no"),
+            ],
+            &30,
+            &oracle_config,
+            &None,
+            &86400u64,
+            &None,
+            &None,
+            &None,
+        );
+
+        assert_eq!(client.get_oracle_config(&market_id), Some(oracle_config));
+        assert_eq!(client.get_oracle_config(&Symbol::new(&env, "missing")), None);
+    }
+
+    #[test]
+    fn test_get_leading_outcome_none_on_tie_or_empty_market() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let admin = Address::generate(&env);
+        let market_id = Symbol::new(&env, "leader_mkt");
+        let outcome = |o: &str| String::from_str(&env, o);
+
+        env.as_contract(&contract_id, || {
+            let market = test_market(
+                &env,
+                &admin,
+                &["a", "b", "c"],
+                env.ledger().timestamp() + 1000,
+            );
+            let stake_on = |label: &str, stake: i128| {
+                let mut stats = crate::bets::BetStorage::get_market_bet_stats(&env, &market_id);
+                let total = stats.outcome_totals.get(outcome(label)).unwrap_or(0);
+                stats.outcome_totals.set(outcome(label), total + stake);
+                crate::bets::BetStorage::store_market_bet_stats(&env, &market_id, &stats).unwrap();
+            };
+
+            // Empty market: no leader
+            env.storage().persistent().set(&market_id, &market);
+            assert_eq!(QueryManager::get_leading_outcome(&env, &market_id), Ok(None));
+
+            // Clear leader
+            stake_on("a", 120);
+            stake_on("b", 300);
+            stake_on("c", 250);
+            assert_eq!(
+                QueryManager::get_leading_outcome(&env, &market_id),
+                Ok(Some(outcome("b")))
+            );
+
+            // c catches up with b: tied at the top, no leader
+            stake_on("c", 50);
+            assert_eq!(QueryManager::get_leading_outcome(&env, &market_id), Ok(None));
+
+            // Breaking the tie restores a leader
+            stake_on("c", 1);
+            assert_eq!(
+                QueryManager::get_leading_outcome(&env, &market_id),
+                Ok(Some(outcome("c")))
+            );
+
+            assert_eq!(
+                QueryManager::get_leading_outcome(&env, &Symbol::new(&env, "missing")),
+                Err(Error::MarketNotFound)
+            );
+        });
+    }
+
+    #[test]
+    fn test_get_outcome_breakdown_pairs_totals_with_voter_counts() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let admin = Address::generate(&env);
+        let market_id = Symbol::new(&env, "breakdown");
+        let outcome = |o: &str| String::from_str(&env, o);
+
+        env.as_contract(&contract_id, || {
+            let mut market = test_market(
+                &env,
+                &admin,
+                &["c", "a", "b"],
+                env.ledger().timestamp() + 1000,
+            );
+            // One whale on "a", three small voters on "c", nobody on "b"
+            for (label, stake) in [("a", 900i128), ("c", 10), ("c", 20), ("c", 30)] {
+                let voter = Address::generate(&env);
+                market.votes.set(voter.clone(), outcome(label));
+                market.stakes.set(voter, stake);
+            }
+            env.storage().persistent().set(&market_id, &market);
+
+            assert_eq!(
+                QueryManager::get_outcome_breakdown(&env, &market_id),
+                Ok(vec![
+                    &env,
+                    (outcome("c"), 60i128, 3u32),
+                    (outcome("a"), 900, 1),
+                    (outcome("b"), 0, 0),
+                ])
+            );
+            assert_eq!(
+                QueryManager::get_outcome_breakdown(&env, &Symbol::new(&env, "missing")),
+                Err(Error::MarketNotFound)
+            );
+        });
+    }
+
+    #[test]
+    fn test_get_implied_odds_splits_stake_in_basis_points() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let admin = Address::generate(&env);
+        let market_id = Symbol::new(&env, "odds");
+        let outcome = |o: &str| String::from_str(&env, o);
+
+        env.as_contract(&contract_id, || {
+            let mut market = test_market(
+                &env,
+                &admin,
+                &["yes", "no", "maybe"],
+                env.ledger().timestamp() + 1000,
+            );
+            env.storage().persistent().set(&market_id, &market);
+            assert_eq!(QueryManager::get_implied_odds(&env, &market_id), Ok(Map::new(&env)));
+
+            for (label, stake) in [("yes", 600i128), ("yes", 150), ("no", 250)] {
+                let voter = Address::generate(&env);
+                market.votes.set(voter.clone(), outcome(label));
+                market.stakes.set(voter, stake);
+                market.total_staked += stake;
+            }
+            env.storage().persistent().set(&market_id, &market);
+
+            let odds = QueryManager::get_implied_odds(&env, &market_id).unwrap();
+            assert_eq!(odds.len(), 3);
+            assert_eq!(odds.get(outcome("yes")), Some(7_500));
+            assert_eq!(odds.get(outcome("no")), Some(2_500));
+            assert_eq!(odds.get(outcome("maybe")), Some(0));
+            assert_eq!(
+                QueryManager::get_implied_odds(&env, &Symbol::new(&env, "missing")),
+                Err(Error::MarketNotFound)
+            );
+        });
+    }
+
+    #[test]
+    fn test_refundable_amount_on_cancelled_market() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let admin = Address::generate(&env);
+        let user = Address::generate(&env);
+        let market_id = Symbol::new(&env, "cancelled");
+
+        env.as_contract(&contract_id, || {
+            let mut market = test_market(
+                &env,
+                &admin,
+                &["yes", "no"],
+                env.ledger().timestamp() + 1000,
+            );
+            env.storage().persistent().set(&market_id, &market);
+            let mut bet = crate::types::Bet::new(
+                &env,
+                user.clone(),
+                market_id.clone(),
+                String::from_str(&env, "yes"),
+                5_000_000,
+            );
+            crate::bets::BetStorage::store_bet(&env, &bet).unwrap();
+            assert_eq!(QueryManager::refundable_amount(&env, &market_id, &user), Ok(0));
+
+            market.state = MarketState::Cancelled;
+            env.storage().persistent().set(&market_id, &market);
+            assert_eq!(
+                QueryManager::refundable_amount(&env, &market_id, &user),
+                Ok(5_000_000)
+            );
+            assert_eq!(
+                QueryManager::refundable_amount(&env, &market_id, &Address::generate(&env)),
+                Ok(0)
+            );
+
+            bet.mark_as_refunded();
+            crate::bets::BetStorage::store_bet(&env, &bet).unwrap();
+            assert_eq!(QueryManager::refundable_amount(&env, &market_id, &user), Ok(0));
+            assert_eq!(
+                QueryManager::refundable_amount(&env, &Symbol::new(&env, "missing"), &user),
+                Err(Error::MarketNotFound)
+            );
+        });
+    }
+
+    #[test]
+    fn test_refundable_amount_after_refund_grace_expires() {
+        use soroban_sdk::testutils::Ledger;
+
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let admin = Address::generate(&env);
+        let user = Address::generate(&env);
+        let market_id = Symbol::new(&env, "expired");
+
+        env.as_contract(&contract_id, || {
+            let mut market = test_market(
+                &env,
+                &admin,
+                &["yes", "no"],
+                env.ledger().timestamp() + 1000,
+            );
+            env.storage().persistent().set(&market_id, &market);
+            let bet = crate::types::Bet::new(
+                &env,
+                user.clone(),
+                market_id.clone(),
+                String::from_str(&env, "no"),
+                7_000_000,
+            );
+            crate::bets::BetStorage::store_bet(&env, &bet).unwrap();
+
+            // Ended but still within the oracle's resolution timeout and grace
+            env.ledger().set_timestamp(market.refund_available_at() - 1);
+            assert_eq!(QueryManager::refundable_amount(&env, &market_id, &user), Ok(0));
+
+            env.ledger().set_timestamp(market.refund_available_at());
+            assert_eq!(
+                QueryManager::refundable_amount(&env, &market_id, &user),
+                Ok(7_000_000)
+            );
+
+            // An oracle result arriving first makes the market resolvable instead
+            market.oracle_result = Some(String::from_str(&env, "no"));
+            env.storage().persistent().set(&market_id, &market);
+            assert_eq!(QueryManager::refundable_amount(&env, &market_id, &user), Ok(0));
+        });
+    }
+
+    #[test]
+    fn test_get_votes_page_pages_through_every_voter_once() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let admin = Address::generate(&env);
+        let market_id = Symbol::new(&env, "voted");
+
+        env.as_contract(&contract_id, || {
+            let mut market = test_market(
+                &env,
+                &admin,
+                &["yes", "no"],
+                env.ledger().timestamp() + 1000,
+            );
+            for i in 0..5i128 {
+                let voter = Address::generate(&env);
+                let outcome = if i % 2 == 0 { "yes" } else { "no" };
+                market.votes.set(voter.clone(), String::from_str(&env, outcome));
+                market.stakes.set(voter, (i + 1) * 1_000_000);
+            }
+            env.storage().persistent().set(&market_id, &market);
+
+            let mut seen = Vec::new(&env);
+            let mut start = 0;
+            loop {
+                let page = QueryManager::get_votes_page(&env, &market_id, start, 2).unwrap();
+                if page.is_empty() {
+                    break;
+                }
+                assert!(page.len() <= 2);
+                for (voter, outcome, stake) in page.iter() {
+                    assert_eq!(market.votes.get(voter.clone()), Some(outcome));
+                    assert_eq!(market.stakes.get(voter.clone()), Some(stake));
+                    assert!(!seen.contains(&voter));
+                    seen.push_back(voter);
+                }
+                start += 2;
+            }
+            assert_eq!(seen.len(), 5);
+
+            // Pages are stable and capped at MAX_PAGE_SIZE
+            assert_eq!(
+                QueryManager::get_votes_page(&env, &market_id, 1, 3),
+                QueryManager::get_votes_page(&env, &market_id, 1, 3)
+            );
+            assert_eq!(
+                QueryManager::get_votes_page(&env, &market_id, 0, u32::MAX)
+                    .unwrap()
+                    .len(),
+                5
+            );
+            assert_eq!(
+                QueryManager::get_votes_page(&env, &Symbol::new(&env, "missing"), 0, 2),
+                Err(Error::MarketNotFound)
+            );
+        });
+    }
+
+    #[test]
+    fn test_get_market_summaries_returns_existing_markets_in_order() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        let ids = [
+            Symbol::new(&env, "first"),
+            Symbol::new(&env, "second"),
+            Symbol::new(&env, "third"),
+        ];
+
+        env.as_contract(&contract_id, || {
+            for (i, market_id) in ids.iter().enumerate() {
+                let mut market = test_market(
+                    &env,
+                    &admin,
+                    &["yes", "no"],
+                    env.ledger().timestamp() + 1000,
+                );
+                market.total_staked = 100 * (i as i128 + 1);
+                env.storage().persistent().set(market_id, &market);
+            }
+        });
+
+        let requested = vec![
+            &env,
+            ids[2].clone(),
+            Symbol::new(&env, "missing"),
+            ids[0].clone(),
+            ids[1].clone(),
+        ];
+        let summaries = client.get_market_summaries(&requested);
+        assert_eq!(summaries.len(), 3);
+        assert_eq!(summaries.get(0).unwrap().market_id, ids[2]);
+        assert_eq!(summaries.get(0).unwrap().total_staked, 300);
+        assert_eq!(summaries.get(1).unwrap().market_id, ids[0]);
+        assert_eq!(summaries.get(2).unwrap().market_id, ids[1]);
+        assert_eq!(summaries.get(2).unwrap().total_staked, 200);
+
+        let mut too_many = Vec::new(&env);
+        for _ in 0..=crate::config::MAX_MARKET_SUMMARIES_QUERY {
+            too_many.push_back(ids[0].clone());
+        }
+        assert_eq!(
+            client.try_get_market_summaries(&too_many),
+            Err(Ok(Error::InvalidInput))
+        );
+    }
 }
@@ -1,4 +1,4 @@
-use soroban_sdk::{contract, contracterror, contractimpl, contracttype, Address, Env, Symbol};
+use soroban_sdk::{contract, contracterror, contractimpl, contracttype, Address, Env, Symbol, Vec};
 
 #[contracttype]
 #[derive(Clone, Debug, PartialEq)]
@@ -11,6 +11,15 @@ pub struct RateLimitConfig {
     pub time_window_seconds: u64,    // Time window in seconds
 }
 
+// Per-admin market creation limit over a rolling window. Kept separate from
+// RateLimitConfig so it stays off (unlimited) unless explicitly configured.
+#[contracttype]
+#[derive(Clone, Debug, PartialEq)]
+pub struct MarketCreationLimit {
+    pub max_markets: u32,    // Max markets per admin per rolling window (0 = no limit)
+    pub window_seconds: u64, // Rolling window length in seconds
+}
+
 // Rate limit tracking
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -28,6 +37,8 @@ pub enum RateLimiterData {
     OracleCalls(Symbol),           // market_id
     UserBets(Address),             // user (global bet count per window)
     AdminEvents(Address),          // admin (events created per window)
+    CreationLimit,                 // market creation limit config
+    AdminCreations(Address),       // admin (creation timestamps in rolling window)
 }
 
 pub struct RateLimiter {
@@ -187,6 +198,109 @@ impl RateLimiter {
         Ok(())
     }
 
+    /// Read-only counterpart of `rate_limit_admin_events`: whether `admin` may
+    /// create another event now, without counting it.
+    pub fn check_admin_events(&self, admin: &Address) -> Result<(), RateLimiterError> {
+        let config = self.get_config()?;
+        if config.events_per_admin_limit == 0 {
+            return Ok(());
+        }
+        let limit = self.get_or_create_limit(&RateLimiterData::AdminEvents(admin.clone()));
+        self.check_limit(limit.count, config.events_per_admin_limit)
+    }
+
+    /// Current market creation limit; unlimited when never configured.
+    pub fn get_market_creation_limit(&self) -> MarketCreationLimit {
+        self.env
+            .storage()
+            .persistent()
+            .get(&RateLimiterData::CreationLimit)
+            .unwrap_or(MarketCreationLimit {
+                max_markets: 0,
+                window_seconds: 0,
+            })
+    }
+
+    /// Set the per-admin market creation limit. `max_markets == 0` disables it.
+    /// Caller must have already authenticated admin.
+    pub fn set_market_creation_limit(
+        &self,
+        limit: MarketCreationLimit,
+    ) -> Result<(), RateLimiterError> {
+        if limit.max_markets > 1000 {
+            return Err(RateLimiterError::InvalidEventsLimit);
+        }
+        if limit.max_markets > 0
+            && (limit.window_seconds < 60 || limit.window_seconds > 2592000)
+        {
+            return Err(RateLimiterError::InvalidTimeWindow);
+        }
+
+        self.env
+            .storage()
+            .persistent()
+            .set(&RateLimiterData::CreationLimit, &limit);
+
+        Ok(())
+    }
+
+    /// Rate limit market creation: max markets per admin in any rolling window.
+    ///
+    /// Unlike the fixed windows above, the admin's recent creation timestamps are
+    /// kept so the limit holds for every `window_seconds` span, not just per bucket.
+    /// Caller (e.g. create_market) must have already authenticated admin.
+    pub fn rate_limit_market_creation(&self, admin: Address) -> Result<(), RateLimiterError> {
+        let limit = self.get_market_creation_limit();
+        if limit.max_markets == 0 {
+            return Ok(());
+        }
+
+        let key = RateLimiterData::AdminCreations(admin);
+        let mut recent = self.recent_creations(&key, limit.window_seconds);
+        self.check_limit(recent.len(), limit.max_markets)?;
+
+        recent.push_back(self.env.ledger().timestamp());
+        self.env.storage().temporary().set(&key, &recent);
+        self.env.storage().temporary().extend_ttl(
+            &key,
+            limit.window_seconds as u32 + 86400,
+            limit.window_seconds as u32 + 86400,
+        );
+
+        Ok(())
+    }
+
+    /// Read-only counterpart of `rate_limit_market_creation`: whether `admin`
+    /// may create another market now, without recording it.
+    pub fn check_market_creation(&self, admin: &Address) -> Result<(), RateLimiterError> {
+        let limit = self.get_market_creation_limit();
+        if limit.max_markets == 0 {
+            return Ok(());
+        }
+        let key = RateLimiterData::AdminCreations(admin.clone());
+        let recent = self.recent_creations(&key, limit.window_seconds);
+        self.check_limit(recent.len(), limit.max_markets)
+    }
+
+    // Creation timestamps under `key` that still fall inside the rolling window
+    fn recent_creations(&self, key: &RateLimiterData, window_seconds: u64) -> Vec<u64> {
+        let now = self.env.ledger().timestamp();
+        let history: Vec<u64> = self
+            .env
+            .storage()
+            .temporary()
+            .get(key)
+            .unwrap_or(Vec::new(&self.env));
+
+        let mut recent = Vec::new(&self.env);
+        for created_at in history.iter() {
+            if now < created_at.saturating_add(window_seconds) {
+                recent.push_back(created_at);
+            }
+        }
+        recent
+    }
+
     // Update rate limits (admin only). Caller must have already authenticated admin.
     pub fn update_rate_limits(
         &self,
@@ -260,6 +374,27 @@ impl RateLimiter {
     }
 }
 
+#[cfg(test)]
+impl RateLimiter {
+    // Store limits that contract-level tests voting or disputing a few times
+    // stay well under; call from inside `env.as_contract`.
+    pub fn init_for_tests(env: &Env, admin: &Address) {
+        RateLimiter::new(env.clone())
+            .init_rate_limiter(
+                admin.clone(),
+                RateLimitConfig {
+                    voting_limit: 10,
+                    dispute_limit: 10,
+                    oracle_call_limit: 10,
+                    bet_limit: 0,
+                    events_per_admin_limit: 0,
+                    time_window_seconds: 3600,
+                },
+            )
+            .unwrap();
+    }
+}
+
 // Rate limit status response
 #[contracttype]
 #[derive(Clone, Debug)]
@@ -327,6 +462,26 @@ impl RateLimiterContract {
         limiter.rate_limit_oracle_calls(market_id)
     }
 
+    // Configure per-admin market creation limit (0 = unlimited)
+    pub fn set_market_creation_limit(
+        env: Env,
+        admin: Address,
+        limit: MarketCreationLimit,
+    ) -> Result<(), RateLimiterError> {
+        admin.require_auth();
+        let limiter = RateLimiter::new(env);
+        limiter.set_market_creation_limit(limit)
+    }
+
+    // Check and enforce per-admin market creation rate limit
+    pub fn check_market_creation_rate_limit(
+        env: Env,
+        admin: Address,
+    ) -> Result<(), RateLimiterError> {
+        let limiter = RateLimiter::new(env);
+        limiter.rate_limit_market_creation(admin)
+    }
+
     // Check and enforce admin event creation rate limit
     pub fn check_admin_event_rate_limit(env: Env, admin: Address) -> Result<(), RateLimiterError> {
         let limiter = RateLimiter::new(env);
@@ -371,7 +526,7 @@ impl RateLimiterContract {
 mod tests {
     use super::*;
     use soroban_sdk::{
-        testutils::{Address as _, AuthorizedInvocation},
+        testutils::{Address as _, AuthorizedInvocation, Ledger},
         Env,
     };
 
@@ -546,4 +701,85 @@ mod tests {
         // Should still be able to vote on market2
         client.check_voting_rate_limit(&user, &market2);
     }
+
+    #[test]
+    fn test_market_creation_unlimited_by_default() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, RateLimiterContract);
+        let client = RateLimiterContractClient::new(&env, &contract_id);
+
+        // No limit configured: creation is never throttled
+        for _ in 0..50 {
+            client.check_market_creation_rate_limit(&admin);
+        }
+    }
+
+    #[test]
+    fn test_market_creation_rolling_window() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let other_admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, RateLimiterContract);
+        let client = RateLimiterContractClient::new(&env, &contract_id);
+
+        client.set_market_creation_limit(
+            &admin,
+            &MarketCreationLimit {
+                max_markets: 3,
+                window_seconds: 3600,
+            },
+        );
+
+        // Spread creations across the window: t = 0, 1000, 2000
+        for _ in 0..3 {
+            client.check_market_creation_rate_limit(&admin);
+            env.ledger().with_mut(|li| li.timestamp += 1000);
+        }
+
+        // t = 3000: all three still inside the window
+        let res = client.try_check_market_creation_rate_limit(&admin);
+        assert_eq!(res, Err(Ok(RateLimiterError::RateLimitExceeded.into())));
+
+        // Limit is tracked per admin
+        client.check_market_creation_rate_limit(&other_admin);
+
+        // t = 3600: the first creation rolls out of the window, freeing one slot
+        env.ledger().with_mut(|li| li.timestamp += 600);
+        client.check_market_creation_rate_limit(&admin);
+        let res = client.try_check_market_creation_rate_limit(&admin);
+        assert_eq!(res, Err(Ok(RateLimiterError::RateLimitExceeded.into())));
+    }
+
+    #[test]
+    fn test_market_creation_limit_validation() {
+        let env = Env::default();
+        env.mock_all_auths();
+
+        let admin = Address::generate(&env);
+        let contract_id = env.register_contract(None, RateLimiterContract);
+        let client = RateLimiterContractClient::new(&env, &contract_id);
+
+        let res = client.try_set_market_creation_limit(
+            &admin,
+            &MarketCreationLimit {
+                max_markets: 5,
+                window_seconds: 30,
+            },
+        );
+        assert_eq!(res, Err(Ok(RateLimiterError::InvalidTimeWindow)));
+
+        // Disabling needs no window
+        client.set_market_creation_limit(
+            &admin,
+            &MarketCreationLimit {
+                max_markets: 0,
+                window_seconds: 0,
+            },
+        );
+    }
 }
@@ -660,7 +660,8 @@ impl RecoveryManager {
                     market
                         .claimed
                         .set(user.clone(), crate::types::ClaimInfo::new(env, stake));
-                    market.total_staked = market.total_staked - stake;
+                    market.total_staked =
+                        crate::utils::NumericUtils::checked_reduce(market.total_staked, stake)?;
                     total_refunded += stake;
                 }
             }
@@ -729,6 +730,7 @@ fn symbol_to_string(env: &Env, sym: &Symbol) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::oracles::ComparisonOp;
     use alloc::string::ToString;
     use soroban_sdk::testutils::Address as _;
 
@@ -1039,7 +1041,8 @@ mod tests {
                 oracle_address: oracle_address.clone(),
                 feed_id: String::from_str(&env, "BTC/USD"),
                 threshold: 50000,
-                comparison: String::from_str(&env, "gt"),
+                comparison: ComparisonOp::Gt,
+                threshold2: None,
             };
             let outcomes = vec![&env, String::from_str(&env, "yes"), String::from_str(&env, "no")];
             let metadata_commitment = crate::types::Market::compute_metadata_commitment(
@@ -1050,6 +1053,7 @@ mod tests {
             );
             let market = crate::types::Market {
                 admin: admin.clone(),
+                creator: admin.clone(),
                 question: String::from_str(&env, "Test market"),
                 outcomes,
                 end_time: 9999999999u64,
@@ -1072,10 +1076,23 @@ mod tests {
                 extension_history: Vec::new(&env),
                 category: None,
                 tags: Vec::new(&env),
+                language: String::from_str(&env, crate::config::DEFAULT_MARKET_LANGUAGE),
                 min_pool_size: None,
+                min_voters: None,
+                outcome_cap_bps: None,
+                resolution_bounty: 0,
                 bet_deadline: 0,
                 dispute_window_seconds: 86400,
                 winnings_swept: false,
+                refund_grace_seconds: crate::config::REFUND_GRACE_SECONDS,
+                early_bird_fee: None,
+                settlement_token: None,
+                settlement_rate_bps: 0,
+                resolution_bond: 0,
+                condition: None,
+                tiebreak_policy: None,
+                payout_model: crate::types::PayoutModel::Parimutuel,
+                claims_wait_for_dispute_window: false,
             };
             env.storage().persistent().set(&market_id, &market);
         });
@@ -297,6 +297,7 @@ mod tests {
     use super::*;
     use crate::PredictifyHybrid;
     use crate::PredictifyHybridClient;
+    use crate::oracles::ComparisonOp;
     use soroban_sdk::{
         testutils::Address as _,
         token::StellarAssetClient,
@@ -579,7 +580,8 @@ mod tests {
                 oracle_address: Address::generate(&env),
                 feed_id: String::from_str(&env, "BTC/USD"),
                 threshold: 10_000_000,
-                comparison: String::from_str(&env, "gt"),
+                comparison: ComparisonOp::Gt,
+                threshold2: None,
             },
             &None,
             &3600u64,
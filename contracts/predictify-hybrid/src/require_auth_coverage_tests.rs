@@ -135,6 +135,7 @@ fn oracle(env: &Env) -> OracleConfig {
         feed_id: String::from_str(env, "BTC/USD"),
         threshold: 50_000,
         comparison: String::from_str(env, "gt"),
+        threshold2: None,
     }
 }
 
@@ -385,6 +386,7 @@ fn test_claim_winnings_authorized_succeeds() {
         &admin,
         &market_id,
         &String::from_str(&env, "yes"),
+        &None,
     );
     advance_past_dispute(&env);
     let result = client(&env, &cid).try_claim_winnings(&user, &market_id);
@@ -419,6 +421,7 @@ fn test_claim_winnings_wrong_subject_gets_nothing_to_claim() {
         &admin,
         &market_id,
         &String::from_str(&env, "yes"),
+        &None,
     );
     advance_past_dispute(&env);
     // user_b never voted – must not silently succeed
@@ -441,6 +444,7 @@ fn test_dispute_market_authorized_succeeds() {
         &admin,
         &market_id,
         &String::from_str(&env, "yes"),
+        &None,
     );
     let user = Address::generate(&env);
     let result = client(&env, &cid).try_dispute_market(&user, &market_id, &1_000i128, &None);
@@ -469,6 +473,7 @@ fn test_vote_on_dispute_authorized_succeeds() {
         &admin,
         &market_id,
         &String::from_str(&env, "yes"),
+        &None,
     );
     let disputer = Address::generate(&env);
     let _ = client(&env, &cid).try_dispute_market(&disputer, &market_id, &1_000i128, &None);
@@ -586,6 +591,7 @@ fn test_resolve_market_manual_authorized_admin_succeeds() {
         &admin,
         &market_id,
         &String::from_str(&env, "yes"),
+        &None,
     );
     assert_auth_ok_panic!(result, "resolve_market_manual rejected authorized admin");
 }
@@ -601,6 +607,7 @@ fn test_resolve_market_manual_forged_admin_rejected() {
         &attacker,
         &market_id,
         &String::from_str(&env, "yes"),
+        &None,
     );
     assert_unauthorized_panic!(result);
 }
@@ -615,7 +622,12 @@ fn test_resolve_market_with_ties_authorized_admin_succeeds() {
     advance_past_end(&env);
     let mut winning = Vec::new(&env);
     winning.push_back(String::from_str(&env, "yes"));
-    let result = client(&env, &cid).try_resolve_market_with_ties(&admin, &market_id, &winning);
+    let result = client(&env, &cid).try_resolve_market_with_ties(
+        &admin,
+        &market_id,
+        &winning,
+        &None,
+    );
     assert_auth_ok_panic!(result, "resolve_market_with_ties rejected authorized admin");
 }
 
@@ -628,7 +640,12 @@ fn test_resolve_market_with_ties_forged_admin_rejected() {
     let attacker = Address::generate(&env);
     let mut winning = Vec::new(&env);
     winning.push_back(String::from_str(&env, "yes"));
-    let result = client(&env, &cid).try_resolve_market_with_ties(&attacker, &market_id, &winning);
+    let result = client(&env, &cid).try_resolve_market_with_ties(
+        &attacker,
+        &market_id,
+        &winning,
+        &None,
+    );
     assert_unauthorized_panic!(result);
 }
 
@@ -642,6 +659,7 @@ fn test_resolve_dispute_authorized_admin_succeeds() {
     advance_past_end(&env);
     let _ = client(&env, &cid).try_resolve_market_manual(
         &admin, &market_id, &String::from_str(&env, "yes"),
+        &None,
     );
     let result = client(&env, &cid).try_resolve_dispute(&admin, &market_id);
     assert_auth_ok_contract!(result, "resolve_dispute rejected authorized admin");
@@ -667,6 +685,7 @@ fn test_collect_fees_authorized_admin_succeeds() {
     advance_past_end(&env);
     let _ = client(&env, &cid).try_resolve_market_manual(
         &admin, &market_id, &String::from_str(&env, "yes"),
+        &None,
     );
     let result = client(&env, &cid).try_collect_fees(&admin, &market_id);
     assert_auth_ok_contract!(result, "collect_fees rejected authorized admin");
@@ -797,6 +816,7 @@ fn test_sweep_unclaimed_winnings_authorized_admin_succeeds() {
     advance_past_end(&env);
     let _ = client(&env, &cid).try_resolve_market_manual(
         &admin, &market_id, &String::from_str(&env, "yes"),
+        &None,
     );
     // Advance well past claim window
     env.ledger().with_mut(|l| l.timestamp += 365 * 24 * 60 * 60);
@@ -1091,6 +1111,7 @@ fn test_archive_event_authorized_admin_succeeds() {
     advance_past_end(&env);
     let _ = client(&env, &cid).try_resolve_market_manual(
         &admin, &market_id, &String::from_str(&env, "yes"),
+        &None,
     );
     let result = client(&env, &cid).try_archive_event(&admin, &market_id);
     assert_auth_ok_contract!(result, "archive_event rejected authorized admin");
@@ -1303,6 +1324,7 @@ fn test_claim_winnings_correct_caller_wrong_subject_panics() {
     advance_past_end(&env);
     let _ = PredictifyHybridClient::new(&env, &cid).try_resolve_market_manual(
         &admin, &market_id, &String::from_str(&env, "yes"),
+        &None,
     );
     advance_past_dispute(&env);
 
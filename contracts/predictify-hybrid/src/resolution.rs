@@ -4,9 +4,12 @@ use crate::bets::BetStorage;
 use crate::err::Error;
 use alloc::string::ToString;
 
-use crate::markets::{CommunityConsensus, MarketAnalytics, MarketStateManager, MarketUtils};
+use crate::markets::{
+    CommunityConsensus, MarketAnalytics, MarketStateLogic, MarketStateManager, MarketUtils,
+    MarketValidator,
+};
 
-use crate::oracles::{OracleFactory, OracleUtils};
+use crate::oracles::{ComparisonOp, OracleFactory, OracleUtils};
 // use crate::reentrancy_guard::ReentrancyGuard; // Removed - module no longer exists
 use crate::types::*;
 
@@ -160,7 +163,7 @@ pub enum ResolutionState {
 /// println!("Oracle result: {}", oracle_resolution.oracle_result);
 /// println!("Price: ${}", oracle_resolution.price / 100);
 /// println!("Threshold: ${}", oracle_resolution.threshold / 100);
-/// println!("Comparison: {}", oracle_resolution.comparison);
+/// println!("Comparison: {}", oracle_resolution.comparison.as_str());
 /// println!("Provider: {:?}", oracle_resolution.provider);
 /// println!("Feed: {}", oracle_resolution.feed_id);
 ///
@@ -228,7 +231,7 @@ pub struct OracleResolution {
     pub oracle_result: String,
     pub price: i128,
     pub threshold: i128,
-    pub comparison: String,
+    pub comparison: ComparisonOp,
     pub timestamp: u64,
     pub provider: OracleProvider,
     pub feed_id: String,
@@ -673,20 +676,13 @@ impl ResolutionOutcomeCache {
     }
 
     /// Validate oracle resolution
+    ///
+    /// Prices and thresholds are signed: feeds such as funding rates or spreads
+    /// legitimately report zero or negative values, so only the outcome is checked.
     pub fn validate_oracle_resolution(
         _env: &Env,
         resolution: &OracleResolution,
     ) -> Result<(), Error> {
-        // Validate price is positive
-        if resolution.price <= 0 {
-            return Err(Error::InvalidInput);
-        }
-
-        // Validate threshold is positive
-        if resolution.threshold <= 0 {
-            return Err(Error::InvalidInput);
-        }
-
         // Validate outcome is not empty
         if resolution.oracle_result.is_empty() {
             return Err(Error::InvalidInput);
@@ -808,7 +804,7 @@ impl ResolutionOutcomeCache {
         let med_cfg = Self::get_median_config(env)?;
         let feed_id = market.oracle_config.feed_id.clone();
         let threshold = market.oracle_config.threshold;
-        let comparison = market.oracle_config.comparison.clone();
+        let comparison = market.oracle_config.comparison;
 
         // ── 3. Fetch from all three oracles sequentially ────────────────────
         let mut raw_quotes: Vec<OracleQuote> = Vec::new(env);
@@ -889,7 +885,7 @@ impl ResolutionOutcomeCache {
 
         // ── 8. Outcome determination ────────────────────────────────────────
         let outcome =
-            OracleUtils::determine_outcome(weighted_median, threshold, &comparison, env)?;
+            OracleUtils::determine_outcome_for_config(weighted_median, &market.oracle_config, env)?;
 
         // ── 9. Persist oracle result and emit events ──────────────────────
         MarketStateManager::set_oracle_result(&mut market, outcome.clone());
@@ -1370,6 +1366,9 @@ pub struct MarketResolutionManager;
 impl MarketResolutionManager {
     /// Resolve a market by combining oracle results and community votes
     pub fn resolve_market(env: &Env, market_id: &Symbol) -> Result<MarketResolution, Error> {
+        // Grouped markets must be settled together via resolve_group
+        MarketGroupManager::require_ungrouped(env, market_id)?;
+
         // Get the market from storage
         let mut market = MarketStateManager::get_market(env, market_id)?;
 
@@ -1392,25 +1391,48 @@ impl MarketResolutionManager {
         }
         validation?;
 
+        // Parent condition unmet; `resolve_market_hybrid` cancels and refunds these
+        if !crate::markets::MarketConditions::is_met(env, &market)? {
+            return Err(Error::InvalidState);
+        }
+
+        // Too few distinct voters; `resolve_market_hybrid` cancels and refunds these
+        if matches!(market.min_voters, Some(required) if market.votes.len() < required) {
+            return Err(Error::InvalidState);
+        }
+
         // Retrieve the oracle result
         let oracle_result = market
             .oracle_result
             .as_ref()
             .ok_or(Error::OracleUnavailable)?
             .clone();
+        MarketValidator::assert_valid_outcome(&market, &oracle_result)?;
 
         // Calculate community consensus
         let community_consensus = MarketAnalytics::calculate_community_consensus(&market);
 
-        // Determine winning outcome(s) using multi-outcome resolution with tie detection
-        // This handles both single winner and tie cases (pool split)
-        let winning_outcomes = MarketUtils::determine_winning_outcomes(
-            env,
-            &market,
-            &oracle_result,
-            &community_consensus,
-            0, // Tie threshold: 0 = exact ties only
-        );
+        // A configured tie-break policy replaces the weighted choice on a conflict;
+        // refunds and escalations are only applied by `resolve_market_hybrid`
+        let winning_outcomes =
+            match crate::markets::MarketTiebreak::triggered(&market, &oracle_result) {
+                Some(TiebreakPolicy::FavorOracle) => Vec::from_array(env, [oracle_result.clone()]),
+                Some(TiebreakPolicy::FavorCommunity) => {
+                    Vec::from_array(env, [community_consensus.outcome.clone()])
+                }
+                Some(TiebreakPolicy::Refund) | Some(TiebreakPolicy::Dispute) => {
+                    return Err(Error::InvalidState)
+                }
+                // Determine winning outcome(s) using multi-outcome resolution with tie detection
+                // This handles both single winner and tie cases (pool split)
+                None => MarketUtils::determine_winning_outcomes(
+                    env,
+                    &market,
+                    &oracle_result,
+                    &community_consensus,
+                    0, // Tie threshold: 0 = exact ties only
+                ),
+            };
 
         // For resolution record, use first outcome (or comma-separated for display)
         let final_result = if winning_outcomes.len() > 0 {
@@ -1452,17 +1474,26 @@ impl MarketResolutionManager {
         // Capture old state for event
         let old_state = market.state.clone();
 
+        // A market past end_time that nothing has moved out of Active yet ends here
+        if market.state == MarketState::Active {
+            MarketStateLogic::transition_state(&mut market, MarketState::Ended)?;
+            crate::markets::MarketStateCounts::record_transition(env, old_state, market.state);
+        }
+        MarketStateLogic::check_function_access_for_state("resolve", market.state)?;
+
         // Set winning outcome(s) - supports both single winner and ties
         MarketStateManager::set_winning_outcomes(
             &mut market,
             winning_outcomes.clone(),
             Some(market_id),
         );
+        // The oracle result and winning outcomes are persisted together or not at all
+        if market.oracle_result.is_none() || market.winning_outcomes.is_none() {
+            return Err(Error::InvalidState);
+        }
+        MarketResolutionValidator::reconcile_oracle_fields(&market)?;
         MarketStateManager::update_market(env, market_id, &market);
-        ResolutionOutcomeCache::refresh(env, market_id, &market)?;
-
-        // Decrement active event count since the event is resolved
-        crate::storage::CreatorLimitsManager::decrement_active_events(env, &market.admin);
+        Self::record_resolved(env, market_id, &market)?;
 
         // Emit market resolved event
         let oracle_result_str = market
@@ -1509,6 +1540,77 @@ impl MarketResolutionManager {
         Ok(resolution)
     }
 
+    /// Resolve a market from its oracle result and community votes, applying
+    /// the market's tie-break policy when the two conflict.
+    ///
+    /// On a conflict (see `MarketTiebreak::triggered`) `FavorOracle` and
+    /// `FavorCommunity` resolve to that source's outcome, `Refund` cancels the
+    /// market and refunds its bets, and `Dispute` moves it to `Disputed` for
+    /// the dispute process. Otherwise this is [`Self::resolve_market`]. A
+    /// conditional market whose parent disqualified it, or one with fewer
+    /// voters than its `min_voters`, is cancelled and refunded first (see
+    /// [`Self::cancel_if_condition_unmet`] and
+    /// [`Self::cancel_if_below_min_voters`]).
+    ///
+    /// Returns the state the market was left in.
+    ///
+    /// # Errors
+    ///
+    /// Those of [`Self::resolve_market`], plus
+    /// `Error::IllegalMarketStateTransition` when escalating a market that is
+    /// already disputed.
+    pub fn resolve_market_hybrid(env: &Env, market_id: &Symbol) -> Result<MarketState, Error> {
+        MarketGroupManager::require_ungrouped(env, market_id)?;
+        let mut market = MarketStateManager::get_market(env, market_id)?;
+        MarketResolutionValidator::validate_market_for_resolution(env, &market)?;
+
+        // Conditional market whose parent disqualified it: refund instead of resolving
+        if Self::cancel_if_condition_unmet(env, market_id, &mut market)? {
+            return Ok(MarketState::Cancelled);
+        }
+        let oracle_result = market
+            .oracle_result
+            .clone()
+            .ok_or(Error::OracleUnavailable)?;
+
+        // Too few distinct voters: refund instead of resolving
+        if Self::cancel_if_below_min_voters(env, market_id, &mut market)? {
+            return Ok(MarketState::Cancelled);
+        }
+
+        match crate::markets::MarketTiebreak::triggered(&market, &oracle_result) {
+            Some(TiebreakPolicy::Refund) => {
+                let old_state = Self::cancel_and_refund(env, market_id, &mut market)?;
+                crate::events::EventEmitter::emit_state_change_event(
+                    env,
+                    market_id,
+                    &old_state,
+                    &MarketState::Cancelled,
+                    &String::from_str(env, "Hybrid tie-break: refund"),
+                );
+                Ok(MarketState::Cancelled)
+            }
+            Some(TiebreakPolicy::Dispute) => {
+                let old_state = market.state;
+                MarketStateLogic::transition_state(&mut market, MarketState::Disputed)?;
+                MarketStateManager::update_market(env, market_id, &market);
+                crate::markets::MarketStateCounts::record_transition(env, old_state, market.state);
+                crate::events::EventEmitter::emit_state_change_event(
+                    env,
+                    market_id,
+                    &old_state,
+                    &MarketState::Disputed,
+                    &String::from_str(env, "Hybrid tie-break: dispute"),
+                );
+                Ok(MarketState::Disputed)
+            }
+            _ => {
+                Self::resolve_market(env, market_id)?;
+                Ok(MarketState::Resolved)
+            }
+        }
+    }
+
     /// Finalize market with admin override
     pub fn finalize_market(
         env: &Env,
@@ -1519,11 +1621,48 @@ impl MarketResolutionManager {
         // Validate admin permissions
         MarketResolutionValidator::validate_admin_permissions(env, admin)?;
 
+        // Grouped markets must be settled together via resolve_group
+        MarketGroupManager::require_ungrouped(env, market_id)?;
+
+        Self::apply_final_outcome(env, market_id, outcome)
+    }
+
+    /// Record `outcome` as the admin-chosen final outcome of `market_id`.
+    ///
+    /// Shared by `finalize_market` and group resolution; callers handle
+    /// authorization and the group lock. An `Active` market past its end time
+    /// is moved to `Ended` first.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InvalidOutcome` - `outcome` is not one of the market's outcomes
+    /// * `Error::MarketResolved` - The market is neither `Active` nor `Ended`
+    /// * `Error::MarketClosed` - The market has not reached its end time
+    pub(crate) fn apply_final_outcome(
+        env: &Env,
+        market_id: &Symbol,
+        outcome: &String,
+    ) -> Result<MarketResolution, Error> {
         // Get the market
         let mut market = MarketStateManager::get_market(env, market_id)?;
 
         // Validate outcome
-        MarketResolutionValidator::validate_outcome(env, outcome, &market.outcomes)?;
+        MarketValidator::assert_valid_outcome(&market, outcome)?;
+
+        // Only a live or ended market can be given a final outcome
+        if !matches!(market.state, MarketState::Active | MarketState::Ended) {
+            return Err(Error::MarketResolved);
+        }
+
+        // A market past end_time that nothing has moved out of Active yet ends here
+        if market.state == MarketState::Active {
+            if market.is_active(env) {
+                return Err(Error::MarketClosed);
+            }
+            let old_state = market.state;
+            MarketStateLogic::transition_state(&mut market, MarketState::Ended)?;
+            crate::markets::MarketStateCounts::record_transition(env, old_state, market.state);
+        }
 
         // Create resolution record
         let resolution = MarketResolution {
@@ -1544,14 +1683,35 @@ impl MarketResolutionManager {
         winning_outcomes.push_back(outcome.clone());
         MarketStateManager::set_winning_outcomes(&mut market, winning_outcomes, Some(market_id));
         MarketStateManager::update_market(env, market_id, &market);
-        ResolutionOutcomeCache::refresh(env, market_id, &market)?;
-
-        // Decrement active event count since the event is manually finalized
-        crate::storage::CreatorLimitsManager::decrement_active_events(env, &market.admin);
+        Self::record_resolved(env, market_id, &market)?;
 
         Ok(resolution)
     }
 
+    /// Bookkeeping shared by every path that resolves `market`, run once it is
+    /// stored with its winning outcome(s): starts the claim window, locks the
+    /// insurance rate and updates the resolution latency, creator leaderboard,
+    /// active-market slot and resolution distribution.
+    ///
+    /// State counts are not touched; they move with the state transition.
+    pub(crate) fn record_resolved(
+        env: &Env,
+        market_id: &Symbol,
+        market: &Market,
+    ) -> Result<(), Error> {
+        crate::recovery::UnclaimedWinningsPolicy::set_claim_window_start_if_missing(
+            env,
+            market_id,
+            env.ledger().timestamp(),
+        );
+        crate::fees::InsuranceFund::lock_market_insurance_bps(env, market_id);
+        ResolutionLatencyTracker::record(env, market.end_time);
+        CreatorLeaderboard::record(env, &market.creator, market.total_staked);
+        crate::markets::MarketCreationCaps::record_closed(env, &market.creator);
+        ResolutionDistribution::record(env, market);
+        Ok(())
+    }
+
     /// Get market resolution
 
     pub fn get_market_resolution(
@@ -1571,392 +1731,1051 @@ impl MarketResolutionManager {
     ) -> Result<(), Error> {
         MarketResolutionValidator::validate_market_resolution(env, resolution)
     }
-}
-
-// ===== RESOLUTION VALIDATION =====
-
-/// Oracle resolution validation
-pub struct OracleResolutionValidator;
 
-impl OracleResolutionValidator {
-    /// Validate market for oracle resolution
-    pub fn validate_market_for_oracle_resolution(env: &Env, market: &Market) -> Result<(), Error> {
-        // Check if the market has already been resolved
-        if market.oracle_result.is_some() {
+    /// Voids an unresolved market: marks it `Cancelled` and refunds every active bet.
+    ///
+    /// Used when the market can no longer be resolved (e.g. its oracle feed was
+    /// delisted). Fails with `MarketResolved` / `InvalidState` for markets that
+    /// are already resolved or not `Active` / `Ended`.
+    pub fn void_and_refund(env: &Env, market_id: &Symbol, reason: &String) -> Result<(), Error> {
+        let mut market = MarketStateManager::get_market(env, market_id)?;
+        if market.state == MarketState::Resolved {
             return Err(Error::MarketResolved);
         }
-
-        // Check if the market ended (we can only fetch oracle result after market ends)
-        let current_time = env.ledger().timestamp();
-        if current_time < market.end_time {
-            return Err(Error::MarketClosed);
+        if !matches!(market.state, MarketState::Active | MarketState::Ended) {
+            return Err(Error::InvalidState);
         }
 
+        let old_state = market.state;
+        MarketStateLogic::transition_state(&mut market, MarketState::Cancelled)?;
+        crate::fees::ResolutionBounty::refund(env, market_id, &mut market)?;
+        MarketStateManager::update_market(env, market_id, &market);
+        crate::markets::MarketCreationCaps::record_closed(env, &market.creator);
+        crate::markets::MarketStateCounts::record_transition(env, old_state, market.state);
+        crate::bets::BetManager::refund_market_bets(env, market_id)?;
+
+        crate::events::EventEmitter::emit_state_change_event(
+            env,
+            market_id,
+            &old_state,
+            &MarketState::Cancelled,
+            reason,
+        );
         Ok(())
     }
 
-    /// Validate oracle resolution
-    pub fn validate_oracle_resolution(
-        _env: &Env,
-        resolution: &OracleResolution,
-    ) -> Result<(), Error> {
-        // Validate price is positive
-        if resolution.price <= 0 {
-            return Err(Error::InvalidInput);
+    /// Replaces the winning outcome of a recently resolved market (admin only).
+    ///
+    /// Allowed only while the market is `Resolved`, within
+    /// [`crate::config::RESOLUTION_CORRECTION_WINDOW_SECONDS`] of `resolved_at` and
+    /// before any winnings have been claimed; after that a wrong outcome has to
+    /// go through the dispute process. Bet statuses and the
+    /// [`ResolutionDistribution`] are recomputed for the corrected outcome, and
+    /// the market's [`ResolutionRecords`] entry is rewritten with `evidence`.
+    ///
+    /// Returns the winning outcome(s) that were replaced.
+    pub fn correct_resolution(
+        env: &Env,
+        admin: &Address,
+        market_id: &Symbol,
+        corrected_outcome: &String,
+        evidence: Option<String>,
+        resolved_at: u64,
+    ) -> Result<Vec<String>, Error> {
+        admin.require_auth();
+        MarketResolutionValidator::validate_admin_permissions(env, admin)?;
+        if let Some(ref text) = evidence {
+            crate::validation::InputValidator::validate_description_length(text)
+                .map_err(|_| Error::EvidenceTooLong)?;
         }
 
-        // Validate threshold is positive
-        if resolution.threshold <= 0 {
-            return Err(Error::InvalidInput);
+        let mut market = MarketStateManager::get_market(env, market_id)?;
+        if market.state != MarketState::Resolved {
+            return Err(Error::MarketNotResolved);
         }
+        let previous = market
+            .winning_outcomes
+            .clone()
+            .ok_or(Error::MarketNotResolved)?;
 
-        // Validate outcome is not empty
-        if resolution.oracle_result.is_empty() {
+        let deadline =
+            resolved_at.saturating_add(crate::config::RESOLUTION_CORRECTION_WINDOW_SECONDS);
+        let any_claimed = market.claimed.values().iter().any(|info| info.is_claimed());
+        if env.ledger().timestamp() > deadline || any_claimed || market.fee_collected {
+            return Err(Error::ResolutionCorrectionClosed);
+        }
+
+        MarketValidator::assert_valid_outcome(&market, corrected_outcome)?;
+        let corrected = Vec::from_array(env, [corrected_outcome.clone()]);
+        if corrected == previous {
             return Err(Error::InvalidInput);
         }
 
-        Ok(())
-    }
-}
+        market.winning_outcomes = Some(corrected.clone());
+        MarketStateManager::update_market(env, market_id, &market);
+        crate::bets::BetManager::resolve_market_bets(env, market_id, &corrected)?;
+        ResolutionDistribution::record_correction(env, &previous, &market);
+        ResolutionRecords::record(env, market_id, &corrected, admin, evidence);
 
-/// Market resolution validation
-pub struct MarketResolutionValidator;
+        crate::events::EventEmitter::emit_resolution_corrected(
+            env,
+            market_id,
+            &previous,
+            corrected_outcome,
+            admin,
+        );
+        Ok(previous)
+    }
 
-impl MarketResolutionValidator {
-    /// Validate market for resolution
-    pub fn validate_market_for_resolution(env: &Env, market: &Market) -> Result<(), Error> {
-        // Check if market is already resolved
-        if market.winning_outcomes.is_some() {
-            return Err(Error::MarketResolved);
+    /// Cancels and refunds `market` instead of resolving it when it has fewer
+    /// distinct voters than its `min_voters`.
+    ///
+    /// Guards against single-voter self-dealing. Returns `true` if the market was
+    /// cancelled, in which case the caller must not resolve it.
+    pub fn cancel_if_below_min_voters(
+        env: &Env,
+        market_id: &Symbol,
+        market: &mut Market,
+    ) -> Result<bool, Error> {
+        let required = match market.min_voters {
+            Some(required) => required,
+            None => return Ok(false),
+        };
+        let voter_count = market.votes.len();
+        if voter_count >= required {
+            return Ok(false);
         }
 
-        // Check if oracle result is available
-        if market.oracle_result.is_none() {
-            return Err(Error::OracleUnavailable);
-        }
+        let old_state = Self::cancel_and_refund(env, market_id, market)?;
 
-        // Check if market has ended
-        if market.is_active(env) {
-            return Err(Error::MarketClosed);
+        crate::events::EventEmitter::emit_min_voters_not_met(
+            env,
+            market_id,
+            voter_count,
+            required,
+        );
+        crate::events::EventEmitter::emit_state_change_event(
+            env,
+            market_id,
+            &old_state,
+            &MarketState::Cancelled,
+            &String::from_str(env, "Minimum voters not met"),
+        );
+        Ok(true)
+    }
+
+    /// Cancels and refunds a conditional `market` instead of resolving it when
+    /// its parent resolved to a disqualifying outcome or was cancelled.
+    ///
+    /// Returns `true` if the market was cancelled, in which case the caller
+    /// must not resolve it. Fails with `Error::ParentMarketUnresolved` while
+    /// the parent is still open.
+    pub fn cancel_if_condition_unmet(
+        env: &Env,
+        market_id: &Symbol,
+        market: &mut Market,
+    ) -> Result<bool, Error> {
+        if crate::markets::MarketConditions::is_met(env, market)? {
+            return Ok(false);
         }
 
-        // Check minimum pool size requirement (per-market override, else global)
-        let global_min: i128 = env
-            .storage()
-            .persistent()
-            .get(&Symbol::new(env, "global_min_pool"))
-            .unwrap_or(0);
-        let min_pool = market.min_pool_size.unwrap_or(global_min);
+        let old_state = Self::cancel_and_refund(env, market_id, market)?;
+        crate::events::EventEmitter::emit_state_change_event(
+            env,
+            market_id,
+            &old_state,
+            &MarketState::Cancelled,
+            &String::from_str(env, "Parent market condition not met"),
+        );
+        Ok(true)
+    }
 
-        // Only check if min pool is set
-        if min_pool > 0 {
-            // Get token decimals to normalize amounts for comparison
-            let token_client = crate::markets::MarketUtils::get_token_client(env)?;
-            let token_decimals = token_client.decimals() as u32;
+    /// Cancels `market`, returns its resolution bounty and refunds its bets.
+    /// Returns the state it was cancelled from.
+    fn cancel_and_refund(
+        env: &Env,
+        market_id: &Symbol,
+        market: &mut Market,
+    ) -> Result<MarketState, Error> {
+        let old_state = market.state;
+        MarketStateLogic::transition_state(market, MarketState::Cancelled)?;
+        crate::fees::ResolutionBounty::refund(env, market_id, market)?;
+        MarketStateManager::update_market(env, market_id, market);
+        crate::markets::MarketCreationCaps::record_closed(env, &market.creator);
+        crate::markets::MarketStateCounts::record_transition(env, old_state, market.state);
+        crate::bets::BetManager::refund_market_bets(env, market_id)?;
+        Ok(old_state)
+    }
 
-            // Normalize both total staked and min pool to canonical scale for comparison
-            let normalized_total = crate::tokens::normalize_amount(market.total_staked, token_decimals);
-            let normalized_min = crate::tokens::normalize_amount(min_pool, token_decimals);
+    /// Simulate paying every participant of a resolved market, without paying anyone.
+    ///
+    /// Participants are voters (vote stake) followed by bettors who did not also
+    /// vote (bet amount). Each is quoted by [`crate::fees::WinnerPayouts`] against
+    /// the pool totals a claim uses, the market's `total_staked` and the winners'
+    /// combined stake, so fee exemptions, early-bird fees, the payout model, the
+    /// insurance slice and the rounding policy apply exactly as they would on a
+    /// claim. As on the claim path, rounded-up
+    /// parimutuel payouts are capped at what the pool has left and stake-back
+    /// bonuses at what the bonus pool holds, in table order. Participants owed
+    /// nothing are omitted; on a stake-back market losers appear with their refund.
+    ///
+    /// Ignores existing claims: the table is the full distribution as of resolution.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::MarketNotFound` - Market does not exist
+    /// * `Error::MarketNotResolved` - No winning outcome set
+    /// * `Error::ConfigNotFound` - Fee configuration missing
+    pub fn simulate_full_distribution(
+        env: &Env,
+        market_id: &Symbol,
+    ) -> Result<Vec<(Address, i128)>, Error> {
+        let market = MarketStateManager::get_market(env, market_id)?;
+        let winning_outcomes = market
+            .winning_outcomes
+            .clone()
+            .ok_or(Error::MarketNotResolved)?;
 
-            if normalized_total < normalized_min {
-                return Err(Error::InvalidState);
+        let mut participants: Vec<(Address, i128, bool)> = Vec::new(env);
+        for (user, outcome) in market.votes.iter() {
+            let stake = market.stakes.get(user.clone()).unwrap_or(0);
+            participants.push_back((user, stake, winning_outcomes.contains(&outcome)));
+        }
+        for user in BetStorage::get_all_bets_for_market(env, market_id).iter() {
+            if market.votes.contains_key(user.clone()) {
+                continue;
+            }
+            if let Some(bet) = BetStorage::get_bet(env, market_id, &user) {
+                let won = winning_outcomes.contains(&bet.outcome);
+                participants.push_back((user, bet.amount, won));
             }
         }
 
-        Ok(())
-    }
-
-    /// Validate admin permissions
-    pub fn validate_admin_permissions(env: &Env, admin: &Address) -> Result<(), Error> {
-        let stored_admin: Option<Address> =
-            env.storage().persistent().get(&Symbol::new(env, "Admin"));
+        let mut winning_total: i128 = 0;
+        for (_, stake, won) in participants.iter() {
+            if won {
+                winning_total = winning_total
+                    .checked_add(stake)
+                    .ok_or(Error::InvalidInput)?;
+            }
+        }
+        let pools = (market.total_staked, winning_total);
+        let mut pool_left = market.total_staked;
+        let mut bonus_left = crate::fees::BonusPool::get_balance(env);
 
-        match stored_admin {
-            Some(stored_admin) => {
-                if admin != &stored_admin {
-                    return Err(Error::Unauthorized);
+        let mut table = Vec::new(env);
+        for (user, stake, won) in participants.iter() {
+            let quote = crate::fees::WinnerPayouts::quote(
+                env,
+                market_id,
+                &market,
+                Some(&user),
+                stake,
+                won,
+                pools,
+            )?;
+            let payout = match market.payout_model {
+                PayoutModel::Parimutuel => {
+                    let payout = quote.payout.min(pool_left.max(0));
+                    pool_left -= payout;
+                    payout
                 }
-                Ok(())
+                PayoutModel::StakeBackPlusBonus(_) => {
+                    let bonus = quote.bonus.min(bonus_left);
+                    bonus_left -= bonus;
+                    quote.payout - (quote.bonus - bonus)
+                }
+            };
+            if payout > 0 {
+                table.push_back((user, payout));
             }
-            None => Err(Error::Unauthorized),
         }
+
+        Ok(table)
     }
+}
 
-    /// Validate outcome
-    pub fn validate_outcome(
-        _env: &Env,
-        outcome: &String,
-        valid_outcomes: &Vec<String>,
-    ) -> Result<(), Error> {
-        if !valid_outcomes.contains(outcome) {
-            return Err(Error::InvalidOutcome);
-        }
+// ===== MARKET GROUPS =====
 
-        Ok(())
+/// Correlation lock for markets that must be resolved together.
+///
+/// Once a market joins a group, every individual resolution path rejects it with
+/// `Error::MarketGrouped`; the only way to settle it is `resolve_group`, which
+/// validates every member's outcome before writing any of them.
+pub struct MarketGroupManager;
+
+impl MarketGroupManager {
+    fn group_key(group_id: &Symbol) -> (Symbol, Symbol) {
+        (symbol_short!("mkt_grp"), group_id.clone())
     }
 
-    /// Validate market resolution
-    pub fn validate_market_resolution(
+    fn member_key(market_id: &Symbol) -> (Symbol, Symbol) {
+        (symbol_short!("grp_of"), market_id.clone())
+    }
+
+    /// Get a group by ID.
+    pub fn get_group(env: &Env, group_id: &Symbol) -> Option<MarketGroup> {
+        env.storage().persistent().get(&Self::group_key(group_id))
+    }
+
+    /// Group a market belongs to, if any.
+    pub fn group_of(env: &Env, market_id: &Symbol) -> Option<Symbol> {
+        env.storage().persistent().get(&Self::member_key(market_id))
+    }
+
+    /// Reject markets that are locked into an unresolved group.
+    pub fn require_ungrouped(env: &Env, market_id: &Symbol) -> Result<(), Error> {
+        match Self::group_of(env, market_id) {
+            Some(_) => Err(Error::MarketGrouped),
+            None => Ok(()),
+        }
+    }
+
+    /// Create a resolution group (admin only).
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Unauthorized` - `admin` is not the contract admin
+    /// * `Error::InvalidInput` - Group ID in use, fewer than 2 members, or duplicate members
+    /// * `Error::MarketNotFound` - A member market does not exist
+    /// * `Error::MarketResolved` - A member market is already resolved
+    /// * `Error::MarketGrouped` - A member market already belongs to a group
+    pub fn create_group(
         env: &Env,
-        resolution: &MarketResolution,
+        admin: &Address,
+        group_id: &Symbol,
+        members: &Vec<Symbol>,
     ) -> Result<(), Error> {
-        // Validate final outcome is not empty
-        if resolution.final_outcome.is_empty() {
+        MarketResolutionValidator::validate_admin_permissions(env, admin)?;
+
+        if Self::get_group(env, group_id).is_some() || members.len() < 2 {
             return Err(Error::InvalidInput);
         }
 
-        // Validate confidence score is within range
-        if resolution.confidence_score > 100 {
-            return Err(Error::InvalidInput);
+        for (i, market_id) in members.iter().enumerate() {
+            if members.first_index_of(&market_id) != Some(i as u32) {
+                return Err(Error::InvalidInput);
+            }
+            let market = MarketStateManager::get_market(env, &market_id)?;
+            if market.winning_outcomes.is_some() {
+                return Err(Error::MarketResolved);
+            }
+            Self::require_ungrouped(env, &market_id)?;
         }
 
-        // Validate timestamp is reasonable
-        let current_time = env.ledger().timestamp();
-        if resolution.resolution_timestamp > current_time {
-            return Err(Error::InvalidInput);
+        for market_id in members.iter() {
+            env.storage()
+                .persistent()
+                .set(&Self::member_key(&market_id), group_id);
         }
+        let group = MarketGroup {
+            group_id: group_id.clone(),
+            members: members.clone(),
+            resolved: false,
+        };
+        env.storage()
+            .persistent()
+            .set(&Self::group_key(group_id), &group);
 
         Ok(())
     }
-}
-
-// ===== RESOLUTION ANALYTICS =====
-
-/// Oracle resolution analytics
-pub struct OracleResolutionAnalytics;
 
-impl OracleResolutionAnalytics {
-    /// Calculate oracle confidence score
-    pub fn calculate_confidence_score(resolution: &OracleResolution) -> u32 {
-        // Base confidence for oracle resolution
-        let mut confidence: u32 = 80;
+    /// Resolve every market in a group in one call (admin only).
+    ///
+    /// `outcomes[i]` is the final outcome for `members[i]`. All members are
+    /// validated before any is written, so the group is settled all-or-nothing.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::Unauthorized` - `resolver` is not the contract admin
+    /// * `Error::InvalidInput` - Unknown group or `outcomes` length differs from member count
+    /// * `Error::MarketResolved` - Group or a member market is already resolved
+    /// * `Error::MarketClosed` - A member market has not ended yet
+    /// * `Error::InvalidOutcome` - An outcome is not valid for its market
+    pub fn resolve_group(
+        env: &Env,
+        resolver: &Address,
+        group_id: &Symbol,
+        outcomes: &Vec<String>,
+    ) -> Result<Vec<MarketResolution>, Error> {
+        MarketResolutionValidator::validate_admin_permissions(env, resolver)?;
+
+        let mut group = Self::get_group(env, group_id).ok_or(Error::InvalidInput)?;
+        if group.resolved {
+            return Err(Error::MarketResolved);
+        }
+        if outcomes.len() != group.members.len() {
+            return Err(Error::InvalidInput);
+        }
 
-        // Adjust based on price deviation from threshold
-        let deviation = ((resolution.price - resolution.threshold).abs() as f64)
-            / (resolution.threshold as f64);
+        // Validate every member before touching any of them
+        for (market_id, outcome) in group.members.iter().zip(outcomes.iter()) {
+            let market = MarketStateManager::get_market(env, &market_id)?;
+            if market.winning_outcomes.is_some()
+                || !matches!(market.state, MarketState::Active | MarketState::Ended)
+            {
+                return Err(Error::MarketResolved);
+            }
+            if market.is_active(env) {
+                return Err(Error::MarketClosed);
+            }
+            MarketValidator::assert_valid_outcome(&market, &outcome)?;
+        }
 
-        if deviation > 0.1 {
-            // High deviation - lower confidence
-            confidence = confidence.saturating_sub(20);
-        } else if deviation < 0.05 {
-            // Low deviation - higher confidence
-            confidence = confidence.saturating_add(10);
+        let mut resolutions = Vec::new(env);
+        for (market_id, outcome) in group.members.iter().zip(outcomes.iter()) {
+            resolutions.push_back(MarketResolutionManager::apply_final_outcome(
+                env, &market_id, &outcome,
+            )?);
+            env.storage()
+                .persistent()
+                .remove(&Self::member_key(&market_id));
         }
 
-        confidence.min(100)
-    }
+        group.resolved = true;
+        env.storage()
+            .persistent()
+            .set(&Self::group_key(group_id), &group);
 
-    /// Get oracle resolution statistics
-    pub fn get_oracle_stats(_env: &Env) -> Result<OracleStats, Error> {
-        Ok(OracleStats::default())
+        Ok(resolutions)
     }
 }
 
-/// Market resolution analytics
-pub struct MarketResolutionAnalytics;
+// ===== AUTO RESOLUTION =====
 
-impl MarketResolutionAnalytics {
-    /// Determine resolution method
-    pub fn determine_resolution_method(
-        _oracle_result: &String,
-        community_consensus: &CommunityConsensus,
-    ) -> ResolutionMethod {
-        if community_consensus.percentage > 70 {
-            ResolutionMethod::Hybrid
-        } else {
-            ResolutionMethod::OracleOnly
-        }
-    }
+/// Per-market opt-in to oracle resolution on the first interaction after close.
+///
+/// When enabled, a `vote` arriving after `end_time` resolves the market from
+/// its oracle instead of failing with `Error::MarketClosed`, so resolution does
+/// not wait for a keeper. Disabled by default, which keeps the existing behavior.
+pub struct AutoResolve;
 
-    /// Calculate confidence score
-    pub fn calculate_confidence_score(
-        _oracle_result: &String,
-        community_consensus: &CommunityConsensus,
-        method: &ResolutionMethod,
-    ) -> u32 {
-        match method {
-            ResolutionMethod::OracleOnly => 85,
-            ResolutionMethod::CommunityOnly => {
-                let base_confidence = community_consensus.percentage as u32;
-                base_confidence.min(90)
-            }
-            ResolutionMethod::Hybrid => {
-                let oracle_confidence = 85;
-                let community_confidence = community_consensus.percentage as u32;
-                ((oracle_confidence + community_confidence) / 2).min(95)
-            }
-            ResolutionMethod::AdminOverride => 100,
-            ResolutionMethod::DisputeResolution => 75,
-            ResolutionMethod::ForceResolve => 100,
-        }
+impl AutoResolve {
+    fn storage_key(market_id: &Symbol) -> (Symbol, Symbol) {
+        (symbol_short!("auto_res"), market_id.clone())
     }
 
-    /// Calculate resolution analytics
-    pub fn calculate_resolution_analytics(_env: &Env) -> Result<ResolutionAnalytics, Error> {
-        Ok(ResolutionAnalytics::default())
+    /// Whether `market_id` auto-resolves on interaction after close.
+    pub fn is_enabled(env: &Env, market_id: &Symbol) -> bool {
+        env.storage()
+            .persistent()
+            .get(&Self::storage_key(market_id))
+            .unwrap_or(false)
     }
 
-    /// Update resolution analytics
-    pub fn update_resolution_analytics(
-        _env: &Env,
-        _resolution: &MarketResolution,
-    ) -> Result<(), Error> {
-        // For now, do nothing since we don't store complex types
+    /// Enable or disable auto-resolution for an unresolved market (admin only).
+    pub fn set(env: &Env, admin: &Address, market_id: &Symbol, enabled: bool) -> Result<(), Error> {
+        admin.require_auth();
+        MarketResolutionValidator::validate_admin_permissions(env, admin)?;
+        let market = MarketStateManager::get_market(env, market_id)?;
+        if market.winning_outcomes.is_some() || market.oracle_result.is_some() {
+            return Err(Error::MarketResolved);
+        }
+        env.storage()
+            .persistent()
+            .set(&Self::storage_key(market_id), &enabled);
         Ok(())
     }
 }
 
-// ===== RESOLUTION UTILITIES =====
+// ===== RESOLUTION LATENCY =====
 
-/// Resolution utility functions
-pub struct ResolutionUtils;
+/// Running sum/count of resolution latencies for operations metrics.
+///
+/// Every resolution path records `resolved_at - end_time` once, so the average
+/// can be read in O(1) without scanning markets.
+pub struct ResolutionLatencyTracker;
 
-impl ResolutionUtils {
-    /// Get resolution state for a market
-    pub fn get_resolution_state(_env: &Env, market: &Market) -> ResolutionState {
-        if market.winning_outcomes.is_some() {
-            ResolutionState::MarketResolved
-        } else if market.oracle_result.is_some() {
-            ResolutionState::OracleResolved
-        } else if market.total_dispute_stakes() > 0 {
-            ResolutionState::Disputed
-        } else {
-            ResolutionState::Active
-        }
+impl ResolutionLatencyTracker {
+    fn storage_key() -> Symbol {
+        symbol_short!("res_lat")
     }
 
-    /// Check if market can be resolved
-    pub fn can_resolve_market(env: &Env, market: &Market) -> bool {
-        market.has_ended(env) && market.oracle_result.is_some() && market.winning_outcomes.is_none()
+    /// Current running totals (zeroed if nothing has been recorded).
+    pub fn get_stats(env: &Env) -> ResolutionLatencyStats {
+        env.storage()
+            .persistent()
+            .get(&Self::storage_key())
+            .unwrap_or_default()
     }
 
-    /// Get resolution eligibility
-    pub fn get_resolution_eligibility(env: &Env, market: &Market) -> (bool, String) {
-        if !market.has_ended(env) {
-            return (false, String::from_str(env, "Market has not ended"));
-        }
-
-        if market.oracle_result.is_none() {
-            return (false, String::from_str(env, "Oracle result not available"));
-        }
+    /// Record that a market ending at `end_time` was resolved at the current ledger time.
+    pub fn record(env: &Env, end_time: u64) {
+        let mut stats = Self::get_stats(env);
+        stats.record(end_time, env.ledger().timestamp());
+        env.storage().persistent().set(&Self::storage_key(), &stats);
+    }
+}
 
-        if market.winning_outcomes.is_some() {
-            return (false, String::from_str(env, "Market already resolved"));
-        }
+// ===== RESOLUTION DISTRIBUTION =====
 
-        (true, String::from_str(env, "Eligible for resolution"))
+/// Contract-wide count of resolved markets per winning outcome label.
+///
+/// Counts are keyed by the label text only, so "yes" on one market and "yes"
+/// on another share a bucket; it says nothing about any individual market.
+/// A tie adds one to each winning label. At most
+/// [`crate::config::MAX_RESOLUTION_LABELS`] labels are tracked; new labels
+/// beyond that are dropped while existing ones keep counting.
+pub struct ResolutionDistribution;
+
+impl ResolutionDistribution {
+    fn storage_key() -> Symbol {
+        symbol_short!("res_dist")
     }
 
-    /// Calculate resolution time
-    pub fn calculate_resolution_time(env: &Env, market: &Market) -> u64 {
-        let current_time = env.ledger().timestamp();
-        if current_time > market.end_time {
-            current_time - market.end_time
-        } else {
-            0
-        }
+    /// Resolved-market count per outcome label.
+    pub fn get(env: &Env) -> Map<String, u32> {
+        env.storage()
+            .persistent()
+            .get(&Self::storage_key())
+            .unwrap_or_else(|| Map::new(env))
     }
 
-    /// Validate resolution parameters
-    pub fn validate_resolution_parameters(
-        _env: &Env,
-        market: &Market,
-        outcome: &String,
-    ) -> Result<(), Error> {
-        // Validate outcome is in market outcomes
-        if !market.outcomes.contains(outcome) {
-            return Err(Error::InvalidOutcome);
+    /// Count the winning outcome(s) of a just-resolved `market`.
+    pub fn record(env: &Env, market: &Market) {
+        let winners = match &market.winning_outcomes {
+            Some(winners) => winners,
+            None => return,
+        };
+        let mut counts = Self::get(env);
+        for label in winners.iter() {
+            match counts.get(label.clone()) {
+                Some(count) => counts.set(label, count.saturating_add(1)),
+                None if counts.len() < crate::config::MAX_RESOLUTION_LABELS => {
+                    counts.set(label, 1)
+                }
+                None => {}
+            }
         }
+        env.storage().persistent().set(&Self::storage_key(), &counts);
+    }
 
-        // Validate market is not already resolved
-        if market.winning_outcomes.is_some() {
-            return Err(Error::MarketResolved);
+    /// Move a corrected `market` from the `previous` winning outcome(s) it was
+    /// counted under to its current ones.
+    pub fn record_correction(env: &Env, previous: &Vec<String>, market: &Market) {
+        let mut counts = Self::get(env);
+        for label in previous.iter() {
+            match counts.get(label.clone()) {
+                Some(count) if count > 1 => counts.set(label, count - 1),
+                Some(_) => {
+                    counts.remove(label);
+                }
+                None => {}
+            }
         }
-
-        Ok(())
+        env.storage().persistent().set(&Self::storage_key(), &counts);
+        Self::record(env, market);
     }
 }
 
-// ===== RESOLUTION TESTING =====
+// ===== RESOLUTION RECORDS =====
 
-/// Resolution testing utilities
-pub struct ResolutionTesting;
+/// Per-market [`ResolutionRecord`] left by the admin resolution paths.
+pub struct ResolutionRecords;
 
-impl ResolutionTesting {
-    /// Create test oracle resolution
-    pub fn create_test_oracle_resolution(env: &Env, market_id: &Symbol) -> OracleResolution {
-        OracleResolution {
+impl ResolutionRecords {
+    fn storage_key(market_id: &Symbol) -> (Symbol, Symbol) {
+        (symbol_short!("res_rec"), market_id.clone())
+    }
+
+    /// Record that `resolved_by` resolved `market_id` to `winning_outcomes` now.
+    pub fn record(
+        env: &Env,
+        market_id: &Symbol,
+        winning_outcomes: &Vec<String>,
+        resolved_by: &Address,
+        evidence: Option<String>,
+    ) {
+        let record = ResolutionRecord {
             market_id: market_id.clone(),
-            oracle_result: String::from_str(env, "yes"),
-            price: 2500000,
-            threshold: 2500000,
-            comparison: String::from_str(env, "gt"),
+            winning_outcomes: winning_outcomes.clone(),
+            resolved_by: resolved_by.clone(),
+            evidence,
             timestamp: env.ledger().timestamp(),
-            provider: OracleProvider::pyth(),
-            feed_id: String::from_str(env, "BTC/USD"),
-        }
+        };
+        env.storage()
+            .persistent()
+            .set(&Self::storage_key(market_id), &record);
     }
 
-    /// Create test market resolution
-    pub fn create_test_market_resolution(env: &Env, market_id: &Symbol) -> MarketResolution {
-        MarketResolution {
-            market_id: market_id.clone(),
-            final_outcome: String::from_str(env, "yes"),
-            oracle_result: String::from_str(env, "yes"),
-            community_consensus: CommunityConsensus {
-                outcome: String::from_str(env, "yes"),
-                votes: 6,
-                total_votes: 10,
-                percentage: 60,
-            },
-            resolution_timestamp: env.ledger().timestamp(),
-            resolution_method: ResolutionMethod::Hybrid,
-            confidence_score: 80,
-        }
+    pub fn get(env: &Env, market_id: &Symbol) -> Option<ResolutionRecord> {
+        env.storage().persistent().get(&Self::storage_key(market_id))
     }
+}
 
-    /// Validate resolution structure
-    pub fn validate_resolution_structure(resolution: &MarketResolution) -> Result<(), Error> {
-        if resolution.final_outcome.is_empty() {
-            return Err(Error::InvalidInput);
-        }
+// ===== CREATOR LEADERBOARD =====
 
-        if resolution.confidence_score > 100 {
-            return Err(Error::InvalidInput);
-        }
+/// Creators ranked by cumulative resolved-market volume.
+///
+/// Per-creator totals are kept individually; only the top
+/// [`crate::config::MAX_TOP_CREATORS`] are kept in the ranked list, so reading
+/// it is O(N) in that cap and each resolution touches at most N entries.
+/// Equal volumes keep the creator who reached it first ahead.
+pub struct CreatorLeaderboard;
+
+impl CreatorLeaderboard {
+    fn list_key() -> Symbol {
+        symbol_short!("top_crt")
+    }
 
-        Ok(())
+    /// Cumulative resolved volume of `creator` (0 if none).
+    pub fn get_volume(env: &Env, creator: &Address) -> i128 {
+        env.storage()
+            .persistent()
+            .get(&(symbol_short!("crt_vol"), creator.clone()))
+            .unwrap_or(0)
     }
 
-    /// Simulate resolution process
-    pub fn simulate_resolution_process(
-        env: &Env,
-        market_id: &Symbol,
-    ) -> Result<MarketResolution, Error> {
-        // Fetch oracle result
-        let _oracle_resolution = OracleResolutionManager::fetch_oracle_result(env, market_id)?;
+    /// Ranked leaderboard, highest volume first.
+    pub fn get_top(env: &Env) -> Vec<CreatorVolume> {
+        env.storage()
+            .persistent()
+            .get(&Self::list_key())
+            .unwrap_or_else(|| Vec::new(env))
+    }
 
-        // Resolve market
-        let market_resolution = MarketResolutionManager::resolve_market(env, market_id)?;
+    /// Add a resolved market's `volume` to `creator` and re-rank them.
+    pub fn record(env: &Env, creator: &Address, volume: i128) {
+        if volume <= 0 {
+            return;
+        }
+        let total = Self::get_volume(env, creator).saturating_add(volume);
+        env.storage()
+            .persistent()
+            .set(&(symbol_short!("crt_vol"), creator.clone()), &total);
 
-        Ok(market_resolution)
+        let mut ranked = Vec::new(env);
+        let mut placed = false;
+        for entry in Self::get_top(env).iter() {
+            if entry.creator == *creator {
+                continue;
+            }
+            if !placed && total > entry.volume {
+                ranked.push_back(CreatorVolume {
+                    creator: creator.clone(),
+                    volume: total,
+                });
+                placed = true;
+            }
+            ranked.push_back(entry);
+        }
+        if !placed {
+            ranked.push_back(CreatorVolume {
+                creator: creator.clone(),
+                volume: total,
+            });
+        }
+        while ranked.len() > crate::config::MAX_TOP_CREATORS {
+            ranked.pop_back();
+        }
+        env.storage().persistent().set(&Self::list_key(), &ranked);
     }
 }
 
-// ===== STATISTICS TYPES =====
+// ===== RESOLUTION VALIDATION =====
 
-/// Oracle statistics
-#[derive(Clone, Debug)]
-#[contracttype]
-pub struct OracleStats {
-    pub total_resolutions: u32,
-    pub successful_resolutions: u32,
-    pub average_confidence: i128,
-    pub provider_distribution: Map<OracleProvider, u32>,
-}
+/// Oracle resolution validation
+pub struct OracleResolutionValidator;
 
-impl Default for OracleStats {
-    fn default() -> Self {
+impl OracleResolutionValidator {
+    /// Validate market for oracle resolution
+    pub fn validate_market_for_oracle_resolution(env: &Env, market: &Market) -> Result<(), Error> {
+        // Check if the market has already been resolved
+        if market.oracle_result.is_some() {
+            return Err(Error::MarketResolved);
+        }
+
+        // Check if the market ended (we can only fetch oracle result after market ends)
+        let current_time = env.ledger().timestamp();
+        if current_time < market.end_time {
+            return Err(Error::MarketClosed);
+        }
+
+        Ok(())
+    }
+
+    /// Validate oracle resolution
+    ///
+    /// Prices and thresholds are signed: feeds such as funding rates or spreads
+    /// legitimately report zero or negative values, so only the outcome is checked.
+    pub fn validate_oracle_resolution(
+        _env: &Env,
+        resolution: &OracleResolution,
+    ) -> Result<(), Error> {
+        // Validate outcome is not empty
+        if resolution.oracle_result.is_empty() {
+            return Err(Error::InvalidInput);
+        }
+
+        Ok(())
+    }
+}
+
+/// Market resolution validation
+pub struct MarketResolutionValidator;
+
+impl MarketResolutionValidator {
+    /// Validate market for resolution
+    pub fn validate_market_for_resolution(env: &Env, market: &Market) -> Result<(), Error> {
+        // Check if market is already resolved
+        if market.winning_outcomes.is_some() {
+            return Err(Error::MarketResolved);
+        }
+
+        // Check if oracle result is available
+        if market.oracle_result.is_none() {
+            return Err(Error::OracleUnavailable);
+        }
+
+        // Check if market has ended
+        if market.is_active(env) {
+            return Err(Error::MarketClosed);
+        }
+
+        // Check minimum pool size requirement (per-market override, else global)
+        let global_min: i128 = env
+            .storage()
+            .persistent()
+            .get(&Symbol::new(env, "global_min_pool"))
+            .unwrap_or(0);
+        let min_pool = market.min_pool_size.unwrap_or(global_min);
+
+        // Only check if min pool is set
+        if min_pool > 0 {
+            // Get token decimals to normalize amounts for comparison
+            let token_client = crate::markets::MarketUtils::get_token_client(env)?;
+            let token_decimals = token_client.decimals() as u32;
+
+            // Normalize both total staked and min pool to canonical scale for comparison
+            let normalized_total = crate::tokens::normalize_amount(market.total_staked, token_decimals);
+            let normalized_min = crate::tokens::normalize_amount(min_pool, token_decimals);
+
+            if normalized_total < normalized_min {
+                return Err(Error::InvalidState);
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Validate admin permissions
+    pub fn validate_admin_permissions(env: &Env, admin: &Address) -> Result<(), Error> {
+        let stored_admin: Option<Address> =
+            env.storage().persistent().get(&Symbol::new(env, "Admin"));
+
+        match stored_admin {
+            Some(stored_admin) => {
+                if admin != &stored_admin {
+                    return Err(Error::Unauthorized);
+                }
+                Ok(())
+            }
+            None => Err(Error::Unauthorized),
+        }
+    }
+
+    /// Validate outcome
+    pub fn validate_outcome(
+        _env: &Env,
+        outcome: &String,
+        valid_outcomes: &Vec<String>,
+    ) -> Result<(), Error> {
+        if !valid_outcomes.contains(outcome) {
+            return Err(Error::InvalidOutcome);
+        }
+
+        Ok(())
+    }
+
+    /// Check that `oracle_result` and `winning_outcomes` agree with each other.
+    ///
+    /// A settled (`Resolved` or `Closed`) market that carries an oracle result
+    /// must also carry its winning outcomes, and recorded winning outcomes must
+    /// never be empty. An oracle result on an unsettled market is fine: it is
+    /// fetched before resolution applies it. Manual resolution may set winning
+    /// outcomes without an oracle result.
+    ///
+    /// # Errors
+    ///
+    /// * `Error::InvalidState` - The two fields are inconsistent
+    pub fn reconcile_oracle_fields(market: &Market) -> Result<(), Error> {
+        if let Some(ref outcomes) = market.winning_outcomes {
+            if outcomes.is_empty() {
+                return Err(Error::InvalidState);
+            }
+        }
+
+        let settled = matches!(market.state, MarketState::Resolved | MarketState::Closed);
+        if settled && market.oracle_result.is_some() && market.winning_outcomes.is_none() {
+            return Err(Error::InvalidState);
+        }
+
+        Ok(())
+    }
+
+    /// Validate market resolution
+    pub fn validate_market_resolution(
+        env: &Env,
+        resolution: &MarketResolution,
+    ) -> Result<(), Error> {
+        // Validate final outcome is not empty
+        if resolution.final_outcome.is_empty() {
+            return Err(Error::InvalidInput);
+        }
+
+        // Validate confidence score is within range
+        if resolution.confidence_score > 100 {
+            return Err(Error::InvalidInput);
+        }
+
+        // Validate timestamp is reasonable
+        let current_time = env.ledger().timestamp();
+        if resolution.resolution_timestamp > current_time {
+            return Err(Error::InvalidInput);
+        }
+
+        Ok(())
+    }
+}
+
+// ===== RESOLUTION ANALYTICS =====
+
+/// Oracle resolution analytics
+pub struct OracleResolutionAnalytics;
+
+impl OracleResolutionAnalytics {
+    /// Calculate oracle confidence score
+    pub fn calculate_confidence_score(resolution: &OracleResolution) -> u32 {
+        // Base confidence for oracle resolution
+        let mut confidence: u32 = 80;
+
+        // Adjust based on price deviation from threshold, relative to the
+        // threshold's magnitude so negative thresholds are scored like positive ones
+        let deviation = (resolution.price.saturating_sub(resolution.threshold).unsigned_abs() as f64)
+            / (resolution.threshold.unsigned_abs() as f64);
+
+        if deviation > 0.1 {
+            // High deviation - lower confidence
+            confidence = confidence.saturating_sub(20);
+        } else if deviation < 0.05 {
+            // Low deviation - higher confidence
+            confidence = confidence.saturating_add(10);
+        }
+
+        confidence.min(100)
+    }
+
+    /// Get oracle resolution statistics
+    pub fn get_oracle_stats(_env: &Env) -> Result<OracleStats, Error> {
+        Ok(OracleStats::default())
+    }
+}
+
+/// Market resolution analytics
+pub struct MarketResolutionAnalytics;
+
+impl MarketResolutionAnalytics {
+    /// Determine resolution method
+    pub fn determine_resolution_method(
+        _oracle_result: &String,
+        community_consensus: &CommunityConsensus,
+    ) -> ResolutionMethod {
+        if community_consensus.percentage > 70 {
+            ResolutionMethod::Hybrid
+        } else {
+            ResolutionMethod::OracleOnly
+        }
+    }
+
+    /// Calculate confidence score
+    pub fn calculate_confidence_score(
+        _oracle_result: &String,
+        community_consensus: &CommunityConsensus,
+        method: &ResolutionMethod,
+    ) -> u32 {
+        match method {
+            ResolutionMethod::OracleOnly => 85,
+            ResolutionMethod::CommunityOnly => {
+                let base_confidence = community_consensus.percentage as u32;
+                base_confidence.min(90)
+            }
+            ResolutionMethod::Hybrid => {
+                let oracle_confidence = 85;
+                let community_confidence = community_consensus.percentage as u32;
+                ((oracle_confidence + community_confidence) / 2).min(95)
+            }
+            ResolutionMethod::AdminOverride => 100,
+            ResolutionMethod::DisputeResolution => 75,
+            ResolutionMethod::ForceResolve => 100,
+        }
+    }
+
+    /// Calculate resolution analytics
+    pub fn calculate_resolution_analytics(_env: &Env) -> Result<ResolutionAnalytics, Error> {
+        Ok(ResolutionAnalytics::default())
+    }
+
+    /// Update resolution analytics
+    pub fn update_resolution_analytics(
+        _env: &Env,
+        _resolution: &MarketResolution,
+    ) -> Result<(), Error> {
+        // For now, do nothing since we don't store complex types
+        Ok(())
+    }
+}
+
+// ===== RESOLUTION UTILITIES =====
+
+/// Resolution utility functions
+pub struct ResolutionUtils;
+
+impl ResolutionUtils {
+    /// Get resolution state for a market
+    pub fn get_resolution_state(_env: &Env, market: &Market) -> ResolutionState {
+        if market.winning_outcomes.is_some() {
+            ResolutionState::MarketResolved
+        } else if market.oracle_result.is_some() {
+            ResolutionState::OracleResolved
+        } else if market.total_dispute_stakes() > 0 {
+            ResolutionState::Disputed
+        } else {
+            ResolutionState::Active
+        }
+    }
+
+    /// Check if market can be resolved
+    pub fn can_resolve_market(env: &Env, market: &Market) -> bool {
+        market.has_ended(env) && market.oracle_result.is_some() && market.winning_outcomes.is_none()
+    }
+
+    /// Get resolution eligibility
+    pub fn get_resolution_eligibility(env: &Env, market: &Market) -> (bool, String) {
+        if !market.has_ended(env) {
+            return (false, String::from_str(env, "Market has not ended"));
+        }
+
+        if market.oracle_result.is_none() {
+            return (false, String::from_str(env, "Oracle result not available"));
+        }
+
+        if market.winning_outcomes.is_some() {
+            return (false, String::from_str(env, "Market already resolved"));
+        }
+
+        (true, String::from_str(env, "Eligible for resolution"))
+    }
+
+    /// Calculate resolution time
+    pub fn calculate_resolution_time(env: &Env, market: &Market) -> u64 {
+        let current_time = env.ledger().timestamp();
+        if current_time > market.end_time {
+            current_time - market.end_time
+        } else {
+            0
+        }
+    }
+
+    /// Validate resolution parameters
+    pub fn validate_resolution_parameters(
+        _env: &Env,
+        market: &Market,
+        outcome: &String,
+    ) -> Result<(), Error> {
+        // Validate outcome is in market outcomes
+        if !market.outcomes.contains(outcome) {
+            return Err(Error::InvalidOutcome);
+        }
+
+        // Validate market is not already resolved
+        if market.winning_outcomes.is_some() {
+            return Err(Error::MarketResolved);
+        }
+
+        Ok(())
+    }
+}
+
+// ===== RESOLUTION TESTING =====
+
+/// Resolution testing utilities
+pub struct ResolutionTesting;
+
+impl ResolutionTesting {
+    /// Create test oracle resolution
+    pub fn create_test_oracle_resolution(env: &Env, market_id: &Symbol) -> OracleResolution {
+        OracleResolution {
+            market_id: market_id.clone(),
+            oracle_result: String::from_str(env, "yes"),
+            price: 2500000,
+            threshold: 2500000,
+            comparison: ComparisonOp::Gt,
+            timestamp: env.ledger().timestamp(),
+            provider: OracleProvider::pyth(),
+            feed_id: String::from_str(env, "BTC/USD"),
+        }
+    }
+
+    /// Create test market resolution
+    pub fn create_test_market_resolution(env: &Env, market_id: &Symbol) -> MarketResolution {
+        MarketResolution {
+            market_id: market_id.clone(),
+            final_outcome: String::from_str(env, "yes"),
+            oracle_result: String::from_str(env, "yes"),
+            community_consensus: CommunityConsensus {
+                outcome: String::from_str(env, "yes"),
+                votes: 6,
+                total_votes: 10,
+                percentage: 60,
+            },
+            resolution_timestamp: env.ledger().timestamp(),
+            resolution_method: ResolutionMethod::Hybrid,
+            confidence_score: 80,
+        }
+    }
+
+    /// Validate resolution structure
+    pub fn validate_resolution_structure(resolution: &MarketResolution) -> Result<(), Error> {
+        if resolution.final_outcome.is_empty() {
+            return Err(Error::InvalidInput);
+        }
+
+        if resolution.confidence_score > 100 {
+            return Err(Error::InvalidInput);
+        }
+
+        Ok(())
+    }
+
+    /// Simulate resolution process
+    pub fn simulate_resolution_process(
+        env: &Env,
+        market_id: &Symbol,
+    ) -> Result<MarketResolution, Error> {
+        // Fetch oracle result
+        let _oracle_resolution = OracleResolutionManager::fetch_oracle_result(env, market_id)?;
+
+        // Resolve market
+        let market_resolution = MarketResolutionManager::resolve_market(env, market_id)?;
+
+        Ok(market_resolution)
+    }
+}
+
+// ===== STATISTICS TYPES =====
+
+/// Oracle statistics
+#[derive(Clone, Debug)]
+#[contracttype]
+pub struct OracleStats {
+    pub total_resolutions: u32,
+    pub successful_resolutions: u32,
+    pub average_confidence: i128,
+    pub provider_distribution: Map<OracleProvider, u32>,
+}
+
+impl Default for OracleStats {
+    fn default() -> Self {
         Self {
             total_resolutions: 0,
             successful_resolutions: 0,
@@ -1966,144 +2785,1148 @@ impl Default for OracleStats {
     }
 }
 
-impl Default for ResolutionAnalytics {
-    fn default() -> Self {
-        Self {
-            total_resolutions: 0,
-            oracle_resolutions: 0,
-            community_resolutions: 0,
-            hybrid_resolutions: 0,
-            average_confidence: 0,
-            resolution_times: Vec::new(&soroban_sdk::Env::default()),
-            outcome_distribution: Map::new(&soroban_sdk::Env::default()),
+impl Default for ResolutionAnalytics {
+    fn default() -> Self {
+        Self {
+            total_resolutions: 0,
+            oracle_resolutions: 0,
+            community_resolutions: 0,
+            hybrid_resolutions: 0,
+            average_confidence: 0,
+            resolution_times: Vec::new(&soroban_sdk::Env::default()),
+            outcome_distribution: Map::new(&soroban_sdk::Env::default()),
+        }
+    }
+}
+
+// ===== MODULE TESTS =====
+
+#[cfg(any())]
+mod tests {
+    use super::*;
+    use crate::{test::PredictifyTest, PredictifyHybridClient};
+    use soroban_sdk::testutils::{Address as _, Ledger, LedgerInfo};
+
+    #[test]
+    fn test_oracle_resolution_manager_fetch_result() {
+        let env = Env::default();
+        let market_id = Symbol::new(&env, "test_market");
+        let _oracle_contract = Address::generate(&env);
+
+        // This test would require a mock oracle setup
+        // For now, we'll test the validation logic
+        let resolution = ResolutionTesting::create_test_oracle_resolution(&env, &market_id);
+        assert_eq!(resolution.oracle_result, String::from_str(&env, "yes"));
+        assert_eq!(resolution.price, 2500000);
+    }
+
+    #[test]
+    fn test_market_resolution_manager_resolve_market() {
+        let env = Env::default();
+        let market_id = Symbol::new(&env, "test_market");
+
+        // This test would require a complete market setup
+        // For now, we'll test the resolution structure
+        let resolution = ResolutionTesting::create_test_market_resolution(&env, &market_id);
+        assert_eq!(resolution.final_outcome, String::from_str(&env, "yes"));
+        assert_eq!(resolution.resolution_method, ResolutionMethod::Hybrid);
+    }
+
+    #[test]
+    fn test_resolution_utils_get_state() {
+        let env = Env::default();
+        let admin = Address::generate(&env);
+        let market = Market::new(
+            &env,
+            admin,
+            String::from_str(&env, "Test Market"),
+            soroban_sdk::vec![
+                &env,
+                String::from_str(&env, "yes"),
+                String::from_str(&env, "no"),
+            ],
+            env.ledger().timestamp() + 86400,
+            OracleConfig {
+                provider: OracleProvider::pyth(),
+                oracle_address: Address::generate(&env),
+                feed_id: String::from_str(&env, "BTC/USD"),
+                threshold: 2500000,
+                comparison: ComparisonOp::Gt,
+                threshold2: None,
+            },
+            None,
+            86400,
+            MarketState::Active,
+        );
+
+        let state = ResolutionUtils::get_resolution_state(&env, &market);
+        assert_eq!(state, ResolutionState::Active);
+    }
+
+    #[test]
+    fn test_resolution_analytics_determine_method() {
+        let env = Env::default();
+        let oracle_result = String::from_str(&env, "yes");
+        let community_consensus = CommunityConsensus {
+            outcome: String::from_str(&env, "yes"),
+            votes: 8,
+            total_votes: 10,
+            percentage: 80,
+        };
+
+        let method = MarketResolutionAnalytics::determine_resolution_method(
+            &oracle_result,
+            &community_consensus,
+        );
+        assert_eq!(method, ResolutionMethod::Hybrid);
+    }
+
+    #[test]
+    fn test_resolution_testing_utilities() {
+        let env = Env::default();
+        let market_id = Symbol::new(&env, "test_market");
+
+        let oracle_resolution = ResolutionTesting::create_test_oracle_resolution(&env, &market_id);
+        assert!(oracle_resolution.oracle_result == String::from_str(&env, "yes"));
+
+        let market_resolution = ResolutionTesting::create_test_market_resolution(&env, &market_id);
+        assert!(ResolutionTesting::validate_resolution_structure(&market_resolution).is_ok());
+    }
+
+    #[test]
+    fn test_resolution_method_determination() {
+        let env = Env::default();
+
+        // Create test data
+        let community_consensus = CommunityConsensus {
+            outcome: String::from_str(&env, "yes"),
+            votes: 75,
+            total_votes: 100,
+            percentage: 75,
+        };
+
+        // Test hybrid resolution
+        let method = MarketResolutionAnalytics::determine_resolution_method(
+            &String::from_str(&env, "yes"),
+            &community_consensus,
+        );
+        assert!(matches!(method, ResolutionMethod::Hybrid));
+
+        // Test oracle-only resolution
+        let low_consensus = CommunityConsensus {
+            outcome: String::from_str(&env, "yes"),
+            votes: 60,
+            total_votes: 100,
+            percentage: 60,
+        };
+        let method = MarketResolutionAnalytics::determine_resolution_method(
+            &String::from_str(&env, "yes"),
+            &low_consensus,
+        );
+        assert!(matches!(method, ResolutionMethod::OracleOnly));
+    }
+}
+
+// ===== OUTCOME GUARD TESTS =====
+
+#[cfg(test)]
+mod outcome_guard_tests {
+    use super::*;
+    use crate::types::{OracleConfig, OracleProvider};
+    use soroban_sdk::testutils::{Address as _, Ledger};
+
+    fn setup_ended_market(env: &Env, market_id: &Symbol, oracle_result: &str) {
+        let admin = Address::generate(env);
+        let mut market = Market::new(
+            env,
+            admin,
+            String::from_str(env, "Will BTC close above 100k?"),
+            soroban_sdk::vec![
+                env,
+                String::from_str(env, "yes"),
+                String::from_str(env, "no"),
+            ],
+            env.ledger().timestamp() + 100,
+            OracleConfig::new(
+                OracleProvider::reflector(),
+                Address::generate(env),
+                String::from_str(env, "BTC"),
+                100,
+                ComparisonOp::Gt,
+            ),
+            None,
+            86400,
+            MarketState::Active,
+        );
+        market.oracle_result = Some(String::from_str(env, oracle_result));
+        env.storage().persistent().set(market_id, &market);
+        env.ledger().with_mut(|li| li.timestamp += 200);
+    }
+
+    #[test]
+    fn test_oracle_path_rejects_outcome_outside_market_outcomes() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let market_id = Symbol::new(&env, "guard_mkt");
+
+        env.as_contract(&contract_id, || {
+            setup_ended_market(&env, &market_id, "maybe");
+
+            let result = MarketResolutionManager::resolve_market(&env, &market_id);
+            assert_eq!(result.err(), Some(Error::InvalidOutcome));
+
+            let market = MarketStateManager::get_market(&env, &market_id).unwrap();
+            assert!(market.winning_outcomes.is_none());
+        });
+    }
+
+    #[test]
+    fn test_oracle_resolution_sets_result_and_winners_together() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let market_id = Symbol::new(&env, "atomic_mkt");
+
+        env.as_contract(&contract_id, || {
+            setup_ended_market(&env, &market_id, "yes");
+            let mut market = MarketStateManager::get_market(&env, &market_id).unwrap();
+            market.state = MarketState::Ended;
+            env.storage().persistent().set(&market_id, &market);
+            assert!(MarketResolutionValidator::reconcile_oracle_fields(&market).is_ok());
+
+            MarketResolutionManager::resolve_market(&env, &market_id).unwrap();
+
+            let market = MarketStateManager::get_market(&env, &market_id).unwrap();
+            let yes = String::from_str(&env, "yes");
+            assert_eq!(market.state, MarketState::Resolved);
+            assert_eq!(market.oracle_result, Some(yes.clone()));
+            assert_eq!(market.winning_outcomes, Some(soroban_sdk::vec![&env, yes]));
+            assert!(MarketResolutionValidator::reconcile_oracle_fields(&market).is_ok());
+
+            // A settled market holding only the oracle result is inconsistent
+            let mut torn = market.clone();
+            torn.winning_outcomes = None;
+            assert_eq!(
+                MarketResolutionValidator::reconcile_oracle_fields(&torn),
+                Err(Error::InvalidState)
+            );
+            torn.winning_outcomes = Some(Vec::new(&env));
+            assert_eq!(
+                MarketResolutionValidator::reconcile_oracle_fields(&torn),
+                Err(Error::InvalidState)
+            );
+        });
+    }
+
+    #[test]
+    fn test_finalize_rejects_outcome_outside_market_outcomes() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let market_id = Symbol::new(&env, "guard_fin");
+
+        env.as_contract(&contract_id, || {
+            setup_ended_market(&env, &market_id, "yes");
+            let admin = Address::generate(&env);
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "Admin"), &admin);
+
+            let result = MarketResolutionManager::finalize_market(
+                &env,
+                &admin,
+                &market_id,
+                &String::from_str(&env, "maybe"),
+            );
+            assert_eq!(result.err(), Some(Error::InvalidOutcome));
+        });
+    }
+}
+
+// ===== MARKET GROUP TESTS =====
+
+#[cfg(test)]
+mod market_group_tests {
+    use super::*;
+    use crate::types::{OracleConfig, OracleProvider};
+    use soroban_sdk::testutils::{Address as _, Ledger};
+
+    fn store_market(env: &Env, market_id: &Symbol, outcomes: &[&str]) {
+        let mut market_outcomes = Vec::new(env);
+        for outcome in outcomes {
+            market_outcomes.push_back(String::from_str(env, outcome));
+        }
+        let mut market = Market::new(
+            env,
+            Address::generate(env),
+            String::from_str(env, "Who wins the final?"),
+            market_outcomes,
+            env.ledger().timestamp() + 100,
+            OracleConfig::new(
+                OracleProvider::reflector(),
+                Address::generate(env),
+                String::from_str(env, "GAME"),
+                100,
+                ComparisonOp::Gt,
+            ),
+            None,
+            86400,
+            MarketState::Active,
+        );
+        market.oracle_result = Some(String::from_str(env, "yes"));
+        env.storage().persistent().set(market_id, &market);
+    }
+
+    fn setup(env: &Env) -> (Address, Symbol, Symbol, Symbol) {
+        let admin = Address::generate(env);
+        env.storage()
+            .persistent()
+            .set(&Symbol::new(env, "Admin"), &admin);
+
+        let team_a = Symbol::new(env, "team_a");
+        let team_b = Symbol::new(env, "team_b");
+        store_market(env, &team_a, &["yes", "no"]);
+        store_market(env, &team_b, &["yes", "no"]);
+        env.ledger().with_mut(|li| li.timestamp += 200);
+
+        let group_id = Symbol::new(env, "final");
+        MarketGroupManager::create_group(
+            env,
+            &admin,
+            &group_id,
+            &soroban_sdk::vec![env, team_a.clone(), team_b.clone()],
+        )
+        .unwrap();
+
+        (admin, group_id, team_a, team_b)
+    }
+
+    #[test]
+    fn test_grouped_market_cannot_be_resolved_individually() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+
+        env.as_contract(&contract_id, || {
+            let (admin, _, team_a, team_b) = setup(&env);
+
+            let result = MarketResolutionManager::finalize_market(
+                &env,
+                &admin,
+                &team_a,
+                &String::from_str(&env, "yes"),
+            );
+            assert_eq!(result.err(), Some(Error::MarketGrouped));
+
+            let result = MarketResolutionManager::resolve_market(&env, &team_b);
+            assert_eq!(result.err(), Some(Error::MarketGrouped));
+
+            assert!(MarketStateManager::get_market(&env, &team_a)
+                .unwrap()
+                .winning_outcomes
+                .is_none());
+        });
+    }
+
+    #[test]
+    fn test_resolve_group_settles_all_members() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+
+        env.as_contract(&contract_id, || {
+            let (admin, group_id, team_a, team_b) = setup(&env);
+            let yes = String::from_str(&env, "yes");
+            let no = String::from_str(&env, "no");
+
+            let resolutions = MarketGroupManager::resolve_group(
+                &env,
+                &admin,
+                &group_id,
+                &soroban_sdk::vec![&env, yes.clone(), no.clone()],
+            )
+            .unwrap();
+            assert_eq!(resolutions.len(), 2);
+
+            let market_a = MarketStateManager::get_market(&env, &team_a).unwrap();
+            let market_b = MarketStateManager::get_market(&env, &team_b).unwrap();
+            assert_eq!(market_a.winning_outcomes, Some(soroban_sdk::vec![&env, yes]));
+            assert_eq!(market_b.winning_outcomes, Some(soroban_sdk::vec![&env, no]));
+            // Members left Active past their end time are ended, then resolved
+            assert_eq!(market_a.state, MarketState::Resolved);
+            assert_eq!(market_b.state, MarketState::Resolved);
+            assert!(MarketGroupManager::get_group(&env, &group_id).unwrap().resolved);
+
+            // A settled group cannot be resolved again
+            let again = MarketGroupManager::resolve_group(
+                &env,
+                &admin,
+                &group_id,
+                &soroban_sdk::vec![
+                    &env,
+                    String::from_str(&env, "no"),
+                    String::from_str(&env, "yes"),
+                ],
+            );
+            assert_eq!(again.err(), Some(Error::MarketResolved));
+        });
+    }
+
+    #[test]
+    fn test_resolve_group_is_all_or_nothing() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+
+        env.as_contract(&contract_id, || {
+            let (admin, group_id, team_a, team_b) = setup(&env);
+
+            // Second outcome is invalid, so the first must not be applied either
+            let result = MarketGroupManager::resolve_group(
+                &env,
+                &admin,
+                &group_id,
+                &soroban_sdk::vec![
+                    &env,
+                    String::from_str(&env, "yes"),
+                    String::from_str(&env, "draw"),
+                ],
+            );
+            assert_eq!(result.err(), Some(Error::InvalidOutcome));
+
+            for market_id in [team_a, team_b] {
+                assert!(MarketStateManager::get_market(&env, &market_id)
+                    .unwrap()
+                    .winning_outcomes
+                    .is_none());
+            }
+            assert!(!MarketGroupManager::get_group(&env, &group_id).unwrap().resolved);
+        });
+    }
+
+    #[test]
+    fn test_resolve_group_rejects_cancelled_member() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+
+        env.as_contract(&contract_id, || {
+            let (admin, group_id, team_a, team_b) = setup(&env);
+            let mut market_b = MarketStateManager::get_market(&env, &team_b).unwrap();
+            market_b.state = MarketState::Cancelled;
+            env.storage().persistent().set(&team_b, &market_b);
+
+            let result = MarketGroupManager::resolve_group(
+                &env,
+                &admin,
+                &group_id,
+                &soroban_sdk::vec![
+                    &env,
+                    String::from_str(&env, "yes"),
+                    String::from_str(&env, "no"),
+                ],
+            );
+            assert_eq!(result.err(), Some(Error::MarketResolved));
+
+            let market_a = MarketStateManager::get_market(&env, &team_a).unwrap();
+            assert_eq!(market_a.state, MarketState::Active);
+            assert!(market_a.winning_outcomes.is_none());
+        });
+    }
+}
+
+// ===== DISTRIBUTION SIMULATION TESTS =====
+
+#[cfg(test)]
+mod distribution_simulation_tests {
+    use super::*;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+
+    #[test]
+    fn simulated_distribution_matches_the_claims() {
+        let env = Env::default();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        env.mock_all_auths();
+        client.initialize(&admin, &None, &None);
+
+        let market_id = Symbol::new(&env, "sim");
+        let yes = String::from_str(&env, "yes");
+        let no = String::from_str(&env, "no");
+        // Stakes chosen so every floor division leaves a remainder
+        let winner_stakes = [7_i128, 13, 29, 13];
+        let winners: [Address; 4] = core::array::from_fn(|_| Address::generate(&env));
+        let end_time = env.ledger().timestamp() + 1000;
+
+        let table = env.as_contract(&contract_id, || {
+            let mut market = Market::new(
+                &env,
+                admin.clone(),
+                String::from_str(&env, "Sim"),
+                soroban_sdk::vec![&env, yes.clone(), no.clone()],
+                end_time,
+                OracleConfig::new(
+                    OracleProvider::reflector(),
+                    Address::generate(&env),
+                    String::from_str(&env, "BTC/USD"),
+                    100,
+                    ComparisonOp::Gt,
+                ),
+                None,
+                86400,
+                MarketState::Active,
+            );
+            for (user, stake) in winners.iter().zip(winner_stakes) {
+                market.votes.set(user.clone(), yes.clone());
+                market.stakes.set(user.clone(), stake);
+            }
+            let loser = Address::generate(&env);
+            market.votes.set(loser.clone(), no.clone());
+            market.stakes.set(loser, 1_001);
+            market.total_staked = winner_stakes.iter().sum::<i128>() + 1_001;
+            market.dispute_window_seconds = 0;
+
+            // Unresolved markets have no distribution yet
+            env.storage().persistent().set(&market_id, &market);
+            assert_eq!(
+                MarketResolutionManager::simulate_full_distribution(&env, &market_id),
+                Err(Error::MarketNotResolved)
+            );
+
+            market.winning_outcomes = Some(soroban_sdk::vec![&env, yes.clone()]);
+            market.state = MarketState::Resolved;
+            env.storage().persistent().set(&market_id, &market);
+
+            let table =
+                MarketResolutionManager::simulate_full_distribution(&env, &market_id).unwrap();
+
+            // Nothing was claimed or paid
+            let after: Market = env.storage().persistent().get(&market_id).unwrap();
+            assert_eq!(after, market);
+            assert!(after.claimed.is_empty());
+            table
+        });
+        // The loser is owed nothing, so only the winners are listed
+        assert_eq!(table.len(), winners.len() as u32);
+
+        // Every winner's claim pays exactly the simulated amount
+        env.ledger().with_mut(|li| li.timestamp = end_time + 1);
+        for (user, payout) in table.iter() {
+            client.claim_winnings(&user, &market_id);
+            let market: Market =
+                env.as_contract(&contract_id, || env.storage().persistent().get(&market_id).unwrap());
+            assert_eq!(market.claimed.get(user).unwrap().payout_amount, payout);
         }
     }
 }
 
-// ===== MODULE TESTS =====
+// ===== MINIMUM VOTERS TESTS =====
 
-#[cfg(any())]
-mod tests {
+#[cfg(test)]
+mod min_voters_tests {
     use super::*;
-    use crate::{test::PredictifyTest, PredictifyHybridClient};
-    use soroban_sdk::testutils::{Address as _, Ledger, LedgerInfo};
+    use soroban_sdk::testutils::{Address as _, Ledger};
+
+    fn setup_ended_market(
+        env: &Env,
+        voters: u32,
+    ) -> (crate::PredictifyHybridClient<'_>, Address, Symbol) {
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(env, &contract_id);
+        let admin = Address::generate(env);
+        env.mock_all_auths();
+        client.initialize(&admin, &None, &None);
+
+        let market_id = Symbol::new(env, "breadth");
+        env.as_contract(&contract_id, || {
+            let market = Market::new(
+                env,
+                admin.clone(),
+                String::from_str(env, "Breadth"),
+                soroban_sdk::vec![env, String::from_str(env, "yes"), String::from_str(env, "no")],
+                env.ledger().timestamp() + 100,
+                OracleConfig::new(
+                    OracleProvider::reflector(),
+                    Address::generate(env),
+                    String::from_str(env, "BTC/USD"),
+                    100,
+                    ComparisonOp::Gt,
+                ),
+                None,
+                86400,
+                MarketState::Active,
+            );
+            env.storage().persistent().set(&market_id, &market);
+        });
+
+        // The floor is set before the first vote, as required
+        client.set_market_min_voters(&admin, &market_id, &Some(3));
+        env.as_contract(&contract_id, || {
+            let mut market = MarketStateManager::get_market(env, &market_id).unwrap();
+            for _ in 0..voters {
+                let voter = Address::generate(env);
+                market.votes.set(voter.clone(), String::from_str(env, "yes"));
+                market.stakes.set(voter, 100);
+                market.total_staked += 100;
+            }
+            MarketStateManager::update_market(env, &market_id, &market);
+        });
+        env.ledger().set_timestamp(env.ledger().timestamp() + 200);
+        (client, admin, market_id)
+    }
 
     #[test]
-    fn test_oracle_resolution_manager_fetch_result() {
+    fn below_min_voters_market_becomes_refundable() {
         let env = Env::default();
-        let market_id = Symbol::new(&env, "test_market");
-        let _oracle_contract = Address::generate(&env);
+        let (client, admin, market_id) = setup_ended_market(&env, 1);
 
-        // This test would require a mock oracle setup
-        // For now, we'll test the validation logic
-        let resolution = ResolutionTesting::create_test_oracle_resolution(&env, &market_id);
-        assert_eq!(resolution.oracle_result, String::from_str(&env, "yes"));
-        assert_eq!(resolution.price, 2500000);
+        client.resolve_market_manual(&admin, &market_id, &String::from_str(&env, "yes"), &None);
+
+        let market = client.get_market(&market_id).unwrap();
+        assert_eq!(market.state, MarketState::Cancelled);
+        assert!(market.winning_outcomes.is_none());
+        // Cancelled markets cannot be reconfigured
+        assert_eq!(
+            client.try_set_market_min_voters(&admin, &market_id, &Some(1)),
+            Err(Ok(Error::InvalidState))
+        );
     }
 
     #[test]
-    fn test_market_resolution_manager_resolve_market() {
+    fn at_or_above_min_voters_market_resolves() {
         let env = Env::default();
-        let market_id = Symbol::new(&env, "test_market");
+        let (client, admin, market_id) = setup_ended_market(&env, 3);
 
-        // This test would require a complete market setup
-        // For now, we'll test the resolution structure
-        let resolution = ResolutionTesting::create_test_market_resolution(&env, &market_id);
-        assert_eq!(resolution.final_outcome, String::from_str(&env, "yes"));
-        assert_eq!(resolution.resolution_method, ResolutionMethod::Hybrid);
+        client.resolve_market_manual(&admin, &market_id, &String::from_str(&env, "yes"), &None);
+
+        let market = client.get_market(&market_id).unwrap();
+        assert_eq!(market.state, MarketState::Resolved);
+        assert_eq!(
+            market.winning_outcomes,
+            Some(soroban_sdk::vec![&env, String::from_str(&env, "yes")])
+        );
+        assert_eq!(
+            client.try_set_market_min_voters(&admin, &market_id, &Some(1)),
+            Err(Ok(Error::InvalidState))
+        );
     }
 
     #[test]
-    fn test_resolution_utils_get_state() {
+    fn min_voters_is_frozen_once_staked() {
         let env = Env::default();
-        let admin = Address::generate(&env);
+        let (client, admin, market_id) = setup_ended_market(&env, 1);
+
+        // Neither raising the floor to force a refund nor dropping it is allowed
+        assert_eq!(
+            client.try_set_market_min_voters(&admin, &market_id, &Some(5)),
+            Err(Ok(Error::InvalidState))
+        );
+        assert_eq!(
+            client.try_set_market_min_voters(&admin, &market_id, &None),
+            Err(Ok(Error::InvalidState))
+        );
+        assert_eq!(client.get_market(&market_id).unwrap().min_voters, Some(3));
+    }
+
+    #[test]
+    fn below_min_voters_is_refunded_by_hybrid_resolution() {
+        let env = Env::default();
+        let (client, _admin, market_id) = setup_ended_market(&env, 2);
+        env.as_contract(&client.address, || {
+            let mut market = MarketStateManager::get_market(&env, &market_id).unwrap();
+            market.oracle_result = Some(String::from_str(&env, "yes"));
+            MarketStateManager::update_market(&env, &market_id, &market);
+        });
+        assert_eq!(client.resolve_market_hybrid(&market_id), MarketState::Cancelled);
+        assert!(client.get_market(&market_id).unwrap().winning_outcomes.is_none());
+    }
+
+    #[test]
+    fn below_min_voters_is_refunded_by_force_resolution() {
+        let env = Env::default();
+        let (client, admin, market_id) = setup_ended_market(&env, 2);
+        client.force_resolve_market(
+            &admin,
+            &market_id,
+            &soroban_sdk::vec![&env, String::from_str(&env, "yes")],
+            &String::from_str(&env, "oracle outage"),
+            &String::from_str(&env, "key-1"),
+        );
+        let market = client.get_market(&market_id).unwrap();
+        assert_eq!(market.state, MarketState::Cancelled);
+        assert!(market.winning_outcomes.is_none());
+    }
+
+    #[test]
+    fn zero_min_voters_is_rejected() {
+        let env = Env::default();
+        let (client, admin, market_id) = setup_ended_market(&env, 0);
+        assert_eq!(
+            client.try_set_market_min_voters(&admin, &market_id, &Some(0)),
+            Err(Ok(Error::InvalidInput))
+        );
+    }
+}
+
+#[cfg(test)]
+mod oracle_batch_resolution_tests {
+    use super::*;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+
+    fn store_market(env: &Env, admin: &Address, id: &Symbol, end_time: u64) -> Market {
         let market = Market::new(
-            &env,
-            admin,
-            String::from_str(&env, "Test Market"),
-            soroban_sdk::vec![
-                &env,
-                String::from_str(&env, "yes"),
-                String::from_str(&env, "no"),
-            ],
-            env.ledger().timestamp() + 86400,
-            OracleConfig {
-                provider: OracleProvider::pyth(),
-                oracle_address: Address::generate(&env),
-                feed_id: String::from_str(&env, "BTC/USD"),
-                threshold: 2500000,
-                comparison: String::from_str(&env, "gt"),
-            },
+            env,
+            admin.clone(),
+            String::from_str(env, "Batch"),
+            soroban_sdk::vec![env, String::from_str(env, "yes"), String::from_str(env, "no")],
+            end_time,
+            OracleConfig::new(
+                OracleProvider::reflector(),
+                Address::generate(env),
+                String::from_str(env, "BTC/USD"),
+                100,
+                ComparisonOp::Gt,
+            ),
             None,
             86400,
             MarketState::Active,
         );
-
-        let state = ResolutionUtils::get_resolution_state(&env, &market);
-        assert_eq!(state, ResolutionState::Active);
+        env.storage().persistent().set(id, &market);
+        market
     }
 
     #[test]
-    fn test_resolution_analytics_determine_method() {
+    fn batch_flags_each_market_and_skips_unresolvable_ones() {
         let env = Env::default();
-        let oracle_result = String::from_str(&env, "yes");
-        let community_consensus = CommunityConsensus {
-            outcome: String::from_str(&env, "yes"),
-            votes: 8,
-            total_votes: 10,
-            percentage: 80,
-        };
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &None, &None);
+        env.ledger().set_timestamp(10_000);
+
+        let due = Symbol::new(&env, "due");
+        let not_due = Symbol::new(&env, "not_due");
+        let settled = Symbol::new(&env, "settled");
+        let missing = Symbol::new(&env, "missing");
+        env.as_contract(&contract_id, || {
+            // Ended, but the oracle has no data for it
+            store_market(&env, &admin, &due, 9_000);
+            // Still open
+            store_market(&env, &admin, &not_due, 20_000);
+            // Oracle result already fetched, but not yet resolved
+            let mut market = store_market(&env, &admin, &settled, 9_000);
+            market.oracle_result = Some(String::from_str(&env, "yes"));
+            env.storage().persistent().set(&settled, &market);
+        });
 
-        let method = MarketResolutionAnalytics::determine_resolution_method(
-            &oracle_result,
-            &community_consensus,
+        let results = client.resolve_markets_oracle_batch(&soroban_sdk::vec![
+            &env,
+            due.clone(),
+            not_due.clone(),
+            settled.clone(),
+            missing.clone(),
+        ]);
+
+        // One flag per input, in order; only the fetched market resolves
+        assert_eq!(results, soroban_sdk::vec![&env, false, false, true, false]);
+        let open = client.get_market(&not_due).unwrap();
+        assert_eq!(open.state, MarketState::Active);
+        assert!(open.oracle_result.is_none());
+        let unfetched = client.get_market(&due).unwrap();
+        assert_eq!(unfetched.state, MarketState::Active);
+        assert!(unfetched.oracle_result.is_none());
+        let resolved = client.get_market(&settled).unwrap();
+        assert_eq!(resolved.state, MarketState::Resolved);
+        assert_eq!(
+            resolved.winning_outcomes,
+            Some(soroban_sdk::vec![&env, String::from_str(&env, "yes")])
         );
-        assert_eq!(method, ResolutionMethod::Hybrid);
     }
 
     #[test]
-    fn test_resolution_testing_utilities() {
+    fn batch_rejects_more_than_max_markets() {
         let env = Env::default();
-        let market_id = Symbol::new(&env, "test_market");
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
 
-        let oracle_resolution = ResolutionTesting::create_test_oracle_resolution(&env, &market_id);
-        assert!(oracle_resolution.oracle_result == String::from_str(&env, "yes"));
+        let mut ids = Vec::new(&env);
+        for _ in 0..=crate::config::MAX_ORACLE_RESOLUTION_BATCH {
+            ids.push_back(Symbol::new(&env, "market"));
+        }
+        assert_eq!(
+            client.try_resolve_markets_oracle_batch(&ids),
+            Err(Ok(Error::InvalidInput))
+        );
+        assert_eq!(
+            client.resolve_markets_oracle_batch(&Vec::new(&env)),
+            Vec::<bool>::new(&env)
+        );
+    }
+}
 
-        let market_resolution = ResolutionTesting::create_test_market_resolution(&env, &market_id);
-        assert!(ResolutionTesting::validate_resolution_structure(&market_resolution).is_ok());
+#[cfg(test)]
+mod negative_price_resolution_tests {
+    use super::*;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+
+    pub(super) mod fixed_price {
+        use crate::types::{ReflectorAsset, ReflectorPriceData};
+        use soroban_sdk::{contract, contractimpl, symbol_short, Env, String};
+
+        /// Reflector-compatible oracle that always reports the price set on it.
+        #[contract]
+        pub struct FixedPriceOracle;
+
+        #[contractimpl]
+        impl FixedPriceOracle {
+            pub fn set_price(env: Env, price: i128) {
+                env.storage().instance().set(&symbol_short!("price"), &price);
+            }
+
+            pub fn lastprice(env: Env, _asset: ReflectorAsset) -> Option<ReflectorPriceData> {
+                let price: i128 = env.storage().instance().get(&symbol_short!("price"))?;
+                Some(ReflectorPriceData {
+                    price,
+                    timestamp: env.ledger().timestamp(),
+                    source: String::from_str(&env, "fixed"),
+                })
+            }
+        }
+    }
+
+    /// Resolves a fresh market comparing `price` against `threshold` with
+    /// `comparison`, returning the oracle outcome.
+    fn resolve_with(price: i128, threshold: i128, comparison: ComparisonOp) -> String {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let oracle = env.register(fixed_price::FixedPriceOracle, ());
+        fixed_price::FixedPriceOracleClient::new(&env, &oracle).set_price(&price);
+
+        let market_id = Symbol::new(&env, "funding");
+        env.as_contract(&contract_id, || {
+            let market = Market::new(
+                &env,
+                Address::generate(&env),
+                String::from_str(&env, "Funding rate vs threshold"),
+                soroban_sdk::vec![&env, String::from_str(&env, "yes"), String::from_str(&env, "no")],
+                env.ledger().timestamp() + 100,
+                OracleConfig::new(
+                    OracleProvider::reflector(),
+                    oracle.clone(),
+                    String::from_str(&env, "BTC/USD"),
+                    threshold,
+                    comparison,
+                ),
+                None,
+                86400,
+                MarketState::Active,
+            );
+            env.storage().persistent().set(&market_id, &market);
+        });
+
+        env.ledger().with_mut(|li| li.timestamp += 101);
+        env.as_contract(&contract_id, || {
+            crate::PredictifyHybrid::fetch_oracle_result(env.clone(), market_id.clone(), oracle)
+                .unwrap()
+        })
+    }
+
+    fn yes(outcome: String) -> bool {
+        outcome == String::from_str(outcome.env(), "yes")
     }
 
     #[test]
-    fn test_resolution_method_determination() {
+    fn gt_compares_signed_prices() {
+        assert!(yes(resolve_with(-50, -100, ComparisonOp::Gt)));
+        assert!(!yes(resolve_with(-150, -100, ComparisonOp::Gt)));
+        assert!(!yes(resolve_with(-100, -100, ComparisonOp::Gt)));
+    }
+
+    #[test]
+    fn lt_compares_signed_prices() {
+        assert!(yes(resolve_with(-150, -100, ComparisonOp::Lt)));
+        assert!(!yes(resolve_with(-50, -100, ComparisonOp::Lt)));
+        assert!(!yes(resolve_with(-100, -100, ComparisonOp::Lt)));
+    }
+
+    #[test]
+    fn eq_compares_signed_prices() {
+        assert!(yes(resolve_with(-100, -100, ComparisonOp::Eq)));
+        assert!(!yes(resolve_with(100, -100, ComparisonOp::Eq)));
+    }
+
+    #[test]
+    fn negative_resolution_passes_validation_and_scores_by_magnitude() {
         let env = Env::default();
+        let mut resolution = ResolutionTesting::create_test_oracle_resolution(
+            &env,
+            &Symbol::new(&env, "funding"),
+        );
+        resolution.price = -101;
+        resolution.threshold = -100;
+        assert!(OracleResolutionValidator::validate_oracle_resolution(&env, &resolution).is_ok());
+
+        // 1% away from the threshold scores the same regardless of sign
+        let negative = OracleResolutionAnalytics::calculate_confidence_score(&resolution);
+        resolution.price = 101;
+        resolution.threshold = 100;
+        assert_eq!(
+            OracleResolutionAnalytics::calculate_confidence_score(&resolution),
+            negative
+        );
+    }
+}
 
-        // Create test data
-        let community_consensus = CommunityConsensus {
-            outcome: String::from_str(&env, "yes"),
-            votes: 75,
-            total_votes: 100,
-            percentage: 75,
-        };
+#[cfg(test)]
+mod auto_resolve_tests {
+    use super::negative_price_resolution_tests::fixed_price;
+    use super::*;
+    use soroban_sdk::testutils::{Address as _, Events, Ledger};
+    use soroban_sdk::TryIntoVal;
+
+    pub(super) struct Setup {
+        pub(super) env: Env,
+        pub(super) client: crate::PredictifyHybridClient<'static>,
+        pub(super) admin: Address,
+        pub(super) end_time: u64,
+    }
 
-        // Test hybrid resolution
-        let method = MarketResolutionAnalytics::determine_resolution_method(
-            &String::from_str(&env, "yes"),
-            &community_consensus,
+    /// Initialized contract with an oracle reporting 150 against a `gt 100` market.
+    pub(super) fn setup() -> (Setup, Address) {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &None, &None);
+        env.as_contract(&contract_id, || {
+            crate::rate_limiter::RateLimiter::init_for_tests(&env, &admin);
+        });
+
+        let oracle = env.register(fixed_price::FixedPriceOracle, ());
+        fixed_price::FixedPriceOracleClient::new(&env, &oracle).set_price(&150);
+        let end_time = env.ledger().timestamp() + 1000;
+        (
+            Setup {
+                env,
+                client,
+                admin,
+                end_time,
+            },
+            oracle,
+        )
+    }
+
+    pub(super) fn store_market(s: &Setup, oracle: &Address, name: &str) -> Symbol {
+        let env = &s.env;
+        let market_id = Symbol::new(env, name);
+        env.as_contract(&s.client.address, || {
+            let market = Market::new(
+                env,
+                s.admin.clone(),
+                String::from_str(env, "BTC above 100?"),
+                soroban_sdk::vec![env, String::from_str(env, "yes"), String::from_str(env, "no")],
+                s.end_time,
+                OracleConfig::new(
+                    OracleProvider::reflector(),
+                    oracle.clone(),
+                    String::from_str(env, "BTC/USD"),
+                    100,
+                    ComparisonOp::Gt,
+                ),
+                None,
+                86400,
+                MarketState::Active,
+            );
+            env.storage().persistent().set(&market_id, &market);
+        });
+        market_id
+    }
+
+    #[test]
+    fn vote_after_close_auto_resolves_opted_in_market() {
+        let (s, oracle) = setup();
+        let auto = store_market(&s, &oracle, "auto");
+        let manual = store_market(&s, &oracle, "manual");
+        s.client.set_market_auto_resolve(&s.admin, &auto, &true);
+
+        s.env.ledger().with_mut(|li| li.timestamp = s.end_time + 1);
+        let voter = Address::generate(&s.env);
+        let yes = String::from_str(&s.env, "yes");
+
+        // Opted in: the late vote resolves the market and is itself rejected
+        s.client.vote(&voter, &auto, &yes, &10_0000000);
+        let topics: std::vec::Vec<Symbol> = s
+            .env
+            .events()
+            .all()
+            .events()
+            .iter()
+            .filter_map(|event| {
+                let body = match &event.body {
+                    soroban_sdk::xdr::ContractEventBody::V0(v0) => v0,
+                };
+                body.topics.get(0)?.clone().try_into_val(&s.env).ok()
+            })
+            .collect();
+        assert!(topics.contains(&symbol_short!("err_log")));
+        let resolved = s.client.get_market(&auto).unwrap();
+        assert_eq!(resolved.state, MarketState::Resolved);
+        assert_eq!(resolved.oracle_result, Some(yes.clone()));
+        assert_eq!(
+            resolved.winning_outcomes,
+            Some(soroban_sdk::vec![&s.env, yes.clone()])
         );
-        assert!(matches!(method, ResolutionMethod::Hybrid));
+        assert!(resolved.votes.get(voter.clone()).is_none());
+        assert_eq!(resolved.total_staked, 0);
 
-        // Test oracle-only resolution
-        let low_consensus = CommunityConsensus {
-            outcome: String::from_str(&env, "yes"),
-            votes: 60,
-            total_votes: 100,
-            percentage: 60,
-        };
-        let method = MarketResolutionAnalytics::determine_resolution_method(
-            &String::from_str(&env, "yes"),
-            &low_consensus,
+        // Default: the same interaction only errors and leaves the market untouched
+        assert_eq!(
+            s.client.try_vote(&voter, &manual, &yes, &10_0000000),
+            Err(Ok(Error::MarketClosed))
+        );
+        assert!(s.client.get_market(&manual).unwrap().oracle_result.is_none());
+    }
+
+    #[test]
+    fn auto_resolve_is_admin_only_and_rejected_once_resolved() {
+        let (s, oracle) = setup();
+        let market_id = store_market(&s, &oracle, "auto");
+        let outsider = Address::generate(&s.env);
+        assert_eq!(
+            s.client.try_set_market_auto_resolve(&outsider, &market_id, &true),
+            Err(Ok(Error::Unauthorized))
+        );
+
+        s.env.ledger().with_mut(|li| li.timestamp = s.end_time + 1);
+        s.client.resolve_markets_oracle_batch(&soroban_sdk::vec![&s.env, market_id.clone()]);
+        assert_eq!(
+            s.client.try_set_market_auto_resolve(&s.admin, &market_id, &true),
+            Err(Ok(Error::MarketResolved))
+        );
+    }
+}
+
+#[cfg(test)]
+mod keeper_resolution_tests {
+    use super::auto_resolve_tests::{setup, store_market};
+    use super::*;
+    use soroban_sdk::testutils::{Address as _, Ledger};
+    use soroban_sdk::token::{Client as TokenClient, StellarAssetClient};
+
+    const BOUNTY: i128 = 5_000_000;
+
+    fn register_token(s: &super::auto_resolve_tests::Setup) -> Address {
+        let token_id = s
+            .env
+            .register_stellar_asset_contract_v2(Address::generate(&s.env))
+            .address();
+        s.env.as_contract(&s.client.address, || {
+            s.env
+                .storage()
+                .persistent()
+                .set(&Symbol::new(&s.env, "TokenID"), &token_id);
+        });
+        token_id
+    }
+
+    /// Overrides the pool minimum of `market_id`; one above its stake blocks resolution.
+    fn set_min_pool(s: &super::auto_resolve_tests::Setup, market_id: &Symbol, min: Option<i128>) {
+        s.env.as_contract(&s.client.address, || {
+            let mut market = MarketStateManager::get_market(&s.env, market_id).unwrap();
+            market.min_pool_size = min;
+            MarketStateManager::update_market(&s.env, market_id, &market);
+        });
+    }
+
+    #[test]
+    fn keeper_is_paid_only_once_the_market_resolves() {
+        let (s, oracle) = setup();
+        let token_id = register_token(&s);
+        StellarAssetClient::new(&s.env, &token_id).mint(&s.client.address, &(2 * BOUNTY));
+        let settles = store_market(&s, &oracle, "settles");
+        let short_pool = store_market(&s, &oracle, "short_pool");
+        set_min_pool(&s, &short_pool, Some(1_000_0000000));
+        s.env.as_contract(&s.client.address, || {
+            s.env
+                .storage()
+                .persistent()
+                .set(&symbol_short!("tot_fees"), &(2 * BOUNTY));
+        });
+        s.client.set_keeper_bounty(&s.admin, &BOUNTY);
+        s.env.ledger().with_mut(|li| li.timestamp = s.end_time + 1);
+        let keeper = Address::generate(&s.env);
+        let token = TokenClient::new(&s.env, &token_id);
+
+        // An oracle outcome alone earns nothing
+        assert_eq!(
+            s.client.try_resolve_market_oracle(&keeper, &short_pool),
+            Err(Ok(Error::InvalidState))
+        );
+        assert_eq!(token.balance(&keeper), 0);
+        let market = s.client.get_market(&short_pool).unwrap();
+        assert_eq!(market.state, MarketState::Active);
+        assert!(market.winning_outcomes.is_none());
+
+        let yes = String::from_str(&s.env, "yes");
+        assert_eq!(s.client.resolve_market_oracle(&keeper, &settles), yes);
+        assert_eq!(token.balance(&keeper), BOUNTY);
+        let market = s.client.get_market(&settles).unwrap();
+        assert_eq!(market.state, MarketState::Resolved);
+        assert_eq!(market.winning_outcomes, Some(soroban_sdk::vec![&s.env, yes]));
+    }
+
+    #[test]
+    fn creator_bounty_stays_escrowed_until_the_market_resolves() {
+        let (s, oracle) = setup();
+        let token_id = register_token(&s);
+        StellarAssetClient::new(&s.env, &token_id).mint(&s.admin, &BOUNTY);
+        let market_id = store_market(&s, &oracle, "escrowed");
+        s.client.escrow_resolution_bounty(&s.admin, &market_id, &BOUNTY);
+        set_min_pool(&s, &market_id, Some(1_000_0000000));
+        s.env.ledger().with_mut(|li| li.timestamp = s.end_time + 1);
+        let keeper = Address::generate(&s.env);
+        let token = TokenClient::new(&s.env, &token_id);
+
+        assert!(s.client.try_resolve_market_oracle(&keeper, &market_id).is_err());
+        assert_eq!(token.balance(&keeper), 0);
+        assert_eq!(
+            s.client.get_market(&market_id).unwrap().resolution_bounty,
+            BOUNTY
+        );
+
+        set_min_pool(&s, &market_id, None);
+        s.client.resolve_market_oracle(&keeper, &market_id);
+        assert_eq!(token.balance(&keeper), BOUNTY);
+        let market = s.client.get_market(&market_id).unwrap();
+        assert_eq!(market.state, MarketState::Resolved);
+        assert_eq!(market.resolution_bounty, 0);
+    }
+
+    #[test]
+    fn keeper_is_not_paid_before_the_market_is_past_due() {
+        let (s, oracle) = setup();
+        let token_id = register_token(&s);
+        StellarAssetClient::new(&s.env, &token_id).mint(&s.client.address, &BOUNTY);
+        s.env.as_contract(&s.client.address, || {
+            s.env
+                .storage()
+                .persistent()
+                .set(&symbol_short!("tot_fees"), &BOUNTY);
+        });
+        s.client.set_keeper_bounty(&s.admin, &BOUNTY);
+        let market_id = store_market(&s, &oracle, "not_due");
+        let keeper = Address::generate(&s.env);
+
+        for now in [s.end_time - 1, s.end_time] {
+            s.env.ledger().with_mut(|li| li.timestamp = now);
+            assert_eq!(
+                s.client.try_resolve_market_oracle(&keeper, &market_id),
+                Err(Ok(Error::MarketClosed))
+            );
+        }
+        assert_eq!(TokenClient::new(&s.env, &token_id).balance(&keeper), 0);
+        assert_eq!(
+            s.client.get_market(&market_id).unwrap().state,
+            MarketState::Active
         );
-        assert!(matches!(method, ResolutionMethod::OracleOnly));
     }
 }
 
@@ -2670,12 +4493,13 @@ impl OracleCallbackResolver {
 
         // Determine outcome based on oracle data
         let outcome = Self::determine_outcome_from_oracle_data(callback_data, &market)?;
+        MarketValidator::assert_valid_outcome(&market, &outcome)?;
 
         // Create oracle resolution with all required fields
         let resolution = OracleResolution {
             market_id: market_id.clone(),
             feed_id: callback_data.feed_id.clone(),
-            comparison: String::from_str(env, "eq"),
+            comparison: ComparisonOp::Eq,
             provider: market.oracle_config.provider.clone(),
             price: callback_data.price,
             timestamp: callback_data.timestamp,
@@ -2702,7 +4526,7 @@ impl OracleCallbackResolver {
             &String::from_str(env, "callback"),
             callback_data.price,
             0,
-            &String::from_str(env, "eq"),
+            &ComparisonOp::Eq,
         );
 
         Ok(())
@@ -14,6 +14,7 @@
 #[cfg(test)]
 mod resolution_event_ordering_tests {
     use crate::config::ConfigManager;
+    use crate::oracles::ComparisonOp;
     use crate::resolution::MarketResolutionManager;
     use crate::types::{Market, MarketState, OracleConfig, OracleProvider};
     use crate::PredictifyHybrid;
@@ -57,7 +58,7 @@ mod resolution_event_ordering_tests {
                 ),
                 String::from_str(&self.env, "BTC/USD"),
                 50_000_00,
-                String::from_str(&self.env, "gt"),
+                ComparisonOp::Gt,
             );
             let mut market = Market::new(
                 &self.env,
@@ -169,7 +170,7 @@ mod resolution_event_ordering_tests {
                 ),
                 String::from_str(&setup.env, "BTC/USD"),
                 50_000_00,
-                String::from_str(&setup.env, "gt"),
+                ComparisonOp::Gt,
             );
             let voter_a = Address::generate(&setup.env);
             let voter_b = Address::generate(&setup.env);
@@ -255,7 +256,7 @@ mod resolution_event_ordering_tests {
                 ),
                 String::from_str(&setup.env, "BTC/USD"),
                 50_000_00,
-                String::from_str(&setup.env, "gt"),
+                ComparisonOp::Gt,
             );
             // No oracle_result — resolution must fail before emitting.
             let market = Market::new(
@@ -78,10 +78,14 @@ pub enum DataKey {
     MarketMetadata(Symbol),
     MarketScratch(Symbol),
     DisputeHistoryCap,
+    /// Address delegated to adjudicate disputes in place of the admin.
+    DisputeResolver,
     DisputeHistory(Symbol),
     DisputeStakeCap(Symbol, Address),
     /// Per-user cumulative dispute stake cap across all active disputes.
     DisputeCumulativeStakeCap(Address),
+    /// Contract-wide ceiling on one address's dispute stake in a round.
+    MaxDisputeStake,
     /// Instance storage cache key for Market structs, keyed by market_id.
     /// Used by MarketReadCache in markets.rs.
     MarketCache(Symbol),
@@ -961,9 +965,28 @@ impl EventManager {
         (Symbol::new(env, "Event"), event_id.clone())
     }
 
+    fn event_index_key(env: &Env) -> Symbol {
+        Symbol::new(env, "event_index")
+    }
+
+    /// IDs of every stored event, in creation order.
+    pub fn get_event_ids(env: &Env) -> Vec<Symbol> {
+        env.storage()
+            .persistent()
+            .get(&Self::event_index_key(env))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
     /// Store a new event in persistent storage
     pub fn store_event(env: &Env, event: &Event) {
         let key = Self::event_storage_key(env, &event.id);
+        if !env.storage().persistent().has(&key) {
+            let mut ids = Self::get_event_ids(env);
+            ids.push_back(event.id.clone());
+            env.storage()
+                .persistent()
+                .set(&Self::event_index_key(env), &ids);
+        }
         StorageOptimizer::set_persistent_with_ttl(
             env,
             &key,
@@ -1114,6 +1137,7 @@ impl StorageUtils {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::oracles::ComparisonOp;
     use soroban_sdk::testutils::storage::Persistent;
     use soroban_sdk::testutils::{Address as _, EnvTestConfig, Ledger};
 
@@ -1179,7 +1203,7 @@ mod tests {
                 ),
                 String::from_str(env, "BTC"),
                 2500000,
-                String::from_str(env, "gt"),
+                ComparisonOp::Gt,
             ),
             None,
             86400,
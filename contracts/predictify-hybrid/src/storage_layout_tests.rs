@@ -54,6 +54,7 @@ fn create_test_market(env: &Env, admin: &Address) -> (Symbol, Market) {
         feed_id: String::from_str(env, "BTC"),
         threshold: 100_000_00,
         comparison: String::from_str(env, "gt"),
+        threshold2: None,
     };
 
     let metadata_commitment = Market::compute_metadata_commitment(
@@ -65,6 +66,7 @@ fn create_test_market(env: &Env, admin: &Address) -> (Symbol, Market) {
 
     let market = Market {
         admin: admin.clone(),
+        creator: admin.clone(),
         question,
         outcomes,
         end_time,
@@ -87,10 +89,23 @@ fn create_test_market(env: &Env, admin: &Address) -> (Symbol, Market) {
         extension_history: vec![env],
         category: None,
         tags: vec![env],
+        language: String::from_str(env, crate::config::DEFAULT_MARKET_LANGUAGE),
         min_pool_size: None,
+        min_voters: None,
+        outcome_cap_bps: None,
+        resolution_bounty: 0,
         bet_deadline: 0,
         dispute_window_seconds: 0,
         winnings_swept: false,
+        refund_grace_seconds: crate::config::REFUND_GRACE_SECONDS,
+        early_bird_fee: None,
+        settlement_token: None,
+        settlement_rate_bps: 0,
+        resolution_bond: 0,
+        condition: None,
+        tiebreak_policy: None,
+        payout_model: PayoutModel::Parimutuel,
+        claims_wait_for_dispute_window: false,
     };
 
     (market_id, market)
@@ -485,6 +500,7 @@ fn test_oracle_config_structure_serialization() {
         feed_id: String::from_str(&env, "BTC/USD"),
         threshold: 50_000_00,
         comparison: String::from_str(&env, "gt"),
+        threshold2: None,
     };
 
     run_as_contract(&env, || {
@@ -159,6 +159,7 @@ impl PredictifyTest {
                 feed_id: String::from_str(&self.env, "BTC"),
                 threshold: 2500000,
                 comparison: String::from_str(&self.env, "gt"),
+                threshold2: None,
             },
             &None,
             &0,
@@ -189,6 +190,7 @@ impl PredictifyTest {
                 feed_id: String::from_str(&self.env, "BTC"),
                 threshold: 50_000_00,
                 comparison: String::from_str(&self.env, "gt"),
+                threshold2: None,
             },
             &None,
             &3600,
@@ -446,6 +448,7 @@ fn test_create_market_successful() {
             feed_id: String::from_str(&test.env, "BTC"),
             threshold: 2500000,
             comparison: String::from_str(&test.env, "gt"),
+            threshold2: None,
         },
         &None,
         &0,
@@ -475,7 +478,12 @@ fn test_create_market_successful() {
 
     // 3. Resolve market to winning outcome
     test.env.mock_all_auths();
-    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    client.resolve_market_manual(
+        &test.admin,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &None,
+    );
 
     // 4. Loser claims (should not mark as claimed)
     test.env.mock_all_auths();
@@ -1648,7 +1656,7 @@ fn test_reinitialize_prevention() {
     assert!(has_admin);
 
     // The initialize function checks if already initialized.
-    // Second call would return AlreadyInitialized (#504).
+    // Second call would return AlreadyInitialized (#540).
 }
 
 #[test]
@@ -1801,7 +1809,12 @@ fn test_automatic_payout_distribution() {
 
     // Resolve market manually (resolve_market_manual internally calls distribute_payouts)
     test.env.mock_all_auths();
-    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    client.resolve_market_manual(
+        &test.admin,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &None,
+    );
 
     // distribute_payouts (called inside resolve_market_manual) already marked winners as claimed
     // Verify market state and that winners were marked as claimed
@@ -1879,7 +1892,12 @@ fn test_automatic_payout_distribution_no_winners() {
     });
 
     test.env.mock_all_auths();
-    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    client.resolve_market_manual(
+        &test.admin,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &None,
+    );
 
     // Distribute payouts (should return 0 with no winners)
     let total = client.distribute_payouts(&market_id);
@@ -2447,6 +2465,7 @@ fn test_create_event_collects_configured_fee_and_emits_event() {
             feed_id: String::from_str(&test.env, "XLM/USD"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            threshold2: None,
         },
         &None,
         &0,
@@ -2520,6 +2539,7 @@ fn test_create_event_rejects_when_fee_insufficient() {
             feed_id: String::from_str(&test.env, "BTC/USD"),
             threshold: 50000,
             comparison: String::from_str(&test.env, "gt"),
+            threshold2: None,
         },
         &None,
         &0,
@@ -2557,6 +2577,7 @@ fn test_create_event_rejects_when_fee_asset_not_configured() {
             feed_id: String::from_str(&test.env, "ETH/USD"),
             threshold: 3000,
             comparison: String::from_str(&test.env, "gt"),
+            threshold2: None,
         },
         &None,
         &0,
@@ -2607,6 +2628,7 @@ fn test_create_event_uses_configured_fee_asset() {
             feed_id: String::from_str(&test.env, "SOL/USD"),
             threshold: 150,
             comparison: String::from_str(&test.env, "gt"),
+            threshold2: None,
         },
         &None,
         &0,
@@ -2732,7 +2754,12 @@ fn test_cancel_event_already_resolved() {
     });
 
     test.env.mock_all_auths();
-    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    client.resolve_market_manual(
+        &test.admin,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &None,
+    );
 
     // Verify market is resolved - trying to cancel would return MarketResolved (#103)
     let resolved_market = test.env.as_contract(&test.contract_id, || {
@@ -2974,7 +3001,10 @@ fn test_refund_on_oracle_failure_after_timeout_any_caller() {
             .unwrap()
     });
     test.env.ledger().set(LedgerInfo {
-        timestamp: market.end_time + crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS + 1,
+        timestamp: market.end_time
+            + crate::config::DEFAULT_RESOLUTION_TIMEOUT_SECONDS
+            + crate::config::REFUND_GRACE_SECONDS
+            + 1,
         protocol_version: 22,
         sequence_number: test.env.ledger().sequence(),
         network_id: Default::default(),
@@ -3012,6 +3042,7 @@ fn test_refund_on_oracle_failure_uses_market_resolution_timeout() {
             feed_id: String::from_str(&test.env, "BTC"),
             threshold: 50_000_00,
             comparison: String::from_str(&test.env, "gt"),
+            threshold2: None,
         },
         &None,
         &resolution_timeout,
@@ -3036,9 +3067,9 @@ fn test_refund_on_oracle_failure_uses_market_resolution_timeout() {
             .unwrap()
     });
     let any_caller = test.create_funded_user();
-    // After market resolution_timeout: any caller can refund (per-market timeout)
+    // After resolution_timeout plus refund grace: any caller can refund (per-market timeout)
     test.env.ledger().set(LedgerInfo {
-        timestamp: market.end_time + resolution_timeout + 1,
+        timestamp: market.end_time + resolution_timeout + crate::config::REFUND_GRACE_SECONDS + 1,
         protocol_version: 22,
         sequence_number: test.env.ledger().sequence(),
         network_id: Default::default(),
@@ -3176,6 +3207,7 @@ fn test_multi_outcome_creation_binary() {
             feed_id: String::from_str(&test.env, "BTC"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            threshold2: None,
         },
         &None,
         &0,
@@ -3215,6 +3247,7 @@ fn test_multi_outcome_creation_three_outcomes() {
             feed_id: String::from_str(&test.env, "X"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            threshold2: None,
         },
         &None,
         &0,
@@ -3254,6 +3287,7 @@ fn test_multi_outcome_invalid_outcome_rejected() {
             feed_id: String::from_str(&test.env, "X"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            threshold2: None,
         },
         &None,
         &0,
@@ -3294,6 +3328,7 @@ fn test_multi_outcome_single_winner_payout() {
             feed_id: String::from_str(&test.env, "X"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            threshold2: None,
         },
         &None,
         &0,
@@ -3336,7 +3371,12 @@ fn test_multi_outcome_single_winner_payout() {
         max_entry_ttl: 10000,
     });
     test.env.mock_all_auths();
-    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "win"));
+    client.resolve_market_manual(
+        &test.admin,
+        &market_id,
+        &String::from_str(&test.env, "win"),
+        &None,
+    );
     test.env.mock_all_auths();
     client.claim_winnings(&winner, &market_id);
     test.env.mock_all_auths();
@@ -3365,6 +3405,7 @@ fn test_multi_outcome_tie_split_payout() {
             feed_id: String::from_str(&test.env, "X"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            threshold2: None,
         },
         &None,
         &0,
@@ -3412,7 +3453,7 @@ fn test_multi_outcome_tie_split_payout() {
         String::from_str(&test.env, "B2"),
     ];
     test.env.mock_all_auths();
-    client.resolve_market_with_ties(&test.admin, &market_id, &winning);
+    client.resolve_market_with_ties(&test.admin, &market_id, &winning, &None);
     test.env.mock_all_auths();
     client.claim_winnings(&u1, &market_id);
     client.claim_winnings(&u2, &market_id);
@@ -3440,6 +3481,7 @@ fn test_multi_outcome_one_outcome_no_bets() {
             feed_id: String::from_str(&test.env, "X"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            threshold2: None,
         },
         &None,
         &0,
@@ -3474,7 +3516,12 @@ fn test_multi_outcome_one_outcome_no_bets() {
         max_entry_ttl: 10000,
     });
     test.env.mock_all_auths();
-    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "A1"));
+    client.resolve_market_manual(
+        &test.admin,
+        &market_id,
+        &String::from_str(&test.env, "A1"),
+        &None,
+    );
     test.env.mock_all_auths();
     client.claim_winnings(&user, &market_id);
 }
@@ -3500,6 +3547,7 @@ fn test_multi_outcome_all_same_outcome() {
             feed_id: String::from_str(&test.env, "X"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            threshold2: None,
         },
         &None,
         &0,
@@ -3542,7 +3590,12 @@ fn test_multi_outcome_all_same_outcome() {
         max_entry_ttl: 10000,
     });
     test.env.mock_all_auths();
-    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    client.resolve_market_manual(
+        &test.admin,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &None,
+    );
     test.env.mock_all_auths();
     client.claim_winnings(&u1, &market_id);
     client.claim_winnings(&u2, &market_id);
@@ -3601,7 +3654,12 @@ fn test_manual_dispute_resolution() {
 
     // Manually resolve market (simulating dispute resolution)
     test.env.mock_all_auths();
-    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    client.resolve_market_manual(
+        &test.admin,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &None,
+    );
 
     // Verify market is resolved - use defensive approach
     let market_after = test.env.as_contract(&test.contract_id, || {
@@ -3760,7 +3818,12 @@ fn test_manual_dispute_resolution_triggers_payout() {
 
     // Manually resolve (distribute_payouts runs inside once dispute window has passed)
     test.env.mock_all_auths();
-    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    client.resolve_market_manual(
+        &test.admin,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &None,
+    );
 
     let market_after = test.env.as_contract(&test.contract_id, || {
         test.env
@@ -3892,7 +3955,12 @@ fn test_claim_winnings_successful() {
 
     // 4. Resolve market manually (as admin); distribute_payouts runs inside and pays winners
     test.env.mock_all_auths();
-    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    client.resolve_market_manual(
+        &test.admin,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &None,
+    );
 
     // 5. Winner was already marked claimed and paid by distribute_payouts inside resolve
     // Verify claimed status
@@ -3947,7 +4015,12 @@ fn test_double_claim_prevention() {
 
     // 3. Resolve market
     test.env.mock_all_auths();
-    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    client.resolve_market_manual(
+        &test.admin,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &None,
+    );
 
     // 4. First claim
     test.env.mock_all_auths();
@@ -3995,7 +4068,12 @@ fn test_claim_by_loser() {
 
     // 3. Resolve market with "yes" as winner (user voted "no", so they lose)
     test.env.mock_all_auths();
-    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    client.resolve_market_manual(
+        &test.admin,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &None,
+    );
 
     // 4. Loser claims - should complete without panic but receive 0 (or minimal) and be marked claimed
     test.env.mock_all_auths();
@@ -4028,6 +4106,10 @@ fn resolve_market_without_distribution(
         market.winning_outcomes = Some(winners);
         market.state = MarketState::Resolved;
         test.env.storage().persistent().set(market_id, &market);
+        // Claims require the market to have actually reached end_time
+        if test.env.ledger().timestamp() < market.end_time {
+            test.env.ledger().set_timestamp(market.end_time);
+        }
     });
 }
 
@@ -4118,6 +4200,7 @@ fn test_claim_winnings_batch_successful() {
         &test.admin,
         &market_id_1,
         &String::from_str(&test.env, "yes"),
+        &None,
     );
 
     test.env.mock_all_auths();
@@ -4125,6 +4208,7 @@ fn test_claim_winnings_batch_successful() {
         &test.admin,
         &market_id_2,
         &String::from_str(&test.env, "yes"),
+        &None,
     );
 
     test.env.mock_all_auths();
@@ -4132,6 +4216,7 @@ fn test_claim_winnings_batch_successful() {
         &test.admin,
         &market_id_3,
         &String::from_str(&test.env, "yes"),
+        &None,
     );
 
     let market_1 = test.env.as_contract(&test.contract_id, || {
@@ -4213,6 +4298,7 @@ fn test_batch_claim_prevent_double_claim() {
         &test.admin,
         &market_id_1,
         &String::from_str(&test.env, "yes"),
+        &None,
     );
 
     let market_ids = vec![&test.env, market_id_1.clone()];
@@ -4345,6 +4431,7 @@ fn test_batch_claim_user_did_not_vote() {
         &test.admin,
         &market_id_1,
         &String::from_str(&test.env, "yes"),
+        &None,
     );
 
     let market_ids = vec![&test.env, market_id_1.clone()];
@@ -4415,6 +4502,7 @@ fn test_batch_claim_partial_winners_losers() {
         &test.admin,
         &market_id_1,
         &String::from_str(&test.env, "yes"),
+        &None,
     );
 
     let m1 = test.env.as_contract(&test.contract_id, || {
@@ -4477,6 +4565,7 @@ fn test_batch_claim_atomicity_revert_on_second_market_failure() {
         &test.admin,
         &market_id_1,
         &String::from_str(&test.env, "yes"),
+        &None,
     );
 
     let m1_after = test.env.as_contract(&test.contract_id, || {
@@ -4513,6 +4602,7 @@ fn test_create_market_without_min_pool_size() {
             feed_id: String::from_str(&test.env, "BTC"),
             threshold: 10000000,
             comparison: String::from_str(&test.env, "gt"),
+            threshold2: None,
         },
         &None,
         &0,
@@ -4826,6 +4916,7 @@ fn test_resolution_allowed_when_pool_exactly_at_min_pool_size() {
             feed_id: String::from_str(&test.env, "TEST"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            threshold2: None,
         },
         &None,
         &0,
@@ -4922,6 +5013,7 @@ fn test_resolution_blocked_and_emits_event_when_pool_below_min_pool_size() {
             feed_id: String::from_str(&test.env, "TEST"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            threshold2: None,
         },
         &None,
         &0,
@@ -5030,6 +5122,7 @@ fn test_resolution_allowed_when_min_pool_size_is_zero() {
             feed_id: String::from_str(&test.env, "TEST"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            threshold2: None,
         },
         &None,
         &0,
@@ -5164,6 +5257,7 @@ fn test_per_market_min_pool_overrides_global_min_pool() {
             feed_id: String::from_str(&test.env, "TEST"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            threshold2: None,
         },
         &None,
         &0,
@@ -5291,6 +5385,7 @@ fn test_create_market_with_min_pool_size() {
             feed_id: String::from_str(&test.env, "TEST"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            threshold2: None,
         },
         &None,
         &0,
@@ -5426,6 +5521,7 @@ fn test_resolution_blocked_when_pool_below_minimum() {
             feed_id: String::from_str(&test.env, "TEST"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            threshold2: None,
         },
         &None,
         &0,
@@ -5560,6 +5656,7 @@ fn test_multi_outcome_tie_three_way() {
             feed_id: String::from_str(&test.env, "TEST"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            threshold2: None,
         },
         &None,
         &0,
@@ -5715,6 +5812,7 @@ fn test_proportional_share_different_stakes() {
             feed_id: String::from_str(&test.env, "TEST"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            threshold2: None,
         },
         &None,
         &0,
@@ -5851,6 +5949,7 @@ fn test_no_dust_left_after_tie_payout() {
             feed_id: String::from_str(&test.env, "TEST"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            threshold2: None,
         },
         &None,
         &0,
@@ -5986,6 +6085,7 @@ fn test_claim_flow_for_tie_winners() {
             feed_id: String::from_str(&test.env, "TEST"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            threshold2: None,
         },
         &None,
         &0,
@@ -6115,6 +6215,7 @@ fn test_edge_case_single_winner_not_tie() {
             feed_id: String::from_str(&test.env, "TEST"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            threshold2: None,
         },
         &None,
         &0,
@@ -6668,6 +6769,7 @@ fn test_cancel_underfunded_event() {
             feed_id: String::from_str(&test.env, "TEST"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            threshold2: None,
         },
         &None,
         &0,
@@ -6798,6 +6900,7 @@ fn test_tie_with_zero_stakers_on_losing_outcome() {
             feed_id: String::from_str(&test.env, "TEST"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            threshold2: None,
         },
         &None,
         &0,
@@ -6898,6 +7001,7 @@ fn test_tie_with_very_small_stakes() {
             feed_id: String::from_str(&test.env, "TEST"),
             threshold: 100,
             comparison: String::from_str(&test.env, "gt"),
+            threshold2: None,
         },
         &None,
         &0,
@@ -6993,6 +7097,7 @@ fn test_unclaimed_winnings_sweep_comprehensive() {
         feed_id: String::from_str(&test.env, "BTC"),
         threshold: 1000,
         comparison: String::from_str(&test.env, "gt"),
+        threshold2: None,
     };
 
     let duration_days = 30;
@@ -7043,7 +7148,12 @@ fn test_unclaimed_winnings_sweep_comprehensive() {
 
     // --- State Transition: Ended -> Resolved ---
     test.env.mock_all_auths();
-    client.resolve_market_manual(&test.admin, &market_id, &String::from_str(&test.env, "yes"));
+    client.resolve_market_manual(
+        &test.admin,
+        &market_id,
+        &String::from_str(&test.env, "yes"),
+        &None,
+    );
 
     // --- State Transition: Resolved -> Swept ---
     // Advance time past the 90-day grace period
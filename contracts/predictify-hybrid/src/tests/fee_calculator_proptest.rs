@@ -21,6 +21,7 @@ pub mod proptest {
     fn create_test_market(env: &Env, admin_address: Address, total_staked: i128) -> Symbol {
         let market_id = Symbol::new(env, "test_market");
         let mut market = Market {
+            creator: admin_address.clone(),
             admin: admin_address,
             question: String::from_str(env, "Test question?"),
             outcomes: vec![env, String::from_str(env, "yes"), String::from_str(env, "no")],
@@ -55,10 +56,23 @@ pub mod proptest {
             extension_history: vec![env],
             category: None,
             tags: vec![env],
+            language: String::from_str(env, crate::config::DEFAULT_MARKET_LANGUAGE),
             min_pool_size: None,
+            min_voters: None,
+            outcome_cap_bps: None,
+            resolution_bounty: 0,
             bet_deadline: 0,
             dispute_window_seconds: 86400,
             winnings_swept: false,
+            refund_grace_seconds: crate::config::REFUND_GRACE_SECONDS,
+            early_bird_fee: None,
+            settlement_token: None,
+            settlement_rate_bps: 0,
+            resolution_bond: 0,
+            condition: None,
+            tiebreak_policy: None,
+            payout_model: crate::types::PayoutModel::Parimutuel,
+            claims_wait_for_dispute_window: false,
         };
 
         MarketStateManager::update_market(env, &market_id, &market);
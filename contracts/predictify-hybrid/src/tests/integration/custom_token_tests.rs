@@ -38,6 +38,7 @@ fn test_place_bet_with_custom_token() {
             feed_id: String::from_str(&env, "BTC/USD"),
             threshold: 10000000,
             comparison: String::from_str(&env, "gt"),
+            threshold2: None,
         };
         let market_id = PredictifyHybrid::create_market(
             env.clone(),
@@ -88,6 +89,7 @@ fn test_place_bet_with_xlm_native() {
             feed_id: String::from_str(&env, "BTC/USD"),
             threshold: 10000000,
             comparison: String::from_str(&env, "gt"),
+            threshold2: None,
         };
         let market_id = PredictifyHybrid::create_market(
             env.clone(),
@@ -145,6 +147,7 @@ fn test_insufficient_balance_for_custom_token() {
             feed_id: String::from_str(&env, "BTC/USD"),
             threshold: 10000000,
             comparison: String::from_str(&env, "gt"),
+            threshold2: None,
         };
         let market_id = PredictifyHybrid::create_market(
             env.clone(),
@@ -540,6 +543,7 @@ fn test_market_creation_with_reflector_assets() {
                 feed_id: asset.feed_id(),
                 threshold: 10000000,
                 comparison: String::from_str(&env, "gt"),
+                threshold2: None,
             };
 
             let outcomes = vec![
@@ -40,6 +40,7 @@ fn test_oracle_config_impossible_combinations() {
         feed_id: String::from_str(&env, "0xabcdef1234567890abcdef1234567890abcdef1234567890abcdef12345678"),
         threshold: 100,
         comparison: String::from_str(&env, "gt"),
+        threshold2: None,
     };
     let result = reflector_invalid.validate(&env);
     assert!(result.is_err());
@@ -52,6 +53,7 @@ fn test_oracle_config_impossible_combinations() {
         feed_id: String::from_str(&env, "BTC/USD"),
         threshold: 100,
         comparison: String::from_str(&env, "gt"),
+        threshold2: None,
     };
     let result = pyth_invalid.validate(&env);
     assert!(result.is_err());
@@ -64,6 +66,7 @@ fn test_oracle_config_impossible_combinations() {
         feed_id: String::from_str(&env, "0xabcdef1234567890abcdef1234567890abcdef1234567890abcdef12345678"),
         threshold: 100,
         comparison: String::from_str(&env, "gt"),
+        threshold2: None,
     };
     let result = band_invalid.validate(&env);
     assert!(result.is_err());
@@ -82,6 +85,7 @@ fn test_oracle_factory_stellar_compatibility() {
         feed_id: String::from_str(&env, "BTC/USD"),
         threshold: 100,
         comparison: String::from_str(&env, "gt"),
+        threshold2: None,
     };
     assert!(crate::oracles::OracleFactory::validate_stellar_compatibility(&reflector_valid).is_ok());
 
@@ -92,6 +96,7 @@ fn test_oracle_factory_stellar_compatibility() {
         feed_id: String::from_str(&env, "0xabcdef1234567890abcdef1234567890abcdef1234567890abcdef12345678"),
         threshold: 100,
         comparison: String::from_str(&env, "gt"),
+        threshold2: None,
     };
     assert!(crate::oracles::OracleFactory::validate_stellar_compatibility(&reflector_invalid).is_err());
 
@@ -102,6 +107,7 @@ fn test_oracle_factory_stellar_compatibility() {
         feed_id: String::from_str(&env, "0xabcdef1234567890abcdef1234567890abcdef1234567890abcdef12345678"),
         threshold: 100,
         comparison: String::from_str(&env, "gt"),
+        threshold2: None,
     };
     assert!(crate::oracles::OracleFactory::validate_stellar_compatibility(&pyth_valid_id).is_ok());
 
@@ -112,6 +118,7 @@ fn test_oracle_factory_stellar_compatibility() {
         feed_id: String::from_str(&env, "BTC/USD"),
         threshold: 100,
         comparison: String::from_str(&env, "gt"),
+        threshold2: None,
     };
     assert!(crate::oracles::OracleFactory::validate_stellar_compatibility(&band_config).is_err());
 }
@@ -33,6 +33,7 @@
 
 use crate::err::Error;
 use crate::markets::{MarketAnalytics, MarketUtils, WinningStats};
+use crate::oracles::ComparisonOp;
 use crate::types::{Market, MarketState, OracleConfig, OracleProvider};
 use crate::voting::PayoutData;
 use crate::{PredictifyHybrid, PredictifyHybridClient};
@@ -111,7 +112,7 @@ impl TieSetup {
                 ),
                 String::from_str(&self.env, "BTC/USD"),
                 5_000_000,
-                String::from_str(&self.env, "gt"),
+                ComparisonOp::Gt,
             ),
             &None,
             &86400u64,
@@ -143,7 +144,7 @@ impl TieSetup {
     /// Resolve with ties via the admin endpoint.
     fn resolve_with_ties(&self, market_id: &Symbol, winning: soroban_sdk::Vec<String>) {
         PredictifyHybridClient::new(&self.env, &self.contract_id)
-            .resolve_market_with_ties(&self.admin, market_id, &winning);
+            .resolve_market_with_ties(&self.admin, market_id, &winning, &None);
     }
 
     /// Read the payout recorded in `market.claimed` for `user`.
@@ -553,6 +554,7 @@ fn test_single_winner_ties_matches_manual_resolve_payout() {
         &manual.admin,
         &mid_manual,
         &String::from_str(&manual.env, "Yes"),
+        &None,
     );
     let p_manual = manual.recorded_payout(&mid_manual, &w_manual);
 
@@ -749,7 +751,7 @@ fn test_calculate_winning_stats_two_way_tie_each_outcome() {
             Address::generate(&env),
             String::from_str(&env, "BTC/USD"),
             5_000_000,
-            String::from_str(&env, "gt"),
+            ComparisonOp::Gt,
         ),
         None,
         0,
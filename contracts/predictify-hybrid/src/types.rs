@@ -1,5 +1,6 @@
 #![allow(dead_code)]
 
+use crate::oracles::ComparisonOp;
 use crate::Error;
 use alloc::string::String as StdString;
 use alloc::string::ToString;
@@ -565,10 +566,12 @@ impl OracleProvider {
 ///
 /// # Supported Comparisons
 ///
-/// The oracle configuration supports various comparison operators:
-/// - **"gt"**: Greater than - price > threshold resolves to "yes"
-/// - **"lt"**: Less than - price < threshold resolves to "yes"
-/// - **"eq"**: Equal to - price == threshold resolves to "yes"
+/// The oracle configuration supports the [`ComparisonOp`] operators:
+/// - **Gt / Gte**: price > (>=) threshold resolves to "yes"
+/// - **Lt / Lte**: price < (<=) threshold resolves to "yes"
+/// - **Eq / Neq**: price == (!=) threshold resolves to "yes"
+/// - **InRange**: threshold <= price <= threshold2 resolves to "yes"
+/// - **OutOfRange**: price outside [threshold, threshold2] resolves to "yes"
 ///
 /// # Price Format Standards
 ///
@@ -590,7 +593,7 @@ impl OracleProvider {
 ///     OracleProvider::Reflector,
 ///     String::from_str(&env, "BTC/USD"),
 ///     50_000_00, // $50,000 in cents
-///     String::from_str(&env, "gt") // Greater than
+///     ComparisonOp::Gt // Greater than
 /// );
 ///
 /// // Validate the configuration
@@ -600,14 +603,14 @@ impl OracleProvider {
 /// println!("Provider: {}", btc_config.provider.name());
 /// println!("Feed: {}", btc_config.feed_id);
 /// println!("Threshold: ${}", btc_config.threshold / 100);
-/// println!("Comparison: {}", btc_config.comparison);
+/// println!("Comparison: {}", btc_config.comparison.as_str());
 ///
 /// // Create config for "Will ETH drop below $2,000?"
 /// let eth_config = OracleConfig::new(
 ///     OracleProvider::Reflector,
 ///     String::from_str(&env, "ETH/USD"),
 ///     2_000_00, // $2,000 in cents
-///     String::from_str(&env, "lt") // Less than
+///     ComparisonOp::Lt // Less than
 /// );
 ///
 /// // Create config for "Will XLM equal exactly $0.50?"
@@ -615,7 +618,7 @@ impl OracleProvider {
 ///     OracleProvider::Reflector,
 ///     String::from_str(&env, "XLM/USD"),
 ///     50, // $0.50 in cents
-///     String::from_str(&env, "eq") // Equal to
+///     ComparisonOp::Eq // Equal to
 /// );
 /// # Ok::<(), predictify_hybrid::errors::Error>(())
 /// ```
@@ -646,7 +649,7 @@ impl OracleProvider {
 ///     OracleProvider::Reflector,
 ///     String::from_str(&env, "BTC/USD"),
 ///     50_000_00,
-///     String::from_str(&env, "gt")
+///     ComparisonOp::Gt
 /// );
 ///
 /// // Validation checks:
@@ -683,7 +686,7 @@ impl OracleProvider {
 ///     Address::from_str(&env, "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF"),
 ///     String::from_str(&env, "BTC/USD"),
 ///     100_000_00,
-///     String::from_str(&env, "gt")
+///     ComparisonOp::Gt
 /// );
 ///
 /// // "Will ETH stay above $1,500?"
@@ -692,7 +695,7 @@ impl OracleProvider {
 ///     Address::from_str(&env, "GAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAWHF"),
 ///     String::from_str(&env, "ETH/USD"),
 ///     1_500_00,
-///     String::from_str(&env, "gt")
+///     ComparisonOp::Gt
 /// );
 /// ```
 ///
@@ -714,8 +717,11 @@ pub struct OracleConfig {
     pub feed_id: String,
     /// Price threshold in cents (e.g., 10_000_00 = $10k)
     pub threshold: i128,
-    /// Comparison operator: "gt", "lt", "eq"
-    pub comparison: String,
+    /// Comparison applied to the oracle price
+    pub comparison: ComparisonOp,
+    /// Upper bound for range comparisons; the range is `[threshold, threshold2]`
+    /// inclusive. Must be `Some` for `in_range`/`out_of_range` and `None` otherwise.
+    pub threshold2: Option<i128>,
 }
 
 impl OracleConfig {
@@ -725,7 +731,7 @@ impl OracleConfig {
         oracle_address: Address,
         feed_id: String,
         threshold: i128,
-        comparison: String,
+        comparison: ComparisonOp,
     ) -> Self {
         Self {
             provider,
@@ -733,17 +739,57 @@ impl OracleConfig {
             feed_id,
             threshold,
             comparison,
+            threshold2: None,
         }
     }
 
+    /// Create a range oracle configuration resolving on `[threshold, threshold2]`.
+    ///
+    /// `comparison` should be `ComparisonOp::InRange` or `ComparisonOp::OutOfRange`.
+    pub fn new_range(
+        provider: OracleProvider,
+        oracle_address: Address,
+        feed_id: String,
+        threshold: i128,
+        threshold2: i128,
+        comparison: ComparisonOp,
+    ) -> Self {
+        Self {
+            provider,
+            oracle_address,
+            feed_id,
+            threshold,
+            comparison,
+            threshold2: Some(threshold2),
+        }
+    }
+
+    /// Smallest allowed `threshold2 - threshold` for a band starting at `threshold`:
+    /// the larger of [`crate::config::MIN_ORACLE_RANGE_WIDTH`] and
+    /// [`crate::config::MIN_ORACLE_RANGE_WIDTH_BPS`] of the lower bound.
+    pub fn min_range_width(threshold: i128) -> i128 {
+        let relative = threshold.saturating_mul(crate::config::MIN_ORACLE_RANGE_WIDTH_BPS) / 10_000;
+        relative.max(crate::config::MIN_ORACLE_RANGE_WIDTH)
+    }
+
+    /// Returns `true` if `comparison` is one of the two-threshold range operators.
+    pub fn is_range(&self) -> bool {
+        self.comparison.is_range()
+    }
+
+    /// Evaluate `price` against this configuration; `true` resolves to "yes".
+    pub fn evaluate(&self, price: i128) -> Result<bool, crate::Error> {
+        self.comparison.apply(price, self.threshold, self.threshold2)
+    }
+
     /// Returns the reserved sentinel used to encode "no fallback oracle" in storage.
     ///
     /// The contracts persist fallback-oracle state as a `(has_fallback, fallback_oracle_config)`
     /// pair instead of `Option<OracleConfig>` for Soroban contract-type compatibility. When
     /// `has_fallback` is `false`, this sentinel is written into `fallback_oracle_config`.
     ///
-    /// Sentinel semantics are reserved by the tuple `(provider=Reflector, feed_id="", threshold=0,
-    /// comparison="")`. Those values are intentionally outside the valid oracle-config domain, so
+    /// Sentinel semantics are reserved by the tuple `(provider=Reflector, feed_id="",
+    /// threshold=0)`. Those values are intentionally outside the valid oracle-config domain, so
     /// the sentinel cannot collide with any configuration that passes validation.
     ///
     /// This value must never be used for live oracle creation or resolution.
@@ -756,20 +802,18 @@ impl OracleConfig {
             ),
             feed_id: String::from_str(env, ""),
             threshold: 0,
-            comparison: String::from_str(env, ""),
+            comparison: ComparisonOp::Eq,
+            threshold2: None,
         }
     }
 
     /// Returns `true` when this configuration is the reserved "no fallback" sentinel.
     ///
-    /// The sentinel identity intentionally ignores `oracle_address`: the reserved semantics are
-    /// carried by the invalid `feed_id` / `threshold` / `comparison` tuple, which keeps the
-    /// sentinel unambiguous even if a caller reuses the placeholder address elsewhere.
+    /// The sentinel identity intentionally ignores `oracle_address` and `comparison`: the reserved
+    /// semantics are carried by the invalid `feed_id` / `threshold` pair, which keeps the sentinel
+    /// unambiguous even if a caller reuses the placeholder address elsewhere.
     pub fn is_none_sentinel(&self) -> bool {
-        self.provider.as_str() == "reflector"
-            && self.feed_id.is_empty()
-            && self.threshold == 0
-            && self.comparison.is_empty()
+        self.provider.as_str() == "reflector" && self.feed_id.is_empty() && self.threshold == 0
     }
 }
 
@@ -782,40 +826,29 @@ impl OracleConfig {
         }
 
         crate::metadata_limits::validate_feed_id_length(&self.feed_id)?;
-        crate::metadata_limits::validate_comparison_length(&self.comparison)?;
 
         // Threshold must be positive
         if self.threshold <= 0 {
             return Err(crate::Error::InvalidThreshold);
         }
 
-        // Only allow gt / lt / eq
-        if self.comparison != String::from_str(env, "gt")
-            && self.comparison != String::from_str(env, "lt")
-            && self.comparison != String::from_str(env, "eq")
-        {
-            return Err(crate::Error::InvalidComparison);
+        // Range operators need a valid upper bound; single-threshold operators none
+        if self.is_range() {
+            match self.threshold2 {
+                Some(upper) if upper >= self.threshold => {
+                    if upper - self.threshold < Self::min_range_width(self.threshold) {
+                        return Err(crate::Error::RangeTooNarrow);
+                    }
+                }
+                _ => return Err(crate::Error::InvalidThreshold),
+            }
+        } else if self.threshold2.is_some() {
+            return Err(crate::Error::InvalidOracleConfig);
         }
 
         // Reject impossible combinations per provider
-        let provider_str = self.provider.as_str();
-        let feed_id_len = self.feed_id.len();
-
-        if provider_str == "reflector" {
-            // Reflector uses short asset symbols like "BTC/USD" or "XLM"
-            // Hex strings of 64+ chars are Pyth feeds and impossible for Reflector
-            if feed_id_len >= 64 {
-                return Err(crate::Error::InvalidOracleConfig);
-            }
-        } else if provider_str == "pyth" {
-            // Pyth uses 64-char hex strings (sometimes 66 with 0x)
-            if feed_id_len < 64 || feed_id_len > 66 {
-                return Err(crate::Error::InvalidOracleConfig);
-            }
-        } else if provider_str == "band_protocol" || provider_str == "dia" {
-            if feed_id_len >= 64 {
-                return Err(crate::Error::InvalidOracleConfig);
-            }
+        if !self.feed_id_matches_provider() {
+            return Err(crate::Error::InvalidOracleConfig);
         }
 
         // Validate provider is supported using new validation method
@@ -824,6 +857,55 @@ impl OracleConfig {
         Ok(())
     }
 
+    /// Validate the oracle configuration, reporting the first offending field.
+    ///
+    /// Fields are checked in the order `feed_id`, `threshold`, `threshold2`,
+    /// `provider`; `comparison` is typed, so every operator is well formed and
+    /// only its pairing with `threshold2` is checked. Shape problems map to a
+    /// per-field error (`InvalidFeedId`, `InvalidThreshold`); length limits and
+    /// range-width checks keep their specific errors. Used by market creation so
+    /// a rejected config says what to fix.
+    pub fn validate_fields(&self, env: &Env) -> Result<(), (Symbol, crate::Error)> {
+        let field = Symbol::new(env, "feed_id");
+        if self.is_none_sentinel() || self.feed_id.is_empty() || !self.feed_id_matches_provider() {
+            return Err((field, crate::Error::InvalidFeedId));
+        }
+        crate::metadata_limits::validate_feed_id_length(&self.feed_id).map_err(|e| (field, e))?;
+
+        if self.threshold <= 0 {
+            return Err((Symbol::new(env, "threshold"), crate::Error::InvalidThreshold));
+        }
+
+        let field = Symbol::new(env, "threshold2");
+        match (self.is_range(), self.threshold2) {
+            (true, Some(upper)) if upper >= self.threshold => {
+                if upper - self.threshold < Self::min_range_width(self.threshold) {
+                    return Err((field, crate::Error::RangeTooNarrow));
+                }
+            }
+            (true, _) => return Err((field, crate::Error::InvalidThreshold)),
+            (false, Some(_)) => return Err((field, crate::Error::InvalidThreshold)),
+            (false, None) => {}
+        }
+
+        self.provider
+            .validate_for_market(env)
+            .map_err(|e| (Symbol::new(env, "provider"), e))
+    }
+
+    /// Returns `false` for feed IDs that cannot belong to this provider.
+    ///
+    /// Reflector, Band and DIA use short asset symbols like "BTC/USD"; Pyth uses
+    /// 64-char hex IDs (66 with a `0x` prefix).
+    fn feed_id_matches_provider(&self) -> bool {
+        let feed_id_len = self.feed_id.len();
+        match self.provider.as_str() {
+            "reflector" | "band_protocol" | "dia" => feed_id_len < 64,
+            "pyth" => (64..=66).contains(&feed_id_len),
+            _ => true,
+        }
+    }
+
     /// Returns `true` if this oracle configuration is active and valid.
     ///
     /// An oracle is considered active if it's not the none sentinel.
@@ -891,7 +973,7 @@ impl OracleConfig {
 ///         OracleProvider::Reflector,
 ///         String::from_str(&env, "BTC/USD"),
 ///         100_000_00, // $100,000
-///         String::from_str(&env, "gt")
+///         ComparisonOp::Gt
 ///     ),
 ///     MarketState::Active
 /// );
@@ -1023,6 +1105,8 @@ impl OracleConfig {
 pub struct Market {
     /// Market administrator address
     pub admin: Address,
+    /// Address that created the market; set once at creation and never changed
+    pub creator: Address,
     /// Market question/prediction
     pub question: String,
     /// Available outcomes for the market
@@ -1090,8 +1174,22 @@ pub struct Market {
     /// Searchable tags for filtering: bounded list length, per-tag length, and no duplicates (enforced in
     /// `metadata_limits::validate_event_tags` and `Market::validate`).
     pub tags: Vec<String>,
+    /// Language of the question and outcomes as a BCP-47-style tag (e.g. "en", "pt-BR").
+    ///
+    /// Purely descriptive so clients can filter or pick a display locale; nothing is translated.
+    /// Defaults to `config::DEFAULT_MARKET_LANGUAGE`; validated by `metadata_limits::validate_language_tag`.
+    pub language: String,
     /// Minimum total pool size required for resolution (None = no minimum)
     pub min_pool_size: Option<i128>,
+    /// Minimum number of distinct voters required for resolution (None = no minimum).
+    /// A market resolved below it is cancelled and refunded instead.
+    pub min_voters: Option<u32>,
+    /// Largest share of `total_staked`, in basis points, that any one outcome may
+    /// hold after a vote (None = uncapped). Votes that would breach it are rejected.
+    pub outcome_cap_bps: Option<u32>,
+    /// Bounty escrowed by the creator for whoever resolves the market (0 = none).
+    /// Paid out on resolution, refunded to the creator if the market is voided.
+    pub resolution_bounty: i128,
     /// Bet deadline (Unix timestamp). No bets accepted after this time. 0 = use end_time (no early cutoff).
     pub bet_deadline: u64,
     /// Dispute window in seconds after end_time. Payouts allowed only after end_time + this period (or dispute resolved).
@@ -1099,6 +1197,95 @@ pub struct Market {
     /// Whether unclaimed winnings have already been swept for this market.
     /// Set to true after the first successful sweep to prevent double-crediting the treasury.
     pub winnings_swept: bool,
+    /// Extra wait in seconds after `end_time + resolution_timeout` before a
+    /// non-admin may refund the market on oracle failure.
+    ///
+    /// Set to `config::REFUND_GRACE_SECONDS` at creation.
+    pub refund_grace_seconds: u64,
+    /// Optional reduced platform fee for voters who commit before a cutoff.
+    ///
+    /// `None` at creation; see [`EarlyBirdFee`].
+    pub early_bird_fee: Option<EarlyBirdFee>,
+    /// Token winnings are paid in when it differs from the staking token.
+    ///
+    /// `None` pays out in the staking token through the internal balance ledger.
+    pub settlement_token: Option<Address>,
+    /// Settlement-token units paid per staking-token unit, in units of
+    /// `config::SETTLEMENT_RATE_DENOMINATOR`. Fixed at creation; 0 when
+    /// `settlement_token` is `None`.
+    pub settlement_rate_bps: u32,
+    /// Bond the resolving admin posted with a manual resolution, held until
+    /// the resolution is either upheld or overturned by a dispute (0 if none).
+    pub resolution_bond: i128,
+    /// Parent market and outcome this market is conditional on ("if A then B").
+    ///
+    /// `None` for an unconditional market. See [`MarketCondition`].
+    pub condition: Option<MarketCondition>,
+    /// How a hybrid resolution settles an oracle/community disagreement.
+    ///
+    /// `None` keeps the default weighted choice between the two sources.
+    /// See [`TiebreakPolicy`].
+    pub tiebreak_policy: Option<TiebreakPolicy>,
+    /// How claims are paid out. See [`PayoutModel`].
+    pub payout_model: PayoutModel,
+    /// Whether claims also wait `dispute_window_seconds` after the market is
+    /// resolved, not only after `end_time`, so a late resolution can still be
+    /// disputed before anything is paid out.
+    pub claims_wait_for_dispute_window: bool,
+}
+
+/// How a resolved market pays its participants.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PayoutModel {
+    /// Losing stakes fund the winners pro rata, less the platform fee (default)
+    Parimutuel,
+    /// Every participant recovers their stake, and winners also receive this
+    /// share of their stake (bps) from the contract's bonus pool
+    StakeBackPlusBonus(u32),
+}
+
+/// Deterministic fallback for a hybrid resolution in which the community
+/// consensus disagrees with the oracle result or is itself tied.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum TiebreakPolicy {
+    /// Settle on the oracle result alone
+    FavorOracle,
+    /// Settle on the community consensus outcome
+    FavorCommunity,
+    /// Cancel the market and refund every bet
+    Refund,
+    /// Move the market to `Disputed` for the dispute process to settle
+    Dispute,
+}
+
+/// Dependency of a conditional market on another market's resolution.
+///
+/// The market can only be resolved once its parent has resolved to
+/// `required_outcome`. A parent resolved to anything else, or cancelled,
+/// disqualifies it: it is cancelled and its bets refunded instead.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketCondition {
+    /// Market whose resolution this market depends on
+    pub parent_market_id: Symbol,
+    /// Parent outcome under which this market stays valid
+    pub required_outcome: String,
+}
+
+/// Early-liquidity incentive on a market: voters who commit before `cutoff`
+/// pay `fee_percentage` instead of the platform fee when they claim.
+///
+/// The applicable fee is snapshotted per voter at vote time, so changing the
+/// schedule later does not affect votes already placed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct EarlyBirdFee {
+    /// Votes placed strictly before this timestamp qualify
+    pub cutoff: u64,
+    /// Reduced fee, in the same percentage units as the platform fee
+    pub fee_percentage: i128,
 }
 
 /// Canonical payload committed by `Market::metadata_commitment`.
@@ -1114,6 +1301,23 @@ pub struct MarketMetadataCommitmentPayload {
     pub oracle_config: OracleConfig,
 }
 
+/// A set of correlated markets that must be resolved together.
+///
+/// Used for markets that are different views of the same real-world event
+/// (e.g. "Team A wins" / "Team B wins"). Members cannot be resolved on their own;
+/// `MarketGroupManager::resolve_group` settles every member in one call so the
+/// outcomes cannot contradict each other.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketGroup {
+    /// Group identifier
+    pub group_id: Symbol,
+    /// Member market IDs, in the order outcomes are supplied to `resolve_group`
+    pub members: Vec<Symbol>,
+    /// Whether the group has been resolved
+    pub resolved: bool,
+}
+
 // ===== CLAIM INFO =====
 
 /// Claim information for tracking idempotent winnings claims.
@@ -1229,6 +1433,62 @@ impl ClaimInfo {
     }
 }
 
+/// Per-market result of a best-effort batch claim.
+///
+/// A failed market leaves no state behind, so it can simply be claimed again
+/// once the cause is fixed.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimStatus {
+    /// Market the claim was attempted on
+    pub market_id: Symbol,
+    /// Whether the claim was recorded
+    pub success: bool,
+    /// Amount credited (0 for a losing vote or a failed claim)
+    pub payout: i128,
+    /// `Error` code that stopped the claim, 0 on success
+    pub error_code: u32,
+}
+
+/// Running settlement counters for a resolved market.
+///
+/// Advanced each time a winner is paid (self-claim or batch distribution) so
+/// operators can track settlement progress without scanning `Market::claimed`.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ClaimProgress {
+    /// Number of winners paid so far
+    pub claimed_count: u32,
+    /// Total amount paid out to winners so far
+    pub total_paid: i128,
+    /// Fees and insurance slices withheld from winners' shares so far
+    pub total_withheld: i128,
+}
+
+/// Net stake added per outcome during one fixed-width time bucket.
+///
+/// Kept in a bounded per-market ring by `voting::OutcomeMomentum`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MomentumBucket {
+    /// Bucket start (ledger timestamp, aligned to the bucket width)
+    pub start: u64,
+    /// Net stake change per outcome within the bucket (stakes minus withdrawals)
+    pub flows: Map<String, i128>,
+}
+
+/// One entry in a user's claim history, appended each time they are paid out.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ClaimHistoryEntry {
+    /// Market the winnings were claimed from
+    pub market_id: Symbol,
+    /// Net amount paid to the user
+    pub payout: i128,
+    /// Ledger timestamp of the claim
+    pub timestamp: u64,
+}
+
 // ===== BET LIMITS =====
 
 /// Configurable minimum and maximum bet amount for an event or globally.
@@ -1313,6 +1573,47 @@ pub struct UserStatistics {
     pub last_activity_ts: u64,
 }
 
+/// Running totals used to report mean resolution latency.
+///
+/// Latency is the time between a market's `end_time` and the ledger timestamp
+/// at which it was resolved; markets resolved before `end_time` count as 0.
+#[contracttype]
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct ResolutionLatencyStats {
+    /// Sum of all recorded latencies in seconds
+    pub total_latency_secs: u64,
+    /// Number of resolutions recorded
+    pub resolved_count: u64,
+}
+
+impl ResolutionLatencyStats {
+    /// Add one resolution to the running totals (saturating).
+    pub fn record(&mut self, end_time: u64, resolved_at: u64) {
+        let latency = resolved_at.saturating_sub(end_time);
+        self.total_latency_secs = self.total_latency_secs.saturating_add(latency);
+        self.resolved_count = self.resolved_count.saturating_add(1);
+    }
+
+    /// Mean latency in seconds, floored (0 when nothing has been resolved).
+    pub fn average(&self) -> u64 {
+        if self.resolved_count == 0 {
+            0
+        } else {
+            self.total_latency_secs / self.resolved_count
+        }
+    }
+}
+
+/// Leaderboard entry: a creator and the total staked across their resolved markets.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreatorVolume {
+    /// Market creator (the market's `creator`)
+    pub creator: Address,
+    /// Cumulative `total_staked` of the creator's resolved markets
+    pub volume: i128,
+}
+
 // ===== DASHBOARD STATISTICS TYPES =====
 
 /// Market statistics optimized for dashboard display
@@ -1501,6 +1802,7 @@ impl Market {
             &oracle_config,
         );
         Self {
+            creator: admin.clone(),
             admin,
             question,
             outcomes,
@@ -1526,13 +1828,34 @@ impl Market {
 
             category: None,
             tags: Vec::new(env),
+            language: String::from_str(env, crate::config::DEFAULT_MARKET_LANGUAGE),
             min_pool_size: None,
+            min_voters: None,
+            outcome_cap_bps: None,
+            resolution_bounty: 0,
             bet_deadline: 0,
             dispute_window_seconds: 86400, // 24h default
             winnings_swept: false,
+            refund_grace_seconds: crate::config::REFUND_GRACE_SECONDS,
+            early_bird_fee: None,
+            settlement_token: None,
+            settlement_rate_bps: 0,
+            resolution_bond: 0,
+            condition: None,
+            tiebreak_policy: None,
+            payout_model: PayoutModel::Parimutuel,
+            claims_wait_for_dispute_window: false,
         }
     }
 
+    /// Earliest time anyone may refund this market on oracle failure:
+    /// `end_time + resolution_timeout + refund_grace_seconds`.
+    pub fn refund_available_at(&self) -> u64 {
+        self.end_time
+            .saturating_add(self.resolution_timeout)
+            .saturating_add(self.refund_grace_seconds)
+    }
+
     /// Check if the market is active (not ended) using the current ledger timestamp
     pub fn is_active(&self, env: &Env) -> bool {
         env.ledger().timestamp() < self.end_time
@@ -1543,6 +1866,17 @@ impl Market {
         env.ledger().timestamp() >= self.end_time
     }
 
+    /// Time at which voting closes: `bet_deadline` when set, otherwise `end_time`.
+    ///
+    /// Between this cutoff and `end_time` the market stays `Active` but rejects votes.
+    pub fn voting_end_time(&self) -> u64 {
+        if self.bet_deadline > 0 {
+            self.bet_deadline
+        } else {
+            self.end_time
+        }
+    }
+
     /// Check if the market is resolved
     pub fn is_resolved(&self) -> bool {
         self.winning_outcomes.is_some()
@@ -1624,6 +1958,7 @@ impl Market {
         // Optional category and tags: size limits and duplicate-tag rejection (see metadata_limits)
         crate::metadata_limits::validate_option_category_metadata(&self.category)?;
         crate::metadata_limits::validate_event_tags(&self.tags)?;
+        crate::metadata_limits::validate_language_tag(&self.language)?;
 
         Ok(())
     }
@@ -1699,7 +2034,7 @@ impl Market {
 ///     OracleProvider::Reflector,
 ///     String::from_str(&env, &btc_asset.feed_id()),
 ///     50_000_00, // $50,000 threshold
-///     String::from_str(&env, "gt")
+///     ComparisonOp::Gt
 /// );
 ///
 /// // Validate asset support
@@ -1757,7 +2092,7 @@ impl Market {
 ///     OracleProvider::Reflector,
 ///     String::from_str(&env, &ReflectorAsset::BTC.feed_id()),
 ///     100_000_00,
-///     String::from_str(&env, "gt")
+///     ComparisonOp::Gt
 /// );
 ///
 /// // Create market for "Will ETH drop below $1,000?"
@@ -1765,7 +2100,7 @@ impl Market {
 ///     OracleProvider::Reflector,
 ///     String::from_str(&env, &ReflectorAsset::ETH.feed_id()),
 ///     1_000_00,
-///     String::from_str(&env, "lt")
+///     ComparisonOp::Lt
 /// );
 ///
 /// // Create market for "Will XLM reach $1?"
@@ -1773,7 +2108,7 @@ impl Market {
 ///     OracleProvider::Reflector,
 ///     String::from_str(&env, &ReflectorAsset::XLM.feed_id()),
 ///     100, // $1.00
-///     String::from_str(&env, "gt")
+///     ComparisonOp::Gt
 /// );
 /// ```
 ///
@@ -1845,7 +2180,7 @@ pub enum ReflectorAsset {
 ///     outcome: String::from_str(&env, "yes"),
 ///     price: 52_000_00,
 ///     threshold: 50_000_00,
-///     comparison: String::from_str(&env, "gt"),
+///     comparison: ComparisonOp::Gt,
 ///     provider: OracleProvider::Reflector,
 ///     feed_id: String::from_str(&env, "BTC/USD"),
 ///     timestamp: env.ledger().timestamp(),
@@ -1868,8 +2203,8 @@ pub struct OracleResult {
     pub price: i128,
     /// Threshold configured for this market
     pub threshold: i128,
-    /// Comparison operator used ("gt", "lt", "eq")
-    pub comparison: String,
+    /// Comparison operator used
+    pub comparison: ComparisonOp,
     /// Oracle provider that provided the result
     pub provider: OracleProvider,
     /// Feed ID used for price lookup
@@ -2933,7 +3268,7 @@ pub struct ExtensionStats {
 ///         OracleProvider::Reflector,
 ///         String::from_str(&env, "BTC/USD"),
 ///         100_000_00, // $100,000 threshold
-///         String::from_str(&env, "gt")
+///         ComparisonOp::Gt
 ///     ),
 ///     5_000_000 // 5 XLM creation fee
 /// );
@@ -3114,7 +3449,7 @@ pub struct ExtensionStats {
 ///             OracleProvider::Reflector,
 ///             String::from_str(&env, "BTC/USD"),
 ///             threshold,
-///             String::from_str(&env, "gt")
+///             ComparisonOp::Gt
 ///         ),
 ///         calculate_creation_fee(days)
 ///     )
@@ -3636,6 +3971,76 @@ pub struct MarketPoolQuery {
     pub implied_probability_no: u32,
 }
 
+/// Per-market configuration flags query response.
+///
+/// Collects the boolean/enum flags that are otherwise spread across the
+/// `Market` record and several side tables, so clients can read them in one call.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketFlags {
+    /// Market/event ID
+    pub market_id: Symbol,
+    /// Current lifecycle state
+    pub state: MarketState,
+    /// Whether a fallback oracle is configured
+    pub has_fallback: bool,
+    /// Whether platform fees have been collected
+    pub fee_collected: bool,
+    /// Whether unclaimed winnings have been swept to the treasury
+    pub winnings_swept: bool,
+    /// Whether the market belongs to a resolution group
+    pub grouped: bool,
+    /// Whether the oracle result has been verified
+    pub result_verified: bool,
+    /// Whether the market has been archived
+    pub archived: bool,
+}
+
+/// Who resolved a market, to what, and on what evidence.
+///
+/// Written by the admin resolution entrypoints so dispute reviewers can see
+/// the justification given at resolution time.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ResolutionRecord {
+    /// Market/event ID
+    pub market_id: Symbol,
+    /// Winning outcome(s) as resolved
+    pub winning_outcomes: Vec<String>,
+    /// Admin who resolved the market
+    pub resolved_by: Address,
+    /// Optional justification, e.g. a URL to the source of truth
+    pub evidence: Option<String>,
+    /// Resolution timestamp
+    pub timestamp: u64,
+}
+
+/// Everything needed to render a market card, returned by one query.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MarketSummary {
+    /// Market/event ID
+    pub market_id: Symbol,
+    /// Prediction question
+    pub question: String,
+    /// Possible outcomes
+    pub outcomes: Vec<String>,
+    /// Total staked on each outcome
+    pub outcome_totals: Map<String, i128>,
+    /// Total amount staked in the market
+    pub total_staked: i128,
+    /// Number of distinct voters
+    pub voter_count: u32,
+    /// Current lifecycle state
+    pub state: MarketState,
+    /// Market end timestamp
+    pub end_time: u64,
+    /// Winning outcome(s) once resolved
+    pub winning_outcomes: Option<Vec<String>>,
+    /// Oracle result, if one has been fetched
+    pub oracle_result: Option<String>,
+}
+
 /// Contract global state statistics query response.
 ///
 /// Provides system-level metrics and statistics across all markets,
@@ -4140,7 +4545,7 @@ mod tests {
             Address::generate(env),
             String::from_str(env, "BTC/USD"),
             1_000_000,
-            String::from_str(env, "gt"),
+            ComparisonOp::Gt,
         )
     }
 
@@ -4158,7 +4563,7 @@ mod tests {
             Address::generate(&env),
             String::from_str(&env, "BTC/USD"),
             1_000_000,
-            String::from_str(&env, "gt"),
+            ComparisonOp::Gt,
         );
 
         let market = Market::new(
@@ -4202,4 +4607,177 @@ mod tests {
 
         assert_eq!(market.validate(&env), Ok(()));
     }
+
+    fn range_config(env: &Env, low: i128, high: i128, comparison: ComparisonOp) -> OracleConfig {
+        OracleConfig::new_range(
+            OracleProvider::Reflector,
+            Address::generate(env),
+            String::from_str(env, "BTC/USD"),
+            low,
+            high,
+            comparison,
+        )
+    }
+
+    #[test]
+    fn validate_fields_reports_offending_field() {
+        let env = Env::default();
+        let field = |name: &str| Symbol::new(&env, name);
+
+        assert_eq!(valid_oracle_config(&env).validate_fields(&env), Ok(()));
+        assert_eq!(
+            range_config(&env, 40_000_00, 50_000_00, ComparisonOp::InRange).validate_fields(&env),
+            Ok(())
+        );
+
+        // feed_id: empty, or the wrong shape for the provider
+        let mut config = valid_oracle_config(&env);
+        config.feed_id = String::from_str(&env, "");
+        assert_eq!(
+            config.validate_fields(&env),
+            Err((field("feed_id"), crate::Error::InvalidFeedId))
+        );
+        config.provider = OracleProvider::Pyth;
+        config.feed_id = String::from_str(&env, "BTC/USD");
+        assert_eq!(
+            config.validate_fields(&env),
+            Err((field("feed_id"), crate::Error::InvalidFeedId))
+        );
+
+        // threshold
+        let mut config = valid_oracle_config(&env);
+        config.threshold = 0;
+        assert_eq!(
+            config.validate_fields(&env),
+            Err((field("threshold"), crate::Error::InvalidThreshold))
+        );
+
+        // comparison: every single-threshold operator is accepted at creation
+        for op in [ComparisonOp::Gte, ComparisonOp::Lte, ComparisonOp::Neq].iter() {
+            let mut config = valid_oracle_config(&env);
+            config.comparison = *op;
+            assert_eq!(config.validate_fields(&env), Ok(()));
+            assert_eq!(config.validate(&env), Ok(()));
+        }
+
+        // threshold2: missing upper bound, or set on a single-threshold operator
+        let mut config = range_config(&env, 40_000_00, 50_000_00, ComparisonOp::InRange);
+        config.threshold2 = None;
+        assert_eq!(
+            config.validate_fields(&env),
+            Err((field("threshold2"), crate::Error::InvalidThreshold))
+        );
+        let mut config = valid_oracle_config(&env);
+        config.threshold2 = Some(2_000_000);
+        assert_eq!(
+            config.validate_fields(&env),
+            Err((field("threshold2"), crate::Error::InvalidThreshold))
+        );
+
+        // First offending field wins
+        let mut config = valid_oracle_config(&env);
+        config.feed_id = String::from_str(&env, "");
+        config.threshold = -1;
+        config.threshold2 = Some(1);
+        assert_eq!(
+            config.validate_fields(&env),
+            Err((field("feed_id"), crate::Error::InvalidFeedId))
+        );
+    }
+
+    #[test]
+    fn range_config_validates_second_threshold() {
+        let env = Env::default();
+
+        let config = range_config(&env, 40_000_00, 50_000_00, ComparisonOp::InRange);
+        assert_eq!(config.validate(&env), Ok(()));
+        assert_eq!(
+            range_config(&env, 50_000_00, 40_000_00, ComparisonOp::InRange).validate(&env),
+            Err(crate::Error::InvalidThreshold)
+        );
+
+        let mut missing_upper = range_config(&env, 40_000_00, 50_000_00, ComparisonOp::InRange);
+        missing_upper.threshold2 = None;
+        assert_eq!(missing_upper.validate(&env), Err(crate::Error::InvalidThreshold));
+
+        let mut stray_upper = valid_oracle_config(&env);
+        stray_upper.threshold2 = Some(2_000_000);
+        assert_eq!(stray_upper.validate(&env), Err(crate::Error::InvalidOracleConfig));
+    }
+
+    #[test]
+    fn range_config_rejects_too_narrow_band() {
+        let env = Env::default();
+
+        // Lower bound $40,000 -> 1% floor is $400 (40_000 cents)
+        assert_eq!(OracleConfig::min_range_width(40_000_00), 40_000);
+        assert_eq!(
+            range_config(&env, 40_000_00, 40_000_00 + 40_000, ComparisonOp::InRange).validate(&env),
+            Ok(())
+        );
+        assert_eq!(
+            range_config(&env, 40_000_00, 45_000_00, ComparisonOp::OutOfRange).validate(&env),
+            Ok(())
+        );
+        assert_eq!(
+            range_config(&env, 40_000_00, 40_000_00 + 39_999, ComparisonOp::InRange).validate(&env),
+            Err(crate::Error::RangeTooNarrow)
+        );
+        assert_eq!(
+            range_config(&env, 40_000_00, 40_000_00, ComparisonOp::OutOfRange).validate(&env),
+            Err(crate::Error::RangeTooNarrow)
+        );
+
+        // Low-priced feed: the absolute floor ($1.00) applies
+        assert_eq!(OracleConfig::min_range_width(1_000), 100);
+        assert_eq!(range_config(&env, 1_000, 1_100, ComparisonOp::InRange).validate(&env), Ok(()));
+        assert_eq!(
+            range_config(&env, 1_000, 1_099, ComparisonOp::InRange).validate(&env),
+            Err(crate::Error::RangeTooNarrow)
+        );
+    }
+
+    #[test]
+    fn in_range_resolution_picks_outcome_by_bounds() {
+        let env = Env::default();
+        let config = range_config(&env, 40_000_00, 50_000_00, ComparisonOp::InRange);
+        let yes = String::from_str(&env, "yes");
+        let no = String::from_str(&env, "no");
+
+        for (price, expected) in [
+            (45_000_00, &yes),
+            (40_000_00, &yes),
+            (50_000_00, &yes),
+            (39_999_99, &no),
+            (50_000_01, &no),
+        ] {
+            assert_eq!(
+                crate::oracles::OracleUtils::determine_outcome_for_config(price, &config, &env)
+                    .unwrap(),
+                *expected
+            );
+        }
+    }
+
+    #[test]
+    fn out_of_range_resolution_picks_outcome_by_bounds() {
+        let env = Env::default();
+        let config = range_config(&env, 40_000_00, 50_000_00, ComparisonOp::OutOfRange);
+        let yes = String::from_str(&env, "yes");
+        let no = String::from_str(&env, "no");
+
+        for (price, expected) in [
+            (45_000_00, &no),
+            (40_000_00, &no),
+            (50_000_00, &no),
+            (39_999_99, &yes),
+            (60_000_00, &yes),
+        ] {
+            assert_eq!(
+                crate::oracles::OracleUtils::determine_outcome_for_config(price, &config, &env)
+                    .unwrap(),
+                *expected
+            );
+        }
+    }
 }
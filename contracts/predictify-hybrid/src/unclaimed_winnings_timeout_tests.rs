@@ -74,6 +74,7 @@ impl TimeoutSweepSetup {
                     feed_id: String::from_str(&env, "TEST/YES"),
                     threshold: 1,
                     comparison: String::from_str(&env, "gt"),
+                    threshold2: None,
                 },
                 None,
                 86_400,
@@ -265,6 +266,7 @@ fn test_delayed_resolution_has_fresh_claim_window() {
                 feed_id: String::from_str(&setup.env, "TEST/YES"),
                 threshold: 1,
                 comparison: String::from_str(&setup.env, "gt"),
+                threshold2: None,
             },
             None,
             86_400,
@@ -288,6 +290,7 @@ fn test_delayed_resolution_has_fresh_claim_window() {
         &setup.admin,
         &setup.market_id,
         &String::from_str(&setup.env, "yes"),
+        &None,
     );
 
     setup
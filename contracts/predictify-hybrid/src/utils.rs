@@ -2,7 +2,7 @@ extern crate alloc;
 
 use alloc::string::ToString; // Only for primitive types, not soroban_sdk::String
 
-use soroban_sdk::{Address, Env, Map, String, Symbol, Vec};
+use soroban_sdk::{contracttype, symbol_short, Address, Env, Map, String, Symbol, Vec};
 
 use crate::err::Error;
 
@@ -960,6 +960,46 @@ impl NumericUtils {
         x
     }
 
+    /// Subtract `amount` from a tracked stake total.
+    ///
+    /// Fails with `Error::ArithmeticUnderflow` instead of letting the total go
+    /// negative (or wrap), so a stake-reducing path can never take out more
+    /// than was recorded.
+    pub fn checked_reduce(total: i128, amount: i128) -> Result<i128, Error> {
+        total
+            .checked_sub(amount)
+            .filter(|remaining| *remaining >= 0)
+            .ok_or(Error::ArithmeticUnderflow)
+    }
+
+    /// Find the outcome that can most cheaply become the strict leader.
+    ///
+    /// For each entry in `totals` the required stake is `max(others) - own + 1`,
+    /// or 0 if it already leads strictly. Returns `(index, additional_stake)` for
+    /// the smallest requirement; ties go to the lowest index. `None` if empty.
+    pub fn cheapest_strict_lead(totals: &Vec<i128>) -> Option<(u32, i128)> {
+        let mut best: Option<(u32, i128)> = None;
+        for i in 0..totals.len() {
+            let own = totals.get_unchecked(i);
+            let mut max_other = 0i128;
+            for j in 0..totals.len() {
+                if j != i {
+                    max_other = max_other.max(totals.get_unchecked(j));
+                }
+            }
+            let needed = if own > max_other {
+                0
+            } else {
+                max_other.saturating_sub(own).saturating_add(1)
+            };
+            match best {
+                Some((_, cost)) if cost <= needed => {}
+                _ => best = Some((i, needed)),
+            }
+        }
+        best
+    }
+
     /// Calculate weighted average
     pub fn weighted_average(values: &Vec<i128>, weights: &Vec<i128>) -> i128 {
         if values.len() != weights.len() || values.len() == 0 {
@@ -998,6 +1038,66 @@ impl NumericUtils {
         // Return 0 as placeholder
         0
     }
+
+    /// Divide a non-negative `numerator` by a positive `denominator`, rounding
+    /// the remainder according to `rounding`.
+    pub fn div_rounded(numerator: i128, denominator: i128, rounding: PayoutRounding) -> i128 {
+        let quotient = numerator / denominator;
+        let remainder = numerator % denominator;
+        match rounding {
+            PayoutRounding::Floor => quotient,
+            PayoutRounding::HalfEven => {
+                // Compare remainder with denominator / 2 without doubling it
+                let above_half = remainder > denominator - remainder;
+                let exactly_half = remainder == denominator - remainder;
+                if above_half || (exactly_half && quotient % 2 != 0) {
+                    quotient + 1
+                } else {
+                    quotient
+                }
+            }
+        }
+    }
+}
+
+// ===== PAYOUT ROUNDING =====
+
+/// Storage key for the configured payout rounding mode.
+const PAYOUT_ROUNDING_KEY: Symbol = symbol_short!("pay_rnd");
+
+/// How payout divisions treat the fractional remainder.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum PayoutRounding {
+    /// Always round down. Remainders stay in the contract as dust.
+    Floor,
+    /// Banker's rounding: round to nearest, ties to the even result.
+    HalfEven,
+}
+
+/// Deployment-wide rounding policy for winner payouts.
+///
+/// Defaults to [`PayoutRounding::Floor`]. Whatever the mode, `claim_winnings`
+/// caps each payout at what is left of the market's pool, so rounding up can
+/// never pay out more than the pool holds; late claimants absorb the shortfall.
+pub struct PayoutRoundingPolicy;
+
+impl PayoutRoundingPolicy {
+    /// Current rounding mode.
+    pub fn get(env: &Env) -> PayoutRounding {
+        env.storage()
+            .persistent()
+            .get(&PAYOUT_ROUNDING_KEY)
+            .unwrap_or(PayoutRounding::Floor)
+    }
+
+    /// Set the rounding mode (admin only).
+    pub fn set(env: &Env, admin: &Address, rounding: PayoutRounding) -> Result<(), Error> {
+        admin.require_auth();
+        crate::resolution::MarketResolutionValidator::validate_admin_permissions(env, admin)?;
+        env.storage().persistent().set(&PAYOUT_ROUNDING_KEY, &rounding);
+        Ok(())
+    }
 }
 
 // ===== VALIDATION UTILITIES =====
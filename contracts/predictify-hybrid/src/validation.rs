@@ -5,6 +5,7 @@ extern crate alloc;
 use crate::{
     config,
     errors::Error,
+    oracles::ComparisonOp,
     types::{BetLimits, Market, OracleConfig, OracleProvider},
 };
 use alloc::{string::String as StdString, vec::Vec as AllocVec};
@@ -1681,7 +1682,7 @@ impl InputValidator {
 ///     provider: OracleProvider::Reflector,
 ///     feed_id: String::from_str(&env, "BTC/USD"),
 ///     threshold: 100000000000i128, // $100k
-///     comparison: String::from_str(&env, "gte"),
+///     comparison: ComparisonOp::Gte,
 /// };
 ///
 /// let creation_result = MarketValidator::validate_market_creation(
@@ -1748,7 +1749,7 @@ impl InputValidator {
 ///             provider: OracleProvider::Reflector,
 ///             feed_id: String::from_str(&env, "BTC/USD"),
 ///             threshold: 100000000000i128,
-///             comparison: String::from_str(&env, "gte"),
+///             comparison: ComparisonOp::Gte,
 ///         },
 ///         "Valid market with proper parameters"
 ///     ),
@@ -1766,7 +1767,7 @@ impl InputValidator {
 ///             provider: OracleProvider::Reflector,
 ///             feed_id: String::from_str(&env, "BTC/USD"),
 ///             threshold: 100000000000i128,
-///             comparison: String::from_str(&env, "gte"),
+///             comparison: ComparisonOp::Gte,
 ///         },
 ///         "Market with question too short"
 ///     ),
@@ -1784,7 +1785,7 @@ impl InputValidator {
 ///             provider: OracleProvider::Reflector,
 ///             feed_id: String::from_str(&env, "ETH/USD"),
 ///             threshold: 5000000000i128,
-///             comparison: String::from_str(&env, "gte"),
+///             comparison: ComparisonOp::Gte,
 ///         },
 ///         "Market with invalid duration"
 ///     ),
@@ -1903,7 +1904,7 @@ impl InputValidator {
 ///             provider: OracleProvider::Reflector,
 ///             feed_id: String::from_str(&env, "BTC/USD"),
 ///             threshold: 100000000000i128, // $100k
-///             comparison: String::from_str(&env, "gte"),
+///             comparison: ComparisonOp::Gte,
 ///         },
 ///         "Valid Reflector oracle configuration"
 ///     ),
@@ -1913,7 +1914,7 @@ impl InputValidator {
 ///             provider: OracleProvider::Pyth,
 ///             feed_id: String::from_str(&env, "ETH/USD"),
 ///             threshold: 5000000000i128, // $5k
-///             comparison: String::from_str(&env, "gte"),
+///             comparison: ComparisonOp::Gte,
 ///         },
 ///         "Valid Pyth oracle configuration"
 ///     ),
@@ -1923,7 +1924,7 @@ impl InputValidator {
 ///             provider: OracleProvider::Reflector,
 ///             feed_id: String::from_str(&env, "XLM/USD"),
 ///             threshold: -1000000i128, // Negative threshold
-///             comparison: String::from_str(&env, "gte"),
+///             comparison: ComparisonOp::Gte,
 ///         },
 ///         "Oracle with negative threshold"
 ///     ),
@@ -1933,7 +1934,7 @@ impl InputValidator {
 ///             provider: OracleProvider::Reflector,
 ///             feed_id: String::from_str(&env, "B"), // Too short
 ///             threshold: 50000000000i128,
-///             comparison: String::from_str(&env, "gte"),
+///             comparison: ComparisonOp::Gte,
 ///         },
 ///         "Oracle with invalid feed ID"
 ///     ),
@@ -1986,7 +1987,7 @@ impl InputValidator {
 ///                 provider: OracleProvider::Reflector,
 ///                 feed_id: String::from_str(&env, "BTC/USD"),
 ///                 threshold: 100000000000i128,
-///                 comparison: String::from_str(&env, "gte"),
+///                 comparison: ComparisonOp::Gte,
 ///             },
 ///             state: MarketState::Active,
 ///         },
@@ -2008,7 +2009,7 @@ impl InputValidator {
 ///                 provider: OracleProvider::Reflector,
 ///                 feed_id: String::from_str(&env, "ETH/USD"),
 ///                 threshold: 5000000000i128,
-///                 comparison: String::from_str(&env, "gte"),
+///                 comparison: ComparisonOp::Gte,
 ///             },
 ///             state: MarketState::Resolved,
 ///         },
@@ -2089,7 +2090,7 @@ impl InputValidator {
 ///             provider: OracleProvider::Reflector,
 ///             feed_id: String::from_str(&env, "BTC/USD"),
 ///             threshold: 100000000000i128,
-///             comparison: String::from_str(&env, "gte"),
+///             comparison: ComparisonOp::Gte,
 ///         },
 ///     ),
 ///     (
@@ -2104,7 +2105,7 @@ impl InputValidator {
 ///             provider: OracleProvider::Reflector,
 ///             feed_id: String::from_str(&env, "ETH/USD"),
 ///             threshold: 5000000000i128,
-///             comparison: String::from_str(&env, "gte"),
+///             comparison: ComparisonOp::Gte,
 ///         },
 ///     ),
 /// ];
@@ -2410,26 +2411,14 @@ impl OracleValidator {
         OracleConfigValidator::validate_oracle_config_all_together(oracle_config)
     }
 
-    /// Validate comparison operator
+    /// Validate a comparison operator given as a string (see [`ComparisonOp::parse`])
     pub fn validate_comparison_operator(
         env: &Env,
         comparison: &String,
     ) -> Result<(), ValidationError> {
-        let valid_operators = vec![
-            env,
-            String::from_str(env, "gt"),
-            String::from_str(env, "gte"),
-            String::from_str(env, "lt"),
-            String::from_str(env, "lte"),
-            String::from_str(env, "eq"),
-            String::from_str(env, "ne"),
-        ];
-
-        if !valid_operators.contains(comparison) {
-            return Err(ValidationError::InvalidOracle);
-        }
-
-        Ok(())
+        ComparisonOp::parse(env, comparison)
+            .map(|_| ())
+            .map_err(|_| ValidationError::InvalidOracle)
     }
 
     /// Validate oracle provider
@@ -2918,7 +2907,7 @@ pub fn validate_bet_amount_against_limits(amount: i128, limits: &BetLimits) -> R
 ///         provider: OracleProvider::Reflector,
 ///         feed_id: String::from_str(&env, "BTC/USD"),
 ///         threshold: 100000000000i128,
-///         comparison: String::from_str(&env, "gte"),
+///         comparison: ComparisonOp::Gte,
 ///     },
 ///     state: MarketState::Resolved,
 /// };
@@ -3154,7 +3143,7 @@ impl ConfigValidator {
 ///     provider: OracleProvider::Reflector,
 ///     feed_id: String::from_str(&env, "BTC/USD"),
 ///     threshold: 100000000000i128,
-///     comparison: String::from_str(&env, "gte"),
+///     comparison: ComparisonOp::Gte,
 /// };
 ///
 /// let result = ComprehensiveValidator::validate_complete_market_creation(
@@ -3460,7 +3449,8 @@ impl ValidationTestingUtils {
                 ),
                 feed_id: String::from_str(env, "BTC/USD"),
                 threshold: 2500000,
-                comparison: String::from_str(env, "gt"),
+                comparison: ComparisonOp::Gt,
+                threshold2: None,
             },
             None,
             86400,
@@ -3478,7 +3468,8 @@ impl ValidationTestingUtils {
             ),
             feed_id: String::from_str(env, "BTC/USD"),
             threshold: 2500000,
-            comparison: String::from_str(env, "gt"),
+            comparison: ComparisonOp::Gt,
+            threshold2: None,
         }
     }
 }
@@ -4158,6 +4149,11 @@ impl MarketParameterValidator {
     /// * `"lt"` - Less than
     /// * `"lte"` - Less than or equal to
     /// * `"eq"` - Equal to
+    /// * `"neq"` - Not equal to
+    /// * `"in_range"` / `"out_of_range"` - Within / outside a two-threshold band
+    ///
+    /// Legacy symbolic spellings (`">="`, `"!="`, ...) are accepted as well; see
+    /// [`ComparisonOp::parse`].
     ///
     /// # Example
     ///
@@ -4198,21 +4194,9 @@ impl MarketParameterValidator {
         }
 
         // Validate comparison operator is supported
-        let env = &comparison.env();
-        let valid_operators = vec![
-            &env,
-            String::from_str(env, "gt"),
-            String::from_str(env, "gte"),
-            String::from_str(env, "lt"),
-            String::from_str(env, "lte"),
-            String::from_str(env, "eq"),
-        ];
-
-        if !valid_operators.contains(&comparison) {
-            return Err(ValidationError::InvalidInput);
-        }
-
-        Ok(())
+        ComparisonOp::parse(comparison.env(), &comparison)
+            .map(|_| ())
+            .map_err(|_| ValidationError::InvalidInput)
     }
 
     /// Validates all market parameters together for comprehensive validation.
@@ -4550,7 +4534,7 @@ impl MarketParams {
 ///     OracleProvider::Reflector,
 ///     String::from_str(&env, "BTC/USD"),
 ///     50_000_00, // $50,000 threshold
-///     String::from_str(&env, "gt")
+///     ComparisonOp::Gt
 /// );
 ///
 /// // Validate the complete configuration
@@ -4731,44 +4715,21 @@ impl OracleConfigValidator {
     ///
     /// # Arguments
     /// * `comparison` - The comparison operator to validate
-    /// * `supported_operators` - Vector of supported operators for the provider
+    /// * `supported_operators` - Operators supported for the provider
     ///
     /// # Returns
     /// * `Ok(())` - Comparison operator is supported
     /// * `Err(ValidationError)` - Comparison operator is not supported
     ///
-    /// # Supported Operators
-    ///
-    /// **Standard Operators:**
-    /// - "gt": Greater than
-    /// - "gte": Greater than or equal
-    /// - "lt": Less than
-    /// - "lte": Less than or equal
-    /// - "eq": Equal to
-    /// - "ne": Not equal to
-    ///
     /// # Provider-Specific Support
     ///
-    /// **Reflector Oracle:**
-    /// - Supported: "gt", "lt", "eq"
-    /// - Not supported: "gte", "lte", "ne"
-    ///
-    /// **Pyth Network:**
-    /// - Supported: "gt", "gte", "lt", "lte", "eq"
-    /// - Not supported: "ne"
-    ///
-    /// **Band Protocol & DIA:**
-    /// - Not supported on Stellar
+    /// The comparison is applied by the contract to the fetched price, so every
+    /// [`ComparisonOp`] is available on Reflector and Pyth. Band Protocol and DIA
+    /// are not supported on Stellar and have no operators.
     pub fn validate_comparison_operator(
-        comparison: &String,
-        supported_operators: &Vec<String>,
+        comparison: &ComparisonOp,
+        supported_operators: &[ComparisonOp],
     ) -> Result<(), ValidationError> {
-        // Check if comparison is empty
-        if comparison.is_empty() {
-            return Err(ValidationError::InvalidOracle);
-        }
-
-        // Check if comparison is in supported operators list
         if !supported_operators.contains(comparison) {
             return Err(ValidationError::InvalidOracle);
         }
@@ -4851,7 +4812,17 @@ impl OracleConfigValidator {
         let supported_operators = Self::get_supported_operators_for_provider(&config.provider);
 
         // Validate comparison operator
-        Self::validate_comparison_operator_literals(&config.comparison, supported_operators)?;
+        Self::validate_comparison_operator(&config.comparison, supported_operators)?;
+
+        // Range operators need an upper bound no lower than the threshold
+        if config.is_range() {
+            match config.threshold2 {
+                Some(upper) if upper >= config.threshold => {}
+                _ => return Err(ValidationError::InvalidOracle),
+            }
+        } else if config.threshold2.is_some() {
+            return Err(ValidationError::InvalidOracle);
+        }
 
         // Additional consistency checks
         match config.provider {
@@ -5029,7 +5000,7 @@ impl OracleConfigValidator {
 
         // Step 4: Get supported operators and validate comparison
         let supported_operators = Self::get_supported_operators_for_provider(&config.provider);
-        Self::validate_comparison_operator_literals(&config.comparison, supported_operators)?;
+        Self::validate_comparison_operator(&config.comparison, supported_operators)?;
 
         // Step 5: Validate configuration consistency
         Self::validate_config_consistency(config)?;
@@ -5043,46 +5014,18 @@ impl OracleConfigValidator {
     /// * `provider` - The oracle provider to get operators for
     ///
     /// # Returns
-    /// * `&[&str]` - Provider-specific supported comparison operators
+    /// * `&[ComparisonOp]` - Provider-specific supported comparison operators
     ///
     /// # Provider-Specific Operators
     ///
-    /// **Reflector Oracle:**
-    /// - "gt": Greater than
-    /// - "lt": Less than
-    /// - "eq": Equal to
-    ///
-    /// **Pyth Network:**
-    /// - "gt": Greater than
-    /// - "gte": Greater than or equal
-    /// - "lt": Less than
-    /// - "lte": Less than or equal
-    /// - "eq": Equal to
+    /// **Reflector Oracle & Pyth Network:**
+    /// - Every [`ComparisonOp`]; the comparison runs in the contract
     ///
     /// **Band Protocol & DIA:**
-    /// - Empty vector (not supported)
-    fn validate_comparison_operator_literals(
-        comparison: &String,
-        supported_operators: &[&str],
-    ) -> Result<(), ValidationError> {
-        if comparison.is_empty() {
-            return Err(ValidationError::InvalidOracle);
-        }
-
-        if supported_operators
-            .iter()
-            .any(|operator| comparison == &String::from_str(comparison.env(), operator))
-        {
-            return Ok(());
-        }
-
-        Err(ValidationError::InvalidOracle)
-    }
-
-    fn get_supported_operators_for_provider(provider: &OracleProvider) -> &'static [&'static str] {
+    /// - Empty slice (not supported)
+    fn get_supported_operators_for_provider(provider: &OracleProvider) -> &'static [ComparisonOp] {
         match provider {
-            OracleProvider::Reflector => &["gt", "lt", "eq"],
-            OracleProvider::Pyth => &["gt", "gte", "lt", "lte", "eq"],
+            OracleProvider::Reflector | OracleProvider::Pyth => &ComparisonOp::ALL,
             _ => &[],
         }
     }
@@ -5147,7 +5090,9 @@ impl CreationValidator {
     /// Validate creation outcomes for market and event creation.
     ///
     /// This enforces the configured outcome count bounds, rejects empty or
-    /// whitespace-only outcomes, and rejects duplicate or ambiguous outcomes.
+    /// whitespace-only outcomes, rejects the reserved
+    /// [`config::RESERVED_INVALID_OUTCOME`] label with `Error::ReservedOutcome`,
+    /// and rejects duplicate or ambiguous outcomes.
     pub fn validate_creation_outcomes(env: &Env, outcomes: &Vec<String>) -> Result<(), Error> {
         let cfg = config::ConfigManager::get_config(env).map_err(|_| Error::ConfigNotFound)?;
         let outcome_count = outcomes.len() as u32;
@@ -5166,6 +5111,10 @@ impl CreationValidator {
             if length < config::MIN_OUTCOME_LENGTH || length > cfg.market.max_outcome_length {
                 return Err(Error::InvalidOutcomes);
             }
+
+            if trimmed.eq_ignore_ascii_case(config::RESERVED_INVALID_OUTCOME) {
+                return Err(Error::ReservedOutcome);
+            }
         }
 
         OutcomeDeduplicator::validate_outcomes(outcomes).map_err(|_| Error::InvalidOutcomes)?;
@@ -5184,6 +5133,25 @@ impl CreationValidator {
         Ok(())
     }
 
+    /// Validate a seconds-based market duration during market creation.
+    ///
+    /// The lower bound is [`config::MIN_MARKET_DURATION_SECONDS`]; the upper
+    /// bound is the configured `max_duration_days` expressed in seconds.
+    pub fn validate_market_duration_seconds(
+        env: &Env,
+        duration_seconds: &u64,
+    ) -> Result<(), Error> {
+        let cfg = config::ConfigManager::get_config(env).map_err(|_| Error::ConfigNotFound)?;
+        let max_seconds = (cfg.market.max_duration_days as u64) * 86_400;
+        if *duration_seconds < config::MIN_MARKET_DURATION_SECONDS
+            || *duration_seconds > max_seconds
+        {
+            return Err(Error::InvalidDuration);
+        }
+
+        Ok(())
+    }
+
     /// Validate that an event end time is still in the future.
     pub fn validate_event_end_time(env: &Env, end_time: &u64) -> Result<(), Error> {
         if *end_time <= env.ledger().timestamp() {
@@ -5206,6 +5174,19 @@ impl CreationValidator {
         Ok(())
     }
 
+    /// Validate all shared market creation inputs for a seconds-based duration.
+    pub fn validate_market_creation_seconds(
+        env: &Env,
+        question: &String,
+        outcomes: &Vec<String>,
+        duration_seconds: &u64,
+    ) -> Result<(), Error> {
+        Self::validate_market_question(env, question)?;
+        Self::validate_creation_outcomes(env, outcomes)?;
+        Self::validate_market_duration_seconds(env, duration_seconds)?;
+        Ok(())
+    }
+
     /// Validate all shared event creation inputs.
     pub fn validate_event_creation(
         env: &Env,
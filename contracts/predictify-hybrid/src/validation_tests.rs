@@ -628,6 +628,7 @@ fn test_validate_comprehensive_inputs() {
         feed_id: String::from_str(&env, "BTC/USD"),
         threshold: 100000,
         comparison: String::from_str(&env, "gt"),
+        threshold2: None,
     };
 
     // Test question format
@@ -661,6 +662,7 @@ fn test_validate_market_creation() {
         feed_id: String::from_str(&env, "BTC/USD"),
         threshold: 100000,
         comparison: String::from_str(&env, "gt"),
+        threshold2: None,
     };
 
     // Test question format
@@ -2768,6 +2770,7 @@ fn test_market_validator_for_voting_empty_question() {
         feed_id: String::from_str(&env, "BTC/USD"),
         threshold: 1_000_00,
         comparison: String::from_str(&env, "gt"),
+        threshold2: None,
     };
     // Construct a market with an empty question — simulates "does not exist".
     let market = Market::new(
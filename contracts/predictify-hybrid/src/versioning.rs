@@ -681,6 +681,9 @@ impl VersionManager {
         history.upgrade_to_version(env, target_version)?;
         self.store_version_history(env, &history)?;
 
+        // Runs on the new build, so this is where a bumped event schema is announced.
+        crate::events::EventSchemaRegistry::sync_schema_version(env);
+
         Ok(())
     }
 
@@ -37,6 +37,12 @@ pub const FEE_PERCENTAGE: i128 = crate::config::DEFAULT_PLATFORM_FEE_PERCENTAGE;
 /// Dispute extension period in hours
 pub const DISPUTE_EXTENSION_HOURS: u32 = crate::config::DISPUTE_EXTENSION_HOURS;
 
+/// Width of one outcome-momentum bucket (1 hour)
+pub const MOMENTUM_BUCKET_SECONDS: u64 = 3600;
+
+/// Buckets retained per market; windows longer than this (24 hours) are truncated
+pub const MAX_MOMENTUM_BUCKETS: u32 = 24;
+
 // ===== VOTING STRUCTURES =====
 
 /// Represents a user's vote on a prediction market.
@@ -502,6 +508,304 @@ impl VotingManager {
     }
 }
 
+// ===== VOTE FREEZE =====
+
+const VOTE_FREEZE_KEY: Symbol = symbol_short!("vote_frz");
+
+/// Short freeze before `end_time` during which votes are rejected, so a
+/// last-moment vote cannot be placed with the oracle close already in view.
+///
+/// Unlike a market's voting cutoff (`bet_deadline`) this is contract-wide and
+/// capped at [`crate::config::MAX_VOTE_FREEZE_SECONDS`]. Disabled (0) by default.
+pub struct VoteFreeze;
+
+impl VoteFreeze {
+    /// Configured freeze length in seconds (0 if never set).
+    pub fn get_seconds(env: &Env) -> u64 {
+        env.storage()
+            .persistent()
+            .get(&VOTE_FREEZE_KEY)
+            .unwrap_or(0)
+    }
+
+    /// Set the freeze length (admin only). 0 disables it.
+    pub fn set_seconds(env: &Env, admin: &Address, seconds: u64) -> Result<(), Error> {
+        admin.require_auth();
+        crate::resolution::MarketResolutionValidator::validate_admin_permissions(env, admin)?;
+        if seconds > crate::config::MAX_VOTE_FREEZE_SECONDS {
+            return Err(Error::InvalidInput);
+        }
+        env.storage().persistent().set(&VOTE_FREEZE_KEY, &seconds);
+        Ok(())
+    }
+
+    /// Whether `market` is inside its pre-close freeze at the current ledger time.
+    pub fn is_frozen(env: &Env, market: &Market) -> bool {
+        let freeze = Self::get_seconds(env);
+        freeze > 0 && env.ledger().timestamp() >= market.end_time.saturating_sub(freeze)
+    }
+}
+
+// ===== OUTCOME STAKE CAP =====
+
+/// Enforces a market's `outcome_cap_bps`, the largest share of `total_staked`
+/// a single outcome may hold.
+///
+/// The first stake on a market is exempt, since any lone stake is the whole
+/// pool; from then on a vote is rejected if its outcome's total would exceed
+/// the cap of the new `total_staked`.
+pub struct OutcomeStakeCap;
+
+impl OutcomeStakeCap {
+    /// Fails with `Error::OutcomeCapExceeded` if staking `stake` on `outcome`
+    /// would breach the market's cap.
+    pub fn check(market: &Market, outcome: &String, stake: i128) -> Result<(), Error> {
+        let cap_bps = match market.outcome_cap_bps {
+            Some(cap_bps) => cap_bps,
+            None => return Ok(()),
+        };
+        if market.total_staked == 0 {
+            return Ok(());
+        }
+
+        let outcome_total = outcome_total_with(market, outcome, stake)?;
+        let new_total = market
+            .total_staked
+            .checked_add(stake)
+            .ok_or(Error::InvalidInput)?;
+        let outcome_bps = outcome_total
+            .checked_mul(10_000)
+            .ok_or(Error::InvalidInput)?;
+        let cap = new_total
+            .checked_mul(cap_bps as i128)
+            .ok_or(Error::InvalidInput)?;
+        if outcome_bps > cap {
+            return Err(Error::OutcomeCapExceeded);
+        }
+        Ok(())
+    }
+}
+
+/// Total stake on `outcome` once `stake` is added to it.
+fn outcome_total_with(market: &Market, outcome: &String, stake: i128) -> Result<i128, Error> {
+    let mut outcome_total = stake;
+    for (voter, voted_outcome) in market.votes.iter() {
+        if voted_outcome == *outcome {
+            outcome_total = outcome_total
+                .checked_add(market.stakes.get(voter).unwrap_or(0))
+                .ok_or(Error::InvalidInput)?;
+        }
+    }
+    Ok(outcome_total)
+}
+
+// ===== DUST STAKE GUARD =====
+
+const DUST_GUARD_KEY: Symbol = symbol_short!("dust_grd");
+
+/// Opt-in rejection of vote stakes too small to ever pay out.
+///
+/// While enabled, a vote is rejected if its claim would pay nothing even were
+/// its outcome to win against the current pool. The payout is quoted by
+/// [`crate::fees::WinnerPayouts`], the same math the claim uses, and on a
+/// settlement market it must still be non-zero once converted to the
+/// settlement token. Disabled by default.
+pub struct DustStakeGuard;
+
+impl DustStakeGuard {
+    /// Whether the guard is enabled.
+    pub fn is_enabled(env: &Env) -> bool {
+        env.storage()
+            .persistent()
+            .get(&DUST_GUARD_KEY)
+            .unwrap_or(false)
+    }
+
+    /// Turn the guard on or off (admin only).
+    pub fn set_enabled(env: &Env, admin: &Address, enabled: bool) -> Result<(), Error> {
+        admin.require_auth();
+        crate::resolution::MarketResolutionValidator::validate_admin_permissions(env, admin)?;
+        env.storage().persistent().set(&DUST_GUARD_KEY, &enabled);
+        Ok(())
+    }
+
+    /// Fails with `Error::StakeTooSmall` if `user` staking `stake` on `outcome`
+    /// would receive a zero payout should that outcome win.
+    pub fn check(
+        env: &Env,
+        market_id: &Symbol,
+        market: &Market,
+        user: &Address,
+        outcome: &String,
+        stake: i128,
+    ) -> Result<(), Error> {
+        if !Self::is_enabled(env) {
+            return Ok(());
+        }
+
+        let total_pool = market
+            .total_staked
+            .checked_add(stake)
+            .ok_or(Error::InvalidInput)?;
+        let quote = crate::fees::WinnerPayouts::quote(
+            env,
+            market_id,
+            market,
+            Some(user),
+            stake,
+            true,
+            (total_pool, outcome_total_with(market, outcome, stake)?),
+        )?;
+        // Settlement markets pay the converted amount, which floors on its own
+        let payout = match crate::markets::MarketUtils::settlement_payout(market, quote.payout)? {
+            Some((_, converted)) => converted,
+            None => quote.payout,
+        };
+        if payout <= 0 {
+            return Err(Error::StakeTooSmall);
+        }
+        Ok(())
+    }
+}
+
+// ===== VOTER BLACKLIST =====
+
+const VOTER_BLACKLIST_KEY: Symbol = symbol_short!("vote_bl");
+
+/// Contract-wide list of addresses barred from `vote` and `dispute_market`,
+/// for sybil or abusive actors.
+///
+/// The list is admin-managed and capped at
+/// [`crate::config::MAX_BLACKLISTED_ADDRESSES`] entries. It is a governance
+/// lever with no appeal path: a compromised or careless admin can silence
+/// honest voters and disputers, including those contesting the admin's own
+/// resolution. Existing votes and stakes are unaffected, as are claims.
+pub struct VoterBlacklist;
+
+impl VoterBlacklist {
+    /// Blacklisted addresses (empty if none).
+    pub fn get(env: &Env) -> Vec<Address> {
+        env.storage()
+            .persistent()
+            .get(&VOTER_BLACKLIST_KEY)
+            .unwrap_or(vec![env])
+    }
+
+    /// Whether `address` is blacklisted.
+    pub fn is_blacklisted(env: &Env, address: &Address) -> bool {
+        Self::get(env).contains(address)
+    }
+
+    /// Fails with `Error::AddressBlacklisted` if `address` is blacklisted.
+    pub fn check(env: &Env, address: &Address) -> Result<(), Error> {
+        if Self::is_blacklisted(env, address) {
+            return Err(Error::AddressBlacklisted);
+        }
+        Ok(())
+    }
+
+    /// Add `address` to the blacklist (admin only). Adding an address that is
+    /// already listed is a no-op.
+    pub fn add(env: &Env, admin: &Address, address: &Address) -> Result<(), Error> {
+        admin.require_auth();
+        crate::resolution::MarketResolutionValidator::validate_admin_permissions(env, admin)?;
+
+        let mut list = Self::get(env);
+        if list.contains(address) {
+            return Ok(());
+        }
+        if list.len() >= crate::config::MAX_BLACKLISTED_ADDRESSES {
+            return Err(Error::InvalidInput);
+        }
+        list.push_back(address.clone());
+        env.storage().persistent().set(&VOTER_BLACKLIST_KEY, &list);
+        Ok(())
+    }
+
+    /// Remove `address` from the blacklist (admin only). Removing an address
+    /// that is not listed is a no-op.
+    pub fn remove(env: &Env, admin: &Address, address: &Address) -> Result<(), Error> {
+        admin.require_auth();
+        crate::resolution::MarketResolutionValidator::validate_admin_permissions(env, admin)?;
+
+        let mut list = Self::get(env);
+        if let Some(index) = list.first_index_of(address) {
+            list.remove(index);
+            env.storage().persistent().set(&VOTER_BLACKLIST_KEY, &list);
+        }
+        Ok(())
+    }
+}
+
+// ===== OUTCOME MOMENTUM =====
+
+/// Tracks recent stake inflow per outcome in fixed-width time buckets.
+///
+/// Each market keeps at most [`MAX_MOMENTUM_BUCKETS`] buckets of
+/// [`MOMENTUM_BUCKET_SECONDS`]; the oldest bucket is dropped when a new one opens.
+pub struct OutcomeMomentum;
+
+impl OutcomeMomentum {
+    fn storage_key(market_id: &Symbol) -> (Symbol, Symbol) {
+        (symbol_short!("momentum"), market_id.clone())
+    }
+
+    /// Returns the retained buckets for a market, oldest first.
+    pub fn get_buckets(env: &Env, market_id: &Symbol) -> Vec<crate::types::MomentumBucket> {
+        env.storage()
+            .persistent()
+            .get(&Self::storage_key(market_id))
+            .unwrap_or_else(|| Vec::new(env))
+    }
+
+    /// Adds `delta` to `outcome`'s flow in the current bucket.
+    ///
+    /// Positive for new stake, negative for withdrawn stake.
+    pub fn record_flow(env: &Env, market_id: &Symbol, outcome: &String, delta: i128) {
+        let now = env.ledger().timestamp();
+        let start = now - now % MOMENTUM_BUCKET_SECONDS;
+        let mut buckets = Self::get_buckets(env, market_id);
+
+        let mut bucket = match buckets.last() {
+            Some(last) if last.start == start => buckets.pop_back_unchecked(),
+            _ => crate::types::MomentumBucket {
+                start,
+                flows: Map::new(env),
+            },
+        };
+        let current = bucket.flows.get(outcome.clone()).unwrap_or(0);
+        bucket
+            .flows
+            .set(outcome.clone(), current.saturating_add(delta));
+        buckets.push_back(bucket);
+
+        while buckets.len() > MAX_MOMENTUM_BUCKETS {
+            buckets.pop_front();
+        }
+        env.storage()
+            .persistent()
+            .set(&Self::storage_key(market_id), &buckets);
+    }
+
+    /// Sums per-outcome flows over buckets overlapping the last `window_seconds`.
+    ///
+    /// Resolution is one bucket: a bucket counts if any part of it falls in the window.
+    pub fn net_flows(env: &Env, market_id: &Symbol, window_seconds: u64) -> Map<String, i128> {
+        let since = env.ledger().timestamp().saturating_sub(window_seconds);
+        let mut totals = Map::new(env);
+        for bucket in Self::get_buckets(env, market_id).iter() {
+            if bucket.start + MOMENTUM_BUCKET_SECONDS <= since {
+                continue;
+            }
+            for (outcome, flow) in bucket.flows.iter() {
+                let current: i128 = totals.get(outcome.clone()).unwrap_or(0);
+                totals.set(outcome, current.saturating_add(flow));
+            }
+        }
+        totals
+    }
+}
+
 // ===== THRESHOLD UTILITIES =====
 
 /// Comprehensive threshold management utilities for dynamic dispute thresholds.
@@ -929,16 +1233,14 @@ impl VotingValidator {
             return Err(Error::InvalidState);
         }
 
-        // Respect bet_deadline: if set (non-zero), no votes after that time
+        // Votes stop at the voting cutoff (bet_deadline, or end_time when unset)
         let current_time = env.ledger().timestamp();
-        let cutoff = if market.bet_deadline > 0 {
-            market.bet_deadline
-        } else {
-            market.end_time
-        };
-        if current_time >= cutoff {
+        if current_time >= market.end_time {
             return Err(Error::MarketClosed);
         }
+        if current_time >= market.voting_end_time() || VoteFreeze::is_frozen(env, market) {
+            return Err(Error::VotingClosed);
+        }
 
         Ok(())
     }
@@ -1622,6 +1924,7 @@ pub mod testing {
 mod tests {
     use super::*;
 
+    use crate::oracles::ComparisonOp;
     use crate::types::{OracleConfig, OracleProvider};
     use soroban_sdk::{testutils::Address as _, vec};
 
@@ -1661,7 +1964,7 @@ mod tests {
                 Address::generate(&env),
                 String::from_str(&env, "BTC/USD"),
                 2500000,
-                String::from_str(&env, "gt"),
+                ComparisonOp::Gt,
             ),
             None,
             0,
@@ -1691,7 +1994,7 @@ mod tests {
                 Address::generate(&env),
                 String::from_str(&env, "BTC/USD"),
                 2500000,
-                String::from_str(&env, "gt"),
+                ComparisonOp::Gt,
             ),
             None,
             0,
@@ -1727,7 +2030,7 @@ mod tests {
                 Address::generate(&env),
                 String::from_str(&env, "BTC/USD"),
                 2500000,
-                String::from_str(&env, "gt"),
+                ComparisonOp::Gt,
             ),
             None,
             0,
@@ -1756,4 +2059,387 @@ mod tests {
         let stats = testing::create_test_voting_stats(&env);
         assert!(testing::validate_voting_stats(&stats).is_ok());
     }
+
+    #[test]
+    fn test_votes_rejected_between_cutoff_and_end_time() {
+        use soroban_sdk::testutils::Ledger;
+
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &None, &None);
+
+        let token_id = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        let early = Address::generate(&env);
+        let late = Address::generate(&env);
+        let token_admin = soroban_sdk::token::StellarAssetClient::new(&env, &token_id);
+        token_admin.mint(&early, &100_0000000);
+        token_admin.mint(&late, &100_0000000);
+
+        let market_id = Symbol::new(&env, "cutoff");
+        let end_time = env.ledger().timestamp() + 86400;
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "TokenID"), &token_id);
+            crate::rate_limiter::RateLimiter::init_for_tests(&env, &admin);
+
+            let mut market = Market::new(
+                &env,
+                admin.clone(),
+                String::from_str(&env, "Cutoff market"),
+                vec![
+                    &env,
+                    String::from_str(&env, "yes"),
+                    String::from_str(&env, "no"),
+                ],
+                end_time,
+                OracleConfig::new(
+                    OracleProvider::pyth(),
+                    Address::generate(&env),
+                    String::from_str(&env, "BTC/USD"),
+                    2500000,
+                    ComparisonOp::Gt,
+                ),
+                None,
+                0,
+                crate::types::MarketState::Active,
+            );
+            // Voting closes one hour before the market ends
+            market.bet_deadline = end_time - 3600;
+            env.storage().persistent().set(&market_id, &market);
+        });
+
+        let yes = String::from_str(&env, "yes");
+        client.vote(&early, &market_id, &yes, &10_0000000);
+
+        env.ledger().with_mut(|li| li.timestamp = end_time - 1800);
+        assert_eq!(
+            client.try_vote(&late, &market_id, &yes, &10_0000000),
+            Err(Ok(Error::VotingClosed))
+        );
+
+        let market = client.get_market(&market_id).unwrap();
+        assert_eq!(market.state, crate::types::MarketState::Active);
+        assert_eq!(market.voting_end_time(), end_time - 3600);
+        assert!(market.votes.get(late).is_none());
+    }
+
+    #[test]
+    fn test_votes_rejected_inside_pre_close_freeze() {
+        use soroban_sdk::testutils::Ledger;
+
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &None, &None);
+
+        let token_id = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        let outside = Address::generate(&env);
+        let inside = Address::generate(&env);
+        let token_admin = soroban_sdk::token::StellarAssetClient::new(&env, &token_id);
+        token_admin.mint(&outside, &100_0000000);
+        token_admin.mint(&inside, &100_0000000);
+
+        let market_id = Symbol::new(&env, "freeze");
+        let end_time = env.ledger().timestamp() + 86400;
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "TokenID"), &token_id);
+            crate::rate_limiter::RateLimiter::init_for_tests(&env, &admin);
+
+            let market = Market::new(
+                &env,
+                admin.clone(),
+                String::from_str(&env, "Freeze market"),
+                vec![
+                    &env,
+                    String::from_str(&env, "yes"),
+                    String::from_str(&env, "no"),
+                ],
+                end_time,
+                OracleConfig::new(
+                    OracleProvider::pyth(),
+                    Address::generate(&env),
+                    String::from_str(&env, "BTC/USD"),
+                    2500000,
+                    ComparisonOp::Gt,
+                ),
+                None,
+                0,
+                crate::types::MarketState::Active,
+            );
+            env.storage().persistent().set(&market_id, &market);
+        });
+
+        // Votes freeze for the final 5 minutes
+        client.set_vote_freeze_seconds(&admin, &300);
+        assert_eq!(
+            client.try_set_vote_freeze_seconds(
+                &admin,
+                &(crate::config::MAX_VOTE_FREEZE_SECONDS + 1)
+            ),
+            Err(Ok(Error::InvalidInput))
+        );
+
+        let yes = String::from_str(&env, "yes");
+        env.ledger().with_mut(|li| li.timestamp = end_time - 301);
+        client.vote(&outside, &market_id, &yes, &10_0000000);
+
+        env.ledger().with_mut(|li| li.timestamp = end_time - 300);
+        assert_eq!(
+            client.try_vote(&inside, &market_id, &yes, &10_0000000),
+            Err(Ok(Error::VotingClosed))
+        );
+
+        let market = client.get_market(&market_id).unwrap();
+        assert_eq!(market.state, crate::types::MarketState::Active);
+        assert!(market.votes.get(outside).is_some());
+        assert!(market.votes.get(inside).is_none());
+    }
+
+    #[test]
+    fn test_outcome_cap_rejects_votes_past_the_cap() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &None, &None);
+
+        let token_id = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        let token_admin = soroban_sdk::token::StellarAssetClient::new(&env, &token_id);
+        let voters: [Address; 5] = core::array::from_fn(|_| {
+            let voter = Address::generate(&env);
+            token_admin.mint(&voter, &1000_0000000);
+            voter
+        });
+
+        let market_id = Symbol::new(&env, "capped");
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "TokenID"), &token_id);
+            crate::rate_limiter::RateLimiter::init_for_tests(&env, &admin);
+
+            let market = Market::new(
+                &env,
+                admin.clone(),
+                String::from_str(&env, "Capped market"),
+                vec![
+                    &env,
+                    String::from_str(&env, "yes"),
+                    String::from_str(&env, "no"),
+                ],
+                env.ledger().timestamp() + 86400,
+                OracleConfig::new(
+                    OracleProvider::pyth(),
+                    Address::generate(&env),
+                    String::from_str(&env, "BTC/USD"),
+                    2500000,
+                    ComparisonOp::Gt,
+                ),
+                None,
+                0,
+                crate::types::MarketState::Active,
+            );
+            env.storage().persistent().set(&market_id, &market);
+        });
+
+        assert_eq!(
+            client.try_set_market_outcome_cap(&admin, &market_id, &Some(10_001)),
+            Err(Ok(Error::InvalidInput))
+        );
+        // No outcome may hold more than 60% of the pool
+        client.set_market_outcome_cap(&admin, &market_id, &Some(6_000));
+
+        let yes = String::from_str(&env, "yes");
+        let no = String::from_str(&env, "no");
+        client.vote(&voters[0], &market_id, &yes, &100_0000000);
+        client.vote(&voters[1], &market_id, &no, &100_0000000);
+        // yes reaches exactly 60% (150 of 250)
+        client.vote(&voters[2], &market_id, &yes, &50_0000000);
+
+        assert_eq!(
+            client.try_vote(&voters[3], &market_id, &yes, &10_0000000),
+            Err(Ok(Error::OutcomeCapExceeded))
+        );
+        client.vote(&voters[4], &market_id, &no, &10_0000000);
+
+        let market = client.get_market(&market_id).unwrap();
+        assert!(market.votes.get(voters[3].clone()).is_none());
+        assert_eq!(market.total_staked, 260_0000000);
+
+        // The cap is frozen once stake exists
+        for cap in [Some(9_000), None] {
+            assert_eq!(
+                client.try_set_market_outcome_cap(&admin, &market_id, &cap),
+                Err(Ok(Error::InvalidState))
+            );
+        }
+        assert_eq!(client.get_market(&market_id).unwrap().outcome_cap_bps, Some(6_000));
+    }
+
+    #[test]
+    fn test_dust_stake_guard_rejects_zero_payout_votes_when_enabled() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &None, &None);
+
+        let token_id = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        let token_admin = soroban_sdk::token::StellarAssetClient::new(&env, &token_id);
+        let voters: [Address; 4] = core::array::from_fn(|_| {
+            let voter = Address::generate(&env);
+            token_admin.mint(&voter, &1000_0000000);
+            voter
+        });
+
+        let market_id = Symbol::new(&env, "dusty");
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "TokenID"), &token_id);
+            let mut cfg = crate::config::ConfigManager::get_development_config(&env);
+            cfg.fees.platform_fee_percentage = 2;
+            crate::config::ConfigManager::store_config(&env, &cfg).unwrap();
+            crate::rate_limiter::RateLimiter::init_for_tests(&env, &admin);
+
+            let market = Market::new(
+                &env,
+                admin.clone(),
+                String::from_str(&env, "Dusty market"),
+                vec![
+                    &env,
+                    String::from_str(&env, "yes"),
+                    String::from_str(&env, "no"),
+                ],
+                env.ledger().timestamp() + 86400,
+                OracleConfig::new(
+                    OracleProvider::pyth(),
+                    Address::generate(&env),
+                    String::from_str(&env, "BTC/USD"),
+                    2500000,
+                    ComparisonOp::Gt,
+                ),
+                None,
+                0,
+                crate::types::MarketState::Active,
+            );
+            env.storage().persistent().set(&market_id, &market);
+        });
+
+        let yes = String::from_str(&env, "yes");
+        let no = String::from_str(&env, "no");
+        client.vote(&voters[0], &market_id, &yes, &100_0000000);
+
+        // Disabled by default: a 1-stroop stake is accepted
+        client.vote(&voters[1], &market_id, &no, &1);
+
+        assert_eq!(
+            client.try_set_dust_stake_guard(&voters[0], &true),
+            Err(Ok(Error::Unauthorized))
+        );
+        client.set_dust_stake_guard(&admin, &true);
+
+        // 1 * 98 / 100 floors to zero before the pool is even considered
+        assert_eq!(
+            client.try_vote(&voters[2], &market_id, &no, &1),
+            Err(Ok(Error::StakeTooSmall))
+        );
+        client.vote(&voters[3], &market_id, &no, &10);
+
+        let market = client.get_market(&market_id).unwrap();
+        assert!(market.votes.get(voters[2].clone()).is_none());
+        assert_eq!(market.total_staked, 100_0000000 + 1 + 10);
+    }
+
+    #[test]
+    fn test_dust_stake_guard_checks_the_converted_settlement_payout() {
+        let env = Env::default();
+        env.mock_all_auths();
+        let contract_id = env.register(crate::PredictifyHybrid, ());
+        let client = crate::PredictifyHybridClient::new(&env, &contract_id);
+        let admin = Address::generate(&env);
+        client.initialize(&admin, &None, &None);
+
+        let token_id = env
+            .register_stellar_asset_contract_v2(Address::generate(&env))
+            .address();
+        let token_admin = soroban_sdk::token::StellarAssetClient::new(&env, &token_id);
+        let voters: [Address; 3] = core::array::from_fn(|_| {
+            let voter = Address::generate(&env);
+            token_admin.mint(&voter, &1000_0000000);
+            voter
+        });
+
+        let market_id = Symbol::new(&env, "dusty");
+        env.as_contract(&contract_id, || {
+            env.storage()
+                .persistent()
+                .set(&Symbol::new(&env, "TokenID"), &token_id);
+            let mut cfg = crate::config::ConfigManager::get_development_config(&env);
+            cfg.fees.platform_fee_percentage = 2;
+            crate::config::ConfigManager::store_config(&env, &cfg).unwrap();
+            crate::rate_limiter::RateLimiter::init_for_tests(&env, &admin);
+
+            let mut market = Market::new(
+                &env,
+                admin.clone(),
+                String::from_str(&env, "Dusty settlement market"),
+                vec![
+                    &env,
+                    String::from_str(&env, "yes"),
+                    String::from_str(&env, "no"),
+                ],
+                env.ledger().timestamp() + 86400,
+                OracleConfig::new(
+                    OracleProvider::pyth(),
+                    Address::generate(&env),
+                    String::from_str(&env, "BTC/USD"),
+                    2500000,
+                    ComparisonOp::Gt,
+                ),
+                None,
+                0,
+                crate::types::MarketState::Active,
+            );
+            // One settlement unit per 10_000 staking units
+            market.settlement_token = Some(Address::generate(&env));
+            market.settlement_rate_bps = 1;
+            env.storage().persistent().set(&market_id, &market);
+        });
+
+        let yes = String::from_str(&env, "yes");
+        client.vote(&voters[0], &market_id, &yes, &100_0000000);
+        client.set_dust_stake_guard(&admin, &true);
+
+        // A 100 stake on the favourite would be paid 98 staking units, which
+        // convert to 0 settlement units
+        assert_eq!(
+            client.try_vote(&voters[1], &market_id, &yes, &100),
+            Err(Ok(Error::StakeTooSmall))
+        );
+        // 980_000 staking units convert to 98
+        client.vote(&voters[2], &market_id, &yes, &1_000_000);
+
+        let market = client.get_market(&market_id).unwrap();
+        assert!(market.votes.get(voters[1].clone()).is_none());
+        assert_eq!(market.total_staked, 100_0000000 + 1_000_000);
+    }
 }
@@ -135,7 +135,7 @@ fn test_vote_rejected_on_resolved_market() {
     let user = s.user();
     let mid = s.create_market(1);
     s.env.ledger().with_mut(|li| li.timestamp += 2 * 86400);
-    client.resolve_market_manual(&s.admin, &mid, &String::from_str(&s.env, "Yes"));
+    client.resolve_market_manual(&s.admin, &mid, &String::from_str(&s.env, "Yes"), &None);
     assert!(
         client
             .try_vote(&user, &mid, &String::from_str(&s.env, "Yes"), &1_000_000i128)
@@ -173,7 +173,7 @@ fn test_payout_proportional_to_stake() {
     client.vote(&user2, &mid, &String::from_str(&s.env, "Yes"), &1_000_000i128);
 
     s.env.ledger().with_mut(|li| li.timestamp += 2 * 86400);
-    client.resolve_market_manual(&s.admin, &mid, &String::from_str(&s.env, "Yes"));
+    client.resolve_market_manual(&s.admin, &mid, &String::from_str(&s.env, "Yes"), &None);
     s.env.ledger().with_mut(|li| li.timestamp += 25 * 3600);
 
     // resolve_market_manual auto-distributes, so just read claimed amounts
@@ -197,7 +197,7 @@ fn test_losing_voter_gets_zero_payout() {
     client.vote(&loser, &mid, &String::from_str(&s.env, "No"), &1_000_000i128);
 
     s.env.ledger().with_mut(|li| li.timestamp += 2 * 86400);
-    client.resolve_market_manual(&s.admin, &mid, &String::from_str(&s.env, "Yes"));
+    client.resolve_market_manual(&s.admin, &mid, &String::from_str(&s.env, "Yes"), &None);
     s.env.ledger().with_mut(|li| li.timestamp += 25 * 3600);
 
     // resolve_market_manual auto-distributes, loser gets 0
@@ -215,7 +215,7 @@ fn test_claim_blocked_during_dispute_window() {
 
     client.vote(&user, &mid, &String::from_str(&s.env, "Yes"), &1_000_000i128);
     s.env.ledger().with_mut(|li| li.timestamp += 2 * 86400);
-    client.resolve_market_manual(&s.admin, &mid, &String::from_str(&s.env, "Yes"));
+    client.resolve_market_manual(&s.admin, &mid, &String::from_str(&s.env, "Yes"), &None);
 
     // resolve_market_manual auto-distributes, but let's test manual claim is blocked
     // Actually, since auto-distribute already claimed, this will fail with AlreadyClaimed
@@ -234,7 +234,7 @@ fn test_claim_allowed_after_dispute_window() {
 
     client.vote(&user, &mid, &String::from_str(&s.env, "Yes"), &1_000_000i128);
     s.env.ledger().with_mut(|li| li.timestamp += 2 * 86400);
-    client.resolve_market_manual(&s.admin, &mid, &String::from_str(&s.env, "Yes"));
+    client.resolve_market_manual(&s.admin, &mid, &String::from_str(&s.env, "Yes"), &None);
     s.env.ledger().with_mut(|li| li.timestamp += 25 * 3600);
 
     // resolve_market_manual auto-distributes after dispute window
@@ -250,7 +250,7 @@ fn test_double_claim_rejected() {
 
     client.vote(&user, &mid, &String::from_str(&s.env, "Yes"), &1_000_000i128);
     s.env.ledger().with_mut(|li| li.timestamp += 2 * 86400);
-    client.resolve_market_manual(&s.admin, &mid, &String::from_str(&s.env, "Yes"));
+    client.resolve_market_manual(&s.admin, &mid, &String::from_str(&s.env, "Yes"), &None);
     s.env.ledger().with_mut(|li| li.timestamp += 25 * 3600);
 
     // resolve_market_manual auto-distributes, so second claim must fail
@@ -386,7 +386,7 @@ fn test_validate_market_for_voting_respects_bet_deadline() {
         market.bet_deadline = now - 1; // already past
         assert_eq!(
             VotingValidator::validate_market_for_voting(&env, &market),
-            Err(Error::MarketClosed)
+            Err(Error::VotingClosed)
         );
     });
 }
@@ -413,7 +413,7 @@ fn test_dispute_stake_cap_enforcement() {
 
     // Resolve the market so we can dispute it
     s.env.ledger().with_mut(|l| l.timestamp += 8 * 24 * 60 * 60); // Past end time
-    client.resolve_market_manual(&s.admin, &mid, &String::from_str(&s.env, "Yes"));
+    client.resolve_market_manual(&s.admin, &mid, &String::from_str(&s.env, "Yes"), &None);
 
     // Attempting to dispute with stake above the cap (e.g., 20_000_000) should fail
     let err = client.try_dispute_market(&user, &mid, &20_000_000i128, &None).unwrap_err();
@@ -432,7 +432,7 @@ fn test_dispute_stake_cap_disabled_by_default() {
 
     // Resolve market
     s.env.ledger().with_mut(|l| l.timestamp += 8 * 24 * 60 * 60);
-    client.resolve_market_manual(&s.admin, &mid, &String::from_str(&s.env, "Yes"));
+    client.resolve_market_manual(&s.admin, &mid, &String::from_str(&s.env, "Yes"), &None);
 
     // By default cap = 0, so large stakes should succeed (e.g., 50_000_000)
     assert!(client.try_dispute_market(&user, &mid, &50_000_000i128, &None).is_ok());
@@ -34,7 +34,7 @@ fn user_errors() {
     assert_eq!(Error::InsufficientBalance as u32, 112);
 }
 
-// ===== Oracle Errors (200-214) =====
+// ===== Oracle Errors (200-216) =====
 
 #[test]
 fn oracle_errors() {
@@ -53,6 +53,8 @@ fn oracle_errors() {
     assert_eq!(Error::OracleCallbackInvalidSignature as u32, 212);
     assert_eq!(Error::OracleCallbackReplayDetected as u32, 213);
     assert_eq!(Error::OracleCallbackTimeout as u32, 214);
+    assert_eq!(Error::OracleCallFailed as u32, 215);
+    assert_eq!(Error::OracleNoData as u32, 216);
 }
 
 // ===== Validation Errors (300-304) =====
@@ -126,6 +128,85 @@ fn circuit_breaker_errors() {
     assert_eq!(Error::FeeExceedsMax as u32, 508);
 }
 
+// ===== Initialization Errors (540) =====
+
+#[test]
+fn initialization_errors() {
+    assert_eq!(Error::AlreadyInitialized as u32, 540);
+}
+
+// ===== Market Creation Errors (541) =====
+
+#[test]
+fn market_creation_errors() {
+    assert_eq!(Error::ReservedOutcome as u32, 541);
+}
+
+// ===== Voting Errors (542-543) =====
+
+#[test]
+fn voting_errors() {
+    assert_eq!(Error::OutcomeCapExceeded as u32, 542);
+    assert_eq!(Error::StakeTooSmall as u32, 543);
+}
+
+// ===== Resolution Errors (544, 546) =====
+
+#[test]
+fn resolution_errors() {
+    assert_eq!(Error::ResolutionCorrectionClosed as u32, 544);
+    assert_eq!(Error::EvidenceTooLong as u32, 546);
+}
+
+// ===== Accounting Errors (545) =====
+
+#[test]
+fn accounting_errors() {
+    assert_eq!(Error::ArithmeticUnderflow as u32, 545);
+}
+
+// ===== Settlement Errors (547) =====
+
+#[test]
+fn settlement_errors() {
+    assert_eq!(Error::InvalidSettlementToken as u32, 547);
+}
+
+// ===== Dispute Stake Errors (548) =====
+
+#[test]
+fn dispute_stake_errors() {
+    assert_eq!(Error::DisputeStakeTooLarge as u32, 548);
+}
+
+// ===== Access Errors (549) =====
+
+#[test]
+fn access_errors() {
+    assert_eq!(Error::AddressBlacklisted as u32, 549);
+}
+
+// ===== Token Configuration Errors (550) =====
+
+#[test]
+fn token_configuration_errors() {
+    assert_eq!(Error::TokenNotConfigured as u32, 550);
+}
+
+// ===== Conditional Market Errors (551) =====
+
+#[test]
+fn conditional_market_errors() {
+    assert_eq!(Error::ParentMarketUnresolved as u32, 551);
+}
+
+// ===== Fee Bounds Errors (552) =====
+
+#[test]
+fn fee_bounds_errors() {
+    assert_eq!(Error::FeeOutOfBounds as u32, 552);
+}
+
 // ===== Asset decimals =====
 
 #[test]